@@ -0,0 +1,239 @@
+//! engine benchmarks
+//!
+//! times every stage of the pic cycle of the electrostatic engine on cubic grids of increasing size, all problems
+//! are seeded so that every run loads the same macro-particles, and deterministic charge deposition on a large grid
+//! on an increasing number of threads
+
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use picrs::config::Config;
+use picrs::constants::{ELEC_CHARGE, ELEC_MASS, EV_TEMP};
+use picrs::engine::{Electrostatic, Engine};
+use picrs::parallel::ParallelConfig;
+use picrs::species::Species;
+use std::hint::black_box;
+
+/// number of nodes along every axis of benchmarked grids
+const NODES: [usize; 3] = [17, 33, 65];
+
+/// number of nodes along every axis of the grid of the deposition scaling benchmark
+const LARGE_NODES: usize = 97;
+
+/// number of threads of the deposition scaling benchmark
+const THREADS: [usize; 4] = [1, 2, 4, 8];
+
+/// (m) length of every side of the bounding box
+const LENGTH: f64 = 0.01;
+
+/// (m^-3) electron number density
+const DENSITY: f64 = 1e12;
+
+/// number of macro-particles loaded per node
+const PARTICLES_PER_NODE: f64 = 4.0;
+
+/// (s) time step
+const DT: f64 = 1e-12;
+
+/// sets up an electrostatic engine on a cubic grid with a single electron species and its charge density deposited
+///
+/// # Arguments
+/// - `nodes`: usize number of nodes along every axis
+///
+/// # Returns
+/// `Electrostatic` engine with a zero potential and electric field
+///
+/// # Errors
+///
+fn setup(nodes: usize) -> Electrostatic {
+    let mut config = Config::new(&[LENGTH; 3], &[nodes; 3], DT).unwrap();
+    let weight = DENSITY * LENGTH.powi(3) / (PARTICLES_PER_NODE * nodes.pow(3) as f64);
+    config.species.push(
+        Species::new(
+            "electron",
+            -ELEC_CHARGE,
+            ELEC_MASS,
+            DENSITY,
+            EV_TEMP,
+            weight,
+        )
+        .unwrap(),
+    );
+
+    let mut electrostatic: Electrostatic = Electrostatic::new(&config).unwrap();
+    electrostatic.deposit_charge().unwrap();
+    electrostatic
+}
+
+/// sets up an electrostatic engine as `setup()` with its potential and electric field solved once
+///
+/// # Arguments
+/// - `nodes`: usize number of nodes along every axis
+///
+/// # Returns
+/// `Electrostatic` engine ready to push macro-particles
+///
+/// # Errors
+///
+fn solved(nodes: usize) -> Electrostatic {
+    let mut electrostatic = setup(nodes);
+    electrostatic.update_potential().unwrap();
+    electrostatic.solve_electric_field().unwrap();
+    electrostatic
+}
+
+/// benchmarks charge deposition, which overwrites the charge density so may be repeated on a single engine
+///
+/// # Arguments
+/// - `c`: &mut Criterion benchmark manager
+///
+/// # Returns
+///
+/// # Errors
+///
+fn deposit_charge(c: &mut Criterion) {
+    let mut group = c.benchmark_group("deposit_charge");
+    for nodes in NODES {
+        let mut electrostatic = setup(nodes);
+        group.bench_function(BenchmarkId::from_parameter(nodes), |b| {
+            b.iter(|| black_box(&mut electrostatic).deposit_charge().unwrap())
+        });
+    }
+    group.finish();
+}
+
+/// benchmarks deterministic charge deposition on a large grid on an increasing number of threads, where the number of
+/// macro-particles per node is too small for blocks sized by the number of nodes to keep every thread busy
+///
+/// # Arguments
+/// - `c`: &mut Criterion benchmark manager
+///
+/// # Returns
+///
+/// # Errors
+///
+fn deposit_charge_threads(c: &mut Criterion) {
+    let mut group = c.benchmark_group(format!("deposit_charge_threads/{LARGE_NODES}"));
+    group.sample_size(10);
+    let mut electrostatic = setup(LARGE_NODES);
+    for threads in THREADS {
+        let pool = ParallelConfig {
+            threads,
+            deterministic: true,
+        }
+        .thread_pool()
+        .unwrap();
+        group.bench_function(BenchmarkId::from_parameter(threads), |b| {
+            b.iter(|| pool.install(|| black_box(&mut electrostatic).deposit_charge().unwrap()))
+        });
+    }
+    group.finish();
+}
+
+/// benchmarks the gauss-seidel sor solve of the potential from a zero initial guess
+///
+/// # Arguments
+/// - `c`: &mut Criterion benchmark manager
+///
+/// # Returns
+///
+/// # Errors
+///
+fn update_potential(c: &mut Criterion) {
+    let mut group = c.benchmark_group("update_potential");
+    group.sample_size(10);
+    for nodes in NODES {
+        let electrostatic = setup(nodes);
+        group.bench_function(BenchmarkId::from_parameter(nodes), |b| {
+            b.iter_batched_ref(
+                || electrostatic.clone(),
+                |electrostatic| electrostatic.update_potential().unwrap(),
+                BatchSize::LargeInput,
+            )
+        });
+    }
+    group.finish();
+}
+
+/// benchmarks the electric field solve, which overwrites the electric field so may be repeated on a single engine
+///
+/// # Arguments
+/// - `c`: &mut Criterion benchmark manager
+///
+/// # Returns
+///
+/// # Errors
+///
+fn solve_electric_field(c: &mut Criterion) {
+    let mut group = c.benchmark_group("solve_electric_field");
+    for nodes in NODES {
+        let mut electrostatic = solved(nodes);
+        group.bench_function(BenchmarkId::from_parameter(nodes), |b| {
+            b.iter(|| {
+                black_box(&mut electrostatic)
+                    .solve_electric_field()
+                    .unwrap()
+            })
+        });
+    }
+    group.finish();
+}
+
+/// benchmarks gathering the electric field at and pushing every macro-particle, each push starts from the same
+/// macro-particles so that absorption at walls does not change the workload
+///
+/// # Arguments
+/// - `c`: &mut Criterion benchmark manager
+///
+/// # Returns
+///
+/// # Errors
+///
+fn push_particles(c: &mut Criterion) {
+    let mut group = c.benchmark_group("push_particles");
+    for nodes in NODES {
+        let electrostatic = solved(nodes);
+        group.bench_function(BenchmarkId::from_parameter(nodes), |b| {
+            b.iter_batched_ref(
+                || electrostatic.clone(),
+                |electrostatic| electrostatic.push_particles().unwrap(),
+                BatchSize::LargeInput,
+            )
+        });
+    }
+    group.finish();
+}
+
+/// benchmarks a complete time step
+///
+/// # Arguments
+/// - `c`: &mut Criterion benchmark manager
+///
+/// # Returns
+///
+/// # Errors
+///
+fn update(c: &mut Criterion) {
+    let mut group = c.benchmark_group("update");
+    group.sample_size(10);
+    for nodes in NODES {
+        let electrostatic = solved(nodes);
+        group.bench_function(BenchmarkId::from_parameter(nodes), |b| {
+            b.iter_batched_ref(
+                || electrostatic.clone(),
+                |electrostatic| electrostatic.update().unwrap(),
+                BatchSize::LargeInput,
+            )
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    deposit_charge,
+    deposit_charge_threads,
+    update_potential,
+    solve_electric_field,
+    push_particles,
+    update
+);
+criterion_main!(benches);
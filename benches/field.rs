@@ -0,0 +1,125 @@
+//! field benchmarks
+//!
+//! times arithmetic, reductions, and differential operators of fields on cubic grids of increasing size
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use picrs::field::differential::{BoundaryStencil, Differential};
+use picrs::field::scalar::ScalarField;
+use picrs::grid::{Axis, Spacing};
+use picrs::utils::coordinate_triplet::CoordinateTriplet;
+use std::hint::black_box;
+
+/// number of nodes along every axis of benchmarked grids
+const NODES: [usize; 4] = [17, 33, 65, 129];
+
+/// sets up a scalar field on a cubic grid with reproducible values
+///
+/// # Arguments
+/// - `nodes`: usize number of nodes along every axis
+/// - `phase`: f64 phase of values so that distinct fields may be set up
+///
+/// # Returns
+/// `ScalarField<f64>`
+///
+/// # Errors
+///
+fn setup(nodes: usize, phase: f64) -> ScalarField<f64> {
+    let cells = CoordinateTriplet::new(nodes, nodes, nodes).unwrap();
+    let mut field: ScalarField<f64> = ScalarField::new(&cells).unwrap();
+    for i in 0..nodes {
+        for j in 0..nodes {
+            for k in 0..nodes {
+                let (x, y, z) = (i as f64, j as f64, k as f64);
+                field[(i, j, k)] = 2.0 + (0.3 * x - 0.5 * y + 0.7 * z + phase).sin();
+            }
+        }
+    }
+    field
+}
+
+/// benchmarks elementwise arithmetic of scalar fields
+///
+/// # Arguments
+/// - `c`: &mut Criterion benchmark manager
+///
+/// # Returns
+///
+/// # Errors
+///
+fn arithmetic(c: &mut Criterion) {
+    let mut group = c.benchmark_group("arithmetic");
+    for nodes in NODES {
+        let mut lhs = setup(nodes, 0.0);
+        let rhs = setup(nodes, 1.0);
+        group.bench_function(BenchmarkId::new("add_assign", nodes), |b| {
+            b.iter(|| *black_box(&mut lhs) += &rhs)
+        });
+        group.bench_function(BenchmarkId::new("mul_assign_scalar", nodes), |b| {
+            b.iter(|| *black_box(&mut lhs) *= 1.0)
+        });
+        group.bench_function(BenchmarkId::new("axpy", nodes), |b| {
+            b.iter(|| black_box(&mut lhs).axpy(0.0, &rhs))
+        });
+        group.bench_function(BenchmarkId::new("mul", nodes), |b| {
+            b.iter(|| black_box(&lhs) * &rhs)
+        });
+    }
+    group.finish();
+}
+
+/// benchmarks reductions of scalar fields
+///
+/// # Arguments
+/// - `c`: &mut Criterion benchmark manager
+///
+/// # Returns
+///
+/// # Errors
+///
+fn reductions(c: &mut Criterion) {
+    let mut group = c.benchmark_group("reductions");
+    for nodes in NODES {
+        let field = setup(nodes, 0.0);
+        group.bench_function(BenchmarkId::new("sum", nodes), |b| {
+            b.iter(|| black_box(&field).sum())
+        });
+        group.bench_function(BenchmarkId::new("norm_l2", nodes), |b| {
+            b.iter(|| black_box(&field).norm_l2())
+        });
+        group.bench_function(BenchmarkId::new("statistics", nodes), |b| {
+            b.iter(|| black_box(&field).statistics())
+        });
+    }
+    group.finish();
+}
+
+/// benchmarks differential operators on uniformly spaced nodes
+///
+/// # Arguments
+/// - `c`: &mut Criterion benchmark manager
+///
+/// # Returns
+///
+/// # Errors
+///
+fn differential(c: &mut Criterion) {
+    let mut group = c.benchmark_group("differential");
+    for nodes in NODES {
+        let field = setup(nodes, 0.0);
+        let axis = Axis::new(&Spacing::Uniform, 0.01, nodes).unwrap();
+        let boundary = [BoundaryStencil::OneSided; 2];
+        let boundary = CoordinateTriplet::new(boundary, boundary, boundary).unwrap();
+        let x = axis.nodes();
+        let differential = Differential::new(x, x, x, &boundary).unwrap();
+        group.bench_function(BenchmarkId::new("gradient", nodes), |b| {
+            b.iter(|| differential.gradient(black_box(&field)).unwrap())
+        });
+        group.bench_function(BenchmarkId::new("laplacian", nodes), |b| {
+            b.iter(|| differential.laplacian(black_box(&field)).unwrap())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, arithmetic, reductions, differential);
+criterion_main!(benches);
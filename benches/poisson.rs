@@ -0,0 +1,131 @@
+//! poisson benchmarks
+//!
+//! compares the indexed, row, and red-black plane gauss-seidel sor kernels of the electrostatic engine
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use picrs::engine::poisson::Poisson;
+use picrs::field::scalar::ScalarField;
+use picrs::grid::{Axis, Spacing};
+use picrs::precision::Real;
+use picrs::utils::coordinate_triplet::CoordinateTriplet;
+use std::hint::black_box;
+
+/// number of nodes along every axis of benchmarked grids
+const NODES: [usize; 3] = [17, 33, 65];
+
+/// sor acceleration constant of benchmarked sweeps
+const SOR_ACC: f64 = 1.4;
+
+/// sets up coefficients, potential, and charge density of a cubic grid with a reproducible charge density
+///
+/// # Arguments
+/// - `nodes`: usize number of nodes along every axis
+///
+/// # Returns
+/// `(Poisson<F>, ScalarField<F>, ScalarField<F>)` coefficients, potential, and charge density
+///
+/// # Errors
+///
+fn setup<F: Real>(nodes: usize) -> (Poisson<F>, ScalarField<F>, ScalarField<F>) {
+    let axis = Axis::new(&Spacing::Uniform, 0.01, nodes).unwrap();
+    let laplacian =
+        CoordinateTriplet::new(axis.laplacian(), axis.laplacian(), axis.laplacian()).unwrap();
+    let poisson = Poisson::new(&laplacian).unwrap();
+
+    let cells = CoordinateTriplet::new(nodes, nodes, nodes).unwrap();
+    let potential: ScalarField<F> = ScalarField::new(&cells).unwrap();
+    let mut charge_density: ScalarField<F> = ScalarField::new(&cells).unwrap();
+    for i in 0..nodes {
+        for j in 0..nodes {
+            for k in 0..nodes {
+                let (x, y, z) = (i as f64, j as f64, k as f64);
+                charge_density[(i, j, k)] = F::narrow(1e-6 * (0.3 * x + 0.7 * y - 0.2 * z).sin());
+            }
+        }
+    }
+
+    (poisson, potential, charge_density)
+}
+
+/// benchmarks a single sweep of both kernels in precision `F`
+///
+/// # Arguments
+/// - `c`: &mut Criterion benchmark manager
+/// - `name`: &str name of benchmark group
+///
+/// # Returns
+///
+/// # Errors
+///
+fn sweep<F: Real>(c: &mut Criterion, name: &str) {
+    let mut group = c.benchmark_group(name);
+    for nodes in NODES {
+        let (poisson, mut potential, charge_density) = setup::<F>(nodes);
+        let sor_acc = F::narrow(SOR_ACC);
+        group.bench_function(BenchmarkId::new("indexed", nodes), |b| {
+            b.iter(|| {
+                poisson
+                    .sweep_indexed(black_box(&mut potential), &charge_density, sor_acc)
+                    .unwrap()
+            })
+        });
+        group.bench_function(BenchmarkId::new("rows", nodes), |b| {
+            b.iter(|| {
+                poisson
+                    .sweep_rows(black_box(&mut potential), &charge_density, sor_acc)
+                    .unwrap()
+            })
+        });
+        group.bench_function(BenchmarkId::new("planes", nodes), |b| {
+            b.iter(|| {
+                poisson
+                    .sweep_planes(black_box(&mut potential), &charge_density, sor_acc)
+                    .unwrap()
+            })
+        });
+    }
+    group.finish();
+}
+
+/// benchmarks residue accumulation of both kernels in double precision
+///
+/// # Arguments
+/// - `c`: &mut Criterion benchmark manager
+///
+/// # Returns
+///
+/// # Errors
+///
+fn residue(c: &mut Criterion) {
+    let mut group = c.benchmark_group("residue");
+    for nodes in NODES {
+        let (poisson, potential, charge_density) = setup::<f64>(nodes);
+        group.bench_function(BenchmarkId::new("indexed", nodes), |b| {
+            b.iter(|| poisson.residue_indexed(black_box(&potential), &charge_density))
+        });
+        group.bench_function(BenchmarkId::new("rows", nodes), |b| {
+            b.iter(|| poisson.residue_rows(black_box(&potential), &charge_density))
+        });
+        group.bench_function(BenchmarkId::new("planes", nodes), |b| {
+            b.iter(|| poisson.residue_planes(black_box(&potential), &charge_density))
+        });
+    }
+    group.finish();
+}
+
+/// benchmarks sweeps in double and single precision
+///
+/// # Arguments
+/// - `c`: &mut Criterion benchmark manager
+///
+/// # Returns
+///
+/// # Errors
+///
+fn sweeps(c: &mut Criterion) {
+    sweep::<f64>(c, "sweep_f64");
+    sweep::<f32>(c, "sweep_f32");
+}
+
+criterion_group!(benches, sweeps, residue);
+criterion_main!(benches);
@@ -0,0 +1,1042 @@
+//! checkpoint module
+//!
+//! describes the versioned binary checkpoint format used to restart picrs models
+
+use crate::collisions::coulomb::CoulombConfig;
+use crate::collisions::cross_section::Process;
+use crate::collisions::dsmc::{DsmcConfig, Molecule};
+use crate::collisions::mcc::{BackgroundGas, GasDensity, MccConfig, Reaction};
+use crate::config::{Config, EngineKind};
+use crate::emission::{EmissionConfig, EmissionEnergy, EmissionRule, Surface, YieldModel};
+use crate::engine::cylindrical::{AxialBoundary, CylindricalConfig};
+use crate::engine::Engine;
+use crate::grid::{GridConfig, Spacing};
+use crate::magnetostatics::{MagnetostaticConfig, Source};
+use crate::parallel::ParallelConfig;
+use crate::precision::{Precision, Real};
+use crate::species::{Particle, Species};
+use crate::utils::coordinate_triplet::CoordinateTriplet;
+use crate::utils::rng::Rng;
+use anyhow::{anyhow, Context};
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+
+/// identifies a file as a picrs checkpoint
+const MAGIC: &[u8; 8] = b"PICRSCKP";
+
+/// checkpoint format version, increment whenever the layout of a checkpoint or the serialization hashed by
+/// `config_hash()` changes
+const VERSION: u32 = 2;
+
+/// `CheckpointConfig` struct
+///
+/// describes when and where checkpoints are written
+#[derive(Debug, Clone, PartialEq)]
+pub struct CheckpointConfig {
+    /// number of steps between checkpoints, 0 disables periodic checkpoints
+    pub interval: u64,
+
+    /// directory checkpoints are written to
+    pub directory: PathBuf,
+
+    /// write a checkpoint and stop when SIGTERM is received
+    pub on_sigterm: bool,
+}
+
+impl Default for CheckpointConfig {
+    /// default `CheckpointConfig`
+    ///
+    /// periodic checkpoints are disabled, a checkpoint is written to `checkpoints` on SIGTERM
+    ///
+    /// # Arguments
+    ///
+    /// # Returns
+    /// `CheckpointConfig`
+    ///
+    /// # Errors
+    ///
+    fn default() -> CheckpointConfig {
+        CheckpointConfig {
+            interval: 0,
+            directory: PathBuf::from("checkpoints"),
+            on_sigterm: true,
+        }
+    }
+}
+
+/// computes a hash identifying the physical configuration of a model
+///
+/// every field influencing the evolution of a model is serialized explicitly in little endian binary format, with
+/// numbered tags of enum variants and lengths of sequences, so that the hash only changes with the configuration or a
+/// change of serialization, which increments `VERSION`
+///
+/// run length, thread count, checkpoint, output, and diagnostics settings, validation criteria, and the path of the
+/// magnetostatic vtk file do not influence the hash so that runs may be extended and restarted with different
+/// settings, the parallel reduction mode does as only deterministic reductions restart identically
+///
+/// # Arguments
+/// - `config`: &Config model configuration
+///
+/// # Returns
+/// `u64` 64-bit fnv-1a hash
+///
+/// # Errors
+///
+pub fn config_hash(config: &Config) -> u64 {
+    let mut hash = Fingerprint::new();
+
+    // every field is destructured without a rest pattern so that a new field fails to compile until it is hashed or
+    // explicitly ignored
+    let Config {
+        size,
+        cells,
+        grid,
+        dt,
+        steps: _,
+        seed,
+        engine,
+        precision,
+        parallel,
+        magnetic_field,
+        magnetostatics,
+        species,
+        mcc,
+        coulomb,
+        dsmc,
+        emission,
+        cylindrical,
+        validation: _,
+        checkpoint: _,
+        output: _,
+        diagnostics: _,
+    } = config;
+
+    hash.f64s(size);
+    hash.usizes(cells);
+    let GridConfig { x, y, z } = grid;
+    for spacing in [x, y, z] {
+        match spacing {
+            Spacing::Uniform => hash.tag(0),
+            Spacing::Tanh(stretch) => {
+                hash.tag(1);
+                hash.f64(*stretch);
+            }
+            Spacing::Nodes(nodes) => {
+                hash.tag(2);
+                hash.f64s(nodes);
+            }
+        }
+    }
+    hash.f64(*dt);
+    hash.u64(*seed);
+    hash.tag(match engine {
+        EngineKind::Electrostatic => 0,
+        EngineKind::Electromagnetic => 1,
+        EngineKind::Electrostatic1d => 2,
+        EngineKind::Electrostatic2d => 3,
+        EngineKind::Cylindrical => 4,
+    });
+    hash.tag(match precision {
+        Precision::Double => 0,
+        Precision::Single => 1,
+        Precision::Mixed => 2,
+    });
+    let ParallelConfig {
+        threads: _,
+        deterministic,
+    } = parallel;
+    hash.tag(*deterministic as u8);
+    hash.f64s(magnetic_field);
+
+    // magnetostatic sources
+    let MagnetostaticConfig {
+        sources,
+        segments,
+        vtk: _,
+    } = magnetostatics;
+    hash.usize(sources.len());
+    for source in sources.iter() {
+        match source {
+            Source::Loop {
+                center,
+                normal,
+                radius,
+                current,
+            } => {
+                hash.tag(0);
+                hash.f64s(center);
+                hash.f64s(normal);
+                hash.f64s(&[*radius, *current]);
+            }
+            Source::Solenoid {
+                center,
+                axis,
+                radius,
+                length,
+                turns,
+                current,
+            } => {
+                hash.tag(1);
+                hash.f64s(center);
+                hash.f64s(axis);
+                hash.f64s(&[*radius, *length]);
+                hash.usize(*turns);
+                hash.f64(*current);
+            }
+            Source::Block {
+                min,
+                max,
+                magnetization,
+            } => {
+                hash.tag(2);
+                hash.f64s(min);
+                hash.f64s(max);
+                hash.f64s(magnetization);
+            }
+        }
+    }
+    hash.usize(*segments);
+
+    // species and any macro-particles loaded with them
+    hash.usize(species.len());
+    for Species {
+        name,
+        charge,
+        mass,
+        density,
+        temperature,
+        weight,
+        particles,
+    } in species.iter()
+    {
+        hash.str(name);
+        hash.f64s(&[*charge, *mass, *density, *temperature, *weight]);
+        hash.usize(particles.len());
+        for Particle { position, velocity } in particles.iter() {
+            hash.f64s(position);
+            hash.f64s(velocity);
+        }
+    }
+
+    // monte carlo collisions
+    let MccConfig {
+        gas,
+        reactions,
+        electron,
+        ion,
+    } = mcc;
+    match gas {
+        None => hash.tag(0),
+        Some(BackgroundGas {
+            mass,
+            temperature,
+            density,
+        }) => {
+            hash.tag(1);
+            hash.f64s(&[*mass, *temperature]);
+            match density {
+                GasDensity::Uniform(density) => {
+                    hash.tag(0);
+                    hash.f64(*density);
+                }
+                GasDensity::Profile(density) => {
+                    hash.tag(1);
+                    let cells = density.cells();
+                    hash.usizes(&[cells.x, cells.y, cells.z]);
+                    density.iter().for_each(|value| hash.f64(*value));
+                }
+            }
+        }
+    }
+    hash.usize(reactions.len());
+    for Reaction {
+        species,
+        cross_section,
+    } in reactions.iter()
+    {
+        hash.str(species);
+        hash.tag(match cross_section.process {
+            Process::Elastic => 0,
+            Process::Excitation => 1,
+            Process::Ionization => 2,
+        });
+        hash.f64(cross_section.threshold);
+        hash.f64s(cross_section.energy());
+        hash.f64s(cross_section.sigma());
+    }
+    hash.str(electron);
+    hash.str(ion);
+
+    // coulomb collisions
+    let CoulombConfig {
+        enabled,
+        coulomb_log,
+    } = coulomb;
+    hash.tag(*enabled as u8);
+    hash.f64(*coulomb_log);
+
+    // dsmc collisions
+    let DsmcConfig { molecules } = dsmc;
+    hash.usize(molecules.len());
+    for Molecule {
+        species,
+        diameter,
+        omega,
+        reference_temperature,
+    } in molecules.iter()
+    {
+        hash.str(species);
+        hash.f64s(&[*diameter, *omega, *reference_temperature]);
+    }
+
+    // secondary electron emission
+    let EmissionConfig {
+        rules,
+        electron,
+        energy,
+    } = emission;
+    hash.usize(rules.len());
+    for EmissionRule {
+        species,
+        surfaces,
+        yield_model,
+    } in rules.iter()
+    {
+        hash.str(species);
+        hash.usize(surfaces.len());
+        for surface in surfaces.iter() {
+            hash.tag(match surface {
+                Surface::XLow => 0,
+                Surface::XHigh => 1,
+                Surface::YLow => 2,
+                Surface::YHigh => 3,
+                Surface::ZLow => 4,
+                Surface::ZHigh => 5,
+            });
+        }
+        match yield_model {
+            YieldModel::Constant(value) => {
+                hash.tag(0);
+                hash.f64(*value);
+            }
+            YieldModel::Vaughan {
+                max_yield,
+                max_energy,
+                threshold,
+                smoothness,
+            } => {
+                hash.tag(1);
+                hash.f64s(&[*max_yield, *max_energy, *threshold, *smoothness]);
+            }
+            YieldModel::Furman {
+                max_yield,
+                max_energy,
+                shape,
+                angular,
+            } => {
+                hash.tag(2);
+                hash.f64s(&[*max_yield, *max_energy, *shape]);
+                hash.f64s(angular);
+            }
+        }
+    }
+    hash.str(electron);
+    match energy {
+        EmissionEnergy::Maxwellian(temperature) => {
+            hash.tag(0);
+            hash.f64(*temperature);
+        }
+        EmissionEnergy::Monoenergetic(energy) => {
+            hash.tag(1);
+            hash.f64(*energy);
+        }
+    }
+
+    // electrodes of the cylindrical engine
+    let CylindricalConfig {
+        inner_radius,
+        inner_potential,
+        outer_potential,
+        axial,
+    } = cylindrical;
+    hash.f64s(&[*inner_radius, *inner_potential, *outer_potential]);
+    hash.tag(match axial {
+        AxialBoundary::Grounded => 0,
+        AxialBoundary::Symmetric => 1,
+    });
+
+    hash.finish()
+}
+
+/// `Fingerprint` struct
+///
+/// 64-bit fnv-1a hash of values serialized in little endian binary format
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Fingerprint(u64);
+
+impl Fingerprint {
+    /// `Fingerprint` constructor
+    ///
+    /// # Arguments
+    ///
+    /// # Returns
+    /// `Fingerprint` hash of no bytes
+    ///
+    /// # Errors
+    ///
+    fn new() -> Fingerprint {
+        Fingerprint(0xcbf29ce484222325)
+    }
+
+    /// hashes raw bytes
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `bytes`: &[u8] bytes to hash
+    ///
+    /// # Returns
+    ///
+    /// # Errors
+    ///
+    fn bytes(&mut self, bytes: &[u8]) {
+        self.0 = bytes.iter().fold(self.0, |hash, byte| {
+            (hash ^ *byte as u64).wrapping_mul(0x100000001b3)
+        });
+    }
+
+    /// hashes the tag of an enum variant or a flag
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `tag`: u8 number of variant
+    ///
+    /// # Returns
+    ///
+    /// # Errors
+    ///
+    fn tag(&mut self, tag: u8) {
+        self.bytes(&[tag]);
+    }
+
+    /// hashes a u64
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `value`: u64 value to hash
+    ///
+    /// # Returns
+    ///
+    /// # Errors
+    ///
+    fn u64(&mut self, value: u64) {
+        self.bytes(&value.to_le_bytes());
+    }
+
+    /// hashes a usize as a u64 so that the hash does not depend on the platform
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `value`: usize value to hash
+    ///
+    /// # Returns
+    ///
+    /// # Errors
+    ///
+    fn usize(&mut self, value: usize) {
+        self.u64(value as u64);
+    }
+
+    /// hashes a f64
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `value`: f64 value to hash
+    ///
+    /// # Returns
+    ///
+    /// # Errors
+    ///
+    fn f64(&mut self, value: f64) {
+        self.bytes(&value.to_le_bytes());
+    }
+
+    /// hashes a sequence of usize preceded by its length
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `values`: &[usize] values to hash
+    ///
+    /// # Returns
+    ///
+    /// # Errors
+    ///
+    fn usizes(&mut self, values: &[usize]) {
+        self.usize(values.len());
+        values.iter().for_each(|value| self.usize(*value));
+    }
+
+    /// hashes a sequence of f64 preceded by its length
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `values`: &[f64] values to hash
+    ///
+    /// # Returns
+    ///
+    /// # Errors
+    ///
+    fn f64s(&mut self, values: &[f64]) {
+        self.usize(values.len());
+        values.iter().for_each(|value| self.f64(*value));
+    }
+
+    /// hashes a string preceded by its length in bytes
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `value`: &str string to hash
+    ///
+    /// # Returns
+    ///
+    /// # Errors
+    ///
+    fn str(&mut self, value: &str) {
+        self.usize(value.len());
+        self.bytes(value.as_bytes());
+    }
+
+    /// returns the hash of all values hashed so far
+    ///
+    /// # Arguments
+    /// - `self` fingerprint to finish
+    ///
+    /// # Returns
+    /// `u64` 64-bit fnv-1a hash
+    ///
+    /// # Errors
+    ///
+    fn finish(self) -> u64 {
+        self.0
+    }
+}
+
+/// returns path of the checkpoint for a given step
+///
+/// # Arguments
+/// - `directory`: &Path directory checkpoints are written to
+/// - `step`: u64 step of checkpoint
+///
+/// # Returns
+/// `PathBuf`
+///
+/// # Errors
+///
+pub fn path(directory: &Path, step: u64) -> PathBuf {
+    directory.join(format!("checkpoint_{step:010}.bin"))
+}
+
+/// writes a checkpoint of `engine` to `path`
+///
+/// the checkpoint is written to a temporary file which is then renamed so that an interrupted write never leaves
+/// a truncated checkpoint behind
+///
+/// # Arguments
+/// - `path`: &Path destination of checkpoint
+/// - `config_hash`: u64 hash of model configuration
+/// - `engine`: &dyn Engine engine to checkpoint
+///
+/// # Returns
+/// `Result<(), anyhow::Error>`
+///
+/// # Errors
+/// - checkpoint directory could not be created
+/// - checkpoint could not be written
+pub fn save(path: &Path, config_hash: u64, engine: &dyn Engine) -> Result<(), anyhow::Error> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("could not create directory {}", parent.display()))?;
+    }
+
+    let tmp = path.with_extension("tmp");
+    let mut writer = BufWriter::new(
+        File::create(&tmp).with_context(|| format!("could not create {}", tmp.display()))?,
+    );
+
+    writer.write_all(MAGIC)?;
+    writer.write_all(&VERSION.to_le_bytes())?;
+    write_u64(&mut writer, config_hash)?;
+    engine.write_checkpoint(&mut writer)?;
+    writer.flush()?;
+    drop(writer);
+
+    std::fs::rename(&tmp, path)
+        .with_context(|| format!("could not move checkpoint to {}", path.display()))?;
+
+    Ok(())
+}
+
+/// restores the state of `engine` from the checkpoint at `path`
+///
+/// # Arguments
+/// - `path`: &Path source of checkpoint
+/// - `config_hash`: u64 hash of model configuration
+/// - `engine`: &mut dyn Engine engine to restore
+///
+/// # Returns
+/// `Result<(), anyhow::Error>`
+///
+/// # Errors
+/// - checkpoint could not be read
+/// - file is not a picrs checkpoint
+/// - checkpoint format version is incompatible
+/// - checkpoint was written with a different configuration
+pub fn load(path: &Path, config_hash: u64, engine: &mut dyn Engine) -> Result<(), anyhow::Error> {
+    let mut reader = BufReader::new(
+        File::open(path).with_context(|| format!("could not open {}", path.display()))?,
+    );
+
+    let mut magic = [0u8; 8];
+    reader.read_exact(&mut magic)?;
+    if &magic != MAGIC {
+        return Err(anyhow!("{} is not a picrs checkpoint", path.display()));
+    }
+
+    let mut version = [0u8; 4];
+    reader.read_exact(&mut version)?;
+    let version = u32::from_le_bytes(version);
+    if version != VERSION {
+        return Err(anyhow!(
+            "checkpoint {} has format version {version} which is incompatible with supported version {VERSION}",
+            path.display()
+        ));
+    }
+
+    let hash = read_u64(&mut reader)?;
+    if hash != config_hash {
+        return Err(anyhow!(
+            "checkpoint {} was written with a different configuration (hash {hash:016x}, expected {config_hash:016x})",
+            path.display()
+        ));
+    }
+
+    engine
+        .read_checkpoint(&mut reader)
+        .with_context(|| format!("could not read checkpoint {}", path.display()))?;
+
+    Ok(())
+}
+
+/// writes a u64 in little endian binary format
+///
+/// # Arguments
+/// - `writer`: &mut dyn Write destination of data
+/// - `value`: u64 value to write
+///
+/// # Returns
+/// `Result<(), anyhow::Error>`
+///
+/// # Errors
+/// - call to `Write::write_all()` fails
+pub fn write_u64(writer: &mut dyn Write, value: u64) -> Result<(), anyhow::Error> {
+    writer.write_all(&value.to_le_bytes())?;
+    Ok(())
+}
+
+/// reads a u64 from little endian binary format
+///
+/// # Arguments
+/// - `reader`: &mut dyn Read source of data
+///
+/// # Returns
+/// `Result<u64, anyhow::Error>`
+///
+/// # Errors
+/// - call to `Read::read_exact()` fails
+pub fn read_u64(reader: &mut dyn Read) -> Result<u64, anyhow::Error> {
+    let mut buf = [0u8; 8];
+    reader.read_exact(&mut buf)?;
+    Ok(u64::from_le_bytes(buf))
+}
+
+/// writes a f64 in little endian binary format
+///
+/// # Arguments
+/// - `writer`: &mut dyn Write destination of data
+/// - `value`: f64 value to write
+///
+/// # Returns
+/// `Result<(), anyhow::Error>`
+///
+/// # Errors
+/// - call to `Write::write_all()` fails
+pub fn write_f64(writer: &mut dyn Write, value: f64) -> Result<(), anyhow::Error> {
+    writer.write_all(&value.to_le_bytes())?;
+    Ok(())
+}
+
+/// reads a f64 from little endian binary format
+///
+/// # Arguments
+/// - `reader`: &mut dyn Read source of data
+///
+/// # Returns
+/// `Result<f64, anyhow::Error>`
+///
+/// # Errors
+/// - call to `Read::read_exact()` fails
+pub fn read_f64(reader: &mut dyn Read) -> Result<f64, anyhow::Error> {
+    let mut buf = [0u8; 8];
+    reader.read_exact(&mut buf)?;
+    Ok(f64::from_le_bytes(buf))
+}
+
+/// writes the time stepping state and random number generator state of an engine in little endian binary format
+///
+/// # Arguments
+/// - `writer`: &mut dyn Write destination of data
+/// - `step`: u64 number of completed time steps
+/// - `time`: f64 (s) simulated time
+/// - `rng`: &Rng random number generator
+///
+/// # Returns
+/// `Result<(), anyhow::Error>`
+///
+/// # Errors
+/// - any call to `write_u64()` or `write_f64()` fails
+pub fn write_clock(
+    writer: &mut dyn Write,
+    step: u64,
+    time: f64,
+    rng: &Rng,
+) -> Result<(), anyhow::Error> {
+    write_u64(writer, step)?;
+    write_f64(writer, time)?;
+    for elem in rng.state() {
+        write_u64(writer, elem)?;
+    }
+    Ok(())
+}
+
+/// reads the time stepping state and random number generator state of an engine from little endian binary format
+///
+/// # Arguments
+/// - `reader`: &mut dyn Read source of data
+///
+/// # Returns
+/// `Result<(u64, f64, Rng), anyhow::Error>` number of completed time steps, (s) simulated time, and random number
+/// generator
+///
+/// # Errors
+/// - any call to `read_u64()` or `read_f64()` fails
+pub fn read_clock(reader: &mut dyn Read) -> Result<(u64, f64, Rng), anyhow::Error> {
+    let step = read_u64(reader)?;
+    let time = read_f64(reader)?;
+    let mut state = [0u64; 4];
+    for elem in state.iter_mut() {
+        *elem = read_u64(reader)?;
+    }
+    Ok((step, time, Rng::from_state(state)))
+}
+
+/// writes the number of cells of an engine in little endian binary format
+///
+/// # Arguments
+/// - `writer`: &mut dyn Write destination of data
+/// - `cells`: &CoordinateTriplet<usize> number of cells in bounding box
+///
+/// # Returns
+/// `Result<(), anyhow::Error>`
+///
+/// # Errors
+/// - any call to `write_u64()` fails
+pub fn write_cells(
+    writer: &mut dyn Write,
+    cells: &CoordinateTriplet<usize>,
+) -> Result<(), anyhow::Error> {
+    for elem in [cells.x, cells.y, cells.z] {
+        write_u64(writer, elem as u64)?;
+    }
+    Ok(())
+}
+
+/// reads the number of cells of an engine from little endian binary format and checks it against the engine
+///
+/// # Arguments
+/// - `reader`: &mut dyn Read source of data
+/// - `cells`: &CoordinateTriplet<usize> number of cells in bounding box of engine
+///
+/// # Returns
+/// `Result<(), anyhow::Error>`
+///
+/// # Errors
+/// - any call to `read_u64()` fails
+/// - number of cells does not match `cells`
+pub fn read_cells(
+    reader: &mut dyn Read,
+    cells: &CoordinateTriplet<usize>,
+) -> Result<(), anyhow::Error> {
+    let read = [read_u64(reader)?, read_u64(reader)?, read_u64(reader)?];
+    if read != [cells.x as u64, cells.y as u64, cells.z as u64] {
+        return Err(anyhow!(
+            "checkpoint has {read:?} cells but engine has {cells}"
+        ));
+    }
+    Ok(())
+}
+
+/// writes the macro-particles of all species in little endian binary format, widened to double precision
+///
+/// # Arguments
+/// - `writer`: &mut dyn Write destination of data
+/// - `species`: &[Species<P>] species of engine
+///
+/// # Returns
+/// `Result<(), anyhow::Error>`
+///
+/// # Errors
+/// - any call to `write_u64()` or `write_f64()` fails
+pub fn write_particles<P: Real>(
+    writer: &mut dyn Write,
+    species: &[Species<P>],
+) -> Result<(), anyhow::Error> {
+    write_u64(writer, species.len() as u64)?;
+    for species in species.iter() {
+        write_u64(writer, species.particles.len() as u64)?;
+        for particle in species.particles.iter() {
+            for elem in particle.position.iter().chain(particle.velocity.iter()) {
+                write_f64(writer, elem.widen())?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// reads the macro-particles of all species from little endian binary format, narrowed to precision `P`, replacing
+/// any existing macro-particles
+///
+/// # Arguments
+/// - `reader`: &mut dyn Read source of data
+/// - `species`: &mut [Species<P>] species of engine
+///
+/// # Returns
+/// `Result<(), anyhow::Error>`
+///
+/// # Errors
+/// - any call to `read_u64()` or `read_f64()` fails
+/// - number of species does not match `species`
+pub fn read_particles<P: Real>(
+    reader: &mut dyn Read,
+    species: &mut [Species<P>],
+) -> Result<(), anyhow::Error> {
+    let species_count = read_u64(reader)? as usize;
+    if species_count != species.len() {
+        return Err(anyhow!(
+            "checkpoint has {species_count} species but engine has {}",
+            species.len()
+        ));
+    }
+    for species in species.iter_mut() {
+        let count = read_u64(reader)? as usize;
+        species.particles.clear();
+        species.particles.reserve(count);
+        for _ in 0..count {
+            let mut elems = [0.0; 6];
+            for elem in elems.iter_mut() {
+                *elem = read_f64(reader)?;
+            }
+            species.particles.push(Particle {
+                position: [elems[0], elems[1], elems[2]].map(P::narrow),
+                velocity: [elems[3], elems[4], elems[5]].map(P::narrow),
+            });
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::checkpoint::{
+        config_hash, load, path, read_cells, read_particles, save, write_cells, write_particles,
+    };
+    use crate::config::Config;
+    use crate::constants::{ELEC_CHARGE, ELEC_MASS, EV_TEMP};
+    use crate::engine::{Electrostatic, Engine, PARTICLE_CHUNK};
+    use crate::species::{Particle, Species};
+    use crate::utils::coordinate_triplet::CoordinateTriplet;
+    use std::path::PathBuf;
+
+    /// helper function that sets up a `Config` with a single electron species of more than `PARTICLE_CHUNK`
+    /// macro-particles and the default engine configuration for testing
+    ///
+    /// # Arguments
+    ///
+    /// # Returns
+    /// `Result<Config, anyhow::Error>`
+    ///
+    /// # Errors
+    /// - `Config::new()` fails
+    /// - `Species::new()` fails
+    fn setup() -> Result<Config, anyhow::Error> {
+        let mut config = Config::new(&[0.01, 0.01, 0.01], &[11, 11, 11], 1e-10)?;
+        config.species.push(Species::new(
+            "electron",
+            -ELEC_CHARGE,
+            ELEC_MASS,
+            1e12,
+            EV_TEMP,
+            50.0,
+        )?);
+        Ok(config)
+    }
+
+    /// helper function that returns a unique temporary directory for testing
+    ///
+    /// # Arguments
+    /// - `name`: &str name of test
+    ///
+    /// # Returns
+    /// `PathBuf`
+    ///
+    /// # Errors
+    ///
+    fn tmp_dir(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("picrs_checkpoint_{name}_{}", std::process::id()))
+    }
+
+    /// tests that a restarted engine evolves bit-for-bit identically to an uninterrupted engine, with enough
+    /// macro-particles for charge to be deposited in parallel
+    ///
+    /// # Errors
+    /// - call to `save()` or `load()` fails
+    /// - restarted engine differs from uninterrupted engine
+    ///
+    #[test]
+    fn restart_bit_for_bit() {
+        // setup
+        let config = setup().unwrap();
+        let hash = config_hash(&config);
+        let dir = tmp_dir("restart");
+        let file = path(&dir, 3);
+
+        let mut uninterrupted = Electrostatic::<f64>::new(&config).unwrap();
+        for _ in 0..3 {
+            uninterrupted.update().unwrap();
+        }
+        save(&file, hash, &uninterrupted).unwrap();
+        for _ in 0..3 {
+            uninterrupted.update().unwrap();
+        }
+
+        let mut restarted = Electrostatic::<f64>::new(&config).unwrap();
+        load(&file, hash, &mut restarted).unwrap();
+        for _ in 0..3 {
+            restarted.update().unwrap();
+        }
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        // assertions
+        let mut a: Vec<u8> = Vec::new();
+        let mut b: Vec<u8> = Vec::new();
+        uninterrupted.write_checkpoint(&mut a).unwrap();
+        restarted.write_checkpoint(&mut b).unwrap();
+        assert!(uninterrupted.species()[0].particles.len() > PARTICLE_CHUNK);
+        assert_eq!(restarted.step(), 6);
+        assert!(a == b);
+    }
+
+    /// tests `load()` for failure on incompatible checkpoints
+    ///
+    /// # Errors
+    /// - `load()` succeeds for a checkpoint written with a different configuration
+    /// - `load()` succeeds for a checkpoint with a different format version
+    /// - `load()` succeeds for a file that is not a checkpoint
+    ///
+    #[test]
+    fn load_incompatible() {
+        // setup
+        let config = setup().unwrap();
+        let hash = config_hash(&config);
+        let dir = tmp_dir("incompatible");
+        let file = path(&dir, 0);
+        let mut engine = Electrostatic::<f64>::new(&config).unwrap();
+        save(&file, hash, &engine).unwrap();
+
+        // assertions
+        let err = load(&file, hash ^ 1, &mut engine).unwrap_err();
+        assert!(err.to_string().contains("different configuration"));
+
+        let mut bytes = std::fs::read(&file).unwrap();
+        bytes[8] += 1;
+        std::fs::write(&file, &bytes).unwrap();
+        let err = load(&file, hash, &mut engine).unwrap_err();
+        assert!(err.to_string().contains("format version"));
+
+        bytes[0] = b'X';
+        std::fs::write(&file, &bytes).unwrap();
+        let err = load(&file, hash, &mut engine).unwrap_err();
+        assert!(err.to_string().contains("not a picrs checkpoint"));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    /// tests `config_hash()` ignores run length, checkpoint, and thread settings
+    ///
+    /// # Errors
+    /// - `config_hash()` depends on `Config.steps`, `Config.checkpoint`, or `Config.parallel.threads`
+    /// - `config_hash()` does not depend on `Config.dt` or `Config.parallel.deterministic`
+    /// - `config_hash()` differs between identical configurations
+    ///
+    #[test]
+    fn config_hash_identity() {
+        let config = setup().unwrap();
+        let mut other = config.clone();
+        other.steps += 100;
+        other.checkpoint.interval = 7;
+        other.parallel.threads = 3;
+        assert_eq!(config_hash(&config), config_hash(&other));
+        assert_eq!(config_hash(&config), config_hash(&setup().unwrap()));
+
+        let mut adaptive = config.clone();
+        adaptive.parallel.deterministic = false;
+        assert_ne!(config_hash(&config), config_hash(&adaptive));
+
+        other.dt *= 2.0;
+        assert_ne!(config_hash(&config), config_hash(&other));
+    }
+
+    /// tests `write_particles()` and `read_particles()` for a round trip narrowing to single precision and
+    /// `read_cells()` and `read_particles()` for rejection of a different engine
+    ///
+    /// # Errors
+    /// - macro-particles are not narrowed to the nearest single precision values
+    /// - `read_cells()` succeeds for a different number of cells
+    /// - `read_particles()` succeeds for a different number of species
+    ///
+    #[test]
+    fn particles_round_trip() {
+        // setup
+        let mut species = Species::new("electron", -ELEC_CHARGE, ELEC_MASS, 0.0, 0.0, 1.0).unwrap();
+        species.particles = vec![Particle {
+            position: [0.1, 0.2, 0.3],
+            velocity: [1e5, -2e5, 3e5],
+        }];
+        let mut bytes = Vec::new();
+        write_cells(&mut bytes, &CoordinateTriplet::new(3, 4, 5).unwrap()).unwrap();
+        write_particles(&mut bytes, &[species.clone()]).unwrap();
+
+        let mut narrowed = [Species::<f32>::narrow(&species)];
+        narrowed[0].particles.clear();
+        let mut reader = &bytes[24..];
+        read_particles(&mut reader, &mut narrowed).unwrap();
+
+        // assertions
+        assert_eq!(
+            narrowed[0].particles,
+            vec![Particle::narrow(&species.particles[0])]
+        );
+        assert!(read_cells(&mut &bytes[..], &CoordinateTriplet::new(3, 4, 6).unwrap()).is_err());
+        let mut two = [species.clone(), species];
+        assert!(read_particles(&mut &bytes[24..], &mut two).is_err());
+    }
+}
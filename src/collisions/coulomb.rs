@@ -0,0 +1,507 @@
+use crate::collisions::bin;
+use crate::constants::VAC_PERM;
+use crate::precision::Real;
+use crate::species::Species;
+use crate::utils::coordinate_triplet::CoordinateTriplet;
+use crate::utils::rng::Rng;
+use anyhow::anyhow;
+use std::f64::consts::PI;
+
+/// `CoulombConfig` struct
+///
+/// describes binary coulomb collisions between charged species
+#[derive(Debug, Clone, PartialEq)]
+pub struct CoulombConfig {
+    /// perform coulomb collisions
+    pub enabled: bool,
+
+    /// coulomb logarithm
+    pub coulomb_log: f64,
+}
+
+impl Default for CoulombConfig {
+    /// default `CoulombConfig`
+    ///
+    /// coulomb collisions are disabled
+    ///
+    /// # Arguments
+    ///
+    /// # Returns
+    /// `CoulombConfig`
+    ///
+    /// # Errors
+    ///
+    fn default() -> CoulombConfig {
+        CoulombConfig {
+            enabled: false,
+            coulomb_log: 10.0,
+        }
+    }
+}
+
+/// `Coulomb` struct
+///
+/// performs binary coulomb collisions between macro-particles sharing a cell using the takizuka-abe scheme
+///
+/// every pair of colliding macro-particles is deflected by a random angle in the center of mass frame, which conserves
+/// momentum and energy to round-off
+#[derive(Debug, Clone)]
+pub struct Coulomb {
+    /// indices of colliding species pairs, intra-species pairs have equal indices
+    pairs: Vec<(usize, usize)>,
+
+    /// coulomb logarithm
+    coulomb_log: f64,
+}
+
+impl Coulomb {
+    /// `Coulomb` constructor
+    ///
+    /// all charged species collide with themselves and with each other
+    ///
+    /// # Arguments
+    /// - `config`: &CoulombConfig coulomb collision configuration
+    /// - `species`: &[Species<P>] species present in model
+    ///
+    /// # Returns
+    /// `Result<Coulomb, anyhow::Error>`
+    ///
+    /// # Errors
+    /// - coulomb logarithm is not positive
+    /// - any two charged species have different weights
+    pub fn new<P>(
+        config: &CoulombConfig,
+        species: &[Species<P>],
+    ) -> Result<Coulomb, anyhow::Error> {
+        if config.coulomb_log.is_nan() || config.coulomb_log <= 0.0 {
+            return Err(anyhow!(
+                "coulomb logarithm must be positive, got {}",
+                config.coulomb_log
+            ));
+        }
+
+        let charged: Vec<usize> = (0..species.len())
+            .filter(|s| species[*s].charge != 0.0)
+            .collect();
+
+        let mut pairs = Vec::new();
+        for (n, a) in charged.iter().enumerate() {
+            for b in charged[n..].iter() {
+                // pairs of unequal weight would only conserve momentum and energy on average
+                if species[*a].weight != species[*b].weight {
+                    return Err(anyhow!(
+                        "coulomb collisions require equal weights, species `{}` and `{}` have weights {} and {}",
+                        species[*a].name,
+                        species[*b].name,
+                        species[*a].weight,
+                        species[*b].weight
+                    ));
+                }
+                pairs.push((*a, *b));
+            }
+        }
+
+        Ok(Coulomb {
+            pairs,
+            coulomb_log: config.coulomb_log,
+        })
+    }
+
+    /// performs coulomb collisions of all charged species over a single time step
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `species`: &mut [Species<P>] species present in model
+    /// - `delta`: &CoordinateTriplet<f64> (m) spatial increment
+    /// - `cells`: &CoordinateTriplet<usize> number of cells in bounding box
+    /// - `dt`: f64 (s) time step
+    /// - `rng`: &mut Rng random number generator
+    ///
+    /// # Returns
+    ///
+    /// # Errors
+    ///
+    pub fn collide<P: Real>(
+        &self,
+        species: &mut [Species<P>],
+        delta: &CoordinateTriplet<f64>,
+        cells: &CoordinateTriplet<usize>,
+        dt: f64,
+        rng: &mut Rng,
+    ) {
+        // sort macro-particles into cells in random order
+        let bins: Vec<Vec<Vec<usize>>> = species
+            .iter()
+            .map(|s| {
+                let mut bins = bin(&s.particles, delta, cells);
+                bins.iter_mut().for_each(|bin| shuffle(bin, rng));
+                bins
+            })
+            .collect();
+
+        // (m^3) volume of a cell
+        let volume = delta.x * delta.y * delta.z;
+
+        for (a, b) in self.pairs.iter().copied() {
+            let (ma, mb) = (species[a].mass, species[b].mass);
+            let reduced_mass = ma * mb / (ma + mb);
+
+            // variance of tan(theta / 2) is `factor * density / u^3`
+            let factor = (species[a].charge * species[b].charge).powi(2) * self.coulomb_log * dt
+                / (8.0 * PI * VAC_PERM * VAC_PERM * reduced_mass * reduced_mass);
+
+            for (la, lb) in bins[a].iter().zip(bins[b].iter()) {
+                if a == b {
+                    // intra-species pairs, an odd number of macro-particles starts with a triplet of half strength
+                    let n = la.len();
+                    if n < 2 {
+                        continue;
+                    }
+                    let factor = factor * n as f64 * species[a].weight / volume;
+                    let mut start = 0;
+                    if n % 2 == 1 {
+                        for (i, j) in [(0, 1), (1, 2), (2, 0)] {
+                            collide_pair(species, (a, la[i]), (a, la[j]), 0.5 * factor, rng);
+                        }
+                        start = 3;
+                    }
+                    for pair in la[start..].chunks_exact(2) {
+                        collide_pair(species, (a, pair[0]), (a, pair[1]), factor, rng);
+                    }
+                } else {
+                    // inter-species pairs, macro-particles of the less numerous species are reused
+                    if la.is_empty() || lb.is_empty() {
+                        continue;
+                    }
+                    let minor = la.len().min(lb.len());
+                    let factor = factor * minor as f64 * species[a].weight / volume;
+                    for n in 0..la.len().max(lb.len()) {
+                        let i = la[n % la.len()];
+                        let j = lb[n % lb.len()];
+                        collide_pair(species, (a, i), (b, j), factor, rng);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// deflects the relative velocity of a pair of macro-particles by a random angle in the center of mass frame
+///
+/// # Arguments
+/// - `species`: &mut [Species<P>] species present in model
+/// - `a`: (usize, usize) indices of species and macro-particle of first partner
+/// - `b`: (usize, usize) indices of species and macro-particle of second partner
+/// - `factor`: f64 (m^3/s^3) variance of tan(theta / 2) times relative speed cubed
+/// - `rng`: &mut Rng random number generator
+///
+/// # Returns
+///
+/// # Errors
+///
+fn collide_pair<P: Real>(
+    species: &mut [Species<P>],
+    a: (usize, usize),
+    b: (usize, usize),
+    factor: f64,
+    rng: &mut Rng,
+) {
+    let (ma, mb) = (species[a.0].mass, species[b.0].mass);
+    let va = species[a.0].particles[a.1].velocity.map(P::widen);
+    let vb = species[b.0].particles[b.1].velocity.map(P::widen);
+
+    // relative velocity
+    let u = [va[0] - vb[0], va[1] - vb[1], va[2] - vb[2]];
+    let u_perp = (u[0] * u[0] + u[1] * u[1]).sqrt();
+    let u_mag = (u_perp * u_perp + u[2] * u[2]).sqrt();
+    if u_mag == 0.0 {
+        return;
+    }
+
+    // scattering angle from tan(theta / 2) sampled from a normal distribution
+    let delta = rng.normal() * (factor / (u_mag * u_mag * u_mag)).sqrt();
+    let sin_theta = 2.0 * delta / (1.0 + delta * delta);
+    let one_minus_cos_theta = 2.0 * delta * delta / (1.0 + delta * delta);
+    let phi = 2.0 * PI * rng.uniform();
+    let (sin_phi, cos_phi) = phi.sin_cos();
+
+    // change of relative velocity
+    let du = if u_perp > 0.0 {
+        [
+            (u[0] / u_perp) * u[2] * sin_theta * cos_phi
+                - (u[1] / u_perp) * u_mag * sin_theta * sin_phi
+                - u[0] * one_minus_cos_theta,
+            (u[1] / u_perp) * u[2] * sin_theta * cos_phi
+                + (u[0] / u_perp) * u_mag * sin_theta * sin_phi
+                - u[1] * one_minus_cos_theta,
+            -u_perp * sin_theta * cos_phi - u[2] * one_minus_cos_theta,
+        ]
+    } else {
+        [
+            u_mag * sin_theta * cos_phi,
+            u_mag * sin_theta * sin_phi,
+            -u_mag * one_minus_cos_theta,
+        ]
+    };
+
+    let (fa, fb) = (mb / (ma + mb), ma / (ma + mb));
+    let pa = &mut species[a.0].particles[a.1].velocity;
+    for (v, d) in pa.iter_mut().zip(du.iter()) {
+        *v = P::narrow(v.widen() + fa * d);
+    }
+    let pb = &mut species[b.0].particles[b.1].velocity;
+    for (v, d) in pb.iter_mut().zip(du.iter()) {
+        *v = P::narrow(v.widen() - fb * d);
+    }
+}
+
+/// shuffles a slice in place using the fisher-yates algorithm
+///
+/// # Arguments
+/// - `values`: &mut [usize] values to shuffle
+/// - `rng`: &mut Rng random number generator
+///
+/// # Returns
+///
+/// # Errors
+///
+fn shuffle(values: &mut [usize], rng: &mut Rng) {
+    for i in (1..values.len()).rev() {
+        let j = ((rng.uniform() * (i + 1) as f64) as usize).min(i);
+        values.swap(i, j);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::collisions::coulomb::{Coulomb, CoulombConfig};
+    use crate::constants::{AMU, BOLTZMANN, ELEC_CHARGE, ELEC_MASS, EV_TEMP, VAC_PERM};
+    use crate::species::{Particle, Species};
+    use crate::utils::coordinate_triplet::CoordinateTriplet;
+    use crate::utils::rng::Rng;
+    use std::f64::consts::PI;
+
+    /// helper function that returns a species with an anisotropic maxwellian velocity distribution in a single cell
+    ///
+    /// # Arguments
+    /// - `name`: &str name of species
+    /// - `charge`: f64 (C) charge of a single physical particle
+    /// - `mass`: f64 (kg) mass of a single physical particle
+    /// - `count`: usize number of macro-particles
+    /// - `temperature`: [f64; 2] (K) perpendicular and parallel temperature
+    /// - `rng`: &mut Rng random number generator
+    ///
+    /// # Returns
+    /// `Result<Species, anyhow::Error>`
+    ///
+    /// # Errors
+    /// - call to `Species::new()` fails
+    fn anisotropic(
+        name: &str,
+        charge: f64,
+        mass: f64,
+        count: usize,
+        temperature: [f64; 2],
+        rng: &mut Rng,
+    ) -> Result<Species, anyhow::Error> {
+        let mut species = Species::new(name, charge, mass, 0.0, 0.0, 1e18 / count as f64)?;
+        let v_perp = (BOLTZMANN * temperature[0] / mass).sqrt();
+        let v_par = (BOLTZMANN * temperature[1] / mass).sqrt();
+        species.particles = (0..count)
+            .map(|_| Particle {
+                position: [0.5, 0.5, 0.5],
+                velocity: [
+                    rng.normal() * v_perp,
+                    rng.normal() * v_perp,
+                    rng.normal() * v_par,
+                ],
+            })
+            .collect();
+        Ok(species)
+    }
+
+    /// helper function that returns perpendicular and parallel temperature of a species
+    ///
+    /// # Arguments
+    /// - `species`: &Species species
+    ///
+    /// # Returns
+    /// `[f64; 2]` (K) perpendicular and parallel temperature
+    ///
+    /// # Errors
+    ///
+    fn temperature(species: &Species) -> [f64; 2] {
+        let n = species.particles.len() as f64;
+        let (mut perp, mut par) = (0.0, 0.0);
+        for p in species.particles.iter() {
+            perp += 0.5 * (p.velocity[0] * p.velocity[0] + p.velocity[1] * p.velocity[1]);
+            par += p.velocity[2] * p.velocity[2];
+        }
+        [
+            species.mass * perp / (n * BOLTZMANN),
+            species.mass * par / (n * BOLTZMANN),
+        ]
+    }
+
+    /// tests `Coulomb::new()` for failure on invalid input
+    ///
+    /// # Errors
+    /// - `Coulomb::new()` succeeds for a non-positive coulomb logarithm
+    /// - `Coulomb::new()` succeeds for charged species of different weight
+    ///
+    #[test]
+    fn new_failure() {
+        let electron = Species::new("electron", -ELEC_CHARGE, ELEC_MASS, 0.0, 0.0, 1.0).unwrap();
+        let ion = Species::new("ion", ELEC_CHARGE, AMU, 0.0, 0.0, 2.0).unwrap();
+        let neutral = Species::new("neutral", 0.0, AMU, 0.0, 0.0, 2.0).unwrap();
+        let config = CoulombConfig {
+            enabled: true,
+            ..CoulombConfig::default()
+        };
+
+        assert!(Coulomb::new(
+            &CoulombConfig {
+                coulomb_log: 0.0,
+                ..config.clone()
+            },
+            std::slice::from_ref(&electron)
+        )
+        .is_err());
+        assert!(Coulomb::new(&config, &[electron.clone(), ion]).is_err());
+        assert!(Coulomb::new(&config, &[electron, neutral]).is_ok());
+    }
+
+    /// tests `Coulomb::collide()` for conservation of momentum and energy to round-off
+    ///
+    /// # Errors
+    /// - total momentum or kinetic energy of an electron ion mixture changes
+    ///
+    #[test]
+    fn collide_conservative() {
+        // setup
+        let mut rng = Rng::new(0);
+        let mut species = vec![
+            anisotropic(
+                "electron",
+                -ELEC_CHARGE,
+                ELEC_MASS,
+                1001,
+                [2.0 * EV_TEMP, EV_TEMP],
+                &mut rng,
+            )
+            .unwrap(),
+            anisotropic("ion", ELEC_CHARGE, AMU, 1001, [EV_TEMP, EV_TEMP], &mut rng).unwrap(),
+        ];
+        species[1].particles.truncate(300);
+        let moments = |species: &[Species]| {
+            // momentum, kinetic energy, and sum of momentum magnitudes
+            let mut moments = [0.0; 5];
+            for s in species.iter() {
+                for p in s.particles.iter() {
+                    for d in 0..3 {
+                        moments[d] += s.mass * p.velocity[d];
+                        moments[3] += 0.5 * s.mass * p.velocity[d] * p.velocity[d];
+                        moments[4] += s.mass * p.velocity[d].abs();
+                    }
+                }
+            }
+            moments
+        };
+        let initial = moments(&species);
+
+        let coulomb = Coulomb::new(
+            &CoulombConfig {
+                enabled: true,
+                ..CoulombConfig::default()
+            },
+            &species,
+        )
+        .unwrap();
+        let cells = CoordinateTriplet::new(3, 3, 3).unwrap();
+        let delta = CoordinateTriplet::new(1.0, 1.0, 1.0).unwrap();
+        for _ in 0..10 {
+            coulomb.collide(&mut species, &delta, &cells, 1e-8, &mut rng);
+        }
+        let last = moments(&species);
+
+        // assertions
+        for d in 0..3 {
+            assert!((last[d] - initial[d]).abs() / initial[4] < 1e-12);
+        }
+        assert!((last[3] - initial[3]).abs() / initial[3] < 1e-12);
+        assert_ne!(temperature(&species[0]), temperature(&species[1]));
+    }
+
+    /// tests `Coulomb::collide()` for reproducing the temperature isotropization rate of an anisotropic maxwellian
+    ///
+    /// the difference between perpendicular and parallel temperature is compared against the solution of
+    /// `dT_perp/dt = -nu (T_perp - T_par)`, `dT_par/dt = 2 nu (T_perp - T_par)` with the isotropization rate `nu`
+    /// given in the nrl plasma formulary
+    ///
+    /// # Errors
+    /// - temperature difference deviates from expected temperature difference by more than ten percent
+    ///
+    #[test]
+    fn collide_isotropization() {
+        // setup
+        let mut rng = Rng::new(1);
+        let mut species = vec![anisotropic(
+            "electron",
+            -ELEC_CHARGE,
+            ELEC_MASS,
+            20000,
+            [2.0 * EV_TEMP, EV_TEMP],
+            &mut rng,
+        )
+        .unwrap()];
+        let coulomb = Coulomb::new(
+            &CoulombConfig {
+                enabled: true,
+                ..CoulombConfig::default()
+            },
+            &species,
+        )
+        .unwrap();
+        let cells = CoordinateTriplet::new(3, 3, 3).unwrap();
+        let delta = CoordinateTriplet::new(1.0, 1.0, 1.0).unwrap();
+        let (dt, steps) = (1e-9, 50);
+
+        // isotropization rate of nrl plasma formulary in si units
+        let rate = |t: [f64; 2]| {
+            let a = t[0] / t[1] - 1.0;
+            let shape = if a > 0.0 {
+                (-3.0 + (a + 3.0) * a.sqrt().atan() / a.sqrt()) / (a * a)
+            } else if a < 0.0 {
+                (-3.0 + (a + 3.0) * (-a).sqrt().atanh() / (-a).sqrt()) / (a * a)
+            } else {
+                4.0 / 15.0
+            };
+            let e_sq = ELEC_CHARGE * ELEC_CHARGE / (4.0 * PI * VAC_PERM);
+            2.0 * PI.sqrt() * e_sq * e_sq * 1e18 * 10.0 * shape
+                / (ELEC_MASS.sqrt() * (BOLTZMANN * t[1]).powf(1.5))
+        };
+
+        // reference solution using sub-stepped forward euler
+        let mut expected = temperature(&species[0]);
+        for _ in 0..steps * 100 {
+            let nu = rate(expected);
+            let dt = dt / 100.0;
+            let diff = expected[0] - expected[1];
+            expected = [
+                expected[0] - nu * diff * dt,
+                expected[1] + 2.0 * nu * diff * dt,
+            ];
+        }
+
+        for _ in 0..steps {
+            coulomb.collide(&mut species, &delta, &cells, dt, &mut rng);
+        }
+        let last = temperature(&species[0]);
+
+        // assertions
+        let expected_diff = expected[0] - expected[1];
+        let diff = last[0] - last[1];
+        assert!(expected_diff < 0.6 * EV_TEMP);
+        assert!((diff - expected_diff).abs() / expected_diff < 0.1);
+    }
+}
@@ -0,0 +1,362 @@
+use crate::constants::ELEC_CHARGE;
+use anyhow::{anyhow, Context};
+use std::path::Path;
+
+/// `Process` enum
+///
+/// describes the kind of a binary collision
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Process {
+    /// elastic scattering, kinetic energy is conserved in the center of mass frame
+    Elastic,
+
+    /// excitation of the target, the threshold energy is lost
+    Excitation,
+
+    /// ionization of the target, the threshold energy is lost and an electron ion pair is created
+    Ionization,
+}
+
+/// `CrossSection` struct
+///
+/// describes a tabulated cross section of a single collision process
+#[derive(Debug, Clone, PartialEq)]
+pub struct CrossSection {
+    /// kind of collision process
+    pub process: Process,
+
+    /// (J) energy lost by the colliding pair
+    pub threshold: f64,
+
+    /// (J) tabulated center of mass energies, strictly increasing
+    energy: Vec<f64>,
+
+    /// (m^2) tabulated cross sections
+    sigma: Vec<f64>,
+}
+
+impl CrossSection {
+    /// `CrossSection` constructor
+    ///
+    /// # Arguments
+    /// - `process`: Process kind of collision process
+    /// - `threshold`: f64 (J) energy lost by the colliding pair
+    /// - `energy`: Vec<f64> (J) tabulated center of mass energies
+    /// - `sigma`: Vec<f64> (m^2) tabulated cross sections
+    ///
+    /// # Returns
+    /// `Result<CrossSection, anyhow::Error>`
+    ///
+    /// # Errors
+    /// - `threshold` is negative
+    /// - `energy` and `sigma` differ in length or are empty
+    /// - `energy` is not strictly increasing
+    /// - any element of `sigma` is negative
+    pub fn new(
+        process: Process,
+        threshold: f64,
+        energy: Vec<f64>,
+        sigma: Vec<f64>,
+    ) -> Result<CrossSection, anyhow::Error> {
+        if threshold.is_nan() || threshold < 0.0 {
+            return Err(anyhow!(
+                "cross section threshold must be non-negative, got {threshold}"
+            ));
+        }
+
+        if energy.is_empty() || energy.len() != sigma.len() {
+            return Err(anyhow!(
+                "cross section must have equal and non-zero numbers of energies and values, got {} and {}",
+                energy.len(),
+                sigma.len()
+            ));
+        }
+
+        if energy.windows(2).any(|w| w[1].is_nan() || w[1] <= w[0]) {
+            return Err(anyhow!(
+                "cross section energies must be strictly increasing"
+            ));
+        }
+
+        if sigma.iter().any(|s| s.is_nan() || *s < 0.0) {
+            return Err(anyhow!("cross section values must be non-negative"));
+        }
+
+        Ok(CrossSection {
+            process,
+            threshold,
+            energy,
+            sigma,
+        })
+    }
+
+    /// returns tabulated energies of `CrossSection`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `&[f64]` (J) tabulated center of mass energies
+    ///
+    /// # Errors
+    ///
+    pub fn energy(&self) -> &[f64] {
+        &self.energy
+    }
+
+    /// returns tabulated cross sections of `CrossSection`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `&[f64]` (m^2) cross section at every tabulated energy
+    ///
+    /// # Errors
+    ///
+    pub fn sigma(&self) -> &[f64] {
+        &self.sigma
+    }
+
+    /// evaluates `CrossSection` at a given energy using linear interpolation
+    ///
+    /// cross sections are zero below the threshold and outside of the tabulated energy range
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `energy`: f64 (J) center of mass energy
+    ///
+    /// # Returns
+    /// `f64` (m^2) cross section
+    ///
+    /// # Errors
+    ///
+    pub fn value(&self, energy: f64) -> f64 {
+        let last = self.energy.len() - 1;
+        if energy < self.threshold || energy < self.energy[0] || energy > self.energy[last] {
+            return 0.0;
+        }
+
+        // first tabulated energy above `energy`
+        let upper = self.energy.partition_point(|e| *e <= energy);
+        if upper > last {
+            return self.sigma[last];
+        }
+
+        let frac =
+            (energy - self.energy[upper - 1]) / (self.energy[upper] - self.energy[upper - 1]);
+        self.sigma[upper - 1] + frac * (self.sigma[upper] - self.sigma[upper - 1])
+    }
+}
+
+/// parses cross sections from the lxcat text format
+///
+/// `ELASTIC`, `EFFECTIVE`, `EXCITATION`, and `IONIZATION` blocks are parsed, effective momentum transfer cross
+/// sections are treated as elastic, all other blocks are ignored, energies are converted from eV to J
+///
+/// # Arguments
+/// - `text`: &str contents of lxcat file
+///
+/// # Returns
+/// `Result<Vec<CrossSection>, anyhow::Error>`
+///
+/// # Errors
+/// - any block is truncated
+/// - threshold or any table row of a block could not be parsed
+/// - call to `CrossSection::new()` fails
+pub fn parse_lxcat(text: &str) -> Result<Vec<CrossSection>, anyhow::Error> {
+    let mut lines = text.lines().enumerate();
+    let mut cross_sections = Vec::new();
+
+    while let Some((number, line)) = lines.next() {
+        let process = match line.trim() {
+            "ELASTIC" | "EFFECTIVE" => Process::Elastic,
+            "EXCITATION" => Process::Excitation,
+            "IONIZATION" => Process::Ionization,
+            _ => continue,
+        };
+
+        // target line followed by parameter line, which holds the threshold for inelastic processes
+        let truncated = || anyhow!("lxcat block starting on line {} is truncated", number + 1);
+        lines.next().ok_or_else(truncated)?;
+        let (parameter_number, parameter) = lines.next().ok_or_else(truncated)?;
+        let threshold = match process {
+            Process::Elastic => 0.0,
+            _ => {
+                let value = parameter.split_whitespace().next().unwrap_or("");
+                value.parse::<f64>().with_context(|| {
+                    format!(
+                        "could not parse threshold `{value}` on line {}",
+                        parameter_number + 1
+                    )
+                })? * ELEC_CHARGE
+            }
+        };
+
+        // skip comment lines up to table
+        loop {
+            let (_, line) = lines.next().ok_or_else(truncated)?;
+            if line.trim_start().starts_with("-----") {
+                break;
+            }
+        }
+
+        // table of energies in eV and cross sections in m^2
+        let (mut energy, mut sigma) = (Vec::new(), Vec::new());
+        loop {
+            let (row_number, line) = lines.next().ok_or_else(truncated)?;
+            if line.trim_start().starts_with("-----") {
+                break;
+            }
+
+            let mut values = line.split_whitespace().map(|v| v.parse::<f64>());
+            match (values.next(), values.next()) {
+                (Some(Ok(e)), Some(Ok(s))) => {
+                    energy.push(e * ELEC_CHARGE);
+                    sigma.push(s);
+                }
+                _ => {
+                    return Err(anyhow!(
+                        "could not parse lxcat table row `{line}` on line {}",
+                        row_number + 1
+                    ))
+                }
+            }
+        }
+
+        cross_sections.push(
+            CrossSection::new(process, threshold, energy, sigma)
+                .with_context(|| format!("invalid lxcat block on line {}", number + 1))?,
+        );
+    }
+
+    Ok(cross_sections)
+}
+
+/// loads cross sections from a file in the lxcat text format
+///
+/// # Arguments
+/// - `path`: &Path lxcat file
+///
+/// # Returns
+/// `Result<Vec<CrossSection>, anyhow::Error>`
+///
+/// # Errors
+/// - file could not be read
+/// - call to `parse_lxcat()` fails
+pub fn load_lxcat(path: &Path) -> Result<Vec<CrossSection>, anyhow::Error> {
+    let text = std::fs::read_to_string(path)
+        .with_context(|| format!("could not read {}", path.display()))?;
+    parse_lxcat(&text).with_context(|| format!("could not parse {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::collisions::cross_section::{parse_lxcat, CrossSection, Process};
+    use crate::constants::ELEC_CHARGE;
+
+    /// lxcat formatted test data
+    const LXCAT: &str = "\
+xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx
+DATABASE:         test
+EFFECTIVE
+Ar
+ 1.360000e-5
+SPECIES: e / Ar
+PROCESS: E + Ar -> E + Ar, Effective
+COLUMNS: Energy (eV) | Cross section (m2)
+-----------------------------
+ 0.000000e+0	7.500000e-20
+ 1.000000e+0	1.500000e-20
+ 1.000000e+1	1.500000e-19
+-----------------------------
+
+ATTACHMENT
+Ar
+-----------------------------
+ 0.000000e+0	1.000000e-20
+-----------------------------
+
+EXCITATION
+Ar -> Ar*(11.5eV)
+ 1.150000e+1  1.000000e+0
+SPECIES: e / Ar
+-----------------------------
+ 1.150000e+1	0.000000e+0
+ 2.000000e+1	2.000000e-21
+-----------------------------
+
+IONIZATION
+Ar -> Ar^+
+ 1.576000e+1
+-----------------------------
+ 1.576000e+1	0.000000e+0
+ 1.000000e+2	2.800000e-20
+-----------------------------
+xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx
+";
+
+    /// tests `CrossSection::new()` for failure on invalid input
+    ///
+    /// # Errors
+    /// - `CrossSection::new()` succeeds for negative threshold
+    /// - `CrossSection::new()` succeeds for mismatched table lengths
+    /// - `CrossSection::new()` succeeds for non-increasing energies
+    /// - `CrossSection::new()` succeeds for negative cross sections
+    ///
+    #[test]
+    fn new_failure() {
+        assert!(CrossSection::new(Process::Elastic, -1.0, vec![0.0], vec![0.0]).is_err());
+        assert!(CrossSection::new(Process::Elastic, 0.0, vec![0.0, 1.0], vec![0.0]).is_err());
+        assert!(CrossSection::new(Process::Elastic, 0.0, vec![1.0, 1.0], vec![0.0, 0.0]).is_err());
+        assert!(CrossSection::new(Process::Elastic, 0.0, vec![0.0], vec![-1.0]).is_err());
+    }
+
+    /// tests `CrossSection::value()` for correct interpolation
+    ///
+    /// # Errors
+    /// - `CrossSection::value()` is incorrect between tabulated energies
+    /// - `CrossSection::value()` is non-zero outside of tabulated energies or below threshold
+    ///
+    #[test]
+    fn value_correct() {
+        let cross_section = CrossSection::new(
+            Process::Excitation,
+            1.5,
+            vec![1.0, 2.0, 4.0],
+            vec![1.0, 3.0, 2.0],
+        )
+        .unwrap();
+
+        assert_eq!(cross_section.value(2.0), 3.0);
+        assert_eq!(cross_section.value(3.0), 2.5);
+        assert_eq!(cross_section.value(4.0), 2.0);
+        assert_eq!(cross_section.value(1.2), 0.0);
+        assert_eq!(cross_section.value(4.1), 0.0);
+    }
+
+    /// tests `parse_lxcat()` for correct parsing of supported blocks
+    ///
+    /// # Errors
+    /// - incorrect number of cross sections is parsed
+    /// - process, threshold, or table of any cross section is incorrect
+    /// - `parse_lxcat()` succeeds for a truncated block
+    ///
+    #[test]
+    fn parse_lxcat_correct() {
+        let cross_sections = parse_lxcat(LXCAT).unwrap();
+
+        assert_eq!(cross_sections.len(), 3);
+        assert_eq!(cross_sections[0].process, Process::Elastic);
+        assert_eq!(cross_sections[0].threshold, 0.0);
+        assert_eq!(cross_sections[0].energy().len(), 3);
+        assert_eq!(cross_sections[1].process, Process::Excitation);
+        assert_eq!(cross_sections[1].threshold, 11.5 * ELEC_CHARGE);
+        assert_eq!(cross_sections[2].process, Process::Ionization);
+        assert_eq!(cross_sections[2].threshold, 15.76 * ELEC_CHARGE);
+        assert_eq!(cross_sections[2].value(100.0 * ELEC_CHARGE), 2.8e-20);
+
+        let truncated = &LXCAT[..LXCAT.find("IONIZATION").unwrap() + 30];
+        assert!(parse_lxcat(truncated).is_err());
+    }
+}
@@ -0,0 +1,527 @@
+use crate::collisions::{bin, scatter, stochastic_round};
+use crate::constants::BOLTZMANN;
+use crate::precision::Real;
+use crate::species::Species;
+use crate::utils::coordinate_triplet::CoordinateTriplet;
+use crate::utils::rng::Rng;
+use anyhow::anyhow;
+use std::f64::consts::PI;
+
+/// `Molecule` struct
+///
+/// describes the variable hard sphere (vhs) model of a neutral species
+#[derive(Debug, Clone, PartialEq)]
+pub struct Molecule {
+    /// name of neutral species
+    pub species: String,
+
+    /// (m) reference diameter
+    pub diameter: f64,
+
+    /// viscosity temperature exponent, 0.5 recovers hard spheres
+    pub omega: f64,
+
+    /// (K) reference temperature of `diameter`
+    pub reference_temperature: f64,
+}
+
+impl Molecule {
+    /// `Molecule` constructor for a hard sphere
+    ///
+    /// # Arguments
+    /// - `species`: &str name of neutral species
+    /// - `diameter`: f64 (m) diameter
+    ///
+    /// # Returns
+    /// `Molecule`
+    ///
+    /// # Errors
+    ///
+    pub fn hard_sphere(species: &str, diameter: f64) -> Molecule {
+        Molecule {
+            species: species.to_string(),
+            diameter,
+            omega: 0.5,
+            reference_temperature: 273.0,
+        }
+    }
+}
+
+/// `DsmcConfig` struct
+///
+/// describes direct simulation monte carlo collisions between neutral species
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DsmcConfig {
+    /// molecular models of colliding neutral species, empty disables dsmc
+    pub molecules: Vec<Molecule>,
+}
+
+/// `Interaction` struct
+///
+/// describes the vhs interaction of a pair of neutral species
+#[derive(Debug, Clone)]
+struct Interaction {
+    /// indices of colliding species, intra-species pairs have equal indices
+    species: (usize, usize),
+
+    /// cross section times relative speed is `coefficient * g^exponent`
+    coefficient: f64,
+
+    /// exponent of relative speed
+    exponent: f64,
+}
+
+/// `Dsmc` struct
+///
+/// performs direct simulation monte carlo collisions between neutral macro-particles sharing a cell using the no time
+/// counter scheme
+///
+/// the maximum of cross section times relative speed is bounded in every cell from the largest peculiar speed, so that
+/// no state has to be carried between time steps
+#[derive(Debug, Clone)]
+pub struct Dsmc {
+    /// interactions of all pairs of neutral species
+    interactions: Vec<Interaction>,
+}
+
+impl Dsmc {
+    /// `Dsmc` constructor
+    ///
+    /// the parameters of inter-species interactions are the means of the parameters of both species
+    ///
+    /// # Arguments
+    /// - `config`: &DsmcConfig dsmc configuration
+    /// - `species`: &[Species<P>] species present in model
+    ///
+    /// # Returns
+    /// `Result<Dsmc, anyhow::Error>`
+    ///
+    /// # Errors
+    /// - any molecule refers to an unknown or charged species
+    /// - any molecule has a non-positive diameter or reference temperature
+    /// - any molecule has a viscosity temperature exponent outside of [0.5, 1]
+    /// - any two neutral species have different weights
+    pub fn new<P>(config: &DsmcConfig, species: &[Species<P>]) -> Result<Dsmc, anyhow::Error> {
+        let mut indices = Vec::new();
+        for molecule in config.molecules.iter() {
+            let s = species
+                .iter()
+                .position(|s| s.name == molecule.species)
+                .ok_or_else(|| {
+                    anyhow!("molecule refers to unknown species `{}`", molecule.species)
+                })?;
+
+            if species[s].charge != 0.0 {
+                return Err(anyhow!(
+                    "dsmc is only supported for neutral species, `{}` is charged",
+                    molecule.species
+                ));
+            }
+
+            if molecule.diameter.is_nan() || molecule.diameter <= 0.0 {
+                return Err(anyhow!(
+                    "molecule `{}` diameter must be positive, got {}",
+                    molecule.species,
+                    molecule.diameter
+                ));
+            }
+
+            if molecule.reference_temperature.is_nan() || molecule.reference_temperature <= 0.0 {
+                return Err(anyhow!(
+                    "molecule `{}` reference temperature must be positive, got {}",
+                    molecule.species,
+                    molecule.reference_temperature
+                ));
+            }
+
+            if !(0.5..=1.0).contains(&molecule.omega) {
+                return Err(anyhow!(
+                    "molecule `{}` viscosity temperature exponent must lie on [0.5, 1], got {}",
+                    molecule.species,
+                    molecule.omega
+                ));
+            }
+
+            indices.push(s);
+        }
+
+        let mut interactions = Vec::new();
+        for (n, (a, ma)) in indices.iter().zip(config.molecules.iter()).enumerate() {
+            for (b, mb) in indices[n..].iter().zip(config.molecules[n..].iter()) {
+                // pairs of unequal weight would only conserve momentum and energy on average
+                if species[*a].weight != species[*b].weight {
+                    return Err(anyhow!(
+                        "dsmc requires equal weights, species `{}` and `{}` have weights {} and {}",
+                        species[*a].name,
+                        species[*b].name,
+                        species[*a].weight,
+                        species[*b].weight
+                    ));
+                }
+
+                let diameter = 0.5 * (ma.diameter + mb.diameter);
+                let omega = 0.5 * (ma.omega + mb.omega);
+                let temperature = 0.5 * (ma.reference_temperature + mb.reference_temperature);
+                let reduced_mass =
+                    species[*a].mass * species[*b].mass / (species[*a].mass + species[*b].mass);
+
+                interactions.push(Interaction {
+                    species: (*a, *b),
+                    coefficient: PI
+                        * diameter
+                        * diameter
+                        * (2.0 * BOLTZMANN * temperature / reduced_mass).powf(omega - 0.5)
+                        / gamma(2.5 - omega),
+                    exponent: 2.0 - 2.0 * omega,
+                });
+            }
+        }
+
+        Ok(Dsmc { interactions })
+    }
+
+    /// performs dsmc collisions of all neutral species over a single time step
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `species`: &mut [Species<P>] species present in model
+    /// - `delta`: &CoordinateTriplet<f64> (m) spatial increment
+    /// - `cells`: &CoordinateTriplet<usize> number of cells in bounding box
+    /// - `dt`: f64 (s) time step
+    /// - `rng`: &mut Rng random number generator
+    ///
+    /// # Returns
+    /// `usize` number of accepted collisions
+    ///
+    /// # Errors
+    ///
+    pub fn collide<P: Real>(
+        &self,
+        species: &mut [Species<P>],
+        delta: &CoordinateTriplet<f64>,
+        cells: &CoordinateTriplet<usize>,
+        dt: f64,
+        rng: &mut Rng,
+    ) -> usize {
+        let bins: Vec<Vec<Vec<usize>>> = species
+            .iter()
+            .map(|s| bin(&s.particles, delta, cells))
+            .collect();
+
+        // (m^3) volume of a cell
+        let volume = delta.x * delta.y * delta.z;
+
+        let mut collisions = 0;
+        for interaction in self.interactions.iter() {
+            let (a, b) = interaction.species;
+            let (ma, mb) = (species[a].mass, species[b].mass);
+            let weight = species[a].weight;
+
+            for (la, lb) in bins[a].iter().zip(bins[b].iter()) {
+                // number of distinct pairs
+                let pairs = if a == b {
+                    0.5 * la.len() as f64 * (la.len() as f64 - 1.0)
+                } else {
+                    la.len() as f64 * lb.len() as f64
+                };
+                if pairs < 1.0 {
+                    continue;
+                }
+
+                // bound of relative speed from largest peculiar speed of both species
+                let velocities = la
+                    .iter()
+                    .map(|i| species[a].particles[*i].velocity.map(P::widen))
+                    .chain(
+                        lb.iter()
+                            .map(|j| species[b].particles[*j].velocity.map(P::widen)),
+                    );
+                let count = (la.len() + lb.len()) as f64;
+                let mean = velocities.clone().fold([0.0; 3], |mean, v| {
+                    [
+                        mean[0] + v[0] / count,
+                        mean[1] + v[1] / count,
+                        mean[2] + v[2] / count,
+                    ]
+                });
+                let c_max = velocities
+                    .map(|v| {
+                        let c = [v[0] - mean[0], v[1] - mean[1], v[2] - mean[2]];
+                        c[0] * c[0] + c[1] * c[1] + c[2] * c[2]
+                    })
+                    .fold(0.0, f64::max)
+                    .sqrt();
+                let max_rate = interaction.coefficient * (2.0 * c_max).powf(interaction.exponent);
+                if max_rate == 0.0 {
+                    continue;
+                }
+
+                // no time counter number of candidate pairs
+                let candidates = stochastic_round(pairs * weight * max_rate * dt / volume, rng);
+
+                for _ in 0..candidates {
+                    let (i, j) = if a == b {
+                        let i = ((rng.uniform() * la.len() as f64) as usize).min(la.len() - 1);
+                        let j =
+                            ((rng.uniform() * (la.len() - 1) as f64) as usize).min(la.len() - 2);
+                        (la[i], la[if j >= i { j + 1 } else { j }])
+                    } else {
+                        let i = ((rng.uniform() * la.len() as f64) as usize).min(la.len() - 1);
+                        let j = ((rng.uniform() * lb.len() as f64) as usize).min(lb.len() - 1);
+                        (la[i], lb[j])
+                    };
+
+                    let va = species[a].particles[i].velocity.map(P::widen);
+                    let vb = species[b].particles[j].velocity.map(P::widen);
+                    let g = [va[0] - vb[0], va[1] - vb[1], va[2] - vb[2]];
+                    let g_mag = (g[0] * g[0] + g[1] * g[1] + g[2] * g[2]).sqrt();
+
+                    // accept candidate with probability proportional to cross section times relative speed
+                    let rate = interaction.coefficient * g_mag.powf(interaction.exponent);
+                    if rng.uniform() * max_rate >= rate {
+                        continue;
+                    }
+
+                    // isotropic scattering in center of mass frame
+                    let v_cm = [
+                        (ma * va[0] + mb * vb[0]) / (ma + mb),
+                        (ma * va[1] + mb * vb[1]) / (ma + mb),
+                        (ma * va[2] + mb * vb[2]) / (ma + mb),
+                    ];
+                    let va = scatter(&v_cm, mb / (ma + mb) * g_mag, rng);
+                    let vb = [
+                        v_cm[0] - ma / mb * (va[0] - v_cm[0]),
+                        v_cm[1] - ma / mb * (va[1] - v_cm[1]),
+                        v_cm[2] - ma / mb * (va[2] - v_cm[2]),
+                    ];
+                    species[a].particles[i].velocity = va.map(P::narrow);
+                    species[b].particles[j].velocity = vb.map(P::narrow);
+                    collisions += 1;
+                }
+            }
+        }
+
+        collisions
+    }
+}
+
+/// evaluates the gamma function using the lanczos approximation
+///
+/// # Arguments
+/// - `x`: f64 positive argument
+///
+/// # Returns
+/// `f64`
+///
+/// # Errors
+///
+fn gamma(x: f64) -> f64 {
+    const G: f64 = 7.0;
+    const COEFFICIENTS: [f64; 9] = [
+        0.999_999_999_999_809_9,
+        676.520_368_121_885_1,
+        -1_259.139_216_722_402_8,
+        771.323_428_777_653_1,
+        -176.615_029_162_140_6,
+        12.507_343_278_686_905,
+        -0.138_571_095_265_720_12,
+        9.984_369_578_019_572e-6,
+        1.505_632_735_149_311_6e-7,
+    ];
+
+    let x = x - 1.0;
+    let t = x + G + 0.5;
+    let sum = COEFFICIENTS[1..]
+        .iter()
+        .enumerate()
+        .fold(COEFFICIENTS[0], |sum, (i, c)| {
+            sum + c / (x + i as f64 + 1.0)
+        });
+
+    (2.0 * PI).sqrt() * t.powf(x + 0.5) * (-t).exp() * sum
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::collisions::dsmc::{gamma, Dsmc, DsmcConfig, Molecule};
+    use crate::constants::{AMU, BOLTZMANN, ELEC_CHARGE};
+    use crate::species::{Particle, Species};
+    use crate::utils::coordinate_triplet::CoordinateTriplet;
+    use crate::utils::rng::Rng;
+    use std::f64::consts::PI;
+
+    /// helper function that returns argon macro-particles with given velocity scales in a single cell
+    ///
+    /// # Arguments
+    /// - `name`: &str name of species
+    /// - `count`: usize number of macro-particles
+    /// - `v_th`: [f64; 3] (m/s) standard deviation of velocity along each axis
+    /// - `rng`: &mut Rng random number generator
+    ///
+    /// # Returns
+    /// `Result<Species, anyhow::Error>`
+    ///
+    /// # Errors
+    /// - call to `Species::new()` fails
+    fn argon(
+        name: &str,
+        count: usize,
+        v_th: [f64; 3],
+        rng: &mut Rng,
+    ) -> Result<Species, anyhow::Error> {
+        let mut species = Species::new(name, 0.0, 39.948 * AMU, 0.0, 0.0, 1e20 / 1e4)?;
+        species.particles = (0..count)
+            .map(|_| Particle {
+                position: [0.5, 0.5, 0.5],
+                velocity: [
+                    rng.normal() * v_th[0],
+                    rng.normal() * v_th[1],
+                    rng.normal() * v_th[2],
+                ],
+            })
+            .collect();
+        Ok(species)
+    }
+
+    /// tests `gamma()` for correct values
+    ///
+    /// # Errors
+    /// - `gamma()` is incorrect for integer or half-integer arguments
+    ///
+    #[test]
+    fn gamma_correct() {
+        assert!((gamma(1.0) - 1.0).abs() < 1e-12);
+        assert!((gamma(2.0) - 1.0).abs() < 1e-12);
+        assert!((gamma(1.5) - 0.5 * PI.sqrt()).abs() < 1e-12);
+        assert!((gamma(5.0) - 24.0).abs() < 1e-10);
+    }
+
+    /// tests `Dsmc::new()` for failure on invalid input
+    ///
+    /// # Errors
+    /// - `Dsmc::new()` succeeds for an unknown or charged species
+    /// - `Dsmc::new()` succeeds for an invalid viscosity temperature exponent
+    ///
+    #[test]
+    fn new_failure() {
+        let mut rng = Rng::new(0);
+        let neutral = argon("argon", 0, [0.0; 3], &mut rng).unwrap();
+        let ion = Species::new("argon+", ELEC_CHARGE, 39.948 * AMU, 0.0, 0.0, 1.0).unwrap();
+        let species = [neutral, ion];
+
+        let config = |molecule: Molecule| DsmcConfig {
+            molecules: vec![molecule],
+        };
+        assert!(Dsmc::new(&config(Molecule::hard_sphere("argon", 4e-10)), &species).is_ok());
+        assert!(Dsmc::new(&config(Molecule::hard_sphere("xenon", 4e-10)), &species).is_err());
+        assert!(Dsmc::new(&config(Molecule::hard_sphere("argon+", 4e-10)), &species).is_err());
+        assert!(Dsmc::new(
+            &config(Molecule {
+                omega: 0.4,
+                ..Molecule::hard_sphere("argon", 4e-10)
+            }),
+            &species
+        )
+        .is_err());
+    }
+
+    /// tests `Dsmc::collide()` for the equilibrium hard sphere collision rate `0.5 N n sigma <g>`
+    ///
+    /// # Errors
+    /// - number of collisions deviates from equilibrium collision rate by more than five percent
+    ///
+    #[test]
+    fn collide_rate() {
+        // setup
+        let mut rng = Rng::new(0);
+        let temperature = 300.0;
+        let mass = 39.948 * AMU;
+        let v_th = (BOLTZMANN * temperature / mass).sqrt();
+        let mut species = vec![argon("argon", 10000, [v_th; 3], &mut rng).unwrap()];
+        let diameter = 4e-10;
+        let dsmc = Dsmc::new(
+            &DsmcConfig {
+                molecules: vec![Molecule::hard_sphere("argon", diameter)],
+            },
+            &species,
+        )
+        .unwrap();
+        let cells = CoordinateTriplet::new(3, 3, 3).unwrap();
+        let delta = CoordinateTriplet::new(1.0, 1.0, 1.0).unwrap();
+        let dt = 1e-7;
+        let steps = 20;
+
+        let mut collisions = 0;
+        for _ in 0..steps {
+            collisions += dsmc.collide(&mut species, &delta, &cells, dt, &mut rng);
+        }
+
+        // assertions
+        let g_mean = (16.0 * BOLTZMANN * temperature / (PI * mass)).sqrt();
+        let expected = 0.5 * 10000.0 * 1e20 * PI * diameter * diameter * g_mean * dt * steps as f64;
+        assert!((collisions as f64 - expected).abs() / expected < 0.05);
+    }
+
+    /// tests `Dsmc::collide()` for relaxation of an anisotropic mixture to equilibrium with conservation of momentum
+    /// and energy to round-off
+    ///
+    /// # Errors
+    /// - total momentum or kinetic energy changes
+    /// - temperatures along each axis do not relax to within two percent of each other
+    ///
+    #[test]
+    fn collide_relaxation() {
+        // setup
+        let mut rng = Rng::new(2);
+        let mut species = vec![
+            argon("argon", 3001, [400.0, 200.0, 200.0], &mut rng).unwrap(),
+            argon("tracer", 1000, [200.0, 200.0, 400.0], &mut rng).unwrap(),
+        ];
+        species[1].mass *= 2.0;
+        let dsmc = Dsmc::new(
+            &DsmcConfig {
+                molecules: vec![
+                    Molecule::hard_sphere("argon", 4e-10),
+                    Molecule {
+                        omega: 0.81,
+                        ..Molecule::hard_sphere("tracer", 5e-10)
+                    },
+                ],
+            },
+            &species,
+        )
+        .unwrap();
+        let cells = CoordinateTriplet::new(3, 3, 3).unwrap();
+        let delta = CoordinateTriplet::new(1.0, 1.0, 1.0).unwrap();
+
+        let moments = |species: &[Species]| {
+            // momentum, kinetic energy along each axis, and sum of momentum magnitudes
+            let mut moments = [0.0; 7];
+            for s in species.iter() {
+                for p in s.particles.iter() {
+                    for d in 0..3 {
+                        moments[d] += s.mass * p.velocity[d];
+                        moments[3 + d] += 0.5 * s.mass * p.velocity[d] * p.velocity[d];
+                        moments[6] += s.mass * p.velocity[d].abs();
+                    }
+                }
+            }
+            moments
+        };
+        let initial = moments(&species);
+        for _ in 0..100 {
+            dsmc.collide(&mut species, &delta, &cells, 1e-5, &mut rng);
+        }
+        let last = moments(&species);
+
+        // assertions
+        for d in 0..3 {
+            assert!((last[d] - initial[d]).abs() / initial[6] < 1e-12);
+        }
+        let energy = |m: &[f64; 7]| m[3] + m[4] + m[5];
+        assert!((energy(&last) - energy(&initial)).abs() / energy(&initial) < 1e-12);
+        for d in 0..3 {
+            assert!((last[3 + d] - energy(&last) / 3.0).abs() / energy(&last) < 2e-2);
+        }
+    }
+}
@@ -0,0 +1,648 @@
+use crate::collisions::cross_section::{CrossSection, Process};
+use crate::collisions::{scatter, stochastic_round};
+use crate::constants::BOLTZMANN;
+use crate::engine::{interpolate, linear_weights};
+use crate::field::scalar::ScalarField;
+use crate::precision::Real;
+use crate::species::{Particle, Species};
+use crate::utils::coordinate_triplet::CoordinateTriplet;
+use crate::utils::rng::Rng;
+use anyhow::anyhow;
+
+/// `GasDensity` enum
+///
+/// describes the number density of a background gas
+#[derive(Debug, Clone, PartialEq)]
+pub enum GasDensity {
+    /// (m^-3) uniform number density
+    Uniform(f64),
+
+    /// (m^-3) number density on nodes of the grid, linearly interpolated to macro-particles
+    Profile(ScalarField<f64>),
+}
+
+/// `BackgroundGas` struct
+///
+/// describes a stationary neutral background gas with a maxwellian velocity distribution
+#[derive(Debug, Clone, PartialEq)]
+pub struct BackgroundGas {
+    /// (kg) mass of a single gas particle
+    pub mass: f64,
+
+    /// (K) temperature
+    pub temperature: f64,
+
+    /// (m^-3) number density
+    pub density: GasDensity,
+}
+
+/// `Reaction` struct
+///
+/// describes a collision process between a species and the background gas
+#[derive(Debug, Clone, PartialEq)]
+pub struct Reaction {
+    /// name of colliding species
+    pub species: String,
+
+    /// cross section of collision process
+    pub cross_section: CrossSection,
+}
+
+/// `MccConfig` struct
+///
+/// describes monte carlo collisions with a background gas, the time step should be small compared to the inverse of
+/// the maximum total collision frequency of every colliding species for the null collision method to be accurate
+#[derive(Debug, Clone, PartialEq)]
+pub struct MccConfig {
+    /// background gas, `None` disables monte carlo collisions
+    pub gas: Option<BackgroundGas>,
+
+    /// collision processes
+    pub reactions: Vec<Reaction>,
+
+    /// name of species receiving electrons created by ionization
+    pub electron: String,
+
+    /// name of species receiving ions created by ionization
+    pub ion: String,
+}
+
+impl Default for MccConfig {
+    /// default `MccConfig`
+    ///
+    /// monte carlo collisions are disabled
+    ///
+    /// # Arguments
+    ///
+    /// # Returns
+    /// `MccConfig`
+    ///
+    /// # Errors
+    ///
+    fn default() -> MccConfig {
+        MccConfig {
+            gas: None,
+            reactions: Vec::new(),
+            electron: String::from("electron"),
+            ion: String::from("ion"),
+        }
+    }
+}
+
+/// `Channel` struct
+///
+/// describes all collision processes of a single species
+#[derive(Debug, Clone)]
+struct Channel {
+    /// index of colliding species
+    species: usize,
+
+    /// cross sections of colliding species
+    cross_sections: Vec<CrossSection>,
+
+    /// (kg) reduced mass of colliding pair
+    reduced_mass: f64,
+
+    /// (s^-1) upper bound of total collision frequency used by the null collision method
+    max_frequency: f64,
+}
+
+/// `Mcc` struct
+///
+/// performs monte carlo collisions with a background gas using the null collision method
+#[derive(Debug, Clone)]
+pub struct Mcc {
+    /// background gas
+    gas: BackgroundGas,
+
+    /// collision processes grouped by colliding species
+    channels: Vec<Channel>,
+
+    /// index of species receiving electrons created by ionization
+    electron: Option<usize>,
+
+    /// index of species receiving ions created by ionization
+    ion: Option<usize>,
+}
+
+impl Mcc {
+    /// `Mcc` constructor
+    ///
+    /// # Arguments
+    /// - `gas`: &BackgroundGas background gas
+    /// - `config`: &MccConfig monte carlo collision configuration
+    /// - `species`: &[Species<P>] species present in model
+    /// - `cells`: &CoordinateTriplet<usize> number of cells in bounding box
+    ///
+    /// # Returns
+    /// `Result<Mcc, anyhow::Error>`
+    ///
+    /// # Errors
+    /// - gas mass, temperature, or density is invalid
+    /// - gas density profile does not match grid
+    /// - any reaction refers to an unknown species
+    /// - ionization is configured for a species other than the electron species
+    /// - ionization is configured and electron or ion species are unknown
+    pub fn new<P>(
+        gas: &BackgroundGas,
+        config: &MccConfig,
+        species: &[Species<P>],
+        cells: &CoordinateTriplet<usize>,
+    ) -> Result<Mcc, anyhow::Error> {
+        if gas.mass.is_nan() || gas.mass <= 0.0 {
+            return Err(anyhow!("gas mass must be positive, got {}", gas.mass));
+        }
+
+        if gas.temperature.is_nan() || gas.temperature < 0.0 {
+            return Err(anyhow!(
+                "gas temperature must be non-negative, got {}",
+                gas.temperature
+            ));
+        }
+
+        let max_density = match &gas.density {
+            GasDensity::Uniform(density) => *density,
+            GasDensity::Profile(profile) => {
+                if profile.cells() != cells {
+                    return Err(anyhow!(
+                        "gas density profile must match grid of {}x{}x{} nodes",
+                        cells.x,
+                        cells.y,
+                        cells.z
+                    ));
+                }
+                profile.iter().fold(0.0, |max: f64, n| max.max(*n))
+            }
+        };
+        let negative = match &gas.density {
+            GasDensity::Uniform(density) => density.is_nan() || *density < 0.0,
+            GasDensity::Profile(profile) => profile.iter().any(|n| n.is_nan() || *n < 0.0),
+        };
+        if negative {
+            return Err(anyhow!("gas density must be non-negative"));
+        }
+
+        let index = |name: &str| species.iter().position(|s| s.name == name);
+        let electron = index(&config.electron);
+        let ion = index(&config.ion);
+
+        // group cross sections by colliding species
+        let mut channels: Vec<Channel> = Vec::new();
+        for reaction in config.reactions.iter() {
+            let s = index(&reaction.species).ok_or_else(|| {
+                anyhow!("reaction refers to unknown species `{}`", reaction.species)
+            })?;
+
+            if reaction.cross_section.process == Process::Ionization {
+                if electron != Some(s) {
+                    return Err(anyhow!(
+                        "ionization is only supported for electron species `{}`, got `{}`",
+                        config.electron,
+                        reaction.species
+                    ));
+                }
+                if ion.is_none() {
+                    return Err(anyhow!("ionization requires ion species `{}`", config.ion));
+                }
+            }
+
+            match channels.iter_mut().find(|c| c.species == s) {
+                Some(channel) => channel.cross_sections.push(reaction.cross_section.clone()),
+                None => channels.push(Channel {
+                    species: s,
+                    cross_sections: vec![reaction.cross_section.clone()],
+                    reduced_mass: species[s].mass * gas.mass / (species[s].mass + gas.mass),
+                    max_frequency: 0.0,
+                }),
+            }
+        }
+
+        for channel in channels.iter_mut() {
+            channel.max_frequency =
+                max_density * max_rate(&channel.cross_sections, channel.reduced_mass);
+        }
+
+        Ok(Mcc {
+            gas: gas.clone(),
+            channels,
+            electron,
+            ion,
+        })
+    }
+
+    /// performs monte carlo collisions of all species with the background gas over a single time step
+    ///
+    /// macro-particles created by ionization are appended to the electron and ion species and do not collide until
+    /// the next time step
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `species`: &mut [Species<P>] species present in model
+    /// - `delta`: &CoordinateTriplet<f64> (m) spatial increment
+    /// - `cells`: &CoordinateTriplet<usize> number of cells in bounding box
+    /// - `dt`: f64 (s) time step
+    /// - `rng`: &mut Rng random number generator
+    ///
+    /// # Returns
+    ///
+    /// # Errors
+    ///
+    pub fn collide<P: Real>(
+        &self,
+        species: &mut [Species<P>],
+        delta: &CoordinateTriplet<f64>,
+        cells: &CoordinateTriplet<usize>,
+        dt: f64,
+        rng: &mut Rng,
+    ) {
+        let mut electrons: Vec<Particle<P>> = Vec::new();
+        let mut ions: Vec<Particle<P>> = Vec::new();
+        let weights: Vec<f64> = species.iter().map(|s| s.weight).collect();
+
+        // (m/s) thermal velocity of gas
+        let u_th = (BOLTZMANN * self.gas.temperature / self.gas.mass).sqrt();
+
+        for channel in self.channels.iter() {
+            // probability of a null or real collision of a single macro-particle
+            let p_null = 1.0 - (-channel.max_frequency * dt).exp();
+            if p_null == 0.0 {
+                continue;
+            }
+
+            let s = &mut species[channel.species];
+            let (mass, weight) = (s.mass, s.weight);
+            let (gas_frac, proj_frac) = (
+                self.gas.mass / (mass + self.gas.mass),
+                mass / (mass + self.gas.mass),
+            );
+
+            for particle in s.particles.iter_mut() {
+                if rng.uniform() >= p_null {
+                    continue;
+                }
+
+                // sample velocity of target gas particle
+                let u = [
+                    rng.normal() * u_th,
+                    rng.normal() * u_th,
+                    rng.normal() * u_th,
+                ];
+                let v = particle.velocity.map(P::widen);
+                let g = [v[0] - u[0], v[1] - u[1], v[2] - u[2]];
+                let g_mag = (g[0] * g[0] + g[1] * g[1] + g[2] * g[2]).sqrt();
+                let energy = 0.5 * channel.reduced_mass * g_mag * g_mag;
+
+                // select process, the remaining probability is the null collision
+                let density = match &self.gas.density {
+                    GasDensity::Uniform(density) => *density,
+                    GasDensity::Profile(profile) => {
+                        let position = particle.position.map(P::widen);
+                        let (idx, frac) = linear_weights(&position, delta, cells);
+                        interpolate(profile, idx, frac)
+                    }
+                };
+                let mut r = rng.uniform() * channel.max_frequency / (density * g_mag);
+                let Some(cross_section) = channel.cross_sections.iter().find(|c| {
+                    r -= c.value(energy);
+                    r < 0.0
+                }) else {
+                    continue;
+                };
+
+                // center of mass velocity
+                let v_cm = [
+                    proj_frac * v[0] + gas_frac * u[0],
+                    proj_frac * v[1] + gas_frac * u[1],
+                    proj_frac * v[2] + gas_frac * u[2],
+                ];
+
+                // (J) center of mass energy after collision
+                let energy = (energy - cross_section.threshold).max(0.0);
+
+                match cross_section.process {
+                    Process::Elastic | Process::Excitation => {
+                        let g_mag = (2.0 * energy / channel.reduced_mass).sqrt();
+                        particle.velocity = scatter(&v_cm, gas_frac * g_mag, rng).map(P::narrow);
+                    }
+                    Process::Ionization => {
+                        // remaining energy is shared randomly between scattered and ejected electron, the ion
+                        // retains the velocity of the target
+                        let share = rng.uniform();
+                        let g_scattered = (2.0 * share * energy / channel.reduced_mass).sqrt();
+                        let g_ejected =
+                            (2.0 * (1.0 - share) * energy / channel.reduced_mass).sqrt();
+                        particle.velocity =
+                            scatter(&v_cm, gas_frac * g_scattered, rng).map(P::narrow);
+
+                        let ejected = Particle {
+                            position: particle.position,
+                            velocity: scatter(&v_cm, gas_frac * g_ejected, rng).map(P::narrow),
+                        };
+                        let ion = Particle {
+                            position: particle.position,
+                            velocity: u.map(P::narrow),
+                        };
+
+                        // macro-particles of differing weight are created with a probability matching the number of
+                        // physical particles created
+                        if let (Some(e), Some(i)) = (self.electron, self.ion) {
+                            let n = stochastic_round(weight / weights[e], rng);
+                            electrons.extend(std::iter::repeat(ejected).take(n));
+                            let n = stochastic_round(weight / weights[i], rng);
+                            ions.extend(std::iter::repeat(ion).take(n));
+                        }
+                    }
+                }
+            }
+        }
+
+        if let (Some(e), Some(i)) = (self.electron, self.ion) {
+            species[e].particles.extend(electrons);
+            species[i].particles.extend(ions);
+        }
+    }
+}
+
+/// returns the maximum total collision rate coefficient of a set of cross sections
+///
+/// the total cross section is linear between the union of all tabulated energies and thresholds, hence the maximum
+/// of `sigma(energy) * g(energy)` lies on the bounds of a segment or on its stationary point
+///
+/// # Arguments
+/// - `cross_sections`: &[CrossSection] cross sections of a single colliding species
+/// - `reduced_mass`: f64 (kg) reduced mass of colliding pair
+///
+/// # Returns
+/// `f64` (m^3/s) maximum of total cross section times relative speed
+///
+/// # Errors
+///
+fn max_rate(cross_sections: &[CrossSection], reduced_mass: f64) -> f64 {
+    let mut energies: Vec<f64> = cross_sections
+        .iter()
+        .flat_map(|c| {
+            c.energy()
+                .iter()
+                .copied()
+                .chain(std::iter::once(c.threshold))
+        })
+        .collect();
+    energies.sort_by(f64::total_cmp);
+    energies.dedup();
+
+    let sigma = |energy: f64| -> f64 { cross_sections.iter().map(|c| c.value(energy)).sum() };
+    let rate = |sigma: f64, energy: f64| sigma * (2.0 * energy / reduced_mass).sqrt();
+
+    // tabulated energies themselves
+    let mut max = energies
+        .iter()
+        .fold(0.0, |max: f64, e| max.max(rate(sigma(*e), *e)));
+
+    // interior of segments, the line sigma = a + b * energy is sampled away from discontinuities on the bounds
+    for w in energies.windows(2) {
+        let (lower, upper) = (w[0], w[1]);
+        let (m1, m2) = (
+            lower + (upper - lower) / 3.0,
+            lower + 2.0 * (upper - lower) / 3.0,
+        );
+        let b = (sigma(m2) - sigma(m1)) / (m2 - m1);
+        let a = sigma(m1) - b * m1;
+
+        max = max
+            .max(rate(a + b * lower, lower))
+            .max(rate(a + b * upper, upper));
+
+        // (a + b * energy) * sqrt(energy) is stationary at -a / (3 * b)
+        let stationary = -a / (3.0 * b);
+        if b != 0.0 && stationary > lower && stationary < upper {
+            max = max.max(rate(a + b * stationary, stationary));
+        }
+    }
+
+    max
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::collisions::cross_section::{CrossSection, Process};
+    use crate::collisions::mcc::{BackgroundGas, GasDensity, Mcc, MccConfig, Reaction};
+    use crate::constants::{AMU, ELEC_CHARGE, ELEC_MASS};
+    use crate::field::scalar::ScalarField;
+    use crate::species::{Particle, Species};
+    use crate::utils::coordinate_triplet::CoordinateTriplet;
+    use crate::utils::rng::Rng;
+
+    /// helper function that sets up monoenergetic electrons, an empty ion species, and a cold heavy gas for testing
+    ///
+    /// # Arguments
+    /// - `speed`: f64 (m/s) speed of electrons
+    ///
+    /// # Returns
+    /// `Result<(Vec<Species>, BackgroundGas), anyhow::Error>`
+    ///
+    /// # Errors
+    /// - any call to `Species::new()` fails
+    fn setup(speed: f64) -> Result<(Vec<Species>, BackgroundGas), anyhow::Error> {
+        let mut electron = Species::new("electron", -ELEC_CHARGE, ELEC_MASS, 0.0, 0.0, 1.0)?;
+        electron.particles = vec![
+            Particle {
+                position: [0.5, 0.5, 0.5],
+                velocity: [speed, 0.0, 0.0],
+            };
+            100000
+        ];
+        let ion = Species::new("ion", ELEC_CHARGE, 1e6 * AMU, 0.0, 0.0, 1.0)?;
+
+        let gas = BackgroundGas {
+            mass: 1e6 * AMU,
+            temperature: 0.0,
+            density: GasDensity::Uniform(1e21),
+        };
+
+        Ok((vec![electron, ion], gas))
+    }
+
+    /// helper function that returns a constant cross section
+    ///
+    /// # Arguments
+    /// - `process`: Process kind of collision process
+    /// - `threshold`: f64 (J) energy lost by the colliding pair
+    /// - `max`: f64 (J) maximum tabulated energy
+    ///
+    /// # Returns
+    /// `Result<CrossSection, anyhow::Error>`
+    ///
+    /// # Errors
+    /// - call to `CrossSection::new()` fails
+    fn constant(process: Process, threshold: f64, max: f64) -> Result<CrossSection, anyhow::Error> {
+        CrossSection::new(process, threshold, vec![0.0, max], vec![1e-19, 1e-19])
+    }
+
+    /// tests `Mcc::new()` for failure on invalid configurations
+    ///
+    /// # Errors
+    /// - `Mcc::new()` succeeds for a reaction of an unknown species
+    /// - `Mcc::new()` succeeds for ionization of a species other than the electron species
+    /// - `Mcc::new()` succeeds for a gas density profile not matching the grid
+    ///
+    #[test]
+    fn new_failure() {
+        // setup
+        let (species, gas) = setup(1e6).unwrap();
+        let cells = CoordinateTriplet::new(3, 3, 3).unwrap();
+        let mut config = MccConfig {
+            reactions: vec![Reaction {
+                species: String::from("argon+"),
+                cross_section: constant(Process::Elastic, 0.0, 10.0 * ELEC_CHARGE).unwrap(),
+            }],
+            ..MccConfig::default()
+        };
+
+        // assertions
+        assert!(Mcc::new(&gas, &config, &species, &cells).is_err());
+
+        config.reactions = vec![Reaction {
+            species: String::from("ion"),
+            cross_section: constant(Process::Ionization, 0.0, 1e3 * ELEC_CHARGE).unwrap(),
+        }];
+        assert!(Mcc::new(&gas, &config, &species, &cells).is_err());
+
+        config.reactions[0].species = String::from("electron");
+        assert!(Mcc::new(&gas, &config, &species, &cells).is_ok());
+
+        let profile = GasDensity::Profile(
+            ScalarField::new(&CoordinateTriplet::new(4, 3, 3).unwrap()).unwrap(),
+        );
+        let gas = BackgroundGas {
+            density: profile,
+            ..gas
+        };
+        assert!(Mcc::new(&gas, &config, &species, &cells).is_err());
+    }
+
+    /// tests `Mcc::collide()` for correct elastic collision probability and conservation of speed on a heavy gas
+    ///
+    /// # Errors
+    /// - fraction of scattered macro-particles differs from `1 - exp(-n sigma v dt)`
+    /// - speed of any macro-particle changes by more than the mass ratio allows
+    ///
+    #[test]
+    fn collide_elastic() {
+        // setup
+        let speed = 1e6;
+        let (mut species, gas) = setup(speed).unwrap();
+        let cells = CoordinateTriplet::new(3, 3, 3).unwrap();
+        let delta = CoordinateTriplet::new(0.5, 0.5, 0.5).unwrap();
+        let config = MccConfig {
+            reactions: vec![Reaction {
+                species: String::from("electron"),
+                cross_section: constant(Process::Elastic, 0.0, 10.0 * ELEC_CHARGE).unwrap(),
+            }],
+            ..MccConfig::default()
+        };
+        let mcc = Mcc::new(&gas, &config, &species, &cells).unwrap();
+        let mut rng = Rng::new(0);
+        mcc.collide(&mut species, &delta, &cells, 1e-10, &mut rng);
+
+        // assertions
+        let scattered = species[0]
+            .particles
+            .iter()
+            .filter(|p| p.velocity != [speed, 0.0, 0.0])
+            .count() as f64
+            / species[0].particles.len() as f64;
+        let expected = 1.0 - (-1e21 * 1e-19 * speed * 1e-10_f64).exp();
+        assert!((scattered - expected).abs() / expected < 0.1);
+
+        assert!(species[0].particles.iter().all(|p| {
+            let v = p.velocity.iter().map(|v| v * v).sum::<f64>().sqrt();
+            (v - speed).abs() / speed < 1e-6
+        }));
+        assert!(species[1].particles.is_empty());
+    }
+
+    /// tests `Mcc::collide()` for creation of electron ion pairs and loss of threshold energy on ionization
+    ///
+    /// # Errors
+    /// - number of created electrons and ions differ
+    /// - no ionization occurs
+    /// - electron kinetic energy is not reduced by the threshold energy of each ionization
+    ///
+    #[test]
+    fn collide_ionization() {
+        // setup
+        let speed = 1e7;
+        let threshold = 15.76 * ELEC_CHARGE;
+        let (mut species, gas) = setup(speed).unwrap();
+        let cells = CoordinateTriplet::new(3, 3, 3).unwrap();
+        let delta = CoordinateTriplet::new(0.5, 0.5, 0.5).unwrap();
+        let config = MccConfig {
+            reactions: vec![Reaction {
+                species: String::from("electron"),
+                cross_section: constant(Process::Ionization, threshold, 1e3 * ELEC_CHARGE).unwrap(),
+            }],
+            ..MccConfig::default()
+        };
+        let mcc = Mcc::new(&gas, &config, &species, &cells).unwrap();
+        let kinetic_energy = |s: &Species| {
+            s.particles
+                .iter()
+                .map(|p| 0.5 * s.mass * p.velocity.iter().map(|v| v * v).sum::<f64>())
+                .sum::<f64>()
+        };
+        let initial = kinetic_energy(&species[0]);
+        let mut rng = Rng::new(0);
+        mcc.collide(&mut species, &delta, &cells, 1e-10, &mut rng);
+
+        // assertions
+        let ionizations = species[1].particles.len();
+        assert!(ionizations > 0);
+        assert_eq!(species[0].particles.len(), 100000 + ionizations);
+
+        let lost = initial - kinetic_energy(&species[0]);
+        let expected = ionizations as f64 * threshold;
+        assert!((lost - expected).abs() / expected < 1e-3);
+    }
+
+    /// tests `Mcc::collide()` for identical results of a uniform gas density profile and a uniform gas density
+    ///
+    /// # Errors
+    /// - macro-particles differ after collisions
+    ///
+    #[test]
+    fn collide_profile() {
+        // setup
+        let (mut uniform, gas) = setup(1e6).unwrap();
+        let mut profiled = uniform.clone();
+        let cells = CoordinateTriplet::new(3, 3, 3).unwrap();
+        let delta = CoordinateTriplet::new(0.5, 0.5, 0.5).unwrap();
+        let config = MccConfig {
+            reactions: vec![Reaction {
+                species: String::from("electron"),
+                cross_section: constant(Process::Elastic, 0.0, 10.0 * ELEC_CHARGE).unwrap(),
+            }],
+            ..MccConfig::default()
+        };
+
+        let mut profile = ScalarField::new(&cells).unwrap();
+        profile.iter_mut().for_each(|n| *n = 1e21);
+        let profile_gas = BackgroundGas {
+            density: GasDensity::Profile(profile),
+            ..gas.clone()
+        };
+
+        Mcc::new(&gas, &config, &uniform, &cells).unwrap().collide(
+            &mut uniform,
+            &delta,
+            &cells,
+            1e-9,
+            &mut Rng::new(0),
+        );
+        Mcc::new(&profile_gas, &config, &profiled, &cells)
+            .unwrap()
+            .collide(&mut profiled, &delta, &cells, 1e-9, &mut Rng::new(0));
+
+        // assertions
+        assert_eq!(uniform, profiled);
+    }
+}
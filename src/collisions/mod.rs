@@ -0,0 +1,80 @@
+//! collisions module
+//!
+//! contains collision models acting on macro-particles
+
+pub mod coulomb;
+pub mod cross_section;
+pub mod dsmc;
+pub mod mcc;
+
+use crate::engine::linear_weights;
+use crate::precision::Real;
+use crate::species::Particle;
+use crate::utils::coordinate_triplet::CoordinateTriplet;
+use crate::utils::rng::Rng;
+use std::f64::consts::PI;
+
+/// sorts macro-particles into the cells of the grid
+///
+/// # Arguments
+/// - `particles`: &[Particle<P>] macro-particles to sort
+/// - `delta`: &CoordinateTriplet<f64> (m) spatial increment
+/// - `cells`: &CoordinateTriplet<usize> number of cells in bounding box
+///
+/// # Returns
+/// `Vec<Vec<usize>>` indices of macro-particles in each cell, cells are ordered like nodes of a `ScalarField`
+///
+/// # Errors
+///
+pub(crate) fn bin<P: Real>(
+    particles: &[Particle<P>],
+    delta: &CoordinateTriplet<f64>,
+    cells: &CoordinateTriplet<usize>,
+) -> Vec<Vec<usize>> {
+    let (ny, nz) = (cells.y - 1, cells.z - 1);
+    let mut bins = vec![Vec::new(); (cells.x - 1) * ny * nz];
+    for (n, particle) in particles.iter().enumerate() {
+        let ((i, j, k), _) = linear_weights(&particle.position.map(P::widen), delta, cells);
+        bins[k + nz * j + ny * nz * i].push(n);
+    }
+    bins
+}
+
+/// returns a velocity scattered isotropically in the center of mass frame
+///
+/// # Arguments
+/// - `v_cm`: &[f64; 3] (m/s) center of mass velocity
+/// - `speed`: f64 (m/s) speed relative to center of mass after scattering
+/// - `rng`: &mut Rng random number generator
+///
+/// # Returns
+/// `[f64; 3]` (m/s) velocity after scattering
+///
+/// # Errors
+///
+pub(crate) fn scatter(v_cm: &[f64; 3], speed: f64, rng: &mut Rng) -> [f64; 3] {
+    let cos_theta = 2.0 * rng.uniform() - 1.0;
+    let sin_theta = (1.0 - cos_theta * cos_theta).sqrt();
+    let phi = 2.0 * PI * rng.uniform();
+
+    [
+        v_cm[0] + speed * sin_theta * phi.cos(),
+        v_cm[1] + speed * sin_theta * phi.sin(),
+        v_cm[2] + speed * cos_theta,
+    ]
+}
+
+/// rounds a non-negative number up or down at random such that the expected value is preserved
+///
+/// # Arguments
+/// - `value`: f64 non-negative number
+/// - `rng`: &mut Rng random number generator
+///
+/// # Returns
+/// `usize`
+///
+/// # Errors
+///
+pub(crate) fn stochastic_round(value: f64, rng: &mut Rng) -> usize {
+    (value + rng.uniform()).floor() as usize
+}
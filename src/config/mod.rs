@@ -0,0 +1,202 @@
+//! config module
+//!
+//! describes the user configuration of a picrs model
+
+use crate::checkpoint::CheckpointConfig;
+use crate::collisions::coulomb::CoulombConfig;
+use crate::collisions::dsmc::DsmcConfig;
+use crate::collisions::mcc::MccConfig;
+use crate::diagnostics::DiagnosticsConfig;
+use crate::emission::EmissionConfig;
+use crate::engine::cylindrical::CylindricalConfig;
+use crate::grid::GridConfig;
+use crate::magnetostatics::MagnetostaticConfig;
+use crate::output::OutputConfig;
+use crate::parallel::ParallelConfig;
+use crate::plasma::ValidationConfig;
+use crate::precision::Precision;
+use crate::species::Species;
+use anyhow::anyhow;
+
+/// `EngineKind` enum
+///
+/// describes the computational engine advancing a model
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum EngineKind {
+    /// electrostatic engine solving poisson's equation for the potential
+    #[default]
+    Electrostatic,
+
+    /// electromagnetic engine advancing maxwell's equations on a yee grid
+    Electromagnetic,
+
+    /// electrostatic engine resolving only x, with a direct tridiagonal poisson solve
+    Electrostatic1d,
+
+    /// electrostatic engine resolving only x and y
+    Electrostatic2d,
+
+    /// axisymmetric electrostatic engine resolving radius along x and axial position along z
+    Cylindrical,
+}
+
+/// `Config` struct
+///
+/// describes all user configurable parameters of a picrs model
+#[derive(Debug, Clone, PartialEq)]
+pub struct Config {
+    /// (m) size of bounding box
+    pub size: [f64; 3],
+
+    /// number of cells in bounding box, entries of axes unresolved by the selected engine are ignored
+    pub cells: [usize; 3],
+
+    /// spacing of nodes along every axis, only the electrostatic engine supports non-uniform spacing
+    pub grid: GridConfig,
+
+    /// (s) time step
+    pub dt: f64,
+
+    /// total number of time steps to run
+    pub steps: u64,
+
+    /// seed of random number generator
+    pub seed: u64,
+
+    /// computational engine
+    pub engine: EngineKind,
+
+    /// floating point precision of field storage and the particle push, only the electrostatic engine supports
+    /// precisions other than double
+    pub precision: Precision,
+
+    /// threads and reproducibility of the electrostatic engine, other engines run field kernels on the global thread
+    /// pool of rayon
+    pub parallel: ParallelConfig,
+
+    /// (T) uniform applied magnetic flux density
+    pub magnetic_field: [f64; 3],
+
+    /// static sources of applied magnetic flux density in addition to `magnetic_field`, only the electrostatic and
+    /// electromagnetic engines support sources
+    pub magnetostatics: MagnetostaticConfig,
+
+    /// species present in model
+    pub species: Vec<Species>,
+
+    /// monte carlo collisions with a background gas
+    pub mcc: MccConfig,
+
+    /// binary coulomb collisions between charged species
+    pub coulomb: CoulombConfig,
+
+    /// direct simulation monte carlo collisions between neutral species
+    pub dsmc: DsmcConfig,
+
+    /// secondary electron emission at the walls of the bounding box
+    pub emission: EmissionConfig,
+
+    /// electrodes of the cylindrical engine
+    pub cylindrical: CylindricalConfig,
+
+    /// plasma parameter validation criteria
+    pub validation: ValidationConfig,
+
+    /// checkpoint settings
+    pub checkpoint: CheckpointConfig,
+
+    /// field output settings
+    pub output: OutputConfig,
+
+    /// conservation diagnostics settings
+    pub diagnostics: DiagnosticsConfig,
+}
+
+impl Config {
+    /// `Config` constructor
+    ///
+    /// all members not passed as arguments are set to their defaults and may be modified directly
+    ///
+    /// # Arguments
+    /// - `size`: &[f64; 3] (m) size of bounding box
+    /// - `cells`: &[usize; 3] number of cells
+    /// - `dt`: f64 (s) time step
+    ///
+    /// # Returns
+    /// `Result<Config, anyhow::Error>`
+    ///
+    /// # Errors
+    /// - any component of `size` is not positive
+    /// - any component of `cells` is less than 3
+    /// - `dt` is not positive
+    pub fn new(size: &[f64; 3], cells: &[usize; 3], dt: f64) -> Result<Config, anyhow::Error> {
+        if size.iter().any(|s| s.is_nan() || *s <= 0.0) {
+            return Err(anyhow!(
+                "all components of size must be positive, got {size:?}"
+            ));
+        }
+
+        if cells.iter().any(|c| *c < 3) {
+            return Err(anyhow!(
+                "all components of cells must be at least 3, got {cells:?}"
+            ));
+        }
+
+        if dt.is_nan() || dt <= 0.0 {
+            return Err(anyhow!("time step must be positive, got {dt}"));
+        }
+
+        Ok(Config {
+            size: *size,
+            cells: *cells,
+            grid: GridConfig::default(),
+            dt,
+            steps: 10,
+            seed: 0,
+            engine: EngineKind::default(),
+            precision: Precision::default(),
+            parallel: ParallelConfig::default(),
+            magnetic_field: [0.0; 3],
+            magnetostatics: MagnetostaticConfig::default(),
+            species: Vec::new(),
+            mcc: MccConfig::default(),
+            coulomb: CoulombConfig::default(),
+            dsmc: DsmcConfig::default(),
+            emission: EmissionConfig::default(),
+            cylindrical: CylindricalConfig::default(),
+            validation: ValidationConfig::default(),
+            checkpoint: CheckpointConfig::default(),
+            output: OutputConfig::default(),
+            diagnostics: DiagnosticsConfig::default(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::Config;
+
+    /// tests `Config::new()` for success
+    ///
+    /// # Errors
+    /// - `Config::new()` fails for valid input
+    ///
+    #[test]
+    fn new_success() {
+        assert!(Config::new(&[1.0, 2.0, 3.0], &[3, 11, 31], 1e-9).is_ok());
+    }
+
+    /// tests `Config::new()` for failure on invalid input
+    ///
+    /// # Errors
+    /// - `Config::new()` succeeds for non-positive size
+    /// - `Config::new()` succeeds for fewer than 3 cells
+    /// - `Config::new()` succeeds for non-positive time step
+    ///
+    #[test]
+    fn new_failure() {
+        assert!(Config::new(&[0.0, 2.0, 3.0], &[3, 11, 31], 1e-9).is_err());
+        assert!(Config::new(&[1.0, 2.0, 3.0], &[2, 11, 31], 1e-9).is_err());
+        assert!(Config::new(&[1.0, 2.0, 3.0], &[3, 11, 31], 0.0).is_err());
+    }
+}
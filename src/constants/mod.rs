@@ -8,6 +8,12 @@ pub const VAC_PERM: f64 = 8.8541878188e-12;
 /// (F^-1 * m) inverse vacuum permittivity
 pub const INV_VAC_PERM: f64 = 1.0 / VAC_PERM;
 
+/// (m * s^-1) speed of light in vacuum https://en.wikipedia.org/wiki/Speed_of_light
+pub const SPEED_OF_LIGHT: f64 = 299792458.0;
+
+/// (H * m^-1) vacuum permeability https://en.wikipedia.org/wiki/Vacuum_permeability
+pub const VAC_PERMEABILITY: f64 = 1.0 / (VAC_PERM * SPEED_OF_LIGHT * SPEED_OF_LIGHT);
+
 /// (C) electron charge https://en.wikipedia.org/wiki/Elementary_charge
 pub const ELEC_CHARGE: f64 = 1.602176634e-19;
 
@@ -21,4 +27,4 @@ pub const ELEC_MASS: f64 = 9.1093837139e-31;
 pub const BOLTZMANN: f64 = 1.380649e-23;
 
 /// (K) electron volt temperature
-pub const EV_TEMP: f64 = ELEC_CHARGE / BOLTZMANN;
\ No newline at end of file
+pub const EV_TEMP: f64 = ELEC_CHARGE / BOLTZMANN;
@@ -0,0 +1,598 @@
+//! diagnostics module
+//!
+//! computes conservation diagnostics of a model and writes them to a csv time history
+
+use crate::constants::SPEED_OF_LIGHT;
+use crate::emission::Surface;
+use crate::engine::cylindrical::Cylindrical;
+use crate::engine::electromagnetic::Electromagnetic;
+use crate::engine::reduced::Reduced;
+use crate::engine::{Electrostatic, Engine};
+use crate::precision::Real;
+use crate::species::Species;
+use anyhow::{anyhow, Context};
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+/// `DiagnosticsConfig` struct
+///
+/// describes when and where diagnostics are written
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiagnosticsConfig {
+    /// number of steps between diagnostics, 0 disables diagnostics
+    pub interval: u64,
+
+    /// path of csv time history
+    pub path: PathBuf,
+}
+
+impl Default for DiagnosticsConfig {
+    /// default `DiagnosticsConfig`
+    ///
+    /// diagnostics are disabled
+    ///
+    /// # Arguments
+    ///
+    /// # Returns
+    /// `DiagnosticsConfig`
+    ///
+    /// # Errors
+    ///
+    fn default() -> DiagnosticsConfig {
+        DiagnosticsConfig {
+            interval: 0,
+            path: PathBuf::from("diagnostics.csv"),
+        }
+    }
+}
+
+/// `SpeciesDiagnostics` struct
+///
+/// describes diagnostics of a single species
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpeciesDiagnostics {
+    /// name of species
+    pub name: String,
+
+    /// (J) kinetic energy
+    pub kinetic_energy: f64,
+
+    /// (kg m/s) momentum
+    pub momentum: [f64; 3],
+
+    /// (C) charge
+    pub charge: f64,
+
+    /// number of macro-particles
+    pub particles: usize,
+}
+
+/// `Diagnostics` struct
+///
+/// describes conservation diagnostics of a model at a single step
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostics {
+    /// step of diagnostics
+    pub step: u64,
+
+    /// (s) time of diagnostics
+    pub time: f64,
+
+    /// (J) electric field energy
+    pub field_energy: f64,
+
+    /// (A) current of secondary electrons emitted from each wall during the last step in the order of `Surface::ALL`
+    pub emitted_current: [f64; 6],
+
+    /// per species diagnostics
+    pub species: Vec<SpeciesDiagnostics>,
+}
+
+impl Diagnostics {
+    /// `Diagnostics` constructor computing diagnostics of an engine
+    ///
+    /// # Arguments
+    /// - `engine`: &Electrostatic<F, P> engine to diagnose
+    ///
+    /// # Returns
+    /// `Diagnostics`
+    ///
+    /// # Errors
+    ///
+    pub fn new<F: Real, P: Real>(engine: &Electrostatic<F, P>) -> Diagnostics {
+        Diagnostics {
+            step: engine.step(),
+            time: engine.time(),
+            field_energy: engine.field_energy(),
+            emitted_current: engine.emitted_current(),
+            species: species_diagnostics(engine.species(), false),
+        }
+    }
+
+    /// `Diagnostics` constructor computing diagnostics of an electromagnetic engine
+    ///
+    /// the field energy includes the self-consistent magnetic field and kinetic energies are relativistic
+    ///
+    /// # Arguments
+    /// - `engine`: &Electromagnetic engine to diagnose
+    ///
+    /// # Returns
+    /// `Diagnostics`
+    ///
+    /// # Errors
+    ///
+    pub fn electromagnetic(engine: &Electromagnetic) -> Diagnostics {
+        Diagnostics {
+            step: engine.step(),
+            time: engine.time(),
+            field_energy: engine.field_energy(),
+            emitted_current: engine.emitted_current(),
+            species: species_diagnostics(engine.species(), true),
+        }
+    }
+
+    /// `Diagnostics` constructor computing diagnostics of a reduced-dimensionality engine
+    ///
+    /// # Arguments
+    /// - `engine`: &Reduced engine to diagnose
+    ///
+    /// # Returns
+    /// `Diagnostics`
+    ///
+    /// # Errors
+    ///
+    pub fn reduced(engine: &Reduced) -> Diagnostics {
+        Diagnostics {
+            step: engine.step(),
+            time: engine.time(),
+            field_energy: engine.field_energy(),
+            emitted_current: [0.0; 6],
+            species: species_diagnostics(engine.species(), false),
+        }
+    }
+
+    /// `Diagnostics` constructor computing diagnostics of a cylindrical engine
+    ///
+    /// momenta are sums of radial, azimuthal, and axial velocity components
+    ///
+    /// # Arguments
+    /// - `engine`: &Cylindrical engine to diagnose
+    ///
+    /// # Returns
+    /// `Diagnostics`
+    ///
+    /// # Errors
+    ///
+    pub fn cylindrical(engine: &Cylindrical) -> Diagnostics {
+        Diagnostics {
+            step: engine.step(),
+            time: engine.time(),
+            field_energy: engine.field_energy(),
+            emitted_current: [0.0; 6],
+            species: species_diagnostics(engine.species(), false),
+        }
+    }
+
+    /// returns total kinetic energy of all species
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `f64` (J) total kinetic energy
+    ///
+    /// # Errors
+    ///
+    pub fn kinetic_energy(&self) -> f64 {
+        self.species.iter().map(|s| s.kinetic_energy).sum()
+    }
+
+    /// returns total momentum of all species
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `[f64; 3]` (kg m/s) total momentum
+    ///
+    /// # Errors
+    ///
+    pub fn momentum(&self) -> [f64; 3] {
+        self.species.iter().fold([0.0; 3], |acc, s| {
+            [
+                acc[0] + s.momentum[0],
+                acc[1] + s.momentum[1],
+                acc[2] + s.momentum[2],
+            ]
+        })
+    }
+
+    /// returns total charge of all species
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `f64` (C) total charge
+    ///
+    /// # Errors
+    ///
+    pub fn charge(&self) -> f64 {
+        self.species.iter().map(|s| s.charge).sum()
+    }
+
+    /// returns total number of macro-particles of all species
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `usize` total number of macro-particles
+    ///
+    /// # Errors
+    ///
+    pub fn particles(&self) -> usize {
+        self.species.iter().map(|s| s.particles).sum()
+    }
+}
+
+/// `HistoryWriter` struct
+///
+/// writes diagnostics to a csv time history
+#[derive(Debug)]
+pub struct HistoryWriter {
+    /// destination of rows
+    writer: BufWriter<File>,
+
+    /// csv header
+    header: String,
+}
+
+impl HistoryWriter {
+    /// `HistoryWriter` constructor creating a new time history, any existing file is truncated
+    ///
+    /// # Arguments
+    /// - `path`: &Path destination of time history
+    /// - `species`: &[String] names of species
+    ///
+    /// # Returns
+    /// `Result<HistoryWriter, anyhow::Error>`
+    ///
+    /// # Errors
+    /// - file could not be created or written
+    pub fn create(path: &Path, species: &[String]) -> Result<HistoryWriter, anyhow::Error> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("could not create directory {}", parent.display()))?;
+        }
+
+        let header = header(species);
+        let mut writer = BufWriter::new(
+            File::create(path).with_context(|| format!("could not create {}", path.display()))?,
+        );
+        writeln!(writer, "{header}")?;
+        writer.flush()?;
+
+        Ok(HistoryWriter { writer, header })
+    }
+
+    /// `HistoryWriter` constructor resuming an existing time history after a restart
+    ///
+    /// rows written after `step` are discarded, a new time history is created if none exists at `path`
+    ///
+    /// # Arguments
+    /// - `path`: &Path destination of time history
+    /// - `species`: &[String] names of species
+    /// - `step`: u64 step the model restarts from
+    ///
+    /// # Returns
+    /// `Result<HistoryWriter, anyhow::Error>`
+    ///
+    /// # Errors
+    /// - file could not be read or written
+    /// - existing time history has a different header
+    pub fn resume(
+        path: &Path,
+        species: &[String],
+        step: u64,
+    ) -> Result<HistoryWriter, anyhow::Error> {
+        if !path.exists() {
+            return HistoryWriter::create(path, species);
+        }
+
+        let header = header(species);
+        let reader = BufReader::new(
+            File::open(path).with_context(|| format!("could not open {}", path.display()))?,
+        );
+
+        // keep complete rows up to and including step
+        let mut kept: Vec<String> = Vec::new();
+        for (i, line) in reader.lines().enumerate() {
+            let line = line?;
+            if i == 0 {
+                if line != header {
+                    return Err(anyhow!(
+                        "{} has a different header than expected",
+                        path.display()
+                    ));
+                }
+                continue;
+            }
+
+            let row_step = line.split(',').next().and_then(|s| s.parse::<u64>().ok());
+            match row_step {
+                Some(row_step) if row_step <= step => kept.push(line),
+                _ => break,
+            }
+        }
+
+        let mut history = HistoryWriter::create(path, species)?;
+        for line in kept.iter() {
+            writeln!(history.writer, "{line}")?;
+        }
+        history.writer.flush()?;
+
+        Ok(history)
+    }
+
+    /// writes a single row
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `diagnostics`: &Diagnostics diagnostics to write
+    ///
+    /// # Returns
+    /// `Result<(), anyhow::Error>`
+    ///
+    /// # Errors
+    /// - number of species does not match header
+    /// - any write fails
+    pub fn write(&mut self, diagnostics: &Diagnostics) -> Result<(), anyhow::Error> {
+        if header(
+            &diagnostics
+                .species
+                .iter()
+                .map(|s| s.name.clone())
+                .collect::<Vec<String>>(),
+        ) != self.header
+        {
+            return Err(anyhow!("diagnostics do not match species of time history"));
+        }
+
+        let kinetic_energy = diagnostics.kinetic_energy();
+        let momentum = diagnostics.momentum();
+        write!(
+            self.writer,
+            "{},{:e},{:e},{:e},{:e},{:e},{:e},{:e},{:e},{}",
+            diagnostics.step,
+            diagnostics.time,
+            diagnostics.field_energy,
+            kinetic_energy,
+            diagnostics.field_energy + kinetic_energy,
+            momentum[0],
+            momentum[1],
+            momentum[2],
+            diagnostics.charge(),
+            diagnostics.particles()
+        )?;
+        for current in diagnostics.emitted_current.iter() {
+            write!(self.writer, ",{current:e}")?;
+        }
+        for s in diagnostics.species.iter() {
+            write!(self.writer, ",{:e},{}", s.kinetic_energy, s.particles)?;
+        }
+        writeln!(self.writer)?;
+        self.writer.flush()?;
+
+        Ok(())
+    }
+}
+
+/// computes moments of all species
+///
+/// # Arguments
+/// - `species`: &[Species<P>] species to diagnose, velocities are widened to double precision
+/// - `relativistic`: bool kinetic energy is computed as (gamma - 1) m c^2 and momentum as gamma m v
+///
+/// # Returns
+/// `Vec<SpeciesDiagnostics>`
+///
+/// # Errors
+///
+fn species_diagnostics<P: Real>(
+    species: &[Species<P>],
+    relativistic: bool,
+) -> Vec<SpeciesDiagnostics> {
+    let c_sq = SPEED_OF_LIGHT * SPEED_OF_LIGHT;
+    species
+        .iter()
+        .map(|s| {
+            let mut kinetic_energy = 0.0;
+            let mut p = [0.0; 3];
+            for particle in s.particles.iter() {
+                let v = particle.velocity.map(P::widen);
+                let v_sq = v[0] * v[0] + v[1] * v[1] + v[2] * v[2];
+                if relativistic {
+                    let gamma = 1.0 / (1.0 - v_sq / c_sq).sqrt();
+                    kinetic_energy += (gamma - 1.0) * c_sq;
+                    for (sum, elem) in p.iter_mut().zip(v.iter()) {
+                        *sum += gamma * elem;
+                    }
+                } else {
+                    kinetic_energy += 0.5 * v_sq;
+                    for (sum, elem) in p.iter_mut().zip(v.iter()) {
+                        *sum += elem;
+                    }
+                }
+            }
+
+            let mass = s.mass * s.weight;
+            SpeciesDiagnostics {
+                name: s.name.clone(),
+                kinetic_energy: mass * kinetic_energy,
+                momentum: [mass * p[0], mass * p[1], mass * p[2]],
+                charge: s.charge * s.weight * s.particles.len() as f64,
+                particles: s.particles.len(),
+            }
+        })
+        .collect()
+}
+
+/// builds the csv header of a time history
+///
+/// # Arguments
+/// - `species`: &[String] names of species
+///
+/// # Returns
+/// `String`
+///
+/// # Errors
+///
+fn header(species: &[String]) -> String {
+    let mut header = String::from(
+        "step,time,field_energy,kinetic_energy,total_energy,momentum_x,momentum_y,momentum_z,charge,particles",
+    );
+    for surface in Surface::ALL.iter() {
+        header.push_str(&format!(",emitted_current_{}", surface.name()));
+    }
+    for name in species.iter() {
+        header.push_str(&format!(",{name}_kinetic_energy,{name}_particles"));
+    }
+    header
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::Config;
+    use crate::constants::{ELEC_CHARGE, ELEC_MASS, EV_TEMP};
+    use crate::diagnostics::{Diagnostics, HistoryWriter};
+    use crate::engine::{Electrostatic, Engine};
+    use crate::species::Species;
+    use std::path::PathBuf;
+
+    /// helper function that sets up an `Electrostatic` with a single electron species for testing
+    ///
+    /// # Arguments
+    ///
+    /// # Returns
+    /// `Result<Electrostatic, anyhow::Error>`
+    ///
+    /// # Errors
+    /// - `Config::new()` fails
+    /// - `Species::new()` fails
+    /// - `Electrostatic::new()` fails
+    fn setup() -> Result<Electrostatic, anyhow::Error> {
+        let mut config = Config::new(&[0.01, 0.01, 0.01], &[11, 11, 11], 1e-12)?;
+        config.species.push(Species::new(
+            "electron",
+            -ELEC_CHARGE,
+            ELEC_MASS,
+            1e12,
+            EV_TEMP,
+            1e3,
+        )?);
+        Electrostatic::new(&config)
+    }
+
+    /// helper function that returns a unique temporary path for testing
+    ///
+    /// # Arguments
+    /// - `name`: &str name of test
+    ///
+    /// # Returns
+    /// `PathBuf`
+    ///
+    /// # Errors
+    ///
+    fn tmp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "picrs_diagnostics_{name}_{}.csv",
+            std::process::id()
+        ))
+    }
+
+    /// tests `Diagnostics::new()` for correct particle moments
+    ///
+    /// # Errors
+    /// - number of macro-particles is incorrect
+    /// - total charge is incorrect
+    /// - kinetic energy does not correspond to loaded temperature
+    ///
+    #[test]
+    fn new_correct_moments() {
+        // setup
+        let engine = setup().unwrap();
+        let diagnostics = Diagnostics::new(&engine);
+
+        // assertions
+        assert_eq!(diagnostics.particles(), 1000);
+        let charge = -ELEC_CHARGE * 1e12 * 1e-6;
+        assert!((diagnostics.charge() - charge).abs() / charge.abs() < 1e-12);
+
+        // 3/2 k T per physical particle
+        let kinetic_energy = 1.5 * ELEC_CHARGE * 1e12 * 1e-6;
+        assert!((diagnostics.kinetic_energy() - kinetic_energy).abs() / kinetic_energy < 0.1);
+        assert_eq!(diagnostics.field_energy, 0.0);
+    }
+
+    /// tests `Diagnostics::new()` for conservation of energy over several steps
+    ///
+    /// the time step is small enough that few macro-particles are absorbed at the boundary
+    ///
+    /// # Errors
+    /// - field energy is zero after a field solve
+    /// - total energy changes by more than one percent
+    ///
+    #[test]
+    fn new_energy_conservation() {
+        // setup
+        let mut engine = setup().unwrap();
+        engine.update().unwrap();
+        let initial = Diagnostics::new(&engine);
+        for _ in 0..5 {
+            engine.update().unwrap();
+        }
+        let last = Diagnostics::new(&engine);
+
+        // assertions
+        assert!(initial.field_energy > 0.0);
+        let initial_total = initial.field_energy + initial.kinetic_energy();
+        let last_total = last.field_energy + last.kinetic_energy();
+        assert!((last_total - initial_total).abs() / initial_total < 1e-2);
+    }
+
+    /// tests `HistoryWriter` for correct rows and resumption
+    ///
+    /// # Errors
+    /// - header or number of rows is incorrect
+    /// - rows after restart step are kept by `HistoryWriter::resume()`
+    ///
+    #[test]
+    fn history_write_resume() {
+        // setup
+        let path = tmp_path("history");
+        let mut engine = setup().unwrap();
+        let species = vec![String::from("electron")];
+        let mut history = HistoryWriter::create(&path, &species).unwrap();
+        for _ in 0..3 {
+            engine.update().unwrap();
+            history.write(&Diagnostics::new(&engine)).unwrap();
+        }
+        drop(history);
+        let full = std::fs::read_to_string(&path).unwrap();
+
+        let history = HistoryWriter::resume(&path, &species, 1).unwrap();
+        drop(history);
+        let resumed = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        // assertions
+        let lines: Vec<&str> = full.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert!(lines[0].ends_with("electron_kinetic_energy,electron_particles"));
+        assert!(lines[1].starts_with("1,"));
+        assert_eq!(resumed.lines().count(), 2);
+        assert_eq!(resumed.lines().last(), Some(lines[1]));
+    }
+}
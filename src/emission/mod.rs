@@ -0,0 +1,683 @@
+//! emission module
+//!
+//! describes secondary electron emission from macro-particles absorbed at the walls of the bounding box
+
+use crate::collisions::stochastic_round;
+use crate::constants::BOLTZMANN;
+use crate::precision::Real;
+use crate::species::{Particle, Species};
+use crate::utils::coordinate_triplet::CoordinateTriplet;
+use crate::utils::rng::Rng;
+use anyhow::anyhow;
+use std::f64::consts::PI;
+
+/// `Surface` enum
+///
+/// describes a single wall of the bounding box
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Surface {
+    /// wall at x = 0
+    XLow,
+
+    /// wall at x = size.x
+    XHigh,
+
+    /// wall at y = 0
+    YLow,
+
+    /// wall at y = size.y
+    YHigh,
+
+    /// wall at z = 0
+    ZLow,
+
+    /// wall at z = size.z
+    ZHigh,
+}
+
+impl Surface {
+    /// all walls of the bounding box in the order of `Surface::index()`
+    pub const ALL: [Surface; 6] = [
+        Surface::XLow,
+        Surface::XHigh,
+        Surface::YLow,
+        Surface::YHigh,
+        Surface::ZLow,
+        Surface::ZHigh,
+    ];
+
+    /// returns index of `Surface` in `Surface::ALL`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `usize`
+    ///
+    /// # Errors
+    ///
+    pub fn index(&self) -> usize {
+        match self {
+            Surface::XLow => 0,
+            Surface::XHigh => 1,
+            Surface::YLow => 2,
+            Surface::YHigh => 3,
+            Surface::ZLow => 4,
+            Surface::ZHigh => 5,
+        }
+    }
+
+    /// returns name of `Surface`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `&'static str`
+    ///
+    /// # Errors
+    ///
+    pub fn name(&self) -> &'static str {
+        match self {
+            Surface::XLow => "x_low",
+            Surface::XHigh => "x_high",
+            Surface::YLow => "y_low",
+            Surface::YHigh => "y_high",
+            Surface::ZLow => "z_low",
+            Surface::ZHigh => "z_high",
+        }
+    }
+
+    /// returns axis normal to `Surface`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `usize` 0, 1, or 2 for x, y, or z
+    ///
+    /// # Errors
+    ///
+    pub fn axis(&self) -> usize {
+        self.index() / 2
+    }
+
+    /// returns sign of normal of `Surface` pointing into the bounding box
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `f64` 1 for lower walls, -1 for upper walls
+    ///
+    /// # Errors
+    ///
+    pub fn inward(&self) -> f64 {
+        if self.index() % 2 == 0 {
+            1.0
+        } else {
+            -1.0
+        }
+    }
+}
+
+/// `Impact` struct
+///
+/// describes a macro-particle absorbed at a wall
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Impact {
+    /// wall hit by macro-particle
+    pub surface: Surface,
+
+    /// (m) point of impact on wall
+    pub position: [f64; 3],
+
+    /// (m/s) velocity at impact
+    pub velocity: [f64; 3],
+}
+
+impl Impact {
+    /// `Impact` constructor from a macro-particle that left the bounding box during the last time step
+    ///
+    /// the wall crossed first along the straight path of the last time step is hit
+    ///
+    /// # Arguments
+    /// - `particle`: &Particle<P> macro-particle outside of bounding box
+    /// - `dt`: f64 (s) time step
+    /// - `size`: &[f64; 3] (m) size of bounding box
+    ///
+    /// # Returns
+    /// `Impact`
+    ///
+    /// # Errors
+    ///
+    pub fn new<P: Real>(particle: &Particle<P>, dt: f64, size: &[f64; 3]) -> Impact {
+        let new = particle.position.map(P::widen);
+        let v = particle.velocity.map(P::widen);
+        let old = [new[0] - v[0] * dt, new[1] - v[1] * dt, new[2] - v[2] * dt];
+
+        // first crossed wall and fraction of time step at which it is crossed
+        let mut hit = (Surface::XLow, f64::INFINITY);
+        for (surface, axis) in Surface::ALL.iter().map(|s| (*s, s.axis())) {
+            let wall = if surface.inward() > 0.0 {
+                0.0
+            } else {
+                size[axis]
+            };
+            let crossed = if surface.inward() > 0.0 {
+                new[axis] < wall
+            } else {
+                new[axis] >= wall
+            };
+            if crossed {
+                let t = ((wall - old[axis]) / (new[axis] - old[axis])).clamp(0.0, 1.0);
+                if t < hit.1 {
+                    hit = (surface, t);
+                }
+            }
+        }
+
+        let (surface, t) = (hit.0, hit.1.min(1.0));
+        let mut position = [
+            old[0] + t * (new[0] - old[0]),
+            old[1] + t * (new[1] - old[1]),
+            old[2] + t * (new[2] - old[2]),
+        ];
+        position[surface.axis()] = if surface.inward() > 0.0 {
+            0.0
+        } else {
+            size[surface.axis()]
+        };
+
+        Impact {
+            surface,
+            position,
+            velocity: v,
+        }
+    }
+}
+
+/// `YieldModel` enum
+///
+/// describes the number of secondary electrons emitted per impacting macro-particle as a function of impact energy
+/// and angle of incidence
+#[derive(Debug, Clone, PartialEq)]
+pub enum YieldModel {
+    /// energy independent yield
+    Constant(f64),
+
+    /// vaughan yield curve
+    Vaughan {
+        /// maximum yield at normal incidence
+        max_yield: f64,
+
+        /// (J) impact energy of maximum yield at normal incidence
+        max_energy: f64,
+
+        /// (J) impact energy below which no electrons are emitted
+        threshold: f64,
+
+        /// surface smoothness factor, 0 for rough and 2 for polished surfaces
+        smoothness: f64,
+    },
+
+    /// true secondary yield of the furman-pivi model
+    Furman {
+        /// maximum yield at normal incidence
+        max_yield: f64,
+
+        /// (J) impact energy of maximum yield at normal incidence
+        max_energy: f64,
+
+        /// shape parameter, larger than 1
+        shape: f64,
+
+        /// angular parameters t1, t2, t3, and t4
+        angular: [f64; 4],
+    },
+}
+
+impl YieldModel {
+    /// evaluates `YieldModel` for a single impact
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `energy`: f64 (J) impact energy
+    /// - `cos_theta`: f64 cosine of angle of incidence measured from the wall normal
+    ///
+    /// # Returns
+    /// `f64` mean number of emitted electrons
+    ///
+    /// # Errors
+    ///
+    pub fn value(&self, energy: f64, cos_theta: f64) -> f64 {
+        match self {
+            YieldModel::Constant(value) => *value,
+            YieldModel::Vaughan {
+                max_yield,
+                max_energy,
+                threshold,
+                smoothness,
+            } => {
+                let theta = cos_theta.clamp(0.0, 1.0).acos();
+                let max_yield = max_yield * (1.0 + smoothness * theta * theta / (2.0 * PI));
+                let max_energy = max_energy * (1.0 + smoothness * theta * theta / PI);
+                if energy <= *threshold || max_energy <= *threshold {
+                    return 0.0;
+                }
+
+                let v = (energy - threshold) / (max_energy - threshold);
+                let k = if v < 1.0 { 0.62 } else { 0.25 };
+                max_yield * (v * (1.0 - v).exp()).powf(k)
+            }
+            YieldModel::Furman {
+                max_yield,
+                max_energy,
+                shape,
+                angular,
+            } => {
+                if energy <= 0.0 {
+                    return 0.0;
+                }
+
+                let cos_theta = cos_theta.clamp(0.0, 1.0);
+                let max_yield = max_yield * (1.0 + angular[0] * (1.0 - cos_theta.powf(angular[1])));
+                let max_energy =
+                    max_energy * (1.0 + angular[2] * (1.0 - cos_theta.powf(angular[3])));
+                let x = energy / max_energy;
+                max_yield * shape * x / (shape - 1.0 + x.powf(*shape))
+            }
+        }
+    }
+}
+
+/// `EmissionEnergy` enum
+///
+/// describes the velocity distribution of emitted electrons, emission is cosine distributed about the wall normal
+#[derive(Debug, Clone, PartialEq)]
+pub enum EmissionEnergy {
+    /// (K) temperature of a half maxwellian flux
+    Maxwellian(f64),
+
+    /// (J) single emission energy
+    Monoenergetic(f64),
+}
+
+/// `EmissionRule` struct
+///
+/// describes secondary electron emission caused by a single species
+#[derive(Debug, Clone, PartialEq)]
+pub struct EmissionRule {
+    /// name of impacting species
+    pub species: String,
+
+    /// walls emitting electrons, empty for all walls
+    pub surfaces: Vec<Surface>,
+
+    /// secondary electron yield
+    pub yield_model: YieldModel,
+}
+
+/// `EmissionConfig` struct
+///
+/// describes secondary electron emission at the walls of the bounding box
+#[derive(Debug, Clone, PartialEq)]
+pub struct EmissionConfig {
+    /// emission rules, empty disables secondary electron emission
+    pub rules: Vec<EmissionRule>,
+
+    /// name of species receiving emitted electrons
+    pub electron: String,
+
+    /// velocity distribution of emitted electrons
+    pub energy: EmissionEnergy,
+}
+
+impl Default for EmissionConfig {
+    /// default `EmissionConfig`
+    ///
+    /// secondary electron emission is disabled
+    ///
+    /// # Arguments
+    ///
+    /// # Returns
+    /// `EmissionConfig`
+    ///
+    /// # Errors
+    ///
+    fn default() -> EmissionConfig {
+        EmissionConfig {
+            rules: Vec::new(),
+            electron: String::from("electron"),
+            energy: EmissionEnergy::Maxwellian(2.0 * crate::constants::EV_TEMP),
+        }
+    }
+}
+
+/// `Emission` struct
+///
+/// emits secondary electrons from macro-particles absorbed at the walls of the bounding box
+#[derive(Debug, Clone)]
+pub struct Emission {
+    /// emission rules with index of impacting species
+    rules: Vec<(usize, EmissionRule)>,
+
+    /// index of species receiving emitted electrons
+    electron: usize,
+
+    /// velocity distribution of emitted electrons
+    energy: EmissionEnergy,
+}
+
+impl Emission {
+    /// `Emission` constructor
+    ///
+    /// # Arguments
+    /// - `config`: &EmissionConfig emission configuration
+    /// - `species`: &[Species<P>] species present in model
+    ///
+    /// # Returns
+    /// `Result<Emission, anyhow::Error>`
+    ///
+    /// # Errors
+    /// - electron species or any impacting species is unknown
+    /// - emission energy is negative
+    pub fn new<P>(
+        config: &EmissionConfig,
+        species: &[Species<P>],
+    ) -> Result<Emission, anyhow::Error> {
+        let index = |name: &str| {
+            species
+                .iter()
+                .position(|s| s.name == name)
+                .ok_or_else(|| anyhow!("emission refers to unknown species `{name}`"))
+        };
+
+        let energy = match config.energy {
+            EmissionEnergy::Maxwellian(value) | EmissionEnergy::Monoenergetic(value) => value,
+        };
+        if energy.is_nan() || energy < 0.0 {
+            return Err(anyhow!(
+                "emission energy must be non-negative, got {energy}"
+            ));
+        }
+
+        Ok(Emission {
+            rules: config
+                .rules
+                .iter()
+                .map(|rule| Ok((index(&rule.species)?, rule.clone())))
+                .collect::<Result<Vec<_>, anyhow::Error>>()?,
+            electron: index(&config.electron)?,
+            energy: config.energy.clone(),
+        })
+    }
+
+    /// returns index of species receiving emitted electrons
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `usize`
+    ///
+    /// # Errors
+    ///
+    pub fn electron(&self) -> usize {
+        self.electron
+    }
+
+    /// emits secondary electrons for the impacts of a single time step
+    ///
+    /// emitted electrons start at the point of impact and are advanced by a random fraction of the time step
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `impacts`: &[Vec<Impact>] impacts of each species during the last time step
+    /// - `species`: &mut [Species<P>] species present in model
+    /// - `size`: &CoordinateTriplet<f64> (m) size of bounding box
+    /// - `dt`: f64 (s) time step
+    /// - `rng`: &mut Rng random number generator
+    ///
+    /// # Returns
+    /// `[f64; 6]` (A) current carried by emitted electrons from each wall in the order of `Surface::ALL`
+    ///
+    /// # Errors
+    ///
+    pub fn emit<P: Real>(
+        &self,
+        impacts: &[Vec<Impact>],
+        species: &mut [Species<P>],
+        size: &CoordinateTriplet<f64>,
+        dt: f64,
+        rng: &mut Rng,
+    ) -> [f64; 6] {
+        let size = [size.x, size.y, size.z];
+        let electron = &species[self.electron];
+        let (charge, mass, weight) = (electron.charge, electron.mass, electron.weight);
+
+        let mut emitted: Vec<Particle<P>> = Vec::new();
+        let mut current = [0.0; 6];
+        for (s, rule) in self.rules.iter() {
+            let (impact_mass, impact_weight) = (species[*s].mass, species[*s].weight);
+
+            for impact in impacts[*s].iter() {
+                if !rule.surfaces.is_empty() && !rule.surfaces.contains(&impact.surface) {
+                    continue;
+                }
+
+                let v = impact.velocity;
+                let v_sq = v[0] * v[0] + v[1] * v[1] + v[2] * v[2];
+                let cos_theta = if v_sq > 0.0 {
+                    v[impact.surface.axis()].abs() / v_sq.sqrt()
+                } else {
+                    1.0
+                };
+                let mean = rule.yield_model.value(0.5 * impact_mass * v_sq, cos_theta);
+
+                // macro-particles of differing weight are emitted with a probability matching the number of physical
+                // electrons emitted
+                for _ in 0..stochastic_round(mean * impact_weight / weight, rng) {
+                    let velocity = self.sample(&impact.surface, mass, rng);
+                    let fraction = (1.0 - rng.uniform()) * dt;
+                    let particle: Particle<P> = Particle::narrow(&Particle {
+                        position: [
+                            impact.position[0] + velocity[0] * fraction,
+                            impact.position[1] + velocity[1] * fraction,
+                            impact.position[2] + velocity[2] * fraction,
+                        ],
+                        velocity,
+                    });
+
+                    // electrons emitted near edges may leave through a neighbouring wall
+                    if particle
+                        .position
+                        .iter()
+                        .zip(size.iter())
+                        .all(|(x, l)| (0.0..*l).contains(&x.widen()))
+                    {
+                        emitted.push(particle);
+                        current[impact.surface.index()] += charge * weight / dt;
+                    }
+                }
+            }
+        }
+
+        species[self.electron].particles.extend(emitted);
+        current
+    }
+
+    /// samples the velocity of a single emitted electron
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `surface`: &Surface emitting wall
+    /// - `mass`: f64 (kg) electron mass
+    /// - `rng`: &mut Rng random number generator
+    ///
+    /// # Returns
+    /// `[f64; 3]` (m/s) velocity pointing into the bounding box
+    ///
+    /// # Errors
+    ///
+    fn sample(&self, surface: &Surface, mass: f64, rng: &mut Rng) -> [f64; 3] {
+        // normal and two tangential components in a frame aligned with the wall
+        let (normal, t1, t2) = match self.energy {
+            EmissionEnergy::Maxwellian(temperature) => {
+                let v_th = (BOLTZMANN * temperature / mass).sqrt();
+                (
+                    v_th * (-2.0 * (1.0 - rng.uniform()).ln()).sqrt(),
+                    v_th * rng.normal(),
+                    v_th * rng.normal(),
+                )
+            }
+            EmissionEnergy::Monoenergetic(energy) => {
+                let speed = (2.0 * energy / mass).sqrt();
+                let cos_theta = rng.uniform().sqrt();
+                let sin_theta = (1.0 - cos_theta * cos_theta).sqrt();
+                let phi = 2.0 * PI * rng.uniform();
+                (
+                    speed * cos_theta,
+                    speed * sin_theta * phi.cos(),
+                    speed * sin_theta * phi.sin(),
+                )
+            }
+        };
+
+        let axis = surface.axis();
+        let mut velocity = [0.0; 3];
+        velocity[axis] = surface.inward() * normal;
+        velocity[(axis + 1) % 3] = t1;
+        velocity[(axis + 2) % 3] = t2;
+        velocity
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::constants::{AMU, ELEC_CHARGE, ELEC_MASS, EV_TEMP};
+    use crate::emission::{
+        Emission, EmissionConfig, EmissionEnergy, EmissionRule, Impact, Surface, YieldModel,
+    };
+    use crate::species::{Particle, Species};
+    use crate::utils::coordinate_triplet::CoordinateTriplet;
+    use crate::utils::rng::Rng;
+
+    /// tests `Impact::new()` for the first crossed wall and point of impact
+    ///
+    /// # Errors
+    /// - incorrect wall is hit
+    /// - point of impact is incorrect
+    ///
+    #[test]
+    fn impact_new_correct() {
+        let size = [1.0, 2.0, 3.0];
+        let impact = Impact::new(
+            &Particle {
+                position: [1.2, 2.05, 1.0],
+                velocity: [0.4, 0.15, 0.0],
+            },
+            1.0,
+            &size,
+        );
+        assert_eq!(impact.surface, Surface::XHigh);
+        assert!((impact.position[0] - 1.0).abs() < 1e-12);
+        assert!((impact.position[1] - 1.975).abs() < 1e-12);
+
+        let impact = Impact::new(
+            &Particle {
+                position: [0.5, 1.0, -0.1],
+                velocity: [0.0, 0.0, -1.0],
+            },
+            1.0,
+            &size,
+        );
+        assert_eq!(impact.surface, Surface::ZLow);
+        assert_eq!(impact.position, [0.5, 1.0, 0.0]);
+    }
+
+    /// tests `YieldModel::value()` for correct yield curves
+    ///
+    /// # Errors
+    /// - yield at energy of maximum yield differs from maximum yield at normal incidence
+    /// - yield is non-zero below threshold
+    /// - yield does not increase for oblique incidence
+    ///
+    #[test]
+    fn yield_value_correct() {
+        let vaughan = YieldModel::Vaughan {
+            max_yield: 2.0,
+            max_energy: 400.0 * ELEC_CHARGE,
+            threshold: 12.5 * ELEC_CHARGE,
+            smoothness: 1.0,
+        };
+        assert!((vaughan.value(400.0 * ELEC_CHARGE, 1.0) - 2.0).abs() < 1e-12);
+        assert_eq!(vaughan.value(10.0 * ELEC_CHARGE, 1.0), 0.0);
+        assert!(vaughan.value(100.0 * ELEC_CHARGE, 1.0) < 2.0);
+        assert!(
+            vaughan.value(1000.0 * ELEC_CHARGE, 0.5) > vaughan.value(1000.0 * ELEC_CHARGE, 1.0)
+        );
+
+        let furman = YieldModel::Furman {
+            max_yield: 1.8848,
+            max_energy: 276.8 * ELEC_CHARGE,
+            shape: 1.54,
+            angular: [0.66, 0.8, 0.7, 1.0],
+        };
+        assert!((furman.value(276.8 * ELEC_CHARGE, 1.0) - 1.8848).abs() < 1e-12);
+        assert!(furman.value(50.0 * ELEC_CHARGE, 1.0) < 1.8848);
+        assert!(furman.value(276.8 * ELEC_CHARGE, 0.5) > 1.8848);
+    }
+
+    /// tests `Emission::emit()` for number, direction, and current of emitted electrons
+    ///
+    /// # Errors
+    /// - number of emitted electrons does not match yield
+    /// - any emitted electron lies outside of bounding box or moves out of the emitting wall
+    /// - emitted current is reported for the wrong wall or has an incorrect magnitude
+    /// - impacts on walls excluded from a rule emit electrons
+    ///
+    #[test]
+    fn emit_correct() {
+        // setup
+        let electron = Species::new("electron", -ELEC_CHARGE, ELEC_MASS, 0.0, 0.0, 1.0).unwrap();
+        let ion = Species::new("argon+", ELEC_CHARGE, 39.948 * AMU, 0.0, 0.0, 1.0).unwrap();
+        let mut species = vec![electron, ion];
+        let size = CoordinateTriplet::new(1.0, 1.0, 1.0).unwrap();
+        let config = EmissionConfig {
+            rules: vec![EmissionRule {
+                species: String::from("argon+"),
+                surfaces: vec![Surface::YHigh],
+                yield_model: YieldModel::Constant(0.5),
+            }],
+            energy: EmissionEnergy::Maxwellian(2.0 * EV_TEMP),
+            ..EmissionConfig::default()
+        };
+        let emission = Emission::new(&config, &species).unwrap();
+
+        let hit = |surface: Surface, position: [f64; 3]| Impact {
+            surface,
+            position,
+            velocity: [0.0, 1e4, 0.0],
+        };
+        let mut impacts = vec![
+            Vec::new(),
+            vec![hit(Surface::YHigh, [0.5, 1.0, 0.5]); 10000],
+        ];
+        impacts[1].push(hit(Surface::YLow, [0.5, 0.0, 0.5]));
+        let dt = 1e-9;
+        let current = emission.emit(&impacts, &mut species, &size, dt, &mut Rng::new(0));
+
+        // assertions
+        let emitted = &species[0].particles;
+        assert!((emitted.len() as f64 - 5000.0).abs() < 200.0);
+        assert!(emitted
+            .iter()
+            .all(|p| { p.velocity[1] < 0.0 && p.position.iter().all(|x| (0.0..1.0).contains(x)) }));
+        assert!(
+            (current[Surface::YHigh.index()] + emitted.len() as f64 * ELEC_CHARGE / dt).abs()
+                < 1e-12
+        );
+        assert_eq!(current[Surface::YLow.index()], 0.0);
+    }
+}
@@ -0,0 +1,1195 @@
+//! cylindrical engine module
+//!
+//! describes an axisymmetric electrostatic engine resolving radius and axial position (r-z)
+//!
+//! fields are stored with r along the first and z along the third index of a `ScalarField` holding a single node
+//! along y, macro-particles are stored at positions (r, 0, z) with velocities (vr, vtheta, vz)
+
+use crate::checkpoint::{
+    read_cells, read_clock, read_particles, write_cells, write_clock, write_particles,
+};
+use crate::collisions::mcc::Mcc;
+use crate::config::Config;
+use crate::constants::{BOLTZMANN, VAC_PERM};
+use crate::diagnostics::Diagnostics;
+use crate::engine::poisson::Poisson;
+use crate::engine::{boris, control_widths, rotation, Engine};
+use crate::field::differential::{BoundaryStencil, Differential};
+use crate::field::scalar::ScalarField;
+use crate::field::vector::VectorField;
+use crate::grid::uniform_nodes;
+use crate::output::{Field, Variable};
+use crate::plasma::PlasmaReport;
+use crate::species::{Particle, Species};
+use crate::utils::coordinate_triplet::CoordinateTriplet;
+use crate::utils::rng::Rng;
+use anyhow::anyhow;
+use std::f64::consts::PI;
+use std::io::{Read, Write};
+
+/// `AxialBoundary` enum
+///
+/// describes the planes bounding a cylindrical engine along z
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum AxialBoundary {
+    /// grounded conducting end plates absorbing macro-particles
+    #[default]
+    Grounded,
+
+    /// symmetry planes of zero axial electric field reflecting macro-particles, modelling an infinitely long device
+    Symmetric,
+}
+
+/// `CylindricalConfig` struct
+///
+/// describes the electrodes of a cylindrical engine
+#[derive(Debug, Clone, PartialEq)]
+pub struct CylindricalConfig {
+    /// (m) radius of a coaxial inner electrode, rounded to the nearest node, 0 for none
+    pub inner_radius: f64,
+
+    /// (V) potential of inner electrode
+    pub inner_potential: f64,
+
+    /// (V) potential of outer wall
+    pub outer_potential: f64,
+
+    /// planes bounding the engine along z
+    pub axial: AxialBoundary,
+}
+
+impl Default for CylindricalConfig {
+    /// `CylindricalConfig` default constructor, a grounded cylinder without inner electrode
+    ///
+    /// # Arguments
+    ///
+    /// # Returns
+    /// `CylindricalConfig`
+    ///
+    /// # Errors
+    ///
+    fn default() -> CylindricalConfig {
+        CylindricalConfig {
+            inner_radius: 0.0,
+            inner_potential: 0.0,
+            outer_potential: 0.0,
+            axial: AxialBoundary::default(),
+        }
+    }
+}
+
+/// `Cylindrical` struct
+///
+/// an axisymmetric electrostatic pic engine on an r-z grid
+#[derive(Debug)]
+pub struct Cylindrical {
+    /// (m) size of bounding box, radius along x and length along z
+    size: CoordinateTriplet<f64>,
+
+    /// number of nodes along r, y, and z, 1 along y
+    cells: CoordinateTriplet<usize>,
+
+    /// (m) spatial increment along r and z, the size of the bounding box along y
+    delta: CoordinateTriplet<f64>,
+
+    /// (m) radius of inner electrode, 0 for none
+    inner_radius: f64,
+
+    /// planes bounding the engine along z
+    axial: AxialBoundary,
+
+    /// (V) electric potential
+    potential: ScalarField<f64>,
+
+    /// (C/m^3) charge density
+    charge_density: ScalarField<f64>,
+
+    /// (V/m) electric field with radial component along x and axial component along z
+    electric_field: VectorField<f64>,
+
+    /// (m^3) node control volumes of the annuli swept by nodes
+    cell_vol: ScalarField<f64>,
+
+    /// (m^-2) coefficients of the finite volume cylindrical poisson operator for use in gauss-seidel sor scheme,
+    /// solving for nodes outside the electrodes
+    poisson: Poisson<f64>,
+
+    /// finite difference stencils of the electric field solve, symmetric on axis and on symmetric axial boundaries
+    differential: Differential,
+
+    /// number of cells of the grid used to bin macro-particles for collisions
+    collision_cells: CoordinateTriplet<usize>,
+
+    /// characteristic plasma parameters and their validation against discretization
+    plasma_report: PlasmaReport,
+
+    /// (s) time step
+    dt: f64,
+
+    /// (s) simulated time
+    time: f64,
+
+    /// number of completed time steps
+    step: u64,
+
+    /// (T) applied uniform axial magnetic flux density
+    magnetic_field: f64,
+
+    /// species present in engine
+    species: Vec<Species>,
+
+    /// monte carlo collisions with background gas
+    mcc: Option<Mcc>,
+
+    /// random number generator
+    rng: Rng,
+}
+
+impl Cylindrical {
+    /// `Cylindrical` constructor
+    ///
+    /// `config.size[0]` and `config.cells[0]` describe the radius and `config.size[2]` and `config.cells[2]` the
+    /// length, a background gas density profile must have `config.cells[0]` nodes along x, 2 along y, and
+    /// `config.cells[2]` along z
+    ///
+    /// # Arguments
+    /// - `config`: &Config model configuration
+    ///
+    /// # Returns
+    /// `Result<Cylindrical, anyhow::Error>`
+    ///
+    /// # Errors
+    /// - applied magnetic flux density is not axial
+    /// - grid is not uniform
+    /// - inner electrode radius is negative or not inside the outer wall
+    /// - coulomb collisions, dsmc collisions, or secondary electron emission are configured
+    /// - magnetostatic sources are configured
+    /// - any call to `CoordinateTriplet::new()` fails
+    /// - any call to `ScalarField::new()` fails
+    /// - any call to `VectorField::new()` fails
+    /// - call to `Poisson::with_unknown()` fails
+    /// - call to `Differential::uniform()` fails
+    /// - any plasma parameter check with `Severity::Error` fails
+    /// - call to `Mcc::new()` fails
+    pub fn new(config: &Config) -> Result<Cylindrical, anyhow::Error> {
+        if config.magnetic_field[0] != 0.0 || config.magnetic_field[1] != 0.0 {
+            return Err(anyhow!(
+                "cylindrical engine requires an axial magnetic flux density, got {:?}",
+                config.magnetic_field
+            ));
+        }
+        if !config.grid.is_uniform() {
+            return Err(anyhow!(
+                "non-uniform grids are not supported by the cylindrical engine"
+            ));
+        }
+        if config.coulomb.enabled || !config.dsmc.molecules.is_empty() {
+            return Err(anyhow!(
+                "coulomb and dsmc collisions are not supported by the cylindrical engine"
+            ));
+        }
+        if !config.magnetostatics.sources.is_empty() {
+            return Err(anyhow!(
+                "magnetostatic sources are not supported by the cylindrical engine"
+            ));
+        }
+        if !config.emission.rules.is_empty() {
+            return Err(anyhow!(
+                "secondary electron emission is not supported by the cylindrical engine"
+            ));
+        }
+
+        // unpack dimensions
+        let size: CoordinateTriplet<f64> =
+            CoordinateTriplet::new(config.size[0], config.size[1], config.size[2])?;
+
+        // unpack cells along r and z
+        let cells: CoordinateTriplet<usize> =
+            CoordinateTriplet::new(config.cells[0], 1, config.cells[2])?;
+
+        // initialize spatial increments
+        let dr = size.x / (cells.x - 1) as f64;
+        let dz = size.z / (cells.z - 1) as f64;
+        let delta: CoordinateTriplet<f64> = CoordinateTriplet::new(dr, size.y, dz)?;
+
+        // locate inner electrode
+        let inner_radius = config.cylindrical.inner_radius;
+        if inner_radius.is_nan() || inner_radius < 0.0 || inner_radius >= size.x - dr {
+            return Err(anyhow!(
+                "inner electrode radius must be in [0, {}), got {inner_radius}",
+                size.x - dr
+            ));
+        }
+        let inner = if inner_radius > 0.0 {
+            Some((inner_radius / dr).round() as usize)
+        } else {
+            None
+        };
+
+        // validate plasma parameters against spatial and temporal discretization
+        let resolved = CoordinateTriplet::new(dr, dr, dz)?;
+        let plasma_report = PlasmaReport::new(
+            &config.species,
+            &config.magnetic_field,
+            &resolved,
+            config.dt,
+            &config.validation,
+        );
+        plasma_report.enforce()?;
+
+        // initialize potential with electrode potentials
+        let mut potential: ScalarField<f64> = ScalarField::new(&cells)?;
+        potential
+            .plane_mut(0, cells.x - 1)?
+            .fill(config.cylindrical.outer_potential);
+        if let Some(inner) = inner {
+            let electrode = CoordinateTriplet::new(inner + 1, cells.y, cells.z)?;
+            potential
+                .sub_box_mut((0, 0, 0), &electrode)?
+                .fill(config.cylindrical.inner_potential);
+        }
+
+        // initialize remaining fields
+        let charge_density: ScalarField<f64> = ScalarField::new(&cells)?;
+        let electric_field: VectorField<f64> = VectorField::new(&cells)?;
+
+        // initialize cell volumes as annuli halfway between nodes in r squared, consistent with volume weighting
+        let r_sq: Vec<f64> = (0..cells.x).map(|i| (i as f64 * dr).powi(2)).collect();
+        let z: Vec<f64> = (0..cells.z).map(|k| k as f64 * dz).collect();
+        let mut cell_vol: ScalarField<f64> = ScalarField::new(&cells)?;
+        for (i, wr) in control_widths(&r_sq).iter().enumerate() {
+            for (k, wz) in control_widths(&z).iter().enumerate() {
+                cell_vol[(i, 0, k)] = PI * wr * wz;
+            }
+        }
+
+        // coefficients of the cylindrical poisson operator, radially r[i +- 1/2] / (r[i] dr^2) of inner and outer
+        // neighbours, which reduces to 4 / dr^2 of the outer neighbour on axis by symmetry, symmetric axial
+        // boundaries couple to their interior neighbour from both sides
+        let (dr_inv_sq, dz_inv_sq) = (1.0 / (dr * dr), 1.0 / (dz * dz));
+        let radial: Vec<[f64; 2]> = (0..cells.x)
+            .map(|i| {
+                if i == 0 {
+                    [0.0, 4.0 * dr_inv_sq]
+                } else {
+                    let i = i as f64;
+                    [(i - 0.5) / i * dr_inv_sq, (i + 0.5) / i * dr_inv_sq]
+                }
+            })
+            .collect();
+        let mut axial = vec![[dz_inv_sq; 2]; cells.z];
+        let axial_unknown = match config.cylindrical.axial {
+            AxialBoundary::Grounded => {
+                axial[0] = [0.0; 2];
+                axial[cells.z - 1] = [0.0; 2];
+                1..(cells.z - 1)
+            }
+            AxialBoundary::Symmetric => {
+                axial[0] = [0.0, 2.0 * dz_inv_sq];
+                axial[cells.z - 1] = [2.0 * dz_inv_sq, 0.0];
+                0..cells.z
+            }
+        };
+        let laplacian = CoordinateTriplet::new(radial, vec![[0.0; 2]], axial)?;
+
+        // unknown nodes lie between the electrodes, symmetric axial boundaries are unknown
+        let unknown = CoordinateTriplet::new(
+            inner.map_or(0, |i| i + 1)..(cells.x - 1),
+            0..1,
+            axial_unknown,
+        )?;
+        let poisson = Poisson::with_unknown(&laplacian, &unknown)?;
+
+        // finite difference stencils, the radial electric field vanishes on axis
+        let axial = match config.cylindrical.axial {
+            AxialBoundary::Grounded => BoundaryStencil::OneSided,
+            AxialBoundary::Symmetric => BoundaryStencil::Symmetric,
+        };
+        let boundary = CoordinateTriplet::new(
+            [BoundaryStencil::Symmetric, BoundaryStencil::OneSided],
+            [BoundaryStencil::OneSided; 2],
+            [axial; 2],
+        )?;
+        let differential = Differential::uniform(&delta, &cells, &boundary)?;
+
+        // collision grid of a single cell along y
+        let collision_cells = CoordinateTriplet::new(cells.x, 2, cells.z)?;
+
+        // load macro-particles of all species uniformly in volume
+        let mut rng = Rng::new(config.seed);
+        let mut species = config.species.clone();
+        for s in species.iter_mut() {
+            load_maxwellian(s, inner_radius, size.x, size.z, &mut rng);
+        }
+
+        // set up monte carlo collisions
+        let mcc = match &config.mcc.gas {
+            Some(gas) => Some(Mcc::new(gas, &config.mcc, &species, &collision_cells)?),
+            None => None,
+        };
+
+        Ok(Cylindrical {
+            size,
+            cells,
+            delta,
+            inner_radius,
+            axial: config.cylindrical.axial,
+            potential,
+            charge_density,
+            electric_field,
+            cell_vol,
+            poisson,
+            differential,
+            collision_cells,
+            plasma_report,
+            dt: config.dt,
+            time: 0.0,
+            step: 0,
+            magnetic_field: config.magnetic_field[2],
+            species,
+            mcc,
+            rng,
+        })
+    }
+
+    /// returns species of `Cylindrical`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `&[Species]` species present in engine
+    ///
+    /// # Errors
+    ///
+    pub fn species(&self) -> &[Species] {
+        &self.species
+    }
+
+    /// returns electric potential of `Cylindrical`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `&ScalarField<f64>` (V) electric potential
+    ///
+    /// # Errors
+    ///
+    pub fn potential(&self) -> &ScalarField<f64> {
+        &self.potential
+    }
+
+    /// returns charge density of `Cylindrical`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `&ScalarField<f64>` (C/m^3) charge density
+    ///
+    /// # Errors
+    ///
+    pub fn charge_density(&self) -> &ScalarField<f64> {
+        &self.charge_density
+    }
+
+    /// returns electric field of `Cylindrical`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `&VectorField<f64>` (V/m) electric field with radial component along x and axial component along z
+    ///
+    /// # Errors
+    ///
+    pub fn electric_field(&self) -> &VectorField<f64> {
+        &self.electric_field
+    }
+
+    /// computes energy stored in the electric field of `Cylindrical`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `f64` (J) field energy
+    ///
+    /// # Errors
+    ///
+    pub fn field_energy(&self) -> f64 {
+        let e = &self.electric_field;
+        0.5 * VAC_PERM
+            * e.x
+                .iter()
+                .zip(e.z.iter())
+                .zip(self.cell_vol.iter())
+                .map(|((r, z), vol)| (r * r + z * z) * vol)
+                .sum::<f64>()
+    }
+
+    /// deposits charge density of all species onto nodes of `Cylindrical` using volume weighting
+    ///
+    /// # Arguments
+    /// - `self`: &mut self mutable reference to self
+    ///
+    /// # Returns
+    /// Result<(), anyhow::Error>`
+    ///
+    /// # Errors
+    fn deposit_charge(&mut self) -> Result<(), anyhow::Error> {
+        // clear previous charge density
+        self.charge_density.iter_mut().for_each(|rho| *rho = 0.0);
+
+        // accumulate charge on nodes
+        for species in self.species.iter() {
+            deposit(
+                &mut self.charge_density,
+                &species.particles,
+                species.charge * species.weight,
+                &self.delta,
+                &self.cells,
+            );
+        }
+
+        // divide by node control volumes
+        self.charge_density
+            .iter_mut()
+            .zip(self.cell_vol.iter())
+            .for_each(|(rho, vol)| *rho /= vol);
+
+        Ok(())
+    }
+
+    /// advances macro-particles of all species using the boris scheme in the local cartesian frame of every
+    /// macro-particle, which is then rotated back onto the r-z plane so that angular momentum is conserved in the
+    /// absence of azimuthal forces
+    ///
+    /// macro-particles reaching the outer wall or the inner electrode are absorbed, macro-particles leaving along
+    /// z are absorbed by grounded and reflected by symmetric axial boundaries
+    ///
+    /// # Arguments
+    /// - `self`: &mut self mutable reference to self
+    ///
+    /// # Returns
+    /// Result<(), anyhow::Error>`
+    ///
+    /// # Errors
+    fn push_particles(&mut self) -> Result<(), anyhow::Error> {
+        let b = [0.0, 0.0, self.magnetic_field];
+        let (radius, length) = (self.size.x, self.size.z);
+        let symmetric = self.axial == AxialBoundary::Symmetric;
+
+        for species in self.species.iter_mut() {
+            // half step charge to mass ratio
+            let qm_half_dt = 0.5 * species.charge / species.mass * self.dt;
+
+            // boris rotation vectors
+            let (t, s) = rotation(&b, qm_half_dt);
+
+            for particle in species.particles.iter_mut() {
+                // gather electric field at macro-particle
+                let mut e = [0.0; 3];
+                for ((i, k), w) in weights(&particle.position, &self.delta, &self.cells) {
+                    e[0] += w * self.electric_field.x[(i, 0, k)];
+                    e[2] += w * self.electric_field.z[(i, 0, k)];
+                }
+
+                // accelerate and rotate
+                let v = boris(&particle.velocity, &e, &t, &s, qm_half_dt);
+
+                // advance position in local cartesian frame
+                let x = particle.position[0] + v[0] * self.dt;
+                let y = v[1] * self.dt;
+                let mut z = particle.position[2] + v[2] * self.dt;
+                let r = x.hypot(y);
+
+                // rotate velocity back onto r-z plane
+                let (cos, sin) = if r > 0.0 { (x / r, y / r) } else { (1.0, 0.0) };
+                let mut vz = v[2];
+
+                // reflect at symmetric axial boundaries
+                if symmetric {
+                    if z < 0.0 {
+                        z = -z;
+                        vz = -vz;
+                    } else if z > length {
+                        z = 2.0 * length - z;
+                        vz = -vz;
+                    }
+                }
+
+                particle.position = [r, 0.0, z];
+                particle.velocity = [cos * v[0] + sin * v[1], -sin * v[0] + cos * v[1], vz];
+            }
+
+            // absorb macro-particles at electrodes and grounded axial boundaries
+            let inner_radius = self.inner_radius;
+            species.particles.retain(|p| {
+                let [r, _, z] = p.position;
+                let axial = if symmetric {
+                    (0.0..=length).contains(&z)
+                } else {
+                    (0.0..length).contains(&z)
+                };
+                r < radius && r >= inner_radius && axial
+            });
+        }
+
+        Ok(())
+    }
+
+    /// updates electric potential of `Cylindrical` using gauss-seidel sor on the finite volume discretization of
+    /// the cylindrical poisson operator
+    ///
+    /// the outer wall and inner electrode hold fixed potentials, on axis the radial operator reduces to
+    /// 4 (phi[1] - phi[0]) / dr^2 by symmetry
+    ///
+    /// # Arguments
+    /// - `self`: &mut self mutable reference to self
+    ///
+    /// # Returns
+    /// Result<(), anyhow::Error>`
+    ///
+    /// # Errors
+    /// - call to `Poisson::solve()` fails
+    fn update_potential(&mut self) -> Result<(), anyhow::Error> {
+        self.poisson
+            .solve(&mut self.potential, &self.charge_density)
+    }
+
+    /// updates electric field of `Cylindrical`, the radial component vanishes on axis and the axial component
+    /// vanishes on symmetric axial boundaries
+    ///
+    /// # Arguments
+    /// - `self`: &mut self mutable reference to self
+    ///
+    /// # Returns
+    /// Result<(), anyhow::Error>`
+    ///
+    /// # Errors
+    /// - call to `Differential::gradient()` fails
+    fn solve_electric_field(&mut self) -> Result<(), anyhow::Error> {
+        // electric field is the negative gradient of the potential
+        self.electric_field = -self.differential.gradient(&self.potential)?;
+
+        Ok(())
+    }
+}
+
+impl Engine for Cylindrical {
+    /// updates `Cylindrical` to the next time step
+    ///
+    /// # Arguments
+    /// - `self`: &mut self mutable reference to self
+    ///
+    /// # Returns
+    /// Result<(), anyhow::Error>`
+    ///
+    /// # Errors
+    /// - any call to `Self::deposit_charge()` fails
+    /// - any call to `Self::update_potential()` fails
+    /// - any call to `Self::solve_electric_field()` fails
+    /// - any call to `Self::push_particles()` fails
+    fn update(&mut self) -> Result<(), anyhow::Error> {
+        // deposit charge density of all species
+        Self::deposit_charge(self)?;
+
+        // update electrostatic potential
+        Self::update_potential(self)?;
+
+        // update electric field from calculated electrostatic potential
+        Self::solve_electric_field(self)?;
+
+        // advance macro-particles in electric and magnetic field
+        Self::push_particles(self)?;
+
+        // collide macro-particles with background gas
+        if let Some(mcc) = self.mcc.as_ref() {
+            mcc.collide(
+                &mut self.species,
+                &self.delta,
+                &self.collision_cells,
+                self.dt,
+                &mut self.rng,
+            );
+        }
+
+        // advance time
+        self.step += 1;
+        self.time += self.dt;
+
+        Ok(())
+    }
+
+    /// returns simulated time of `Cylindrical`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `f64` (s) simulated time
+    ///
+    /// # Errors
+    ///
+    fn time(&self) -> f64 {
+        self.time
+    }
+
+    /// returns number of completed time steps of `Cylindrical`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `u64` number of completed time steps
+    ///
+    /// # Errors
+    ///
+    fn step(&self) -> u64 {
+        self.step
+    }
+
+    /// returns size of bounding box of `Cylindrical`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `&CoordinateTriplet<f64>` (m) size of bounding box, radius along x and length along z
+    ///
+    /// # Errors
+    ///
+    fn size(&self) -> &CoordinateTriplet<f64> {
+        &self.size
+    }
+
+    /// returns number of cells of `Cylindrical`, 1 along y
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `&CoordinateTriplet<usize>` number of cells in bounding box
+    ///
+    /// # Errors
+    ///
+    fn cells(&self) -> &CoordinateTriplet<usize> {
+        &self.cells
+    }
+
+    /// returns equally spaced node coordinates of `Cylindrical`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `CoordinateTriplet<Vec<f64>>` (m) node coordinates along each axis
+    ///
+    /// # Errors
+    ///
+    fn nodes(&self) -> CoordinateTriplet<Vec<f64>> {
+        CoordinateTriplet {
+            x: uniform_nodes(self.size.x, self.cells.x),
+            y: uniform_nodes(self.size.y, self.cells.y),
+            z: uniform_nodes(self.size.z, self.cells.z),
+        }
+    }
+
+    /// returns plasma parameter report of `Cylindrical`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `&PlasmaReport` characteristic plasma parameters and their validation against discretization
+    ///
+    /// # Errors
+    ///
+    fn plasma_report(&self) -> &PlasmaReport {
+        &self.plasma_report
+    }
+
+    /// computes number density of a single species on nodes of `Cylindrical` using volume weighting
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `index`: usize index of species
+    ///
+    /// # Returns
+    /// `Result<ScalarField<f64>, anyhow::Error>` (m^-3) number density
+    ///
+    /// # Errors
+    /// - `index` is out of bounds
+    /// - call to `ScalarField::new()` fails
+    fn number_density(&self, index: usize) -> Result<ScalarField<f64>, anyhow::Error> {
+        let species = self.species.get(index).ok_or_else(|| {
+            anyhow!(
+                "species index {index} out of bounds for {} species",
+                self.species.len()
+            )
+        })?;
+
+        let mut density = ScalarField::new(&self.cells)?;
+        deposit(
+            &mut density,
+            &species.particles,
+            species.weight,
+            &self.delta,
+            &self.cells,
+        );
+        density
+            .iter_mut()
+            .zip(self.cell_vol.iter())
+            .for_each(|(n, vol)| *n /= vol);
+
+        Ok(density)
+    }
+
+    /// returns variables of field output of `Cylindrical`, excluding number densities
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `Result<Vec<Variable>, anyhow::Error>`
+    ///
+    /// # Errors
+    /// - any call to `Variable::new()` fails
+    fn variables(&self) -> Result<Vec<Variable>, anyhow::Error> {
+        Ok(vec![
+            Variable::new("potential", "V", 1)?,
+            Variable::new("charge_density", "C/m^3", 1)?,
+            Variable::new("electric_field", "V/m", 3)?,
+        ])
+    }
+
+    /// returns fields of field output of `Cylindrical` in the order of `Cylindrical::variables()`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `Vec<Field<'_>>`
+    ///
+    /// # Errors
+    ///
+    fn fields(&self) -> Vec<Field<'_>> {
+        vec![
+            Field::Scalar(&self.potential),
+            Field::Scalar(&self.charge_density),
+            Field::Vector(&self.electric_field),
+        ]
+    }
+
+    /// computes conservation diagnostics of `Cylindrical`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `Diagnostics`
+    ///
+    /// # Errors
+    ///
+    fn diagnostics(&self) -> Diagnostics {
+        Diagnostics::cylindrical(self)
+    }
+
+    /// writes the complete evolving state of `Cylindrical` in a little endian binary format
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `writer`: &mut dyn Write destination of state
+    ///
+    /// # Returns
+    /// `Result<(), anyhow::Error>`
+    ///
+    /// # Errors
+    /// - any write fails
+    fn write_checkpoint(&self, writer: &mut dyn Write) -> Result<(), anyhow::Error> {
+        // time stepping and random number generator state
+        write_clock(writer, self.step, self.time, &self.rng)?;
+
+        // field state
+        write_cells(writer, &self.cells)?;
+        self.potential.write_binary(writer)?;
+        self.charge_density.write_binary(writer)?;
+        self.electric_field.write_binary(writer)?;
+
+        // particle state
+        write_particles(writer, &self.species)
+    }
+
+    /// reads the complete evolving state of `Cylindrical` from a little endian binary format
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `reader`: &mut dyn Read source of state
+    ///
+    /// # Returns
+    /// `Result<(), anyhow::Error>`
+    ///
+    /// # Errors
+    /// - any read fails
+    /// - number of cells does not match `Cylindrical`
+    /// - number of species does not match `Cylindrical`
+    fn read_checkpoint(&mut self, reader: &mut dyn Read) -> Result<(), anyhow::Error> {
+        // time stepping and random number generator state
+        (self.step, self.time, self.rng) = read_clock(reader)?;
+
+        // field state
+        read_cells(reader, &self.cells)?;
+        self.potential.read_binary(reader)?;
+        self.charge_density.read_binary(reader)?;
+        self.electric_field.read_binary(reader)?;
+
+        // particle state
+        read_particles(reader, &mut self.species)
+    }
+}
+
+/// loads macro-particles of a species uniformly in the volume of an annulus with a maxwellian velocity
+/// distribution
+///
+/// # Arguments
+/// - `species`: &mut Species species to load
+/// - `inner_radius`: f64 (m) inner radius of annulus
+/// - `radius`: f64 (m) outer radius of annulus
+/// - `length`: f64 (m) length of annulus
+/// - `rng`: &mut Rng random number generator
+///
+/// # Returns
+///
+/// # Errors
+///
+fn load_maxwellian(
+    species: &mut Species,
+    inner_radius: f64,
+    radius: f64,
+    length: f64,
+    rng: &mut Rng,
+) {
+    // number of macro-particles
+    let r_sq = (inner_radius * inner_radius, radius * radius);
+    let volume = PI * (r_sq.1 - r_sq.0) * length;
+    let count = (species.density * volume / species.weight).round() as usize;
+
+    // (m/s) thermal velocity
+    let v_th = (BOLTZMANN * species.temperature / species.mass).sqrt();
+
+    species.particles = (0..count)
+        .map(|_| Particle {
+            position: [
+                (r_sq.0 + rng.uniform() * (r_sq.1 - r_sq.0)).sqrt(),
+                0.0,
+                rng.uniform() * length,
+            ],
+            velocity: [
+                rng.normal() * v_th,
+                rng.normal() * v_th,
+                rng.normal() * v_th,
+            ],
+        })
+        .collect();
+}
+
+/// computes volume weighting of a position on the r-z nodes of a cylindrical grid
+///
+/// radial weights are linear in r squared, so that a uniform density is deposited uniformly
+///
+/// # Arguments
+/// - `position`: &[f64; 3] (m) position (r, 0, z) inside bounding box
+/// - `delta`: &CoordinateTriplet<f64> (m) spatial increment
+/// - `cells`: &CoordinateTriplet<usize> number of cells in bounding box
+///
+/// # Returns
+/// `[((usize, usize), f64); 4]` (i, k) indices and weights of the four surrounding nodes
+///
+/// # Errors
+///
+fn weights(
+    position: &[f64; 3],
+    delta: &CoordinateTriplet<f64>,
+    cells: &CoordinateTriplet<usize>,
+) -> [((usize, usize), f64); 4] {
+    // radial lower node and fractional offset in r squared
+    let lr = position[0] / delta.x;
+    let i = (lr as usize).min(cells.x - 2);
+    let fr = (lr * lr - (i * i) as f64) / (2 * i + 1) as f64;
+
+    // axial lower node and fractional offset
+    let lz = position[2] / delta.z;
+    let k = (lz as usize).min(cells.z - 2);
+    let fz = lz - k as f64;
+
+    [
+        ((i, k), (1.0 - fr) * (1.0 - fz)),
+        ((i + 1, k), fr * (1.0 - fz)),
+        ((i, k + 1), (1.0 - fr) * fz),
+        ((i + 1, k + 1), fr * fz),
+    ]
+}
+
+/// deposits a quantity carried by every macro-particle onto nodes of a cylindrical grid using volume weighting
+///
+/// # Arguments
+/// - `field`: &mut ScalarField<f64> field to accumulate onto
+/// - `particles`: &[Particle] macro-particles to deposit
+/// - `value`: f64 quantity carried by a single macro-particle
+/// - `delta`: &CoordinateTriplet<f64> (m) spatial increment
+/// - `cells`: &CoordinateTriplet<usize> number of cells in bounding box
+///
+/// # Returns
+///
+/// # Errors
+///
+fn deposit(
+    field: &mut ScalarField<f64>,
+    particles: &[Particle],
+    value: f64,
+    delta: &CoordinateTriplet<f64>,
+    cells: &CoordinateTriplet<usize>,
+) {
+    for particle in particles.iter() {
+        for ((i, k), w) in weights(&particle.position, delta, cells) {
+            field[(i, 0, k)] += value * w;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::{Config, EngineKind};
+    use crate::constants::{ELEC_CHARGE, ELEC_MASS, EV_TEMP, VAC_PERM};
+    use crate::engine::cylindrical::{AxialBoundary, Cylindrical};
+    use crate::engine::Engine;
+    use crate::magnetostatics::Source;
+    use crate::species::{Particle, Species};
+
+    /// helper function that sets up a `Config` of a cylindrical engine with electrons for testing
+    ///
+    /// # Arguments
+    ///
+    /// # Returns
+    /// `Result<Config, anyhow::Error>`
+    ///
+    /// # Errors
+    /// - call to `Config::new()` fails
+    /// - call to `Species::new()` fails
+    fn setup() -> Result<Config, anyhow::Error> {
+        let mut config = Config::new(&[0.01, 0.01, 0.02], &[41, 3, 21], 1e-11)?;
+        config.engine = EngineKind::Cylindrical;
+        config.species = vec![Species::new(
+            "electron",
+            -ELEC_CHARGE,
+            ELEC_MASS,
+            1e12,
+            EV_TEMP,
+            1e3,
+        )?];
+        Ok(config)
+    }
+
+    /// tests `Cylindrical::new()` for failure on unsupported configurations
+    ///
+    /// # Errors
+    /// - `Cylindrical::new()` fails for valid input
+    /// - `Cylindrical::new()` succeeds for a transverse magnetic flux density
+    /// - `Cylindrical::new()` succeeds for coulomb collisions
+    /// - `Cylindrical::new()` succeeds for an inner electrode outside the outer wall
+    /// - `Cylindrical::new()` succeeds with magnetostatic sources
+    ///
+    #[test]
+    fn new_failure() {
+        let config = setup().unwrap();
+        assert!(Cylindrical::new(&config).is_ok());
+
+        let mut transverse = config.clone();
+        transverse.magnetic_field = [0.01, 0.0, 0.0];
+        assert!(Cylindrical::new(&transverse).is_err());
+
+        let mut coulomb = config.clone();
+        coulomb.coulomb.enabled = true;
+        assert!(Cylindrical::new(&coulomb).is_err());
+
+        let mut electrode = config.clone();
+        electrode.cylindrical.inner_radius = 0.01;
+        assert!(Cylindrical::new(&electrode).is_err());
+
+        let mut solenoid = config.clone();
+        solenoid.magnetostatics.sources.push(Source::Solenoid {
+            center: [0.0; 3],
+            axis: [0.0, 0.0, 1.0],
+            radius: 0.02,
+            length: 0.1,
+            turns: 100,
+            current: 10.0,
+        });
+        assert!(Cylindrical::new(&solenoid).is_err());
+    }
+
+    /// tests `Cylindrical::update_potential()` and `Cylindrical::solve_electric_field()` against the analytic
+    /// solution of an infinitely long cylindrical capacitor
+    ///
+    /// # Errors
+    /// - potential deviates from v ln(b / r) / ln(b / a)
+    /// - radial electric field deviates from v / (r ln(b / a))
+    ///
+    #[test]
+    fn update_potential_capacitor() {
+        // setup
+        let mut config = setup().unwrap();
+        config.species.clear();
+        config.cylindrical.inner_radius = 0.002;
+        config.cylindrical.inner_potential = 100.0;
+        config.cylindrical.axial = AxialBoundary::Symmetric;
+        let mut engine = Cylindrical::new(&config).unwrap();
+        engine.update_potential().unwrap();
+        engine.solve_electric_field().unwrap();
+
+        // assertions
+        let (a, b, v) = (0.002, 0.01, 100.0);
+        for i in 8..engine.cells.x {
+            let r = i as f64 * engine.delta.x;
+            for k in 0..engine.cells.z {
+                let exact = v * (b / r).ln() / (b / a).ln();
+                assert!((engine.potential[(i, 0, k)] - exact).abs() < 1e-3 * v);
+                assert!(engine.electric_field.z[(i, 0, k)].abs() < 1e-6 * v / b);
+                if i > 8 && i < engine.cells.x - 1 {
+                    let er = v / (r * (b / a).ln());
+                    assert!((engine.electric_field.x[(i, 0, k)] - er).abs() < 1e-2 * er);
+                }
+            }
+        }
+    }
+
+    /// tests `Cylindrical::update_potential()` for the exact parabolic potential of a uniformly charged infinitely
+    /// long cylinder, which exercises the operator on axis
+    ///
+    /// # Errors
+    /// - potential deviates from rho (b^2 - r^2) / (4 eps0)
+    ///
+    #[test]
+    fn update_potential_uniform_charge() {
+        // setup
+        let mut config = setup().unwrap();
+        config.species.clear();
+        config.cylindrical.axial = AxialBoundary::Symmetric;
+        let mut engine = Cylindrical::new(&config).unwrap();
+        let rho = 1e-6;
+        engine.charge_density.iter_mut().for_each(|r| *r = rho);
+        engine.update_potential().unwrap();
+
+        // assertions
+        let b = engine.size.x;
+        let peak = rho * b * b / (4.0 * VAC_PERM);
+        for i in 0..engine.cells.x {
+            let r = i as f64 * engine.delta.x;
+            let exact = rho * (b * b - r * r) / (4.0 * VAC_PERM);
+            for k in 0..engine.cells.z {
+                assert!((engine.potential[(i, 0, k)] - exact).abs() < 1e-6 * peak);
+            }
+        }
+    }
+
+    /// tests `Cylindrical::deposit_charge()` for conservation of charge and a uniform radial profile of uniformly
+    /// loaded macro-particles
+    ///
+    /// # Errors
+    /// - integrated charge density does not match total charge of macro-particles
+    /// - radial profile of charge density is not uniform to within statistical noise
+    ///
+    #[test]
+    fn deposit_charge_uniform() {
+        // setup
+        let mut config = setup().unwrap();
+        config.species[0].weight = 1e2;
+        let mut engine = Cylindrical::new(&config).unwrap();
+        engine.deposit_charge().unwrap();
+
+        // assertions
+        let total: f64 = engine
+            .charge_density
+            .iter()
+            .zip(engine.cell_vol.iter())
+            .map(|(rho, vol)| rho * vol)
+            .sum();
+        let species = &engine.species[0];
+        let expected = species.particles.len() as f64 * species.charge * species.weight;
+        assert!((total - expected).abs() / expected.abs() < 1e-12);
+
+        // average over z to reduce noise, the outermost radial nodes see the most macro-particles
+        let rho_0 = -ELEC_CHARGE * 1e12;
+        for i in [10, 20, 30, 40] {
+            let mean = (0..engine.cells.z)
+                .map(|k| engine.charge_density[(i, 0, k)])
+                .sum::<f64>()
+                / engine.cells.z as f64;
+            assert!((mean - rho_0).abs() / rho_0.abs() < 0.05);
+        }
+    }
+
+    /// tests `Cylindrical::push_particles()` for conservation of angular momentum of a force free macro-particle
+    /// passing close to the axis
+    ///
+    /// # Errors
+    /// - angular momentum r vtheta is not conserved
+    /// - speed is not conserved
+    /// - macro-particle leaves the r-z plane
+    ///
+    #[test]
+    fn push_particles_angular_momentum() {
+        // setup
+        let mut config = setup().unwrap();
+        config.species.clear();
+        config
+            .species
+            .push(Species::new("electron", -ELEC_CHARGE, ELEC_MASS, 0.0, 0.0, 1.0).unwrap());
+        let mut engine = Cylindrical::new(&config).unwrap();
+        let initial = Particle {
+            position: [0.004, 0.0, 0.01],
+            velocity: [-1e6, 2e5, 0.0],
+        };
+        engine.species[0].particles = vec![initial];
+        for _ in 0..500 {
+            engine.push_particles().unwrap();
+        }
+
+        // assertions
+        let particle = engine.species[0].particles[0];
+        let momentum = |p: &Particle| p.position[0] * p.velocity[1];
+        let speed = |p: &Particle| p.velocity.iter().map(|v| v * v).sum::<f64>().sqrt();
+        assert!((momentum(&particle) - momentum(&initial)).abs() / momentum(&initial) < 1e-12);
+        assert!((speed(&particle) - speed(&initial)).abs() / speed(&initial) < 1e-12);
+        assert!(particle.velocity[0] > 0.0);
+        assert_eq!(particle.position[1], 0.0);
+    }
+
+    /// tests `Cylindrical::write_checkpoint()` and `Cylindrical::read_checkpoint()` for a lossless round trip
+    ///
+    /// # Errors
+    /// - restored `Cylindrical` does not write an identical checkpoint
+    ///
+    #[test]
+    fn checkpoint_round_trip() {
+        // setup
+        let config = setup().unwrap();
+        let mut engine = Cylindrical::new(&config).unwrap();
+        for _ in 0..3 {
+            engine.update().unwrap();
+        }
+        let mut a = Vec::new();
+        engine.write_checkpoint(&mut a).unwrap();
+
+        let mut restored = Cylindrical::new(&config).unwrap();
+        restored.read_checkpoint(&mut a.as_slice()).unwrap();
+        let mut b = Vec::new();
+        restored.write_checkpoint(&mut b).unwrap();
+
+        // assertions
+        assert_eq!(a, b);
+        assert_eq!(restored.step(), 3);
+    }
+}
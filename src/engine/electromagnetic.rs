@@ -0,0 +1,1316 @@
+//! electromagnetic engine module
+//!
+//! describes a fully electromagnetic engine advancing maxwell's equations on a staggered yee grid
+
+use crate::checkpoint::{
+    read_cells, read_clock, read_particles, write_cells, write_clock, write_particles,
+};
+use crate::collisions::coulomb::Coulomb;
+use crate::collisions::dsmc::Dsmc;
+use crate::collisions::mcc::Mcc;
+use crate::config::Config;
+use crate::constants::{SPEED_OF_LIGHT, VAC_PERM};
+use crate::diagnostics::Diagnostics;
+use crate::emission::{Emission, Impact};
+use crate::engine::{add, cross, deposit, interpolate, linear_weights, Electrostatic, Engine};
+use crate::field::scalar::ScalarField;
+use crate::field::vector::VectorField;
+use crate::grid::uniform_nodes;
+use crate::output::{Field, Variable};
+use crate::plasma::PlasmaReport;
+use crate::species::{Particle, Species};
+use crate::utils::coordinate_triplet::CoordinateTriplet;
+use crate::utils::rng::Rng;
+use anyhow::anyhow;
+use std::io::{Read, Write};
+
+/// staggering of the electric field components, `true` where a component lies halfway between nodes
+///
+/// ex at (i + 1/2, j, k), ey at (i, j + 1/2, k), and ez at (i, j, k + 1/2), current density shares this staggering
+pub const E_STAGGER: [[bool; 3]; 3] = [
+    [true, false, false],
+    [false, true, false],
+    [false, false, true],
+];
+
+/// staggering of the magnetic flux density components, `true` where a component lies halfway between nodes
+///
+/// bx at (i, j + 1/2, k + 1/2), by at (i + 1/2, j, k + 1/2), and bz at (i + 1/2, j + 1/2, k)
+pub const B_STAGGER: [[bool; 3]; 3] = [
+    [false, true, true],
+    [true, false, true],
+    [true, true, false],
+];
+
+/// `Electromagnetic` struct
+///
+/// describes an electromagnetic pic engine with a yee grid fdtd field solver, esirkepov current deposition, and a
+/// relativistic boris pusher
+///
+/// components staggered along an axis are stored at the lower node of their cell, so the last entry along that
+/// axis is unused, the walls of the bounding box are perfect electric conductors
+#[derive(Debug)]
+pub struct Electromagnetic {
+    /// (m) size of bounding box
+    size: CoordinateTriplet<f64>,
+
+    /// number of nodes along each axis
+    cells: CoordinateTriplet<usize>,
+
+    /// (m) spatial increments
+    delta: CoordinateTriplet<f64>,
+
+    /// (V/m) electric field on yee grid
+    electric_field: VectorField<f64>,
+
+    /// (T) self-consistent magnetic flux density on yee grid, excluding applied magnetic flux density
+    magnetic_field: VectorField<f64>,
+
+    /// (A/m^2) current density of the last time step on yee grid
+    current_density: VectorField<f64>,
+
+    /// (C/m^3) charge density on nodes
+    charge_density: ScalarField<f64>,
+
+    /// (m^3) node control volumes
+    cell_vol: ScalarField<f64>,
+
+    /// characteristic plasma parameters and their validation against discretization
+    plasma_report: PlasmaReport,
+
+    /// (s) time step
+    dt: f64,
+
+    /// (s) simulated time
+    time: f64,
+
+    /// number of completed time steps
+    step: u64,
+
+    /// (T) uniform applied magnetic flux density
+    applied_field: CoordinateTriplet<f64>,
+
+    /// (T) applied magnetic flux density on nodes including magnetostatic sources, `None` if only the uniform
+    /// applied magnetic flux density is used
+    applied_nodes: Option<VectorField<f64>>,
+
+    /// species present in engine, velocities are stored as (m/s) velocity and not as proper velocity
+    species: Vec<Species>,
+
+    /// monte carlo collisions with a background gas, `None` if disabled
+    mcc: Option<Mcc>,
+
+    /// binary coulomb collisions between charged species, `None` if disabled
+    coulomb: Option<Coulomb>,
+
+    /// direct simulation monte carlo collisions between neutral species, `None` if disabled
+    dsmc: Option<Dsmc>,
+
+    /// secondary electron emission at walls, `None` if disabled
+    emission: Option<Emission>,
+
+    /// (A) current emitted from each wall during the last time step in the order of `Surface::ALL`
+    emitted_current: [f64; 6],
+
+    /// random number generator
+    rng: Rng,
+}
+
+impl Electromagnetic {
+    /// `Electromagnetic` constructor
+    ///
+    /// species, collisions, and emission are set up as in `Electrostatic`, the initial electric field is the
+    /// electrostatic field of the loaded macro-particles so that gauss's law holds from the first step
+    ///
+    /// # Arguments
+    /// - `config`: &Config model configuration
+    ///
+    /// # Returns
+    /// `Result<Electromagnetic, anyhow::Error>`
+    ///
+    /// # Errors
+    /// - grid is not uniform
+    /// - call to `Electrostatic::new()` fails
+    /// - time step violates the courant condition of the yee grid
+    /// - any macro-particle is not slower than the speed of light
+    /// - initial potential solve fails
+    /// - any call to `VectorField::new()` fails
+    pub fn new(config: &Config) -> Result<Electromagnetic, anyhow::Error> {
+        if !config.grid.is_uniform() {
+            return Err(anyhow!(
+                "non-uniform grids are not supported by the electromagnetic engine"
+            ));
+        }
+        let mut electrostatic: Electrostatic = Electrostatic::new(config)?;
+
+        // courant condition of the yee grid
+        let delta = electrostatic.delta().clone();
+        let courant = SPEED_OF_LIGHT
+            * config.dt
+            * (1.0 / (delta.x * delta.x) + 1.0 / (delta.y * delta.y) + 1.0 / (delta.z * delta.z))
+                .sqrt();
+        if courant >= 1.0 {
+            return Err(anyhow!(
+                "time step {} violates the courant condition of the yee grid, courant number is {courant}",
+                config.dt
+            ));
+        }
+
+        if electrostatic.species.iter().any(|s| {
+            s.particles.iter().any(|p| {
+                p.velocity.iter().map(|v| v * v).sum::<f64>() >= SPEED_OF_LIGHT * SPEED_OF_LIGHT
+            })
+        }) {
+            return Err(anyhow!(
+                "all macro-particles must be slower than the speed of light"
+            ));
+        }
+
+        // initial electrostatic field, differences of the potential are exactly the staggered yee components
+        electrostatic.deposit_charge()?;
+        electrostatic.update_potential()?;
+        let cells = electrostatic.cells().clone();
+        let mut electric_field: VectorField<f64> = VectorField::new(&cells)?;
+        let potential = electrostatic.potential();
+        for i in 0..cells.x {
+            for j in 0..cells.y {
+                for k in 0..cells.z {
+                    if i + 1 < cells.x {
+                        electric_field.x[(i, j, k)] =
+                            (potential[(i, j, k)] - potential[(i + 1, j, k)]) / delta.x;
+                    }
+                    if j + 1 < cells.y {
+                        electric_field.y[(i, j, k)] =
+                            (potential[(i, j, k)] - potential[(i, j + 1, k)]) / delta.y;
+                    }
+                    if k + 1 < cells.z {
+                        electric_field.z[(i, j, k)] =
+                            (potential[(i, j, k)] - potential[(i, j, k + 1)]) / delta.z;
+                    }
+                }
+            }
+        }
+
+        let Electrostatic {
+            size,
+            charge_density,
+            cell_vol,
+            plasma_report,
+            magnetic_field: applied_field,
+            applied_field: applied_nodes,
+            species,
+            mcc,
+            coulomb,
+            dsmc,
+            emission,
+            rng,
+            ..
+        } = electrostatic;
+
+        let magnetic_field = VectorField::new(&cells)?;
+        let current_density = VectorField::new(&cells)?;
+        Ok(Electromagnetic {
+            size,
+            cells,
+            delta,
+            electric_field,
+            magnetic_field,
+            current_density,
+            charge_density,
+            cell_vol,
+            plasma_report,
+            dt: config.dt,
+            time: 0.0,
+            step: 0,
+            applied_field,
+            applied_nodes,
+            species,
+            mcc,
+            coulomb,
+            dsmc,
+            emission,
+            emitted_current: [0.0; 6],
+            rng,
+        })
+    }
+
+    /// returns species of `Electromagnetic`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `&[Species]` species present in engine
+    ///
+    /// # Errors
+    ///
+    pub fn species(&self) -> &[Species] {
+        &self.species
+    }
+
+    /// returns electric field of `Electromagnetic`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `&VectorField<f64>` (V/m) electric field on yee grid staggered as `E_STAGGER`
+    ///
+    /// # Errors
+    ///
+    pub fn electric_field(&self) -> &VectorField<f64> {
+        &self.electric_field
+    }
+
+    /// returns self-consistent magnetic flux density of `Electromagnetic`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `&VectorField<f64>` (T) magnetic flux density on yee grid staggered as `B_STAGGER`
+    ///
+    /// # Errors
+    ///
+    pub fn magnetic_field(&self) -> &VectorField<f64> {
+        &self.magnetic_field
+    }
+
+    /// returns current density of the last time step of `Electromagnetic`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `&VectorField<f64>` (A/m^2) current density on yee grid staggered as `E_STAGGER`
+    ///
+    /// # Errors
+    ///
+    pub fn current_density(&self) -> &VectorField<f64> {
+        &self.current_density
+    }
+
+    /// returns charge density of `Electromagnetic`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `&ScalarField<f64>` (C/m^3) charge density on nodes
+    ///
+    /// # Errors
+    ///
+    pub fn charge_density(&self) -> &ScalarField<f64> {
+        &self.charge_density
+    }
+
+    /// returns current emitted from each wall of `Electromagnetic` during the last time step
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `[f64; 6]` (A) emitted current in the order of `Surface::ALL`
+    ///
+    /// # Errors
+    ///
+    pub fn emitted_current(&self) -> [f64; 6] {
+        self.emitted_current
+    }
+
+    /// computes energy stored in the electric and self-consistent magnetic field of `Electromagnetic`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `f64` (J) field energy
+    ///
+    /// # Errors
+    ///
+    pub fn field_energy(&self) -> f64 {
+        let delta = [self.delta.x, self.delta.y, self.delta.z];
+        let cells = [self.cells.x, self.cells.y, self.cells.z];
+        0.5 * VAC_PERM
+            * (integrate_squared(&self.electric_field, &E_STAGGER, &delta, &cells)
+                + SPEED_OF_LIGHT
+                    * SPEED_OF_LIGHT
+                    * integrate_squared(&self.magnetic_field, &B_STAGGER, &delta, &cells))
+    }
+
+    /// deposits charge density of all species onto nodes of `Electromagnetic` using linear weighting
+    ///
+    /// # Arguments
+    /// - `self`: &mut self mutable reference to self
+    ///
+    /// # Returns
+    ///
+    /// # Errors
+    ///
+    fn deposit_charge(&mut self) {
+        self.charge_density.iter_mut().for_each(|rho| *rho = 0.0);
+        for species in self.species.iter() {
+            deposit(
+                &mut self.charge_density,
+                &species.particles,
+                species.charge * species.weight,
+                &self.delta,
+                &self.cells,
+            );
+        }
+        self.charge_density
+            .iter_mut()
+            .zip(self.cell_vol.iter())
+            .for_each(|(rho, vol)| *rho /= vol);
+    }
+
+    /// advances macro-particles of all species using the relativistic boris scheme and deposits their current
+    /// density using the charge conserving esirkepov scheme, macro-particles leaving the bounding box are absorbed
+    /// at the wall they cross and may emit secondary electrons
+    ///
+    /// # Arguments
+    /// - `self`: &mut self mutable reference to self
+    ///
+    /// # Returns
+    ///
+    /// # Errors
+    ///
+    fn push_particles(&mut self) {
+        let c_sq = SPEED_OF_LIGHT * SPEED_OF_LIGHT;
+        let size = [self.size.x, self.size.y, self.size.z];
+        let delta = [self.delta.x, self.delta.y, self.delta.z];
+        let cells = [self.cells.x, self.cells.y, self.cells.z];
+        let applied = [
+            self.applied_field.x,
+            self.applied_field.y,
+            self.applied_field.z,
+        ];
+        let record = self.emission.is_some();
+        let mut impacts: Vec<Vec<Impact>> = Vec::with_capacity(self.species.len());
+
+        // clear previous current density
+        for component in [
+            &mut self.current_density.x,
+            &mut self.current_density.y,
+            &mut self.current_density.z,
+        ] {
+            component.iter_mut().for_each(|j| *j = 0.0);
+        }
+
+        for species in self.species.iter_mut() {
+            // half step charge to mass ratio
+            let qm_half_dt = 0.5 * species.charge / species.mass * self.dt;
+            let charge = species.charge * species.weight;
+
+            let mut hits = Vec::new();
+            species.particles.retain_mut(|particle| {
+                // gather fields at macro-particle
+                let e = gather(&self.electric_field, &E_STAGGER, particle, &delta, &cells);
+                let b = gather(&self.magnetic_field, &B_STAGGER, particle, &delta, &cells);
+                let applied = match &self.applied_nodes {
+                    Some(field) => {
+                        let (idx, frac) =
+                            linear_weights(&particle.position, &self.delta, &self.cells);
+                        [
+                            interpolate(&field.x, idx, frac),
+                            interpolate(&field.y, idx, frac),
+                            interpolate(&field.z, idx, frac),
+                        ]
+                    }
+                    None => applied,
+                };
+                let b = add(&b, &applied);
+
+                // proper velocity
+                let v = particle.velocity;
+                let gamma = 1.0 / (1.0 - (v[0] * v[0] + v[1] * v[1] + v[2] * v[2]) / c_sq).sqrt();
+
+                // first half acceleration
+                let u_minus = [
+                    gamma * v[0] + qm_half_dt * e[0],
+                    gamma * v[1] + qm_half_dt * e[1],
+                    gamma * v[2] + qm_half_dt * e[2],
+                ];
+
+                // rotation with lorentz factor at half step
+                let gamma_minus = (1.0
+                    + (u_minus[0] * u_minus[0]
+                        + u_minus[1] * u_minus[1]
+                        + u_minus[2] * u_minus[2])
+                        / c_sq)
+                    .sqrt();
+                let t = [
+                    qm_half_dt * b[0] / gamma_minus,
+                    qm_half_dt * b[1] / gamma_minus,
+                    qm_half_dt * b[2] / gamma_minus,
+                ];
+                let t_sq = t[0] * t[0] + t[1] * t[1] + t[2] * t[2];
+                let s = [
+                    2.0 * t[0] / (1.0 + t_sq),
+                    2.0 * t[1] / (1.0 + t_sq),
+                    2.0 * t[2] / (1.0 + t_sq),
+                ];
+                let u_prime = add(&u_minus, &cross(&u_minus, &t));
+                let u_plus = add(&u_minus, &cross(&u_prime, &s));
+
+                // second half acceleration
+                let u = [
+                    u_plus[0] + qm_half_dt * e[0],
+                    u_plus[1] + qm_half_dt * e[1],
+                    u_plus[2] + qm_half_dt * e[2],
+                ];
+                let gamma = (1.0 + (u[0] * u[0] + u[1] * u[1] + u[2] * u[2]) / c_sq).sqrt();
+                particle.velocity = [u[0] / gamma, u[1] / gamma, u[2] / gamma];
+
+                // advance position
+                let old = particle.position;
+                for (x, v) in particle.position.iter_mut().zip(particle.velocity.iter()) {
+                    *x += v * self.dt;
+                }
+
+                // absorb macro-particles that left bounding box, current is deposited up to the point of impact
+                let inside = particle
+                    .position
+                    .iter()
+                    .zip(size.iter())
+                    .all(|(x, l)| (0.0..*l).contains(x));
+                let end = if inside {
+                    particle.position
+                } else {
+                    let impact = Impact::new(particle, self.dt, &size);
+                    if record {
+                        hits.push(impact);
+                    }
+                    impact.position
+                };
+                deposit_current(
+                    &mut self.current_density,
+                    &old,
+                    &end,
+                    charge,
+                    &delta,
+                    &cells,
+                    self.dt,
+                );
+
+                inside
+            });
+            impacts.push(hits);
+        }
+
+        // emit secondary electrons from walls, their current is deposited from the wall they leave
+        self.emitted_current = [0.0; 6];
+        if let Some(emission) = self.emission.as_ref() {
+            let electron = emission.electron();
+            let before = self.species[electron].particles.len();
+            self.emitted_current = emission.emit(
+                &impacts,
+                &mut self.species,
+                &self.size,
+                self.dt,
+                &mut self.rng,
+            );
+
+            let species = &self.species[electron];
+            let charge = species.charge * species.weight;
+            for particle in species.particles[before..].iter() {
+                let mut start = [0.0; 3];
+                for d in 0..3 {
+                    start[d] =
+                        (particle.position[d] - particle.velocity[d] * self.dt).clamp(0.0, size[d]);
+                }
+                deposit_current(
+                    &mut self.current_density,
+                    &start,
+                    &particle.position,
+                    charge,
+                    &delta,
+                    &cells,
+                    self.dt,
+                );
+            }
+        }
+    }
+
+    /// advances magnetic flux density of `Electromagnetic` using faraday's law
+    ///
+    /// # Arguments
+    /// - `self`: &mut self mutable reference to self
+    /// - `dt`: f64 (s) time increment
+    ///
+    /// # Returns
+    ///
+    /// # Errors
+    ///
+    fn advance_magnetic_field(&mut self, dt: f64) {
+        let (nx, ny, nz) = (self.cells.x, self.cells.y, self.cells.z);
+        let (dx, dy, dz) = (self.delta.x, self.delta.y, self.delta.z);
+        let e = &self.electric_field;
+        let b = &mut self.magnetic_field;
+
+        for i in 0..nx {
+            for j in 0..ny {
+                for k in 0..nz {
+                    if j + 1 < ny && k + 1 < nz {
+                        b.x[(i, j, k)] -= dt
+                            * ((e.z[(i, j + 1, k)] - e.z[(i, j, k)]) / dy
+                                - (e.y[(i, j, k + 1)] - e.y[(i, j, k)]) / dz);
+                    }
+                    if i + 1 < nx && k + 1 < nz {
+                        b.y[(i, j, k)] -= dt
+                            * ((e.x[(i, j, k + 1)] - e.x[(i, j, k)]) / dz
+                                - (e.z[(i + 1, j, k)] - e.z[(i, j, k)]) / dx);
+                    }
+                    if i + 1 < nx && j + 1 < ny {
+                        b.z[(i, j, k)] -= dt
+                            * ((e.y[(i + 1, j, k)] - e.y[(i, j, k)]) / dx
+                                - (e.x[(i, j + 1, k)] - e.x[(i, j, k)]) / dy);
+                    }
+                }
+            }
+        }
+    }
+
+    /// advances electric field of `Electromagnetic` by a full time step using ampere's law, components tangential
+    /// to the walls are held at zero
+    ///
+    /// # Arguments
+    /// - `self`: &mut self mutable reference to self
+    ///
+    /// # Returns
+    ///
+    /// # Errors
+    ///
+    fn advance_electric_field(&mut self) {
+        let (nx, ny, nz) = (self.cells.x, self.cells.y, self.cells.z);
+        let (dx, dy, dz) = (self.delta.x, self.delta.y, self.delta.z);
+        let c_sq_dt = SPEED_OF_LIGHT * SPEED_OF_LIGHT * self.dt;
+        let j_dt = self.dt / VAC_PERM;
+        let b = &self.magnetic_field;
+        let j = &self.current_density;
+        let e = &mut self.electric_field;
+
+        let interior = |n: usize, i: usize| i > 0 && i + 1 < n;
+        for i in 0..nx {
+            for jj in 0..ny {
+                for k in 0..nz {
+                    if i + 1 < nx && interior(ny, jj) && interior(nz, k) {
+                        e.x[(i, jj, k)] += c_sq_dt
+                            * ((b.z[(i, jj, k)] - b.z[(i, jj - 1, k)]) / dy
+                                - (b.y[(i, jj, k)] - b.y[(i, jj, k - 1)]) / dz)
+                            - j_dt * j.x[(i, jj, k)];
+                    }
+                    if interior(nx, i) && jj + 1 < ny && interior(nz, k) {
+                        e.y[(i, jj, k)] += c_sq_dt
+                            * ((b.x[(i, jj, k)] - b.x[(i, jj, k - 1)]) / dz
+                                - (b.z[(i, jj, k)] - b.z[(i - 1, jj, k)]) / dx)
+                            - j_dt * j.y[(i, jj, k)];
+                    }
+                    if interior(nx, i) && interior(ny, jj) && k + 1 < nz {
+                        e.z[(i, jj, k)] += c_sq_dt
+                            * ((b.y[(i, jj, k)] - b.y[(i - 1, jj, k)]) / dx
+                                - (b.x[(i, jj, k)] - b.x[(i, jj - 1, k)]) / dy)
+                            - j_dt * j.z[(i, jj, k)];
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Engine for Electromagnetic {
+    /// advances `Electromagnetic` by a single time step
+    ///
+    /// # Arguments
+    /// - `self`: &mut self mutable reference to self
+    ///
+    /// # Returns
+    /// `Result<(), anyhow::Error>`
+    ///
+    /// # Errors
+    ///
+    fn update(&mut self) -> Result<(), anyhow::Error> {
+        // advance macro-particles and deposit their current density
+        self.push_particles();
+
+        // leapfrog fields, the magnetic flux density is advanced in two half steps around the electric field
+        self.advance_magnetic_field(0.5 * self.dt);
+        self.advance_electric_field();
+        self.advance_magnetic_field(0.5 * self.dt);
+
+        // collide macro-particles with background gas
+        if let Some(mcc) = self.mcc.as_ref() {
+            mcc.collide(
+                &mut self.species,
+                &self.delta,
+                &self.cells,
+                self.dt,
+                &mut self.rng,
+            );
+        }
+
+        // collide charged macro-particles with each other
+        if let Some(coulomb) = self.coulomb.as_ref() {
+            coulomb.collide(
+                &mut self.species,
+                &self.delta,
+                &self.cells,
+                self.dt,
+                &mut self.rng,
+            );
+        }
+
+        // collide neutral macro-particles with each other
+        if let Some(dsmc) = self.dsmc.as_ref() {
+            dsmc.collide(
+                &mut self.species,
+                &self.delta,
+                &self.cells,
+                self.dt,
+                &mut self.rng,
+            );
+        }
+
+        // charge density for output and diagnostics
+        self.deposit_charge();
+
+        // advance time
+        self.step += 1;
+        self.time += self.dt;
+
+        Ok(())
+    }
+
+    /// returns simulated time of `Electromagnetic`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `f64` (s) simulated time
+    ///
+    /// # Errors
+    ///
+    fn time(&self) -> f64 {
+        self.time
+    }
+
+    /// returns number of completed time steps of `Electromagnetic`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `u64` number of completed time steps
+    ///
+    /// # Errors
+    ///
+    fn step(&self) -> u64 {
+        self.step
+    }
+
+    /// returns size of bounding box of `Electromagnetic`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `&CoordinateTriplet<f64>` (m) size of bounding box
+    ///
+    /// # Errors
+    ///
+    fn size(&self) -> &CoordinateTriplet<f64> {
+        &self.size
+    }
+
+    /// returns number of nodes of `Electromagnetic`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `&CoordinateTriplet<usize>` number of nodes along each axis
+    ///
+    /// # Errors
+    ///
+    fn cells(&self) -> &CoordinateTriplet<usize> {
+        &self.cells
+    }
+
+    /// returns equally spaced node coordinates of `Electromagnetic`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `CoordinateTriplet<Vec<f64>>` (m) node coordinates along each axis
+    ///
+    /// # Errors
+    ///
+    fn nodes(&self) -> CoordinateTriplet<Vec<f64>> {
+        CoordinateTriplet {
+            x: uniform_nodes(self.size.x, self.cells.x),
+            y: uniform_nodes(self.size.y, self.cells.y),
+            z: uniform_nodes(self.size.z, self.cells.z),
+        }
+    }
+
+    /// returns plasma parameter report of `Electromagnetic`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `&PlasmaReport` characteristic plasma parameters and their validation against discretization
+    ///
+    /// # Errors
+    ///
+    fn plasma_report(&self) -> &PlasmaReport {
+        &self.plasma_report
+    }
+
+    /// computes number density of a single species on nodes of `Electromagnetic` using linear weighting
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `index`: usize index of species
+    ///
+    /// # Returns
+    /// `Result<ScalarField<f64>, anyhow::Error>` (m^-3) number density
+    ///
+    /// # Errors
+    /// - `index` is out of bounds
+    /// - call to `ScalarField::new()` fails
+    fn number_density(&self, index: usize) -> Result<ScalarField<f64>, anyhow::Error> {
+        let species = self.species.get(index).ok_or_else(|| {
+            anyhow!(
+                "species index {index} out of bounds for {} species",
+                self.species.len()
+            )
+        })?;
+
+        let mut density = ScalarField::new(&self.cells)?;
+        deposit(
+            &mut density,
+            &species.particles,
+            species.weight,
+            &self.delta,
+            &self.cells,
+        );
+        density
+            .iter_mut()
+            .zip(self.cell_vol.iter())
+            .for_each(|(n, vol)| *n /= vol);
+
+        Ok(density)
+    }
+
+    /// returns variables of field output of `Electromagnetic`, excluding number densities
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `Result<Vec<Variable>, anyhow::Error>`
+    ///
+    /// # Errors
+    /// - any call to `Variable::new()` fails
+    fn variables(&self) -> Result<Vec<Variable>, anyhow::Error> {
+        Ok(vec![
+            Variable::new("charge_density", "C/m^3", 1)?,
+            Variable::new("electric_field", "V/m", 3)?,
+            Variable::new("magnetic_field", "T", 3)?,
+            Variable::new("current_density", "A/m^2", 3)?,
+        ])
+    }
+
+    /// returns fields of field output of `Electromagnetic` in the order of `Electromagnetic::variables()`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `Vec<Field<'_>>`
+    ///
+    /// # Errors
+    ///
+    fn fields(&self) -> Vec<Field<'_>> {
+        vec![
+            Field::Scalar(&self.charge_density),
+            Field::Vector(&self.electric_field),
+            Field::Vector(&self.magnetic_field),
+            Field::Vector(&self.current_density),
+        ]
+    }
+
+    /// computes conservation diagnostics of `Electromagnetic`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `Diagnostics`
+    ///
+    /// # Errors
+    ///
+    fn diagnostics(&self) -> Diagnostics {
+        Diagnostics::electromagnetic(self)
+    }
+
+    /// writes the complete evolving state of `Electromagnetic` in a little endian binary format
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `writer`: &mut dyn Write destination of state
+    ///
+    /// # Returns
+    /// `Result<(), anyhow::Error>`
+    ///
+    /// # Errors
+    /// - any write fails
+    fn write_checkpoint(&self, writer: &mut dyn Write) -> Result<(), anyhow::Error> {
+        // time stepping and random number generator state
+        write_clock(writer, self.step, self.time, &self.rng)?;
+
+        // field state
+        write_cells(writer, &self.cells)?;
+        self.electric_field.write_binary(writer)?;
+        self.magnetic_field.write_binary(writer)?;
+        self.current_density.write_binary(writer)?;
+        self.charge_density.write_binary(writer)?;
+
+        // particle state
+        write_particles(writer, &self.species)
+    }
+
+    /// reads the complete evolving state of `Electromagnetic` from a little endian binary format
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `reader`: &mut dyn Read source of state
+    ///
+    /// # Returns
+    /// `Result<(), anyhow::Error>`
+    ///
+    /// # Errors
+    /// - any read fails
+    /// - number of cells does not match `Electromagnetic`
+    /// - number of species does not match `Electromagnetic`
+    fn read_checkpoint(&mut self, reader: &mut dyn Read) -> Result<(), anyhow::Error> {
+        // time stepping and random number generator state
+        (self.step, self.time, self.rng) = read_clock(reader)?;
+
+        // field state
+        read_cells(reader, &self.cells)?;
+        self.electric_field.read_binary(reader)?;
+        self.magnetic_field.read_binary(reader)?;
+        self.current_density.read_binary(reader)?;
+        self.charge_density.read_binary(reader)?;
+
+        // particle state
+        read_particles(reader, &mut self.species)
+    }
+}
+
+/// computes lower index and fractional offset of a position along a single axis of a possibly staggered grid
+///
+/// positions beyond the outermost points of a staggered axis are clamped to them
+///
+/// # Arguments
+/// - `x`: f64 (m) position
+/// - `delta`: f64 (m) spatial increment
+/// - `cells`: usize number of nodes
+/// - `staggered`: bool points lie halfway between nodes
+///
+/// # Returns
+/// `(usize, f64)` lower index and fractional offset
+///
+/// # Errors
+///
+fn axis_weight(x: f64, delta: f64, cells: usize, staggered: bool) -> (usize, f64) {
+    let (offset, points) = if staggered {
+        (0.5, cells - 1)
+    } else {
+        (0.0, cells)
+    };
+    let s = (x / delta - offset).clamp(0.0, (points - 1) as f64);
+    let index = (s.floor() as usize).min(points - 2);
+    (index, s - index as f64)
+}
+
+/// interpolates a field on the yee grid to a macro-particle using trilinear weighting of each component
+///
+/// # Arguments
+/// - `field`: &VectorField<f64> field on yee grid
+/// - `stagger`: &[[bool; 3]; 3] staggering of each component
+/// - `particle`: &Particle macro-particle
+/// - `delta`: &[f64; 3] (m) spatial increments
+/// - `cells`: &[usize; 3] number of nodes
+///
+/// # Returns
+/// `[f64; 3]` interpolated field
+///
+/// # Errors
+///
+fn gather(
+    field: &VectorField<f64>,
+    stagger: &[[bool; 3]; 3],
+    particle: &Particle,
+    delta: &[f64; 3],
+    cells: &[usize; 3],
+) -> [f64; 3] {
+    let mut value = [0.0; 3];
+    for (c, component) in [&field.x, &field.y, &field.z].into_iter().enumerate() {
+        let w: Vec<(usize, f64)> = (0..3)
+            .map(|d| axis_weight(particle.position[d], delta[d], cells[d], stagger[c][d]))
+            .collect();
+        for (a, wx) in [(0, 1.0 - w[0].1), (1, w[0].1)] {
+            for (b, wy) in [(0, 1.0 - w[1].1), (1, w[1].1)] {
+                for (e, wz) in [(0, 1.0 - w[2].1), (1, w[2].1)] {
+                    value[c] += wx * wy * wz * component[(w[0].0 + a, w[1].0 + b, w[2].0 + e)];
+                }
+            }
+        }
+    }
+    value
+}
+
+/// deposits current density of a single macro-particle moving along a straight line during one time step using
+/// the charge conserving esirkepov scheme with linear shape functions
+///
+/// the macro-particle must move less than one spatial increment along each axis and stay within the bounding box
+///
+/// # Arguments
+/// - `current`: &mut VectorField<f64> (A/m^2) current density on yee grid
+/// - `old`: &[f64; 3] (m) position at start of time step
+/// - `new`: &[f64; 3] (m) position at end of time step
+/// - `charge`: f64 (C) charge of macro-particle
+/// - `delta`: &[f64; 3] (m) spatial increments
+/// - `cells`: &[usize; 3] number of nodes
+/// - `dt`: f64 (s) time step
+///
+/// # Returns
+///
+/// # Errors
+///
+fn deposit_current(
+    current: &mut VectorField<f64>,
+    old: &[f64; 3],
+    new: &[f64; 3],
+    charge: f64,
+    delta: &[f64; 3],
+    cells: &[usize; 3],
+    dt: f64,
+) {
+    // shape functions on a stencil of three nodes along each axis covering both positions
+    let mut base = [0usize; 3];
+    let mut s0 = [[0.0; 3]; 3];
+    let mut ds = [[0.0; 3]; 3];
+    for d in 0..3 {
+        let cell = |x: f64| ((x / delta[d]).floor().max(0.0) as usize).min(cells[d] - 2);
+        base[d] = cell(old[d]).min(cell(new[d])).min(cells[d] - 3);
+        for a in 0..3 {
+            let node = (base[d] + a) as f64;
+            let shape = |x: f64| (1.0 - (x / delta[d] - node).abs()).max(0.0);
+            s0[d][a] = shape(old[d]);
+            ds[d][a] = shape(new[d]) - s0[d][a];
+        }
+    }
+
+    // esirkepov decomposition of the change of shape into currents along each axis
+    for (c, component) in [&mut current.x, &mut current.y, &mut current.z]
+        .into_iter()
+        .enumerate()
+    {
+        let (p, q) = ((c + 1) % 3, (c + 2) % 3);
+        let scale = -charge / (dt * delta[p] * delta[q]);
+        for b in 0..3 {
+            for e in 0..3 {
+                let transverse = s0[p][b] * s0[q][e]
+                    + 0.5 * ds[p][b] * s0[q][e]
+                    + 0.5 * s0[p][b] * ds[q][e]
+                    + ds[p][b] * ds[q][e] / 3.0;
+                if transverse == 0.0 {
+                    continue;
+                }
+
+                let mut flux = 0.0;
+                for (a, change) in ds[c].iter().take(2).enumerate() {
+                    flux += change * transverse;
+                    let mut index = [0usize; 3];
+                    index[c] = base[c] + a;
+                    index[p] = base[p] + b;
+                    index[q] = base[q] + e;
+                    component[(index[0], index[1], index[2])] += scale * flux;
+                }
+            }
+        }
+    }
+}
+
+/// integrates the squared magnitude of a field on the yee grid over the bounding box
+///
+/// points on walls along unstaggered axes are weighted by half
+///
+/// # Arguments
+/// - `field`: &VectorField<f64> field on yee grid
+/// - `stagger`: &[[bool; 3]; 3] staggering of each component
+/// - `delta`: &[f64; 3] (m) spatial increments
+/// - `cells`: &[usize; 3] number of nodes
+///
+/// # Returns
+/// `f64` integral of squared magnitude
+///
+/// # Errors
+///
+fn integrate_squared(
+    field: &VectorField<f64>,
+    stagger: &[[bool; 3]; 3],
+    delta: &[f64; 3],
+    cells: &[usize; 3],
+) -> f64 {
+    let volume = delta[0] * delta[1] * delta[2];
+    let weight = |i: usize, n: usize, staggered: bool| match (staggered, i) {
+        (true, i) if i + 1 == n => 0.0,
+        (true, _) => 1.0,
+        (false, i) if i == 0 || i + 1 == n => 0.5,
+        (false, _) => 1.0,
+    };
+
+    let mut sum = 0.0;
+    for (c, component) in [&field.x, &field.y, &field.z].into_iter().enumerate() {
+        for i in 0..cells[0] {
+            for j in 0..cells[1] {
+                for k in 0..cells[2] {
+                    let w = weight(i, cells[0], stagger[c][0])
+                        * weight(j, cells[1], stagger[c][1])
+                        * weight(k, cells[2], stagger[c][2]);
+                    sum += w * component[(i, j, k)] * component[(i, j, k)];
+                }
+            }
+        }
+    }
+    sum * volume
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::Config;
+    use crate::constants::{AMU, ELEC_CHARGE, ELEC_MASS, EV_TEMP, SPEED_OF_LIGHT, VAC_PERM};
+    use crate::engine::electromagnetic::{deposit_current, Electromagnetic};
+    use crate::engine::{deposit, Engine};
+    use crate::field::scalar::ScalarField;
+    use crate::field::vector::VectorField;
+    use crate::species::{Particle, Species};
+    use crate::utils::coordinate_triplet::CoordinateTriplet;
+    use crate::utils::rng::Rng;
+
+    /// helper function that sets up a `Config` with electrons and ions for testing
+    ///
+    /// # Arguments
+    ///
+    /// # Returns
+    /// `Result<Config, anyhow::Error>`
+    ///
+    /// # Errors
+    /// - call to `Config::new()` fails
+    /// - any call to `Species::new()` fails
+    fn setup() -> Result<Config, anyhow::Error> {
+        let mut config = Config::new(&[0.01, 0.01, 0.01], &[11, 11, 11], 1e-12)?;
+        config.species = vec![
+            Species::new("electron", -ELEC_CHARGE, ELEC_MASS, 1e12, EV_TEMP, 1e4)?,
+            Species::new("argon+", ELEC_CHARGE, 39.948 * AMU, 1e12, EV_TEMP, 1e4)?,
+        ];
+        Ok(config)
+    }
+
+    /// tests `Electromagnetic::new()` for failure on a time step violating the courant condition
+    ///
+    /// # Errors
+    /// - `Electromagnetic::new()` succeeds for a time step above the courant limit
+    /// - `Electromagnetic::new()` fails for a time step below the courant limit
+    ///
+    #[test]
+    fn new_courant_failure() {
+        let mut config = setup().unwrap();
+        assert!(Electromagnetic::new(&config).is_ok());
+        config.dt = 1e-11;
+        assert!(Electromagnetic::new(&config).is_err());
+    }
+
+    /// tests `deposit_current()` for satisfying the discrete continuity equation
+    ///
+    /// # Errors
+    /// - change of charge on any node differs from the divergence of the deposited current density
+    ///
+    #[test]
+    fn deposit_current_conservative() {
+        // setup
+        let cells = [6, 5, 7];
+        let delta = [0.1, 0.2, 0.3];
+        let triplet = CoordinateTriplet::new(cells[0], cells[1], cells[2]).unwrap();
+        let delta_triplet = CoordinateTriplet::new(delta[0], delta[1], delta[2]).unwrap();
+        let dt = 1e-9;
+        let mut rng = Rng::new(3);
+        let mut current: VectorField<f64> = VectorField::new(&triplet).unwrap();
+        let mut before: ScalarField<f64> = ScalarField::new(&triplet).unwrap();
+        let mut after: ScalarField<f64> = ScalarField::new(&triplet).unwrap();
+        for _ in 0..50 {
+            let mut old = [0.0; 3];
+            let mut new = [0.0; 3];
+            for d in 0..3 {
+                let size = delta[d] * (cells[d] - 1) as f64;
+                old[d] = size * rng.uniform();
+                new[d] = (old[d] + delta[d] * (2.0 * rng.uniform() - 1.0)).clamp(0.0, size);
+            }
+            let charge = 2.0 * rng.uniform() - 1.0;
+            deposit_current(&mut current, &old, &new, charge, &delta, &cells, dt);
+            deposit(
+                &mut before,
+                &[Particle {
+                    position: old,
+                    velocity: [0.0; 3],
+                }],
+                charge,
+                &delta_triplet,
+                &triplet,
+            );
+            deposit(
+                &mut after,
+                &[Particle {
+                    position: new,
+                    velocity: [0.0; 3],
+                }],
+                charge,
+                &delta_triplet,
+                &triplet,
+            );
+        }
+
+        // assertions
+        let volume = delta[0] * delta[1] * delta[2];
+        let flux = |field: &ScalarField<f64>, i: usize, j: usize, k: usize, d: usize| {
+            let mut index = [i, j, k];
+            let upper = field[(index[0], index[1], index[2])];
+            if index[d] == 0 {
+                return upper;
+            }
+            index[d] -= 1;
+            upper - field[(index[0], index[1], index[2])]
+        };
+        for i in 0..cells[0] {
+            for j in 0..cells[1] {
+                for k in 0..cells[2] {
+                    let rate = (after[(i, j, k)] - before[(i, j, k)]) / (dt * volume);
+                    let div = flux(&current.x, i, j, k, 0) / delta[0]
+                        + flux(&current.y, i, j, k, 1) / delta[1]
+                        + flux(&current.z, i, j, k, 2) / delta[2];
+                    assert!((rate + div).abs() < 1e-6 * (1.0 / (dt * volume)));
+                }
+            }
+        }
+    }
+
+    /// tests `Electromagnetic::update()` for preserving gauss's law on interior nodes
+    ///
+    /// # Errors
+    /// - gauss's law residual on any interior node changes during time stepping
+    /// - no macro-particle remains to test with
+    ///
+    #[test]
+    fn update_gauss_law() {
+        // setup
+        let config = setup().unwrap();
+        let mut engine = Electromagnetic::new(&config).unwrap();
+        let residual = |engine: &Electromagnetic| -> Vec<f64> {
+            let (nx, ny, nz) = (engine.cells.x, engine.cells.y, engine.cells.z);
+            let (dx, dy, dz) = (engine.delta.x, engine.delta.y, engine.delta.z);
+            let e = &engine.electric_field;
+            let mut residual = Vec::new();
+            for i in 1..nx - 1 {
+                for j in 1..ny - 1 {
+                    for k in 1..nz - 1 {
+                        let div = (e.x[(i, j, k)] - e.x[(i - 1, j, k)]) / dx
+                            + (e.y[(i, j, k)] - e.y[(i, j - 1, k)]) / dy
+                            + (e.z[(i, j, k)] - e.z[(i, j, k - 1)]) / dz;
+                        residual.push(VAC_PERM * div - engine.charge_density[(i, j, k)]);
+                    }
+                }
+            }
+            residual
+        };
+        let initial = residual(&engine);
+
+        for _ in 0..20 {
+            engine.update().unwrap();
+        }
+
+        // assertions
+        assert!(engine.species.iter().all(|s| !s.particles.is_empty()));
+        let scale = engine
+            .charge_density
+            .iter()
+            .fold(0.0_f64, |max, rho| max.max(rho.abs()));
+        for (a, b) in initial.iter().zip(residual(&engine).iter()) {
+            assert!((a - b).abs() < 1e-9 * scale);
+        }
+    }
+
+    /// tests `Electromagnetic::push_particles()` for relativistic gyration in an applied magnetic field
+    ///
+    /// # Errors
+    /// - speed of a macro-particle in a pure magnetic field changes
+    /// - velocity does not rotate by the relativistic gyration angle
+    ///
+    #[test]
+    fn push_particles_relativistic() {
+        // setup
+        let mut config = Config::new(&[1.0, 1.0, 1.0], &[5, 5, 5], 1e-10).unwrap();
+        config.magnetic_field = [0.0, 0.0, 0.1];
+        let mut engine = Electromagnetic::new(&config).unwrap();
+        let mut species =
+            Species::new("electron", -ELEC_CHARGE, ELEC_MASS, 0.0, EV_TEMP, 1.0).unwrap();
+        let speed = 0.9 * SPEED_OF_LIGHT;
+        species.particles = vec![Particle {
+            position: [0.5, 0.5, 0.5],
+            velocity: [speed, 0.0, 0.0],
+        }];
+        engine.species.push(species);
+
+        engine.push_particles();
+
+        // assertions
+        let v = engine.species[0].particles[0].velocity;
+        let gamma = 1.0 / (1.0 - 0.81_f64).sqrt();
+        let t = ELEC_CHARGE * 0.1 * 1e-10 / (2.0 * gamma * ELEC_MASS);
+        let angle = v[1].atan2(v[0]);
+        assert!(((v[0] * v[0] + v[1] * v[1]).sqrt() - speed).abs() / speed < 1e-9);
+        assert!((angle - 2.0 * t.atan()).abs() < 1e-6);
+    }
+
+    /// tests `Electromagnetic::write_checkpoint()` and `Electromagnetic::read_checkpoint()` for a lossless round trip
+    ///
+    /// # Errors
+    /// - restored `Electromagnetic` does not write an identical checkpoint
+    ///
+    #[test]
+    fn checkpoint_round_trip() {
+        // setup
+        let config = setup().unwrap();
+        let mut engine = Electromagnetic::new(&config).unwrap();
+        for _ in 0..3 {
+            engine.update().unwrap();
+        }
+        let mut a = Vec::new();
+        engine.write_checkpoint(&mut a).unwrap();
+
+        let mut restored = Electromagnetic::new(&config).unwrap();
+        restored.read_checkpoint(&mut a.as_slice()).unwrap();
+        let mut b = Vec::new();
+        restored.write_checkpoint(&mut b).unwrap();
+
+        // assertions
+        assert_eq!(a, b);
+        assert_eq!(restored.step(), 3);
+    }
+}
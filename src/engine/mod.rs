@@ -2,11 +2,41 @@
 //!
 //! contents describe several computational engines for pic models
 
-use crate::constants::INV_VAC_PERM;
-use crate::field::scalar::ScalarField;
+pub mod cylindrical;
+pub mod electromagnetic;
+pub mod poisson;
+pub mod reduced;
+
+use crate::checkpoint::{
+    read_cells, read_clock, read_particles, write_cells, write_clock, write_particles,
+};
+use crate::collisions::coulomb::Coulomb;
+use crate::collisions::dsmc::Dsmc;
+use crate::collisions::mcc::Mcc;
+use crate::config::{Config, EngineKind};
+use crate::constants::VAC_PERM;
+use crate::diagnostics::Diagnostics;
+use crate::emission::{Emission, Impact};
+use crate::engine::cylindrical::Cylindrical;
+use crate::engine::electromagnetic::Electromagnetic;
+use crate::engine::poisson::Poisson;
+use crate::engine::reduced::Reduced;
+use crate::field::differential::{BoundaryStencil, Differential};
+use crate::field::scalar::{ScalarField, PARALLEL_THRESHOLD};
 use crate::field::vector::VectorField;
+use crate::grid::Axis;
+use crate::magnetostatics;
+use crate::output::{Field, Variable};
+use crate::plasma::PlasmaReport;
+use crate::precision::{Precision, Real};
+use crate::species::{Particle, Species};
 use crate::utils::coordinate_triplet::CoordinateTriplet;
+use crate::utils::rng::Rng;
 use anyhow::anyhow;
+use rayon::prelude::*;
+use rayon::ThreadPool;
+use std::io::{Read, Write};
+use std::sync::Arc;
 
 /// sor acceleration constant
 const SOR_ACC: f64 = 1.4;
@@ -20,56 +50,344 @@ const GS_MAX_ITER: u64 = 10000;
 /// gauss-seidel tolerance
 const GS_TOL: f64 = 1e-5;
 
-/// `Electrostatic` struct
+/// multiple of the machine epsilon of the field precision, relative to the magnitude of the terms of the residue,
+/// below which the gauss-seidel residue is dominated by rounding and the solution is considered converged
+const GS_ROUNDING: f64 = 8.0;
+
+/// minimum number of macro-particles deposited or pushed by a single task
+pub(crate) const PARTICLE_CHUNK: usize = 1 << 12;
+
+/// number of blocks of macro-particles deposited onto separate accumulators in deterministic mode, enough to keep
+/// every thread busy while bounding the accumulators cleared and reduced regardless of the number of nodes
+const DEPOSIT_BLOCKS: usize = 64;
+
+/// `Engine` trait
+///
+/// describes a computational engine advancing a pic model in time, implemented by all engines driven by `Model`
+pub trait Engine {
+    /// advances engine by a single time step
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    ///
+    /// # Returns
+    /// `Result<(), anyhow::Error>`
+    ///
+    /// # Errors
+    /// - any stage of the time step fails
+    fn update(&mut self) -> Result<(), anyhow::Error>;
+
+    /// returns simulated time of engine
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `f64` (s) simulated time
+    ///
+    /// # Errors
+    ///
+    fn time(&self) -> f64;
+
+    /// returns number of completed time steps of engine
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `u64` number of completed time steps
+    ///
+    /// # Errors
+    ///
+    fn step(&self) -> u64;
+
+    /// returns size of bounding box of engine
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `&CoordinateTriplet<f64>` (m) size of bounding box
+    ///
+    /// # Errors
+    ///
+    fn size(&self) -> &CoordinateTriplet<f64>;
+
+    /// returns number of nodes of engine
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `&CoordinateTriplet<usize>` number of nodes along each axis
+    ///
+    /// # Errors
+    ///
+    fn cells(&self) -> &CoordinateTriplet<usize>;
+
+    /// returns node coordinates of engine
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `CoordinateTriplet<Vec<f64>>` (m) node coordinates along each axis
+    ///
+    /// # Errors
+    ///
+    fn nodes(&self) -> CoordinateTriplet<Vec<f64>>;
+
+    /// returns plasma parameter report of engine
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `&PlasmaReport` characteristic plasma parameters and their validation against discretization
+    ///
+    /// # Errors
+    ///
+    fn plasma_report(&self) -> &PlasmaReport;
+
+    /// computes number density of a single species on nodes of engine
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `index`: usize index of species
+    ///
+    /// # Returns
+    /// `Result<ScalarField<f64>, anyhow::Error>` (m^-3) number density
+    ///
+    /// # Errors
+    /// - `index` is out of bounds
+    fn number_density(&self, index: usize) -> Result<ScalarField<f64>, anyhow::Error>;
+
+    /// returns variables of field output of engine, excluding number densities
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `Result<Vec<Variable>, anyhow::Error>`
+    ///
+    /// # Errors
+    /// - any call to `Variable::new()` fails
+    fn variables(&self) -> Result<Vec<Variable>, anyhow::Error>;
+
+    /// returns fields of field output of engine in the order of `Engine::variables()`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `Vec<Field<'_>>`
+    ///
+    /// # Errors
+    ///
+    fn fields(&self) -> Vec<Field<'_>>;
+
+    /// computes conservation diagnostics of engine
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `Diagnostics`
+    ///
+    /// # Errors
+    ///
+    fn diagnostics(&self) -> Diagnostics;
+
+    /// writes the complete evolving state of engine in a little endian binary format
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `writer`: &mut dyn Write destination of state
+    ///
+    /// # Returns
+    /// `Result<(), anyhow::Error>`
+    ///
+    /// # Errors
+    /// - any write fails
+    fn write_checkpoint(&self, writer: &mut dyn Write) -> Result<(), anyhow::Error>;
+
+    /// reads the complete evolving state of engine from a little endian binary format
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `reader`: &mut dyn Read source of state
+    ///
+    /// # Returns
+    /// `Result<(), anyhow::Error>`
+    ///
+    /// # Errors
+    /// - any read fails
+    /// - checkpoint does not match discretization or species of engine
+    fn read_checkpoint(&mut self, reader: &mut dyn Read) -> Result<(), anyhow::Error>;
+}
+
+/// constructs the engine selected in configuration
 ///
-/// an electrostatic pic engine
-#[derive(Debug)]
-pub struct Electrostatic {
+/// # Arguments
+/// - `config`: &Config model configuration
+///
+/// # Returns
+/// `Result<Box<dyn Engine>, anyhow::Error>`
+///
+/// # Errors
+/// - `config.precision` is not `Precision::Double` for an engine other than `EngineKind::Electrostatic`
+/// - call to `Electrostatic::new()` fails
+/// - call to `Electromagnetic::new()` fails
+/// - call to `Reduced::new()` fails
+/// - call to `Cylindrical::new()` fails
+pub fn build(config: &Config) -> Result<Box<dyn Engine>, anyhow::Error> {
+    if config.engine != EngineKind::Electrostatic && config.precision != Precision::Double {
+        return Err(anyhow!(
+            "precision {:?} is only supported by the electrostatic engine, got engine {:?}",
+            config.precision,
+            config.engine
+        ));
+    }
+
+    Ok(match config.engine {
+        EngineKind::Electrostatic => match config.precision {
+            Precision::Double => Box::new(Electrostatic::<f64, f64>::new(config)?),
+            Precision::Single => Box::new(Electrostatic::<f32, f32>::new(config)?),
+            Precision::Mixed => Box::new(Electrostatic::<f64, f32>::new(config)?),
+        },
+        EngineKind::Electromagnetic => Box::new(Electromagnetic::new(config)?),
+        EngineKind::Electrostatic1d => Box::new(Reduced::new(config, 1)?),
+        EngineKind::Electrostatic2d => Box::new(Reduced::new(config, 2)?),
+        EngineKind::Cylindrical => Box::new(Cylindrical::new(config)?),
+    })
+}
+
+/// `Electrostatic<F, P>` struct
+///
+/// an electrostatic pic engine storing and solving fields in precision `F` and storing and pushing macro-particles in
+/// precision `P`
+///
+/// collisions, emission, and deposition widen macro-particles to double precision and narrow their results to `P`,
+/// geometry, the applied magnetic flux density, and all reductions remain in double precision
+///
+/// every time step runs on a thread pool of `config.parallel.threads` threads, collisions and emission draw from a
+/// single random number generator so remain serial
+#[derive(Debug, Clone)]
+pub struct Electrostatic<F = f64, P = f64> {
     /// (m) size of bounding box
     size: CoordinateTriplet<f64>,
 
     /// number of cells in bounding box
     cells: CoordinateTriplet<usize>,
 
-    /// (m) spatial increment
+    /// (m) mean spatial increment, the spacing of the uniform grid macro-particles are binned on for collisions, which
+    /// are only enabled if nodes are equally spaced
     delta: CoordinateTriplet<f64>,
 
+    /// node coordinates along every axis
+    axes: CoordinateTriplet<Axis>,
+
     /// (V) electric field potential
-    potential: ScalarField<f64>,
+    potential: ScalarField<F>,
 
     /// (C/m^3) electric charge density
-    charge_density: ScalarField<f64>,
+    charge_density: ScalarField<F>,
 
     /// (V/m) electric field
-    electric_field: VectorField<f64>,
+    electric_field: VectorField<F>,
 
     /// (m^3) cell volumes
     cell_vol: ScalarField<f64>,
 
-    /// (m^-2) inverse spatial increments squared for use in gauss-seidel sor scheme
-    delta_inv_sq: CoordinateTriplet<f64>,
+    /// (m^-2) coefficients of the second derivative along every axis for use in gauss-seidel sor scheme
+    poisson: Poisson<F>,
+
+    /// finite difference stencils of the electric field solve
+    differential: Differential,
+
+    /// characteristic plasma parameters and their validation against discretization
+    plasma_report: PlasmaReport,
+
+    /// (s) time step
+    dt: f64,
+
+    /// (s) simulated time
+    time: f64,
+
+    /// number of completed time steps
+    step: u64,
+
+    /// (T) uniform applied magnetic flux density
+    magnetic_field: CoordinateTriplet<f64>,
+
+    /// (T) applied magnetic flux density on nodes including magnetostatic sources, `None` if only the uniform
+    /// applied magnetic flux density is used
+    applied_field: Option<VectorField<f64>>,
+
+    /// species present in engine, macro-particles stored in precision `P`
+    species: Vec<Species<P>>,
+
+    /// monte carlo collisions with a background gas, `None` if disabled
+    mcc: Option<Mcc>,
+
+    /// binary coulomb collisions between charged species, `None` if disabled
+    coulomb: Option<Coulomb>,
+
+    /// direct simulation monte carlo collisions between neutral species, `None` if disabled
+    dsmc: Option<Dsmc>,
+
+    /// secondary electron emission at walls, `None` if disabled
+    emission: Option<Emission>,
+
+    /// (A) current emitted from each wall during the last time step in the order of `Surface::ALL`
+    emitted_current: [f64; 6],
+
+    /// random number generator
+    rng: Rng,
+
+    /// thread pool every time step runs on
+    pool: Arc<ThreadPool>,
+
+    /// deposit in blocks reduced in order so that runs on the same number of threads are reproducible
+    deterministic: bool,
 }
 
-impl Electrostatic {
+impl<F: Real, P: Real> Electrostatic<F, P> {
     /// `Electrostatic` constructor
     ///
+    /// nodes are distributed along every axis according to `config.grid`, collisions bin macro-particles on the
+    /// uniform grid of `config.cells` nodes so are only supported if nodes are equally spaced along every axis
+    ///
     /// # Arguments
-    /// - `size`: &[f64; 3] (m) size of bounding box
-    /// - `cells`: &[usize; 3] number of cells
+    /// - `config`: &Config model configuration
     ///
     /// # Returns
-    /// `Result<Electrostatic, anyhow::Error>`
+    /// `Result<Electrostatic<F, P>, anyhow::Error>`
     ///
     /// # Errors
     /// - any call to `CoordinateTriplet::new()` fails
+    /// - any call to `Axis::new()` fails
+    /// - collisions are enabled and `config.grid` is not uniform
+    /// - call to `Poisson::new()` fails
+    /// - call to `Differential::new()` fails
+    /// - call to `magnetostatics::applied_field()` fails
+    /// - call to `magnetostatics::write_vtk()` fails
     /// - any call to `ScalarField::new()` fails
     /// - any call to `VectorField::new()` fails
-    pub fn new(size: &[f64; 3], cells: &[usize; 3]) -> Result<Electrostatic, anyhow::Error> {
+    /// - any plasma parameter check with `Severity::Error` fails
+    /// - call to `Mcc::new()` fails
+    /// - call to `Coulomb::new()` fails
+    /// - call to `Dsmc::new()` fails
+    /// - call to `Emission::new()` fails
+    /// - call to `ParallelConfig::thread_pool()` fails
+    pub fn new(config: &Config) -> Result<Electrostatic<F, P>, anyhow::Error> {
         // unpack dimensions
-        let size: CoordinateTriplet<f64> = CoordinateTriplet::new(size[0], size[1], size[2])?;
+        let size: CoordinateTriplet<f64> =
+            CoordinateTriplet::new(config.size[0], config.size[1], config.size[2])?;
 
         // unpack cells
-        let cells: CoordinateTriplet<usize> = CoordinateTriplet::new(cells[0], cells[1], cells[2])?;
+        let cells: CoordinateTriplet<usize> =
+            CoordinateTriplet::new(config.cells[0], config.cells[1], config.cells[2])?;
 
         // initialize spatial increments
         let dx = size.x / (cells.x - 1) as f64;
@@ -77,40 +395,284 @@ impl Electrostatic {
         let dz = size.z / (cells.z - 1) as f64;
         let delta: CoordinateTriplet<f64> = CoordinateTriplet::new(dx, dy, dz)?;
 
-        // todo add assertion that all spacing is less than that of the Debeye length
+        // distribute nodes along every axis
+        let axes = CoordinateTriplet::new(
+            Axis::new(&config.grid.x, size.x, cells.x)?,
+            Axis::new(&config.grid.y, size.y, cells.y)?,
+            Axis::new(&config.grid.z, size.z, cells.z)?,
+        )?;
+
+        // collisions bin macro-particles into cells of volume delta.x * delta.y * delta.z
+        let collisions =
+            config.mcc.gas.is_some() || config.coulomb.enabled || !config.dsmc.molecules.is_empty();
+        if collisions && !config.grid.is_uniform() {
+            return Err(anyhow!("collisions are not supported on non-uniform grids"));
+        }
+
+        // compute applied magnetic flux density on nodes if it is spatially varying or written to a file
+        let magnetostatic = &config.magnetostatics;
+        let applied_field = if magnetostatic.sources.is_empty() && magnetostatic.vtk.is_none() {
+            None
+        } else {
+            let (x, y, z) = (axes.x.nodes(), axes.y.nodes(), axes.z.nodes());
+            let field =
+                magnetostatics::applied_field(magnetostatic, &config.magnetic_field, x, y, z)?;
+            if let Some(path) = &magnetostatic.vtk {
+                magnetostatics::write_vtk(path, &field, x, y, z)?;
+            }
+            Some(field)
+        };
+
+        // strongest applied magnetic flux density on any node
+        let peak_field = match &applied_field {
+            Some(field) => {
+                let ijk = field.argmax_magnitude();
+                [field.x[ijk], field.y[ijk], field.z[ijk]]
+            }
+            None => config.magnetic_field,
+        };
 
-        // precompute inverse of delta squared for use in gauss-seidel sor scheme
-        let dx_inv_sq = 1.0 / (dx * dx);
-        let dy_inv_sq = 1.0 / (dy * dy);
-        let dz_inv_sq = 1.0 / (dz * dz);
-        let delta_inv_sq = CoordinateTriplet::new(dx_inv_sq, dy_inv_sq, dz_inv_sq)?;
+        // validate plasma parameters against the coarsest spatial and temporal discretization and the strongest
+        // applied magnetic flux density
+        let max_delta =
+            CoordinateTriplet::new(axes.x.max_delta(), axes.y.max_delta(), axes.z.max_delta())?;
+        let plasma_report = PlasmaReport::new(
+            &config.species,
+            &peak_field,
+            &max_delta,
+            config.dt,
+            &config.validation,
+        );
+        plasma_report.enforce()?;
+
+        // precompute finite difference coefficients for use in gauss-seidel sor scheme and electric field solve
+        let laplacian =
+            CoordinateTriplet::new(axes.x.laplacian(), axes.y.laplacian(), axes.z.laplacian())?;
+        let poisson = Poisson::new(&laplacian)?;
+        let boundary = CoordinateTriplet::new(
+            [BoundaryStencil::OneSided; 2],
+            [BoundaryStencil::OneSided; 2],
+            [BoundaryStencil::OneSided; 2],
+        )?;
+        let differential =
+            Differential::new(axes.x.nodes(), axes.y.nodes(), axes.z.nodes(), &boundary)?;
 
         // initialize electric potential
-        let potential: ScalarField<f64> = ScalarField::new(&cells)?;
+        let potential: ScalarField<F> = ScalarField::new(&cells)?;
 
         // initialize charge density
-        let charge_density: ScalarField<f64> = ScalarField::new(&cells)?;
+        let charge_density: ScalarField<F> = ScalarField::new(&cells)?;
 
         // initialize electric field
-        let electric_field: VectorField<f64> = VectorField::new(&cells)?;
+        let electric_field: VectorField<F> = VectorField::new(&cells)?;
+
+        // initialize cell volumes as node control volumes
+        let cell_vol = control_volumes(axes.x.nodes(), axes.y.nodes(), axes.z.nodes())?;
+
+        // unpack applied magnetic flux density
+        let magnetic_field = CoordinateTriplet::new(
+            config.magnetic_field[0],
+            config.magnetic_field[1],
+            config.magnetic_field[2],
+        )?;
+
+        // load macro-particles of all species
+        let mut rng = Rng::new(config.seed);
+        let mut species: Vec<Species<P>> = config.species.iter().map(Species::narrow).collect();
+        for s in species.iter_mut() {
+            s.load_maxwellian(&size, &mut rng);
+        }
+
+        // set up monte carlo collisions
+        let mcc = match &config.mcc.gas {
+            Some(gas) => Some(Mcc::new(gas, &config.mcc, &species, &cells)?),
+            None => None,
+        };
+
+        // set up coulomb collisions
+        let coulomb = if config.coulomb.enabled {
+            Some(Coulomb::new(&config.coulomb, &species)?)
+        } else {
+            None
+        };
+
+        // set up dsmc collisions
+        let dsmc = if config.dsmc.molecules.is_empty() {
+            None
+        } else {
+            Some(Dsmc::new(&config.dsmc, &species)?)
+        };
 
-        // initialize cell volumes
-        // todo fill in properly
-        let cell_vol: ScalarField<f64> = ScalarField::new(&cells)?;
+        // set up secondary electron emission
+        let emission = if config.emission.rules.is_empty() {
+            None
+        } else {
+            Some(Emission::new(&config.emission, &species)?)
+        };
 
         Ok(Electrostatic {
             size,
             cells,
             delta,
+            axes,
             potential,
             charge_density,
             electric_field,
             cell_vol,
-            delta_inv_sq,
+            poisson,
+            differential,
+            plasma_report,
+            dt: config.dt,
+            time: 0.0,
+            step: 0,
+            magnetic_field,
+            applied_field,
+            species,
+            mcc,
+            coulomb,
+            dsmc,
+            emission,
+            emitted_current: [0.0; 6],
+            rng,
+            pool: Arc::new(config.parallel.thread_pool()?),
+            deterministic: config.parallel.deterministic,
         })
     }
 
-    /// updates `Electrostatic` to the next time step
+    /// returns spatial increment of `Electrostatic`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `&CoordinateTriplet<f64>` (m) spatial increment
+    ///
+    /// # Errors
+    ///
+    pub fn delta(&self) -> &CoordinateTriplet<f64> {
+        &self.delta
+    }
+
+    /// returns species of `Electrostatic`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `&[Species<P>]` species present in engine
+    ///
+    /// # Errors
+    ///
+    pub fn species(&self) -> &[Species<P>] {
+        &self.species
+    }
+
+    /// returns electric potential of `Electrostatic`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `&ScalarField<F>` (V) electric potential
+    ///
+    /// # Errors
+    ///
+    pub fn potential(&self) -> &ScalarField<F> {
+        &self.potential
+    }
+
+    /// returns electric charge density of `Electrostatic`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `&ScalarField<F>` (C/m^3) electric charge density
+    ///
+    /// # Errors
+    ///
+    pub fn charge_density(&self) -> &ScalarField<F> {
+        &self.charge_density
+    }
+
+    /// returns electric field of `Electrostatic`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `&VectorField<F>` (V/m) electric field
+    ///
+    /// # Errors
+    ///
+    pub fn electric_field(&self) -> &VectorField<F> {
+        &self.electric_field
+    }
+
+    /// computes energy stored in the electric field of `Electrostatic`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `f64` (J) field energy, 0.5 * eps_0 * |E|^2 integrated over node control volumes
+    ///
+    /// # Errors
+    ///
+    pub fn field_energy(&self) -> f64 {
+        0.5 * VAC_PERM
+            * self
+                .electric_field
+                .magnitude_squared()
+                .integral(&self.cell_vol)
+    }
+
+    /// returns applied magnetic flux density on nodes of `Electrostatic`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `Option<&VectorField<f64>>` (T) applied magnetic flux density, `None` if only the uniform applied magnetic
+    /// flux density is used
+    ///
+    /// # Errors
+    ///
+    pub fn applied_field(&self) -> Option<&VectorField<f64>> {
+        self.applied_field.as_ref()
+    }
+
+    /// returns cell volumes of `Electrostatic`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `&ScalarField<f64>` (m^3) cell volumes
+    ///
+    /// # Errors
+    ///
+    pub fn cell_vol(&self) -> &ScalarField<f64> {
+        &self.cell_vol
+    }
+
+    /// returns current emitted from each wall of `Electrostatic` during the last time step
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `[f64; 6]` (A) emitted current in the order of `Surface::ALL`
+    ///
+    /// # Errors
+    ///
+    pub fn emitted_current(&self) -> [f64; 6] {
+        self.emitted_current
+    }
+
+    /// deposits charge density of all species onto nodes of `Electrostatic` using linear weighting
+    ///
+    /// macro-particles are deposited onto thread-private accumulators reduced at the end
     ///
     /// # Arguments
     /// - `self`: &mut self mutable reference to self
@@ -119,19 +681,42 @@ impl Electrostatic {
     /// Result<(), anyhow::Error>`
     ///
     /// # Errors
-    /// - any call to `Self::update_potential()` fails
-    /// - any call to `Self::solve_electric_field()` fails
-    pub fn update(&mut self) -> Result<(), anyhow::Error> {
-        // update electrostatic potential
-        Self::update_potential(self)?;
-        
-        // update electric field from calculated electrostatic potential
-        Self::solve_electric_field(self)?;
-        
+    /// - any call to `deposit_par()` fails
+    pub fn deposit_charge(&mut self) -> Result<(), anyhow::Error> {
+        // clear previous charge density
+        self.charge_density
+            .as_mut_slice()
+            .par_iter_mut()
+            .with_min_len(PARALLEL_THRESHOLD)
+            .for_each(|rho| *rho = F::zero());
+
+        // accumulate charge on nodes
+        for species in self.species.iter() {
+            deposit_par(
+                &mut self.charge_density,
+                &species.particles,
+                species.charge * species.weight,
+                |position| grid_weights(position, &self.axes),
+                self.deterministic,
+            )?;
+        }
+
+        // divide by node control volumes so that boundary nodes, which own only part of a cell, are not underestimated
+        self.charge_density
+            .as_mut_slice()
+            .par_iter_mut()
+            .with_min_len(PARALLEL_THRESHOLD)
+            .zip(self.cell_vol.as_slice().par_iter())
+            .for_each(|(rho, vol)| *rho /= F::narrow(*vol));
+
         Ok(())
     }
 
-    /// updates electric potential of `Electrostatic`
+    /// advances macro-particles of all species using the boris scheme, macro-particles leaving the bounding box
+    /// are absorbed and may emit secondary electrons
+    ///
+    /// the push is carried out in precision `P` macro-particles are stored in, macro-particles are pushed concurrently
+    /// in chunks and absorbed serially
     ///
     /// # Arguments
     /// - `self`: &mut self mutable reference to self
@@ -140,172 +725,839 @@ impl Electrostatic {
     /// Result<(), anyhow::Error>`
     ///
     /// # Errors
-    /// - solution to potential did not converge to tolerance of GS_TOL in GS_MAX_ITER iterations
-    fn update_potential(&mut self) -> Result<(), anyhow::Error> {
-        // loop counter
-        let mut loop_ctr: u64 = 0;
-
-        // l2 error norm
-        let mut l2_err_norm: f64 = f64::MAX;
-
-        // gauss-seidel sor scheme loop
-        while l2_err_norm > GS_TOL {
-            // update potential on interior nodes
-            for i in 1..(self.cells.x - 1) {
-                for j in 1..(self.cells.y - 1) {
-                    for k in 1..(self.cells.z - 1) {
-                        // solve potential using gauss-seidel
-                        let potential_new: f64 = (self.charge_density[(i, j, k)] * INV_VAC_PERM
-                            + self.delta_inv_sq.x
-                                * (self.potential[(i + 1, j, k)] + self.potential[(i - 1, j, k)])
-                            + self.delta_inv_sq.y
-                                * (self.potential[(i, j + 1, k)] - self.potential[(i, j - 1, k)])
-                            + self.delta_inv_sq.z
-                                * (self.potential[(i, j, k + 1)] - self.potential[(i, j, k - 1)]))
-                            / (2.0
-                                * (self.delta_inv_sq.x
-                                    + self.delta_inv_sq.y
-                                    + self.delta_inv_sq.z));
-
-                        // apply sor
-                        self.potential[(i, j, k)] +=
-                            SOR_ACC * (potential_new - self.potential[(i, j, k)]);
+    pub fn push_particles(&mut self) -> Result<(), anyhow::Error> {
+        let b = [
+            self.magnetic_field.x,
+            self.magnetic_field.y,
+            self.magnetic_field.z,
+        ]
+        .map(P::narrow);
+
+        let dt = P::narrow(self.dt);
+        let size = [self.size.x, self.size.y, self.size.z];
+        let record = self.emission.is_some();
+        let mut impacts: Vec<Vec<Impact>> = Vec::with_capacity(self.species.len());
+
+        for species in self.species.iter_mut() {
+            // half step charge to mass ratio
+            let qm_half_dt = P::narrow(0.5 * species.charge / species.mass * self.dt);
+
+            // boris rotation vectors
+            let (t, s) = rotation(&b, qm_half_dt);
+
+            let push = |particle: &mut Particle<P>| {
+                // gather electric field at macro-particle
+                let (idx, frac) = grid_weights(&particle.position.map(P::widen), &self.axes);
+                let field_frac = frac.map(F::narrow);
+                let e = [
+                    interpolate(&self.electric_field.x, idx, field_frac),
+                    interpolate(&self.electric_field.y, idx, field_frac),
+                    interpolate(&self.electric_field.z, idx, field_frac),
+                ]
+                .map(|e| P::narrow(e.widen()));
+
+                // rotation vectors of spatially varying applied magnetic flux density at macro-particle
+                let (t, s) = match &self.applied_field {
+                    Some(field) => {
+                        let b = [
+                            interpolate(&field.x, idx, frac),
+                            interpolate(&field.y, idx, frac),
+                            interpolate(&field.z, idx, frac),
+                        ]
+                        .map(P::narrow);
+                        rotation(&b, qm_half_dt)
                     }
+                    None => (t, s),
+                };
+
+                // accelerate and rotate
+                particle.velocity = boris(&particle.velocity, &e, &t, &s, qm_half_dt);
+
+                // advance position
+                for (x, v) in particle.position.iter_mut().zip(particle.velocity.iter()) {
+                    *x += *v * dt;
                 }
-            }
+            };
+            species
+                .particles
+                .par_iter_mut()
+                .with_min_len(PARTICLE_CHUNK)
+                .for_each(push);
 
-            // conditionally check for convergence
-            if (loop_ctr % CONV_CHECK_ITER) == 0 {
-                // residue accumulator
-                let mut res_acc: f64 = 0.0;
-
-                // accumulate residue = Ax - b
-                for i in 1..(self.cells.x - 1) {
-                    for j in 1..(self.cells.y - 1) {
-                        for k in 1..(self.cells.z - 1) {
-                            // residue vector value
-                            let res = -self.potential[(i, j, k)]
-                                * 2.0
-                                * (self.delta_inv_sq.x + self.delta_inv_sq.y + self.delta_inv_sq.z)
-                                + self.charge_density[(i, j, k)] * INV_VAC_PERM
-                                + self.delta_inv_sq.x
-                                    * (self.potential[(i + 1, j, k)]
-                                        + self.potential[(i - 1, j, k)])
-                                + self.delta_inv_sq.y
-                                    * (self.potential[(i, j + 1, k)]
-                                        - self.potential[(i, j - 1, k)])
-                                + self.delta_inv_sq.z
-                                    * (self.potential[(i, j, k + 1)]
-                                        - self.potential[(i, j, k - 1)]);
-
-                            res_acc += res * res;
-                        }
-                    }
+            // absorb macro-particles that left bounding box, recording where they hit the walls
+            let mut hits = Vec::new();
+            species.particles.retain(|p| {
+                let inside = p
+                    .position
+                    .iter()
+                    .zip(size.iter())
+                    .all(|(x, l)| (0.0..*l).contains(&x.widen()));
+                if !inside && record {
+                    hits.push(Impact::new(p, self.dt, &size));
                 }
-                // update l2 error norm
-                l2_err_norm =
-                    (res_acc / (self.cells.x * self.cells.y * self.cells.z) as f64).sqrt();
-            }
+                inside
+            });
+            impacts.push(hits);
+        }
 
-            // error if convergence is not met
-            if loop_ctr == GS_MAX_ITER {
-                return Err(anyhow!("solution to potential did not converge to tolerance of {GS_TOL} in {GS_MAX_ITER} iterations"));
-            }
+        // emit secondary electrons from walls
+        self.emitted_current = match self.emission.as_ref() {
+            Some(emission) => emission.emit(
+                &impacts,
+                &mut self.species,
+                &self.size,
+                self.dt,
+                &mut self.rng,
+            ),
+            None => [0.0; 6],
+        };
+
+        Ok(())
+    }
+
+    /// updates electric potential of `Electrostatic`
+    ///
+    /// # Arguments
+    /// - `self`: &mut self mutable reference to self
+    ///
+    /// # Returns
+    /// Result<(), anyhow::Error>`
+    ///
+    /// # Errors
+    /// - call to `Poisson::solve()` fails
+    pub fn update_potential(&mut self) -> Result<(), anyhow::Error> {
+        self.poisson
+            .solve(&mut self.potential, &self.charge_density)
+    }
+
+    /// updates electric electric_field of `Electrostatic` using second order differences on possibly non-uniform
+    /// nodes, central on interior nodes and one-sided on boundary nodes
+    ///
+    /// # Arguments
+    /// - `self`: &mut self mutable reference to self
+    ///
+    /// # Returns
+    /// Result<(), anyhow::Error>`
+    ///
+    /// # Errors
+    /// - call to `Differential::gradient()` fails
+    pub fn solve_electric_field(&mut self) -> Result<(), anyhow::Error> {
+        // electric field is the negative gradient of the potential
+        self.electric_field = -self.differential.gradient(&self.potential)?;
+
+        Ok(())
+    }
+}
+
+impl<F: Real, P: Real> Engine for Electrostatic<F, P> {
+    /// updates `Electrostatic` to the next time step
+    ///
+    /// # Arguments
+    /// - `self`: &mut self mutable reference to self
+    ///
+    /// # Returns
+    /// Result<(), anyhow::Error>`
+    ///
+    /// # Errors
+    /// - any call to `Self::deposit_charge()` fails
+    /// - any call to `Self::update_potential()` fails
+    /// - any call to `Self::solve_electric_field()` fails
+    /// - any call to `Self::push_particles()` fails
+    fn update(&mut self) -> Result<(), anyhow::Error> {
+        // run the field solve and push on the thread pool of the engine
+        let pool = Arc::clone(&self.pool);
+        pool.install(|| -> Result<(), anyhow::Error> {
+            // deposit charge density of all species
+            Self::deposit_charge(self)?;
+
+            // update electrostatic potential
+            Self::update_potential(self)?;
+
+            // update electric field from calculated electrostatic potential
+            Self::solve_electric_field(self)?;
+
+            // advance macro-particles in electric and magnetic field
+            Self::push_particles(self)
+        })?;
+
+        // collide macro-particles with background gas
+        if let Some(mcc) = self.mcc.as_ref() {
+            mcc.collide(
+                &mut self.species,
+                &self.delta,
+                &self.cells,
+                self.dt,
+                &mut self.rng,
+            );
+        }
 
-            // increment loop counter
-            loop_ctr += 1;
+        // collide charged macro-particles with each other
+        if let Some(coulomb) = self.coulomb.as_ref() {
+            coulomb.collide(
+                &mut self.species,
+                &self.delta,
+                &self.cells,
+                self.dt,
+                &mut self.rng,
+            );
         }
 
+        // collide neutral macro-particles with each other
+        if let Some(dsmc) = self.dsmc.as_ref() {
+            dsmc.collide(
+                &mut self.species,
+                &self.delta,
+                &self.cells,
+                self.dt,
+                &mut self.rng,
+            );
+        }
+
+        // advance time
+        self.step += 1;
+        self.time += self.dt;
+
         Ok(())
     }
 
-    /// updates electric electric_field of `Electrostatic`
-    /// 
-    /// todo this implementation can be improved to be branchless
-    ///
-    /// # Arguments
-    /// - `self`: &mut self mutable reference to self
-    ///
-    /// # Returns
-    /// Result<(), anyhow::Error>`
-    ///
-    /// # Errors
-    fn solve_electric_field(&mut self) -> Result<(), anyhow::Error> {
-        // precompute negative inverses
-        let n_two_dx_inv = -1.0 / (2.0 * self.delta.x);
-        let n_two_dy_inv = -1.0 / (2.0 * self.delta.y);
-        let n_two_dz_inv = -1.0 / (2.0 * self.delta.z);
-
-        for i in 0..self.cells.x {
-            for j in 0..self.cells.y {
-                for k in 0..self.cells.z {
-                    // x-component
-                    if i != 0 && i != self.cells.x - 1 {
-                        // central difference interior nodes
-                        self.electric_field.x[(i, j, k)] = n_two_dx_inv
-                            * (self.potential[(i + 1, j, k)] - self.potential[(i - 1, j, k)]);
-                    } else if i == 0 {
-                        // forward difference low edge
-                        self.electric_field.x[(i, j, k)] = n_two_dx_inv
-                            * (-3.0 * self.potential[(i, j, k)]
-                                + 4.0 * self.potential[(i + 1, j, k)]
-                                - self.potential[(i + 2, j, k)]);
-                    } else {
-                        // backward difference high edge
-                        self.electric_field.x[(i, j, k)] = n_two_dx_inv
-                            * (self.potential[(i - 2, j, k)] - 4.0 * self.potential[(i - 1, j, k)]
-                                + 3.0 * self.potential[(i, j, k)]);
-                    }
+    /// returns simulated time of `Electrostatic`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `f64` (s) simulated time
+    ///
+    /// # Errors
+    ///
+    fn time(&self) -> f64 {
+        self.time
+    }
+
+    /// returns number of completed time steps of `Electrostatic`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `u64` number of completed time steps
+    ///
+    /// # Errors
+    ///
+    fn step(&self) -> u64 {
+        self.step
+    }
+
+    /// returns size of bounding box of `Electrostatic`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `&CoordinateTriplet<f64>` (m) size of bounding box
+    ///
+    /// # Errors
+    ///
+    fn size(&self) -> &CoordinateTriplet<f64> {
+        &self.size
+    }
+
+    /// returns number of cells of `Electrostatic`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `&CoordinateTriplet<usize>` number of cells in bounding box
+    ///
+    /// # Errors
+    ///
+    fn cells(&self) -> &CoordinateTriplet<usize> {
+        &self.cells
+    }
+
+    /// returns node coordinates of `Electrostatic`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `CoordinateTriplet<Vec<f64>>` (m) node coordinates along each axis
+    ///
+    /// # Errors
+    ///
+    fn nodes(&self) -> CoordinateTriplet<Vec<f64>> {
+        CoordinateTriplet {
+            x: self.axes.x.nodes().to_vec(),
+            y: self.axes.y.nodes().to_vec(),
+            z: self.axes.z.nodes().to_vec(),
+        }
+    }
+
+    /// returns plasma parameter report of `Electrostatic`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `&PlasmaReport` characteristic plasma parameters and their validation against discretization
+    ///
+    /// # Errors
+    ///
+    fn plasma_report(&self) -> &PlasmaReport {
+        &self.plasma_report
+    }
+
+    /// computes number density of a single species on nodes of `Electrostatic` using linear weighting
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `index`: usize index of species
+    ///
+    /// # Returns
+    /// `Result<ScalarField<f64>, anyhow::Error>` (m^-3) number density
+    ///
+    /// # Errors
+    /// - `index` is out of bounds
+    /// - call to `ScalarField::new()` fails
+    fn number_density(&self, index: usize) -> Result<ScalarField<f64>, anyhow::Error> {
+        let species = self.species.get(index).ok_or_else(|| {
+            anyhow!(
+                "species index {index} out of bounds for {} species",
+                self.species.len()
+            )
+        })?;
+
+        let mut density = ScalarField::new(&self.cells)?;
+        deposit_with(
+            &mut density,
+            &species.particles,
+            species.weight,
+            |position| grid_weights(position, &self.axes),
+        );
+        density
+            .iter_mut()
+            .zip(self.cell_vol.iter())
+            .for_each(|(n, vol)| *n /= vol);
+
+        Ok(density)
+    }
+
+    /// returns variables of field output of `Electrostatic`, excluding number densities
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `Result<Vec<Variable>, anyhow::Error>`
+    ///
+    /// # Errors
+    /// - any call to `Variable::new()` fails
+    fn variables(&self) -> Result<Vec<Variable>, anyhow::Error> {
+        Ok(vec![
+            Variable::new("potential", "V", 1)?,
+            Variable::new("charge_density", "C/m^3", 1)?,
+            Variable::new("electric_field", "V/m", 3)?,
+        ])
+    }
+
+    /// returns fields of field output of `Electrostatic` in the order of `Electrostatic::variables()`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `Vec<Field<'_>>`
+    ///
+    /// # Errors
+    ///
+    fn fields(&self) -> Vec<Field<'_>> {
+        vec![
+            F::scalar_output(&self.potential),
+            F::scalar_output(&self.charge_density),
+            F::vector_output(&self.electric_field),
+        ]
+    }
+
+    /// computes conservation diagnostics of `Electrostatic`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `Diagnostics`
+    ///
+    /// # Errors
+    ///
+    fn diagnostics(&self) -> Diagnostics {
+        Diagnostics::new(self)
+    }
+
+    /// writes the complete evolving state of `Electrostatic` in a little endian binary format
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `writer`: &mut dyn Write destination of state
+    ///
+    /// # Returns
+    /// `Result<(), anyhow::Error>`
+    ///
+    /// # Errors
+    /// - any write fails
+    fn write_checkpoint(&self, writer: &mut dyn Write) -> Result<(), anyhow::Error> {
+        // time stepping and random number generator state
+        write_clock(writer, self.step, self.time, &self.rng)?;
+
+        // field state, the potential is the initial guess of the next potential solve
+        write_cells(writer, &self.cells)?;
+        self.potential.write_binary(writer)?;
+        self.charge_density.write_binary(writer)?;
+        self.electric_field.write_binary(writer)?;
+
+        // particle state
+        write_particles(writer, &self.species)
+    }
+
+    /// reads the complete evolving state of `Electrostatic` from a little endian binary format
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `reader`: &mut dyn Read source of state
+    ///
+    /// # Returns
+    /// `Result<(), anyhow::Error>`
+    ///
+    /// # Errors
+    /// - any read fails
+    /// - number of cells does not match `Electrostatic`
+    /// - number of species does not match `Electrostatic`
+    fn read_checkpoint(&mut self, reader: &mut dyn Read) -> Result<(), anyhow::Error> {
+        // time stepping and random number generator state
+        (self.step, self.time, self.rng) = read_clock(reader)?;
+
+        // field state
+        read_cells(reader, &self.cells)?;
+        self.potential.read_binary(reader)?;
+        self.charge_density.read_binary(reader)?;
+        self.electric_field.read_binary(reader)?;
+
+        // particle state
+        read_particles(reader, &mut self.species)
+    }
+}
+
+/// deposits a quantity carried by every macro-particle onto nodes using linear weighting
+///
+/// # Arguments
+/// - `field`: &mut ScalarField<f64> field to accumulate onto
+/// - `particles`: &[Particle<P>] macro-particles to deposit
+/// - `value`: f64 quantity carried by a single macro-particle
+/// - `delta`: &CoordinateTriplet<f64> (m) spatial increment
+/// - `cells`: &CoordinateTriplet<usize> number of cells in bounding box
+///
+/// # Returns
+///
+/// # Errors
+///
+fn deposit<P: Real>(
+    field: &mut ScalarField<f64>,
+    particles: &[Particle<P>],
+    value: f64,
+    delta: &CoordinateTriplet<f64>,
+    cells: &CoordinateTriplet<usize>,
+) {
+    deposit_with(field, particles, value, |position| {
+        linear_weights(position, delta, cells)
+    });
+}
+
+/// deposits a quantity carried by every macro-particle onto nodes using linear weighting of a given grid
+///
+/// weights are computed in double precision from widened positions and narrowed to the precision of the field they
+/// are accumulated onto
+///
+/// # Arguments
+/// - `field`: &mut ScalarField<T> field to accumulate onto
+/// - `particles`: &[Particle<P>] macro-particles to deposit
+/// - `value`: f64 quantity carried by a single macro-particle
+/// - `weights`: impl Fn(&[f64; 3]) -> ((usize, usize, usize), [f64; 3]) lower node and fractional offsets of a
+///   position
+///
+/// # Returns
+///
+/// # Errors
+///
+fn deposit_with<T: Real, P: Real>(
+    field: &mut ScalarField<T>,
+    particles: &[Particle<P>],
+    value: f64,
+    weights: impl Fn(&[f64; 3]) -> ((usize, usize, usize), [f64; 3]),
+) {
+    for particle in particles.iter() {
+        let ((i, j, k), [fx, fy, fz]) = weights(&particle.position.map(P::widen));
+
+        field[(i, j, k)] += T::narrow(value * (1.0 - fx) * (1.0 - fy) * (1.0 - fz));
+        field[(i + 1, j, k)] += T::narrow(value * fx * (1.0 - fy) * (1.0 - fz));
+        field[(i, j + 1, k)] += T::narrow(value * (1.0 - fx) * fy * (1.0 - fz));
+        field[(i, j, k + 1)] += T::narrow(value * (1.0 - fx) * (1.0 - fy) * fz);
+        field[(i + 1, j + 1, k)] += T::narrow(value * fx * fy * (1.0 - fz));
+        field[(i + 1, j, k + 1)] += T::narrow(value * fx * (1.0 - fy) * fz);
+        field[(i, j + 1, k + 1)] += T::narrow(value * (1.0 - fx) * fy * fz);
+        field[(i + 1, j + 1, k + 1)] += T::narrow(value * fx * fy * fz);
+    }
+}
+
+/// deposits a quantity carried by every macro-particle onto nodes as `deposit_with()` using thread-private
+/// accumulators reduced at the end
+///
+/// in deterministic mode macro-particles are split in up to `DEPOSIT_BLOCKS` blocks of at least `PARTICLE_CHUNK`
+/// macro-particles whose accumulators are added by `reduce_blocks()` in a tree that only depends on the number of
+/// macro-particles, so that the result is independent of the number of threads, otherwise blocks of at least
+/// `PARTICLE_CHUNK` macro-particles are folded and reduced as rayon schedules them, fewer than `PARTICLE_CHUNK`
+/// macro-particles are deposited serially
+///
+/// # Arguments
+/// - `field`: &mut ScalarField<T> field to accumulate onto
+/// - `particles`: &[Particle<P>] macro-particles to deposit
+/// - `value`: f64 quantity carried by a single macro-particle
+/// - `weights`: impl Fn(&[f64; 3]) -> ((usize, usize, usize), [f64; 3]) + Sync lower node and fractional offsets of a
+///   position
+/// - `deterministic`: bool reduce accumulators in a fixed order
+///
+/// # Returns
+/// `Result<(), anyhow::Error>`
+///
+/// # Errors
+/// - call to `ScalarField::with_ghost()` fails
+fn deposit_par<T: Real, P: Real>(
+    field: &mut ScalarField<T>,
+    particles: &[Particle<P>],
+    value: f64,
+    weights: impl Fn(&[f64; 3]) -> ((usize, usize, usize), [f64; 3]) + Sync,
+    deterministic: bool,
+) -> Result<(), anyhow::Error> {
+    if particles.len() < PARTICLE_CHUNK {
+        deposit_with(field, particles, value, weights);
+        return Ok(());
+    }
+
+    let zero: ScalarField<T> = ScalarField::with_ghost(field.cells(), field.ghost())?;
+    let accumulate = |mut acc: ScalarField<T>, block: &[Particle<P>]| {
+        deposit_with(&mut acc, block, value, &weights);
+        acc
+    };
+
+    if deterministic {
+        let block = PARTICLE_CHUNK.max(particles.len().div_ceil(DEPOSIT_BLOCKS));
+        *field += &reduce_blocks(particles, block, &|block| accumulate(zero.clone(), block));
+    } else {
+        let total = particles
+            .par_chunks(PARTICLE_CHUNK)
+            .fold(|| zero.clone(), accumulate)
+            .reduce_with(|mut lhs, rhs| {
+                lhs += &rhs;
+                lhs
+            });
+        if let Some(total) = total {
+            *field += &total;
+        }
+    }
+
+    Ok(())
+}
+
+/// reduces accumulators of consecutive blocks of macro-particles in a balanced tree of fixed shape
+///
+/// macro-particles are split in halves on block boundaries until a single block remains, both halves are reduced
+/// concurrently and the upper half is added to the lower half, so that the order of every addition only depends on
+/// the number of macro-particles and the block size
+///
+/// # Arguments
+/// - `particles`: &[Particle<P>] macro-particles to deposit
+/// - `block`: usize number of macro-particles of every block but the last
+/// - `leaf`: &(impl Fn(&[Particle<P>]) -> ScalarField<T> + Sync) deposits a single block onto a new accumulator
+///
+/// # Returns
+/// `ScalarField<T>` sum of the accumulators of all blocks
+///
+/// # Errors
+///
+fn reduce_blocks<T: Real, P: Real>(
+    particles: &[Particle<P>],
+    block: usize,
+    leaf: &(impl Fn(&[Particle<P>]) -> ScalarField<T> + Sync),
+) -> ScalarField<T> {
+    if particles.len() <= block {
+        return leaf(particles);
+    }
+
+    let mid = particles.len().div_ceil(block).div_ceil(2) * block;
+    let (lower, upper) = particles.split_at(mid);
+    let (mut lower, upper) = rayon::join(
+        || reduce_blocks(lower, block, leaf),
+        || reduce_blocks(upper, block, leaf),
+    );
+    lower += &upper;
+    lower
+}
+
+/// computes the rotation vectors of the boris scheme for a uniform magnetic flux density
+///
+/// # Arguments
+/// - `b`: &[T; 3] (T) magnetic flux density
+/// - `qm_half_dt`: T (C s/kg) half time step times charge to mass ratio
+///
+/// # Returns
+/// `([T; 3], [T; 3])` rotation vectors t and s
+///
+/// # Errors
+///
+pub(crate) fn rotation<T: Real>(b: &[T; 3], qm_half_dt: T) -> ([T; 3], [T; 3]) {
+    let (one, two) = (T::one(), T::narrow(2.0));
+    let t = [qm_half_dt * b[0], qm_half_dt * b[1], qm_half_dt * b[2]];
+    let t_sq = t[0] * t[0] + t[1] * t[1] + t[2] * t[2];
+    let s = [
+        two * t[0] / (one + t_sq),
+        two * t[1] / (one + t_sq),
+        two * t[2] / (one + t_sq),
+    ];
+    (t, s)
+}
+
+/// advances a velocity by a single time step of the non-relativistic boris scheme
+///
+/// # Arguments
+/// - `velocity`: &[T; 3] (m/s) velocity at previous half step
+/// - `e`: &[T; 3] (V/m) electric field at macro-particle
+/// - `t`: &[T; 3] rotation vector t of `rotation()`
+/// - `s`: &[T; 3] rotation vector s of `rotation()`
+/// - `qm_half_dt`: T (C s/kg) half time step times charge to mass ratio
+///
+/// # Returns
+/// `[T; 3]` (m/s) velocity at next half step
+///
+/// # Errors
+///
+pub(crate) fn boris<T: Real>(
+    velocity: &[T; 3],
+    e: &[T; 3],
+    t: &[T; 3],
+    s: &[T; 3],
+    qm_half_dt: T,
+) -> [T; 3] {
+    // first half acceleration
+    let v_minus = [
+        velocity[0] + qm_half_dt * e[0],
+        velocity[1] + qm_half_dt * e[1],
+        velocity[2] + qm_half_dt * e[2],
+    ];
+
+    // rotation
+    let v_prime = add(&v_minus, &cross(&v_minus, t));
+    let v_plus = add(&v_minus, &cross(&v_prime, s));
+
+    // second half acceleration
+    [
+        v_plus[0] + qm_half_dt * e[0],
+        v_plus[1] + qm_half_dt * e[1],
+        v_plus[2] + qm_half_dt * e[2],
+    ]
+}
+
+/// computes widths of node control volumes along a single axis
+///
+/// each node owns half of the spacing to each of its neighbours so that boundary nodes own half a spacing and
+/// non-uniformly spaced nodes are supported
+///
+/// # Arguments
+/// - `nodes`: &[f64] (m) strictly increasing node coordinates
+///
+/// # Returns
+/// `Vec<f64>` (m) control volume width of each node
+///
+/// # Errors
+///
+fn control_widths(nodes: &[f64]) -> Vec<f64> {
+    (0..nodes.len())
+        .map(|i| {
+            let lower = if i > 0 { nodes[i] - nodes[i - 1] } else { 0.0 };
+            let upper = if i + 1 < nodes.len() {
+                nodes[i + 1] - nodes[i]
+            } else {
+                0.0
+            };
+            0.5 * (lower + upper)
+        })
+        .collect()
+}
+
+/// computes node control volumes of a rectilinear grid
+///
+/// control volumes are halved on faces, quartered on edges, and eighthed on corners of the bounding box and sum
+/// to the volume of the bounding box
+///
+/// # Arguments
+/// - `x`: &[f64] (m) strictly increasing node coordinates along x
+/// - `y`: &[f64] (m) strictly increasing node coordinates along y
+/// - `z`: &[f64] (m) strictly increasing node coordinates along z
+///
+/// # Returns
+/// `Result<ScalarField<f64>, anyhow::Error>` (m^3) control volume of each node
+///
+/// # Errors
+/// - call to `CoordinateTriplet::new()` fails
+/// - call to `ScalarField::new()` fails
+fn control_volumes(x: &[f64], y: &[f64], z: &[f64]) -> Result<ScalarField<f64>, anyhow::Error> {
+    let cells = CoordinateTriplet::new(x.len(), y.len(), z.len())?;
+    let mut volumes: ScalarField<f64> = ScalarField::new(&cells)?;
+
+    let (wx, wy, wz) = (control_widths(x), control_widths(y), control_widths(z));
+    for (i, dx) in wx.iter().enumerate() {
+        for (j, dy) in wy.iter().enumerate() {
+            for (k, dz) in wz.iter().enumerate() {
+                volumes[(i, j, k)] = dx * dy * dz;
+            }
+        }
+    }
+
+    Ok(volumes)
+}
+
+/// computes linear weighting of a position on a cartesian grid
+///
+/// # Arguments
+/// - `position`: &[f64; 3] (m) position inside bounding box
+/// - `delta`: &CoordinateTriplet<f64> (m) spatial increment
+/// - `cells`: &CoordinateTriplet<usize> number of cells in bounding box
+///
+/// # Returns
+/// `((usize, usize, usize), [f64; 3])` (i, j, k) indices of lower node and fractional offsets from lower node
+///
+/// # Errors
+///
+pub(crate) fn linear_weights(
+    position: &[f64; 3],
+    delta: &CoordinateTriplet<f64>,
+    cells: &CoordinateTriplet<usize>,
+) -> ((usize, usize, usize), [f64; 3]) {
+    // logical coordinates
+    let lx = position[0] / delta.x;
+    let ly = position[1] / delta.y;
+    let lz = position[2] / delta.z;
+
+    // lower node, clamped so that the upper node is always inside the grid
+    let i = (lx as usize).min(cells.x - 2);
+    let j = (ly as usize).min(cells.y - 2);
+    let k = (lz as usize).min(cells.z - 2);
+
+    ((i, j, k), [lx - i as f64, ly - j as f64, lz - k as f64])
+}
+
+/// computes linear weighting of a position on a rectilinear grid of possibly non-uniformly spaced nodes
+///
+/// # Arguments
+/// - `position`: &[f64; 3] (m) position inside bounding box
+/// - `axes`: &CoordinateTriplet<Axis> node coordinates along every axis
+///
+/// # Returns
+/// `((usize, usize, usize), [f64; 3])` (i, j, k) indices of lower node and fractional offsets from lower node
+///
+/// # Errors
+///
+fn grid_weights(
+    position: &[f64; 3],
+    axes: &CoordinateTriplet<Axis>,
+) -> ((usize, usize, usize), [f64; 3]) {
+    let (i, fx) = axes.x.locate(position[0]);
+    let (j, fy) = axes.y.locate(position[1]);
+    let (k, fz) = axes.z.locate(position[2]);
+
+    ((i, j, k), [fx, fy, fz])
+}
+
+/// interpolates a `ScalarField<T>` using linear weighting
+///
+/// # Arguments
+/// - `field`: &ScalarField<T> field to interpolate
+/// - `idx`: (usize, usize, usize) (i, j, k) indices of lower node
+/// - `frac`: [T; 3] fractional offsets from lower node
+///
+/// # Returns
+/// `T` interpolated value
+///
+/// # Errors
+///
+pub(crate) fn interpolate<T: Real>(
+    field: &ScalarField<T>,
+    idx: (usize, usize, usize),
+    frac: [T; 3],
+) -> T {
+    let (i, j, k) = idx;
+    let [fx, fy, fz] = frac;
+    let [gx, gy, gz] = [T::one() - fx, T::one() - fy, T::one() - fz];
 
-                    // y-component
-                    if j != 0 && j != self.cells.y - 1 {
-                        // central difference interior nodes
-                        self.electric_field.y[(i, j, k)] = n_two_dy_inv
-                            * (self.potential[(i, j + 1, k)] - self.potential[(i, j - 1, k)]);
-                    } else if j == 0 {
-                        // forward difference low edge
-                        self.electric_field.y[(i, j, k)] = n_two_dy_inv
-                            * (-3.0 * self.potential[(i, j, k)]
-                                + 4.0 * self.potential[(i, j + 1, k)]
-                                - self.potential[(i, j + 2, k)]);
-                    } else {
-                        // backward difference high edge
-                        self.electric_field.y[(i, j, k)] = n_two_dy_inv
-                            * (self.potential[(i, j - 2, k)] - 4.0 * self.potential[(i, j - 1, k)]
-                                + 3.0 * self.potential[(i, j, k)]);
-                    }
+    field[(i, j, k)] * gx * gy * gz
+        + field[(i + 1, j, k)] * fx * gy * gz
+        + field[(i, j + 1, k)] * gx * fy * gz
+        + field[(i, j, k + 1)] * gx * gy * fz
+        + field[(i + 1, j + 1, k)] * fx * fy * gz
+        + field[(i + 1, j, k + 1)] * fx * gy * fz
+        + field[(i, j + 1, k + 1)] * gx * fy * fz
+        + field[(i + 1, j + 1, k + 1)] * fx * fy * fz
+}
 
-                    // z-component
-                    if k != 0 && k != self.cells.z - 1 {
-                        // central difference interior nodes
-                        self.electric_field.z[(i, j, k)] = n_two_dz_inv
-                            * (self.potential[(i, j, k + 1)] - self.potential[(i, j, k - 1)]);
-                    } else if k == 0 {
-                        // forward difference low edge
-                        self.electric_field.z[(i, j, k)] = n_two_dz_inv
-                            * (-3.0 * self.potential[(i, j, k)]
-                                + 4.0 * self.potential[(i, j, k + 1)]
-                                - self.potential[(i, j, k + 2)]);
-                    } else {
-                        // backward difference high edge
-                        self.electric_field.z[(i, j, k)] = n_two_dz_inv
-                            * (self.potential[(i, j, k - 2)] - 4.0 * self.potential[(i, j, k - 1)]
-                                + 3.0 * self.potential[(i, j, k)]);
-                    }
-                }
-            }
-        }
+/// computes the cross product of two vectors
+///
+/// # Arguments
+/// - `a`: &[T; 3] lhs of operation
+/// - `b`: &[T; 3] rhs of operation
+///
+/// # Returns
+/// `[T; 3]` a x b
+///
+/// # Errors
+///
+fn cross<T: Real>(a: &[T; 3], b: &[T; 3]) -> [T; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
 
-        Ok(())
-    }
+/// computes the sum of two vectors
+///
+/// # Arguments
+/// - `a`: &[T; 3] lhs of operation
+/// - `b`: &[T; 3] rhs of operation
+///
+/// # Returns
+/// `[T; 3]` a + b
+///
+/// # Errors
+///
+fn add<T: Real>(a: &[T; 3], b: &[T; 3]) -> [T; 3] {
+    [a[0] + b[0], a[1] + b[1], a[2] + b[2]]
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::engine::Electrostatic;
+    use crate::config::{Config, EngineKind};
+    use crate::constants::{AMU, ELEC_CHARGE, ELEC_MASS, EV_TEMP, VAC_PERM};
+    use crate::emission::{EmissionRule, Surface, YieldModel};
+    use crate::engine::{
+        boris, build, control_volumes, rotation, Electrostatic, Engine, PARTICLE_CHUNK,
+    };
     use crate::field::scalar::ScalarField;
     use crate::field::vector::VectorField;
+    use crate::grid::Spacing;
+    use crate::magnetostatics::Source;
+    use crate::output::Field;
+    use crate::parallel::ParallelConfig;
+    use crate::precision::{Precision, Real};
+    use crate::species::{Particle, Species};
     use crate::utils::coordinate_triplet::CoordinateTriplet;
+    use std::f64::consts::PI;
 
     /// helper function that sets up a `Electrostatic` for testing
     ///
@@ -319,7 +1571,38 @@ mod tests {
     fn setup() -> Result<Electrostatic, anyhow::Error> {
         let size: [f64; 3] = [1.0, 2.0, 3.0];
         let cells: [usize; 3] = [3, 11, 31];
-        Electrostatic::new(&size, &cells)
+        let config = Config::new(&size, &cells, 1e-9)?;
+        Electrostatic::new(&config)
+    }
+
+    /// tests `build()` for constructing the engine selected in configuration
+    ///
+    /// # Errors
+    /// - `build()` fails for valid input
+    /// - constructed engine does not provide the field output of the selected engine
+    ///
+    #[test]
+    fn build_selects_engine() {
+        let mut config = Config::new(&[0.01, 0.01, 0.01], &[5, 5, 5], 1e-12).unwrap();
+        let electrostatic = build(&config).unwrap();
+        assert_eq!(electrostatic.variables().unwrap().len(), 3);
+        assert_eq!(electrostatic.fields().len(), 3);
+
+        config.engine = EngineKind::Electromagnetic;
+        let electromagnetic = build(&config).unwrap();
+        assert_eq!(electromagnetic.variables().unwrap().len(), 4);
+        assert_eq!(electromagnetic.fields().len(), 4);
+        assert_eq!(electromagnetic.step(), 0);
+
+        config.engine = EngineKind::Electrostatic1d;
+        let reduced = build(&config).unwrap();
+        assert_eq!(reduced.cells().y, 1);
+        assert_eq!(reduced.variables().unwrap().len(), 3);
+
+        config.engine = EngineKind::Cylindrical;
+        let cylindrical = build(&config).unwrap();
+        assert_eq!(cylindrical.cells().y, 1);
+        assert_eq!(cylindrical.cells().z, 5);
     }
 
     /// tests `Electrostatic::new()` for success
@@ -446,43 +1729,696 @@ mod tests {
     /// tests `Electrostatic::new()` for correct setting of `Electrostatic.cell_vol` member
     ///
     /// # Errors
-    /// - `Electrostatic::new()` sets incorrect `Electrostatic.cell_vol`
-    /// - call to `CoordinateTriplet::new()` fails
-    /// - call to `ScalarField::new()` fails
+    /// - `Electrostatic::new()` sets incorrect `Electrostatic.cell_vol` in interior, on faces, or on corners
     ///
     #[test]
     fn new_correct_cell_vol() {
         // setup
         let electrostatic = setup().unwrap();
-        let cells = CoordinateTriplet::new(3, 11, 31).unwrap();
+        let vol = 0.5 * 0.2 * 0.1;
 
         // assertions
-        assert_eq!(electrostatic.cell_vol, ScalarField::new(&cells).unwrap())
+        assert!((electrostatic.cell_vol[(1, 5, 15)] - vol).abs() < 1e-15);
+        assert!((electrostatic.cell_vol[(0, 5, 15)] - vol / 2.0).abs() < 1e-15);
+        assert!((electrostatic.cell_vol[(0, 0, 15)] - vol / 4.0).abs() < 1e-15);
+        assert!((electrostatic.cell_vol[(2, 10, 30)] - vol / 8.0).abs() < 1e-15);
+
+        let total: f64 = electrostatic.cell_vol.iter().sum();
+        assert!((total - 1.0 * 2.0 * 3.0).abs() < 1e-12);
     }
 
-    /// tests `Electrostatic::new()` for correct setting of `Electrostatic.delta_inv_sq` member
+    /// tests `control_volumes()` for summing to the volume of the bounding box on a non-uniform grid
     ///
     /// # Errors
-    /// - `Electrostatic::new()` sets incorrect `Electrostatic.delta_inv_sq`
-    /// - call to `CoordinateTriplet::new()` fails
+    /// - control volumes do not sum to volume of bounding box
+    /// - control volume of a boundary node is incorrect
+    ///
+    #[test]
+    fn control_volumes_non_uniform() {
+        // setup
+        let x = [0.0, 0.1, 0.3, 0.7, 1.0];
+        let y = [0.0, 0.5, 2.0];
+        let z = [0.0, 0.01, 0.02, 0.04, 0.08, 0.16, 0.32, 0.64, 1.28, 3.0];
+        let volumes = control_volumes(&x, &y, &z).unwrap();
+
+        // assertions
+        let total: f64 = volumes.iter().sum();
+        assert!((total - 1.0 * 2.0 * 3.0).abs() < 1e-12);
+        assert!((volumes[(0, 0, 0)] - 0.05 * 0.25 * 0.005).abs() < 1e-15);
+        assert!((volumes[(2, 2, 9)] - 0.3 * 0.75 * 0.86).abs() < 1e-15);
+    }
+
+    /// tests `Electrostatic::deposit_charge()` for conservation of charge including macro-particles near walls
+    ///
+    /// # Errors
+    /// - volume integral of charge density differs from total charge of macro-particles
+    /// - charge density of a macro-particle on a corner node is incorrect
+    ///
+    #[test]
+    fn deposit_charge_conservative() {
+        // setup
+        let mut electrostatic = setup().unwrap();
+        electrostatic.species =
+            vec![Species::new("electron", -ELEC_CHARGE, ELEC_MASS, 0.0, 0.0, 1.0).unwrap()];
+        electrostatic.species[0].particles = vec![
+            Particle {
+                position: [0.0, 0.0, 0.0],
+                velocity: [0.0; 3],
+            },
+            Particle {
+                position: [0.9, 0.05, 2.99],
+                velocity: [0.0; 3],
+            },
+            Particle {
+                position: [0.3, 1.0, 1.5],
+                velocity: [0.0; 3],
+            },
+        ];
+        electrostatic.deposit_charge().unwrap();
+
+        // assertions
+        let charge: f64 = electrostatic
+            .charge_density
+            .iter()
+            .zip(electrostatic.cell_vol.iter())
+            .map(|(rho, vol)| rho * vol)
+            .sum();
+        assert!((charge + 3.0 * ELEC_CHARGE).abs() / ELEC_CHARGE < 1e-12);
+
+        let corner = -ELEC_CHARGE / electrostatic.cell_vol[(0, 0, 0)];
+        assert!((electrostatic.charge_density[(0, 0, 0)] - corner).abs() / corner.abs() < 1e-12);
+    }
+
+    /// tests `Electrostatic::number_density()` for conservation of the number of physical particles
+    ///
+    /// # Errors
+    /// - volume integral of number density differs from number of physical particles
+    /// - `Electrostatic::number_density()` succeeds for an out of bounds species index
+    ///
+    #[test]
+    fn number_density_conservative() {
+        // setup
+        let mut electrostatic = setup().unwrap();
+        electrostatic.species = vec![Species::new("argon", 0.0, AMU, 0.0, 0.0, 1e3).unwrap()];
+        electrostatic.species[0].particles = vec![
+            Particle {
+                position: [0.0, 1.0, 3.0],
+                velocity: [0.0; 3],
+            },
+            Particle {
+                position: [0.7, 0.35, 2.2],
+                velocity: [0.0; 3],
+            },
+        ];
+        let density = electrostatic.number_density(0).unwrap();
+
+        // assertions
+        let count: f64 = density
+            .iter()
+            .zip(electrostatic.cell_vol.iter())
+            .map(|(n, vol)| n * vol)
+            .sum();
+        assert!((count - 2e3).abs() / 2e3 < 1e-12);
+        assert!(electrostatic.number_density(1).is_err());
+    }
+
+    /// tests `Electrostatic::new()` for correct setting of `Electrostatic.laplacian` member on a uniform grid
+    ///
+    /// # Errors
+    /// - `Electrostatic::new()` sets coefficients other than the inverse spatial increment squared on interior
+    ///   nodes
+    /// - `Electrostatic::new()` sets non-zero coefficients on boundary nodes
     ///
     #[test]
-    fn new_correct_delta_inv_sq() {
+    fn new_correct_laplacian() {
         // setup
         let electrostatic = setup().unwrap();
+        let axes = [
+            (&electrostatic.poisson.laplacian().x, electrostatic.delta.x),
+            (&electrostatic.poisson.laplacian().y, electrostatic.delta.y),
+            (&electrostatic.poisson.laplacian().z, electrostatic.delta.z),
+        ];
 
         // assertions
-        assert_eq!(
-            electrostatic.delta_inv_sq.x,
-            1.0 / (electrostatic.delta.x * electrostatic.delta.x)
+        for (laplacian, delta) in axes {
+            let n = laplacian.len();
+            assert_eq!(laplacian[0], [0.0; 2]);
+            assert_eq!(laplacian[n - 1], [0.0; 2]);
+            for c in laplacian.iter().take(n - 1).skip(1) {
+                assert_eq!(*c, [1.0 / (delta * delta); 2]);
+            }
+        }
+    }
+
+    /// tests `Electrostatic::update_potential()` and `Electrostatic::solve_electric_field()` for a manufactured
+    /// solution on stretched nodes
+    ///
+    /// # Errors
+    /// - potential deviates from the manufactured solution
+    /// - electric field deviates from the gradient of the manufactured solution
+    ///
+    #[test]
+    fn update_potential_stretched() {
+        // setup
+        let mut config = Config::new(&[1.0, 1.0, 1.0], &[25, 25, 5], 1e-9).unwrap();
+        config.grid.x = Spacing::Tanh(1.5);
+        config.grid.y = Spacing::Nodes((0..25).map(|j| (j as f64 / 24.0).powi(2)).collect());
+        let mut electrostatic = Electrostatic::<f64>::new(&config).unwrap();
+        let (x, y) = (
+            electrostatic.axes.x.nodes().to_vec(),
+            electrostatic.axes.y.nodes().to_vec(),
         );
+        let exact = |x: f64, y: f64| (PI * x).sin() * (PI * y).sin();
+        for (i, x) in x.iter().enumerate() {
+            for (j, y) in y.iter().enumerate() {
+                for k in 0..5 {
+                    electrostatic.charge_density[(i, j, k)] =
+                        VAC_PERM * 2.0 * PI * PI * exact(*x, *y);
+                }
+            }
+        }
+
+        // boundary potentials along z match the manufactured solution, which is independent of z
+        for k in [0, 4] {
+            for (i, x) in x.iter().enumerate() {
+                for (j, y) in y.iter().enumerate() {
+                    electrostatic.potential[(i, j, k)] = exact(*x, *y);
+                }
+            }
+        }
+        electrostatic.update_potential().unwrap();
+        electrostatic.solve_electric_field().unwrap();
+
+        // assertions
+        for (i, x) in x.iter().enumerate() {
+            for (j, y) in y.iter().enumerate() {
+                for k in 0..5 {
+                    let phi = electrostatic.potential[(i, j, k)];
+                    assert!((phi - exact(*x, *y)).abs() < 1e-2);
+                    let ex = -PI * (PI * x).cos() * (PI * y).sin();
+                    assert!((electrostatic.electric_field.x[(i, j, k)] - ex).abs() < 5e-2);
+                }
+            }
+        }
+    }
+
+    /// tests `Electrostatic::deposit_charge()` for conservation of charge on stretched nodes
+    ///
+    /// # Errors
+    /// - integrated charge density does not match total charge of macro-particles
+    ///
+    #[test]
+    fn deposit_charge_stretched_conservative() {
+        // setup
+        let mut config = Config::new(&[0.01, 0.01, 0.01], &[11, 9, 7], 1e-12).unwrap();
+        config.grid.x = Spacing::Tanh(2.0);
+        config.grid.z = Spacing::Tanh(1.0);
+        config
+            .species
+            .push(Species::new("electron", -ELEC_CHARGE, ELEC_MASS, 1e12, EV_TEMP, 1e2).unwrap());
+        let mut electrostatic = Electrostatic::<f64>::new(&config).unwrap();
+        electrostatic.deposit_charge().unwrap();
+
+        // assertions
+        let total: f64 = electrostatic
+            .charge_density
+            .iter()
+            .zip(electrostatic.cell_vol.iter())
+            .map(|(rho, vol)| rho * vol)
+            .sum();
+        let species = &electrostatic.species[0];
+        let expected = species.particles.len() as f64 * species.charge * species.weight;
+        assert!((total - expected).abs() / expected.abs() < 1e-12);
+    }
+
+    /// tests `Electrostatic::new()` for rejection of collisions on stretched nodes
+    ///
+    /// # Errors
+    /// - `Electrostatic::new()` succeeds for coulomb collisions on a stretched grid
+    /// - `Electrostatic::new()` fails for coulomb collisions on a uniform grid
+    /// - `Electrostatic::new()` fails for a stretched grid without collisions
+    ///
+    #[test]
+    fn new_stretched_collisions() {
+        // setup
+        let mut config = Config::new(&[0.01, 0.01, 0.01], &[11, 11, 11], 1e-12).unwrap();
+        config
+            .species
+            .push(Species::new("electron", -ELEC_CHARGE, ELEC_MASS, 1e12, EV_TEMP, 1e2).unwrap());
+        config.coulomb.enabled = true;
+        config.grid.x = Spacing::Tanh(2.0);
+
+        // assertions
+        let err = Electrostatic::<f64>::new(&config).unwrap_err();
+        assert!(err.to_string().contains("non-uniform grids"));
+
+        config.grid.x = Spacing::Uniform;
+        assert!(Electrostatic::<f64>::new(&config).is_ok());
+
+        config.coulomb.enabled = false;
+        config.grid.x = Spacing::Tanh(2.0);
+        assert!(Electrostatic::<f64>::new(&config).is_ok());
+    }
+
+    /// tests `Electrostatic::new()` for rejection of an under-resolved debye length
+    ///
+    /// # Errors
+    /// - `Electrostatic::new()` succeeds when spatial increment exceeds debye length
+    /// - `Electrostatic::new()` fails when spatial increment resolves debye length
+    /// - call to `Config::new()` fails
+    /// - call to `Species::new()` fails
+    ///
+    #[test]
+    fn new_debye_length_validation() {
+        // setup
+        let mut config = Config::new(&[1.0, 2.0, 3.0], &[3, 11, 31], 1e-12).unwrap();
+        config
+            .species
+            .push(Species::new("electron", -ELEC_CHARGE, ELEC_MASS, 1e12, EV_TEMP, 1.0).unwrap());
+
+        // assertions
+        assert!(Electrostatic::<f64>::new(&config).is_err());
+
+        // debye length of ~7.4 mm is resolved by 1 mm increments
+        config.size = [0.01, 0.01, 0.01];
+        config.cells = [11, 11, 11];
+        let electrostatic = Electrostatic::<f64>::new(&config).unwrap();
+        assert!(electrostatic
+            .plasma_report()
+            .checks
+            .iter()
+            .all(|c| c.passed));
+    }
+
+    /// tests `Electrostatic::push_particles()` for energy conservation in a magnetic field and absorption at walls
+    ///
+    /// # Errors
+    /// - speed of a macro-particle in a pure magnetic field changes
+    /// - macro-particle leaving bounding box is not absorbed
+    /// - call to `Config::new()` fails
+    /// - call to `Species::new()` fails
+    ///
+    #[test]
+    fn push_particles_correct() {
+        // setup
+        let mut config = Config::new(&[1.0, 1.0, 1.0], &[5, 5, 5], 1e-12).unwrap();
+        config.magnetic_field = [0.0, 0.0, 0.1];
+        let mut electrostatic = Electrostatic::<f64>::new(&config).unwrap();
+        let mut species =
+            Species::new("electron", -ELEC_CHARGE, ELEC_MASS, 0.0, EV_TEMP, 1.0).unwrap();
+        species.particles = vec![
+            Particle {
+                position: [0.5, 0.5, 0.5],
+                velocity: [1e5, 0.0, 1e3],
+            },
+            Particle {
+                position: [0.999, 0.5, 0.5],
+                velocity: [1e10, 0.0, 0.0],
+            },
+        ];
+        electrostatic.species.push(species);
+
+        for _ in 0..100 {
+            electrostatic.push_particles().unwrap();
+        }
+
+        // assertions
+        let particles = &electrostatic.species[0].particles;
+        assert_eq!(particles.len(), 1);
+        let speed = particles[0]
+            .velocity
+            .iter()
+            .map(|v| v * v)
+            .sum::<f64>()
+            .sqrt();
+        let expected = (1e10_f64 + 1e6).sqrt();
+        assert!((speed - expected).abs() / expected < 1e-12);
+        assert!(particles[0].velocity[1].abs() > 0.0);
+    }
+
+    /// tests `Electrostatic::push_particles()` for rotation about the applied magnetic flux density of a current
+    /// loop superposed on the uniform applied magnetic flux density
+    ///
+    /// # Errors
+    /// - applied magnetic flux density is computed without magnetostatic sources
+    /// - applied magnetic flux density on a node deviates from the sum of uniform and loop fields
+    /// - velocity of a macro-particle on a node deviates from a boris rotation about the field on that node
+    /// - call to `Config::new()` fails
+    /// - call to `Species::new()` fails
+    ///
+    #[test]
+    fn push_particles_applied_field() {
+        // setup
+        let mut config = Config::new(&[1.0, 1.0, 1.0], &[5, 5, 5], 1e-11).unwrap();
+        config.magnetic_field = [0.0, 0.0, 0.05];
+        assert!(Electrostatic::<f64>::new(&config)
+            .unwrap()
+            .applied_field()
+            .is_none());
+
+        let source = Source::Loop {
+            center: [0.3, 0.5, 0.2],
+            normal: [0.0, 1.0, 1.0],
+            radius: 0.3,
+            current: 1e4,
+        };
+        config.magnetostatics.sources.push(source.clone());
+        let mut electrostatic = Electrostatic::<f64>::new(&config).unwrap();
+        let mut species =
+            Species::new("electron", -ELEC_CHARGE, ELEC_MASS, 0.0, EV_TEMP, 1.0).unwrap();
+        let velocity = [1e5, -2e5, 3e4];
+        species.particles = vec![Particle {
+            position: [0.5, 0.75, 0.5],
+            velocity,
+        }];
+        electrostatic.species.push(species);
+        electrostatic.push_particles().unwrap();
+
+        // assertions
+        let source_field = source.field(&[0.5, 0.75, 0.5], config.magnetostatics.segments);
+        let b = [source_field[0], source_field[1], source_field[2] + 0.05];
+        let applied = electrostatic.applied_field().unwrap();
         assert_eq!(
-            electrostatic.delta_inv_sq.y,
-            1.0 / (electrostatic.delta.y * electrostatic.delta.y)
+            [
+                applied.x[(2, 3, 2)],
+                applied.y[(2, 3, 2)],
+                applied.z[(2, 3, 2)]
+            ],
+            b
         );
+
+        let qm_half_dt = -0.5 * ELEC_CHARGE / ELEC_MASS * config.dt;
+        let (t, s) = rotation(&b, qm_half_dt);
+        let expected = boris(&velocity, &[0.0; 3], &t, &s, qm_half_dt);
+        let particle = &electrostatic.species[0].particles[0];
+        for (v, e) in particle.velocity.iter().zip(expected.iter()) {
+            assert!((v - e).abs() < 1e-9 * e.abs().max(1.0));
+        }
+        assert!((particle.velocity[0] - velocity[0]).abs() > 1.0);
+    }
+
+    /// tests `Electrostatic::push_particles()` for secondary electron emission of absorbed macro-particles
+    ///
+    /// # Errors
+    /// - absorbed macro-particle does not emit exactly one electron
+    /// - emitted electron lies outside of bounding box or moves out of the emitting wall
+    /// - emitted current is reported for the wrong wall or has an incorrect magnitude
+    /// - call to `Config::new()` fails
+    /// - call to `Species::new()` fails
+    ///
+    #[test]
+    fn push_particles_emission() {
+        // setup
+        let dt = 1e-12;
+        let mut config = Config::new(&[1.0, 1.0, 1.0], &[5, 5, 5], dt).unwrap();
+        config.species = vec![
+            Species::new("electron", -ELEC_CHARGE, ELEC_MASS, 0.0, EV_TEMP, 1.0).unwrap(),
+            Species::new("argon+", ELEC_CHARGE, 39.948 * AMU, 0.0, EV_TEMP, 1.0).unwrap(),
+        ];
+        config.emission.rules = vec![EmissionRule {
+            species: String::from("argon+"),
+            surfaces: vec![Surface::XHigh],
+            yield_model: YieldModel::Constant(1.0),
+        }];
+        let mut electrostatic = Electrostatic::<f64>::new(&config).unwrap();
+        electrostatic.species[1].particles = vec![Particle {
+            position: [0.9995, 0.5, 0.5],
+            velocity: [1e9, 0.0, 0.0],
+        }];
+
+        electrostatic.push_particles().unwrap();
+
+        // assertions
+        assert!(electrostatic.species[1].particles.is_empty());
+        let electrons = &electrostatic.species[0].particles;
+        assert_eq!(electrons.len(), 1);
+        assert!(electrons[0].velocity[0] < 0.0);
+        assert!(electrons[0].position.iter().all(|x| (0.0..1.0).contains(x)));
+        let current = electrostatic.emitted_current();
+        assert!((current[Surface::XHigh.index()] + ELEC_CHARGE / dt).abs() < 1e-12);
+        assert_eq!(current.iter().filter(|c| **c != 0.0).count(), 1);
+    }
+
+    /// helper function that solves the potential of a manufactured solution in precision `F`
+    ///
+    /// # Arguments
+    ///
+    /// # Returns
+    /// `Electrostatic<F>` engine holding the solved potential and electric field
+    ///
+    /// # Errors
+    ///
+    fn manufactured<F: Real>() -> Electrostatic<F> {
+        let config = Config::new(&[1.0, 1.0, 1.0], &[25, 25, 5], 1e-9).unwrap();
+        let mut electrostatic = Electrostatic::<F>::new(&config).unwrap();
+        let exact =
+            |i: usize, j: usize| (PI * i as f64 / 24.0).sin() * (PI * j as f64 / 24.0).sin();
+        for i in 0..25 {
+            for j in 0..25 {
+                for k in 0..5 {
+                    electrostatic.charge_density[(i, j, k)] =
+                        F::narrow(VAC_PERM * 2.0 * PI * PI * exact(i, j));
+                }
+                for k in [0, 4] {
+                    electrostatic.potential[(i, j, k)] = F::narrow(exact(i, j));
+                }
+            }
+        }
+        electrostatic.update_potential().unwrap();
+        electrostatic.solve_electric_field().unwrap();
+        electrostatic
+    }
+
+    /// helper function that advances an `Electrostatic<F, P>` with a single electron species by several steps
+    ///
+    /// # Arguments
+    ///
+    /// # Returns
+    /// `Electrostatic<F, P>`
+    ///
+    /// # Errors
+    ///
+    fn advance<F: Real, P: Real>() -> Electrostatic<F, P> {
+        let mut config = Config::new(&[0.01, 0.01, 0.01], &[11, 11, 11], 1e-12).unwrap();
+        config
+            .species
+            .push(Species::new("electron", -ELEC_CHARGE, ELEC_MASS, 1e12, EV_TEMP, 1e3).unwrap());
+        let mut electrostatic = Electrostatic::<F, P>::new(&config).unwrap();
+        for _ in 0..5 {
+            electrostatic.update().unwrap();
+        }
+        electrostatic
+    }
+
+    /// tests `build()` for constructing the electrostatic engine in the configured precision
+    ///
+    /// # Errors
+    /// - `build()` fails for any precision of the electrostatic engine
+    /// - fields of single precision are not written as single precision field output
+    /// - fields of mixed precision are not written as double precision field output
+    /// - `build()` succeeds for a precision other than double of an engine other than the electrostatic engine
+    ///
+    #[test]
+    fn build_selects_precision() {
+        let mut config = Config::new(&[0.01, 0.01, 0.01], &[5, 5, 5], 1e-12).unwrap();
+        config.precision = Precision::Single;
+        let single = build(&config).unwrap();
+        assert!(matches!(single.fields()[0], Field::SingleScalar(_)));
+        assert!(matches!(single.fields()[2], Field::SingleVector(_)));
+
+        config.precision = Precision::Mixed;
+        let mixed = build(&config).unwrap();
+        assert!(matches!(mixed.fields()[0], Field::Scalar(_)));
+        assert!(matches!(mixed.fields()[2], Field::Vector(_)));
+
+        config.engine = EngineKind::Cylindrical;
+        assert!(build(&config).is_err());
+    }
+
+    /// tests `Electrostatic::update_potential()` and `Electrostatic::solve_electric_field()` in single precision
+    /// against double precision for a manufactured solution
+    ///
+    /// # Errors
+    /// - single precision solution does not converge
+    /// - single precision potential deviates from double precision potential by more than 1e-4 V
+    /// - single precision electric field deviates from double precision electric field by more than 1e-3 V/m
+    ///
+    #[test]
+    fn update_potential_single_precision() {
+        // setup
+        let double = manufactured::<f64>();
+        let single = manufactured::<f32>();
+
+        // assertions
+        let potential: Vec<f64> = single.potential.iter().map(|phi| phi.widen()).collect();
+        for (single, double) in potential.iter().zip(double.potential.iter()) {
+            assert!((single - double).abs() < 1e-4);
+        }
+        let ex: Vec<f64> = single.electric_field.x.iter().map(|e| e.widen()).collect();
+        for (single, double) in ex.iter().zip(double.electric_field.x.iter()) {
+            assert!((single - double).abs() < 1e-3);
+        }
+    }
+
+    /// tests `Electrostatic::update()` in single and mixed precision against double precision over several steps,
+    /// both storing macro-particles in single precision
+    ///
+    /// # Errors
+    /// - number of macro-particles differs from double precision
+    /// - any widened macro-particle position deviates by more than 1e-6 of the bounding box
+    /// - any macro-particle velocity deviates by more than 1e-5 of the fastest macro-particle
+    /// - field energy deviates by more than 1e-3 relative to double precision
+    ///
+    #[test]
+    fn update_precision_bounded() {
+        // setup
+        let double = advance::<f64, f64>();
+        let single = advance::<f32, f32>();
+        let mixed = advance::<f64, f32>();
+        let reference = &double.species[0].particles;
+        let speed = reference
+            .iter()
+            .flat_map(|p| p.velocity.iter())
+            .fold(0.0, |max: f64, v| max.max(v.abs()));
+
+        // assertions
+        for (particles, field_energy) in [
+            (&single.species[0].particles, single.field_energy()),
+            (&mixed.species[0].particles, mixed.field_energy()),
+        ] {
+            assert_eq!(particles.len(), reference.len());
+            for (p, q) in particles.iter().zip(reference.iter()) {
+                let widened = p.widen();
+                for (x, y) in widened.position.iter().zip(q.position.iter()) {
+                    assert!((x - y).abs() < 1e-6 * 0.01);
+                }
+                for (v, w) in widened.velocity.iter().zip(q.velocity.iter()) {
+                    assert!((v - w).abs() < 1e-5 * speed);
+                }
+            }
+            assert!((field_energy - double.field_energy()).abs() < 1e-3 * double.field_energy());
+        }
+    }
+
+    /// helper function that sets up a configuration with enough macro-particles to deposit in parallel
+    ///
+    /// # Arguments
+    /// - `parallel`: ParallelConfig threads and reproducibility of shared-memory parallelism
+    ///
+    /// # Returns
+    /// `Config`
+    ///
+    /// # Errors
+    ///
+    fn parallel_config(parallel: ParallelConfig) -> Config {
+        let mut config = Config::new(&[0.01, 0.01, 0.01], &[11, 11, 11], 1e-12).unwrap();
+        config.parallel = parallel;
+        config
+            .species
+            .push(Species::new("electron", -ELEC_CHARGE, ELEC_MASS, 1e12, EV_TEMP, 50.0).unwrap());
+        config
+    }
+
+    /// helper function that advances an `Electrostatic` with enough macro-particles to deposit in parallel by several
+    /// steps
+    ///
+    /// # Arguments
+    /// - `threads`: usize number of threads
+    /// - `deterministic`: bool reduce deposition in a fixed order
+    ///
+    /// # Returns
+    /// `Electrostatic`
+    ///
+    /// # Errors
+    ///
+    fn advance_parallel(threads: usize, deterministic: bool) -> Electrostatic {
+        let config = parallel_config(ParallelConfig {
+            threads,
+            deterministic,
+        });
+        let mut electrostatic: Electrostatic = Electrostatic::new(&config).unwrap();
+        for _ in 0..3 {
+            electrostatic.update().unwrap();
+        }
+        electrostatic
+    }
+
+    /// tests `Electrostatic::update()` on several threads for reproducibility and agreement with a single thread
+    ///
+    /// # Errors
+    /// - deterministic runs on different numbers of threads are not identical
+    /// - number of macro-particles differs between modes
+    /// - potential deviates by more than 1e-10 of its largest magnitude between modes
+    /// - macro-particles deviate by more than 1e-12 of the bounding box between modes
+    ///
+    #[test]
+    fn update_parallel_reproducible() {
+        // setup
+        let single = advance_parallel(1, true);
+        let several = advance_parallel(3, true);
+        let adaptive = advance_parallel(3, false);
+        let scale = single.potential.norm_linf();
+
+        // assertions
+        assert!(single.species[0].particles.len() > PARTICLE_CHUNK);
+        assert_eq!(single.potential, several.potential);
+        assert_eq!(single.species[0].particles, several.species[0].particles);
         assert_eq!(
-            electrostatic.delta_inv_sq.z,
-            1.0 / (electrostatic.delta.z * electrostatic.delta.z)
+            adaptive.species[0].particles.len(),
+            single.species[0].particles.len()
         );
+        for (a, b) in single.potential.iter().zip(adaptive.potential.iter()) {
+            assert!((a - b).abs() < 1e-10 * scale);
+        }
+        for (p, q) in single.species[0]
+            .particles
+            .iter()
+            .zip(adaptive.species[0].particles.iter())
+        {
+            for (x, y) in p.position.iter().zip(q.position.iter()) {
+                assert!((x - y).abs() < 1e-12 * 0.01);
+            }
+        }
+    }
+
+    /// tests `Electrostatic::write_checkpoint()` and `Electrostatic::read_checkpoint()` for a bit-for-bit restart
+    /// under the default parallel configuration with enough macro-particles to deposit in parallel, restarted on a
+    /// different number of threads
+    ///
+    /// # Errors
+    /// - restarted engine differs from uninterrupted engine
+    ///
+    #[test]
+    fn restart_parallel_bit_for_bit() {
+        // setup
+        let config = parallel_config(ParallelConfig::default());
+        let mut uninterrupted: Electrostatic = Electrostatic::new(&config).unwrap();
+        for _ in 0..3 {
+            uninterrupted.update().unwrap();
+        }
+        let mut checkpoint: Vec<u8> = Vec::new();
+        uninterrupted.write_checkpoint(&mut checkpoint).unwrap();
+        for _ in 0..3 {
+            uninterrupted.update().unwrap();
+        }
+
+        let mut other = config.clone();
+        other.parallel.threads = 2;
+        let mut restarted: Electrostatic = Electrostatic::new(&other).unwrap();
+        restarted
+            .read_checkpoint(&mut checkpoint.as_slice())
+            .unwrap();
+        for _ in 0..3 {
+            restarted.update().unwrap();
+        }
+
+        // assertions
+        let mut a: Vec<u8> = Vec::new();
+        let mut b: Vec<u8> = Vec::new();
+        uninterrupted.write_checkpoint(&mut a).unwrap();
+        restarted.write_checkpoint(&mut b).unwrap();
+        assert!(uninterrupted.species[0].particles.len() > PARTICLE_CHUNK);
+        assert_eq!(restarted.step(), 6);
+        assert!(a == b);
     }
 }
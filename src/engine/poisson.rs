@@ -0,0 +1,1045 @@
+//! poisson module
+//!
+//! describes gauss-seidel sor kernels of the discrete poisson equation of the electrostatic engines, an indexed
+//! reference kernel, a kernel running over contiguous rows of nodes along k, and a red-black kernel running over
+//! planes of constant i concurrently
+
+use crate::constants::INV_VAC_PERM;
+use crate::engine::{CONV_CHECK_ITER, GS_MAX_ITER, GS_ROUNDING, GS_TOL, SOR_ACC};
+use crate::field::scalar::{check_shapes, ScalarField};
+use crate::precision::Real;
+use crate::utils::coordinate_triplet::CoordinateTriplet;
+use anyhow::anyhow;
+use rayon::prelude::*;
+use std::ops::Range;
+
+/// `Poisson<F>` struct
+///
+/// coefficients of the 7-point discrete laplacian of a rectilinear grid of possibly non-uniformly spaced nodes in
+/// precision `F`, coefficients along z are additionally stored as structure of arrays so that row kernels read them
+/// from contiguous slices alongside the rows of the fields
+///
+/// the potential is solved for on a box of unknown nodes, all other nodes hold fixed boundary values, operators of
+/// other geometries and boundaries are described by their coefficients, a node on a symmetry plane couples to its
+/// only neighbour by the sum of both coefficients and an axis of a single node has vanishing coefficients
+#[derive(Debug, Clone, PartialEq)]
+pub struct Poisson<F> {
+    /// number of nodes along each axis
+    cells: CoordinateTriplet<usize>,
+
+    /// indices of unknown nodes along each axis
+    unknown: CoordinateTriplet<Range<usize>>,
+
+    /// (m^-2) coefficients of the second derivative coupling each node to its lower and upper neighbour along every
+    /// axis
+    laplacian: CoordinateTriplet<Vec<[F; 2]>>,
+
+    /// (m^-2) coefficients coupling each node to its lower neighbour along z
+    z_lower: Vec<F>,
+
+    /// (m^-2) coefficients coupling each node to its upper neighbour along z
+    z_upper: Vec<F>,
+
+    /// (m^-2) sum of coefficients along z of each node, its contribution to the diagonal
+    z_diagonal: Vec<F>,
+}
+
+impl<F: Real> Poisson<F> {
+    /// `Poisson<F>` constructor solving for all interior nodes
+    ///
+    /// # Arguments
+    /// - `laplacian`: &CoordinateTriplet<Vec<[f64; 2]>> (m^-2) coefficients of the second derivative coupling each
+    ///   node to its lower and upper neighbour along every axis as returned by `Axis::laplacian()`
+    ///
+    /// # Returns
+    /// `Result<Poisson<F>, anyhow::Error>`
+    ///
+    /// # Errors
+    /// - call to `Poisson::with_unknown()` fails
+    pub fn new(laplacian: &CoordinateTriplet<Vec<[f64; 2]>>) -> Result<Poisson<F>, anyhow::Error> {
+        let interior = |coefficients: &[[f64; 2]]| 1..coefficients.len().saturating_sub(1);
+        let unknown = CoordinateTriplet::new(
+            interior(&laplacian.x),
+            interior(&laplacian.y),
+            interior(&laplacian.z),
+        )?;
+        Poisson::with_unknown(laplacian, &unknown)
+    }
+
+    /// `Poisson<F>` constructor solving for a box of unknown nodes
+    ///
+    /// # Arguments
+    /// - `laplacian`: &CoordinateTriplet<Vec<[f64; 2]>> (m^-2) coefficients of the second derivative coupling each
+    ///   node to its lower and upper neighbour along every axis
+    /// - `unknown`: &CoordinateTriplet<Range<usize>> indices of unknown nodes along every axis
+    ///
+    /// # Returns
+    /// `Result<Poisson<F>, anyhow::Error>`
+    ///
+    /// # Errors
+    /// - unknown nodes exceed the nodes of an axis
+    /// - an unknown first or last node of an axis couples to a node beyond the axis
+    /// - any call to `CoordinateTriplet::new()` fails
+    pub fn with_unknown(
+        laplacian: &CoordinateTriplet<Vec<[f64; 2]>>,
+        unknown: &CoordinateTriplet<Range<usize>>,
+    ) -> Result<Poisson<F>, anyhow::Error> {
+        for (coefficients, range) in [
+            (&laplacian.x, &unknown.x),
+            (&laplacian.y, &unknown.y),
+            (&laplacian.z, &unknown.z),
+        ] {
+            let n = coefficients.len();
+            if range.end > n {
+                return Err(anyhow!("unknown nodes {range:?} exceed axis of {n} nodes"));
+            }
+            if range.is_empty() {
+                continue;
+            }
+            if (range.start == 0 && coefficients[0][0] != 0.0)
+                || (range.end == n && coefficients[n - 1][1] != 0.0)
+            {
+                return Err(anyhow!(
+                    "unknown nodes {range:?} couple to nodes beyond axis of {n} nodes"
+                ));
+            }
+        }
+
+        let narrow = |coefficients: &[[f64; 2]]| -> Vec<[F; 2]> {
+            coefficients.iter().map(|c| c.map(F::narrow)).collect()
+        };
+        let laplacian = CoordinateTriplet::new(
+            narrow(&laplacian.x),
+            narrow(&laplacian.y),
+            narrow(&laplacian.z),
+        )?;
+        let cells =
+            CoordinateTriplet::new(laplacian.x.len(), laplacian.y.len(), laplacian.z.len())?;
+
+        let z_lower = laplacian.z.iter().map(|[lower, _]| *lower).collect();
+        let z_upper = laplacian.z.iter().map(|[_, upper]| *upper).collect();
+        let z_diagonal = laplacian
+            .z
+            .iter()
+            .map(|[lower, upper]| *lower + *upper)
+            .collect();
+
+        Ok(Poisson {
+            cells,
+            unknown: unknown.clone(),
+            laplacian,
+            z_lower,
+            z_upper,
+            z_diagonal,
+        })
+    }
+
+    /// returns number of nodes of `Poisson<F>`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `&CoordinateTriplet<usize>` number of nodes along each axis
+    ///
+    /// # Errors
+    ///
+    pub fn cells(&self) -> &CoordinateTriplet<usize> {
+        &self.cells
+    }
+
+    /// returns indices of unknown nodes of `Poisson<F>`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `&CoordinateTriplet<Range<usize>>` indices of unknown nodes along each axis
+    ///
+    /// # Errors
+    ///
+    pub fn unknown(&self) -> &CoordinateTriplet<Range<usize>> {
+        &self.unknown
+    }
+
+    /// returns coefficients of the second derivative of `Poisson<F>`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `&CoordinateTriplet<Vec<[F; 2]>>` (m^-2) coefficients coupling each node to its lower and upper neighbour
+    ///
+    /// # Errors
+    ///
+    pub fn laplacian(&self) -> &CoordinateTriplet<Vec<[F; 2]>> {
+        &self.laplacian
+    }
+
+    /// solves the discrete poisson equation using gauss-seidel sor
+    ///
+    /// interior unknown nodes are swept with `Poisson::sweep_planes()`, any other box of unknown nodes with
+    /// `Poisson::sweep_indexed()`, the tolerance is GS_TOL unless the residue attainable in precision `F` is larger,
+    /// in which case the solution is converged once the residue is within GS_ROUNDING machine epsilons of the
+    /// magnitude of its terms
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `potential`: &mut ScalarField<F> (V) electric potential updated in place
+    /// - `charge_density`: &ScalarField<F> (C/m^3) electric charge density
+    ///
+    /// # Returns
+    /// `Result<(), anyhow::Error>`
+    ///
+    /// # Errors
+    /// - cells of `potential` or `charge_density` do not match nodes of `Poisson<F>`
+    /// - solution to potential did not converge to tolerance in GS_MAX_ITER iterations
+    pub fn solve(
+        &self,
+        potential: &mut ScalarField<F>,
+        charge_density: &ScalarField<F>,
+    ) -> Result<(), anyhow::Error> {
+        // red-black planes require every unknown node to have neighbours on both sides
+        let planes = self.is_interior();
+
+        // loop counter
+        let mut loop_ctr: u64 = 0;
+
+        // l2 error norm
+        let mut l2_err_norm: f64 = f64::MAX;
+
+        // tolerance of l2 error norm, raised if the precision of the potential cannot attain GS_TOL
+        let mut tolerance = GS_TOL;
+
+        // sor acceleration constant in field precision
+        let sor_acc = F::narrow(SOR_ACC);
+
+        // gauss-seidel sor scheme loop
+        while l2_err_norm > tolerance {
+            // update potential on unknown nodes
+            if planes {
+                self.sweep_planes(potential, charge_density, sor_acc)?;
+            } else {
+                self.sweep_indexed(potential, charge_density, sor_acc)?;
+            }
+
+            // conditionally check for convergence
+            if (loop_ctr % CONV_CHECK_ITER) == 0 {
+                // accumulate residue = Ax - b and magnitude of its largest terms, which bound its rounding error
+                let (res_acc, term_acc) = if planes {
+                    self.residue_planes(potential, charge_density)?
+                } else {
+                    self.residue_indexed(potential, charge_density)?
+                };
+
+                // update l2 error norm and tolerance
+                let nodes = (self.cells.x * self.cells.y * self.cells.z) as f64;
+                l2_err_norm = (res_acc / nodes).sqrt();
+                tolerance =
+                    GS_TOL.max(GS_ROUNDING * F::epsilon().widen() * (term_acc / nodes).sqrt());
+            }
+
+            // error if convergence is not met
+            if loop_ctr == GS_MAX_ITER {
+                return Err(anyhow!("solution to potential did not converge to tolerance of {tolerance} in {GS_MAX_ITER} iterations"));
+            }
+
+            // increment loop counter
+            loop_ctr += 1;
+        }
+
+        Ok(())
+    }
+
+    /// computes residue of the discrete poisson equation at an unknown node
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `potential`: &ScalarField<F> (V) electric potential
+    /// - `charge_density`: &ScalarField<F> (C/m^3) electric charge density
+    /// - `i`: usize index of node along x
+    /// - `j`: usize index of node along y
+    /// - `k`: usize index of node along z
+    ///
+    /// # Returns
+    /// `(F, F)` (V/m^2) residue Ax - b and (m^-2) diagonal of A
+    ///
+    /// # Errors
+    ///
+    pub fn residue(
+        &self,
+        potential: &ScalarField<F>,
+        charge_density: &ScalarField<F>,
+        i: usize,
+        j: usize,
+        k: usize,
+    ) -> (F, F) {
+        let [x_lower, x_upper] = self.laplacian.x[i];
+        let [y_lower, y_upper] = self.laplacian.y[j];
+        let [z_lower, z_upper] = self.laplacian.z[k];
+        let diag = x_lower + x_upper + y_lower + y_upper + z_lower + z_upper;
+
+        // neighbours beyond the grid have vanishing coefficients
+        let neighbour = |coefficient: F, node: Option<(usize, usize, usize)>| {
+            node.map_or(F::zero(), |node| coefficient * potential[node])
+        };
+        let (nx, ny, nz) = (self.cells.x, self.cells.y, self.cells.z);
+
+        let res = charge_density[(i, j, k)] * F::narrow(INV_VAC_PERM) - diag * potential[(i, j, k)]
+            + neighbour(x_lower, i.checked_sub(1).map(|i| (i, j, k)))
+            + neighbour(x_upper, (i + 1 < nx).then_some((i + 1, j, k)))
+            + neighbour(y_lower, j.checked_sub(1).map(|j| (i, j, k)))
+            + neighbour(y_upper, (j + 1 < ny).then_some((i, j + 1, k)))
+            + neighbour(z_lower, k.checked_sub(1).map(|k| (i, j, k)))
+            + neighbour(z_upper, (k + 1 < nz).then_some((i, j, k + 1)));
+
+        (res, diag)
+    }
+
+    /// performs a single gauss-seidel sor sweep over unknown nodes in i, j, k order indexing every node
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `potential`: &mut ScalarField<F> (V) electric potential updated in place
+    /// - `charge_density`: &ScalarField<F> (C/m^3) electric charge density
+    /// - `sor_acc`: F sor acceleration constant
+    ///
+    /// # Returns
+    /// `Result<(), anyhow::Error>`
+    ///
+    /// # Errors
+    /// - cells of `potential` or `charge_density` do not match nodes of `Poisson<F>`
+    pub fn sweep_indexed(
+        &self,
+        potential: &mut ScalarField<F>,
+        charge_density: &ScalarField<F>,
+        sor_acc: F,
+    ) -> Result<(), anyhow::Error> {
+        self.check(potential, charge_density)?;
+
+        for i in self.unknown.x.clone() {
+            for j in self.unknown.y.clone() {
+                for k in self.unknown.z.clone() {
+                    // solve potential using gauss-seidel, the update is the residue over the diagonal
+                    let (res, diag) = self.residue(potential, charge_density, i, j, k);
+
+                    // apply sor
+                    potential[(i, j, k)] += sor_acc * res / diag;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// performs a single gauss-seidel sor sweep over interior nodes in i, j, k order running over contiguous rows
+    ///
+    /// every row is updated by `Poisson::relax_row()` so that the sweep matches `Poisson::sweep_indexed()` up to
+    /// rounding
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `potential`: &mut ScalarField<F> (V) electric potential updated in place
+    /// - `charge_density`: &ScalarField<F> (C/m^3) electric charge density
+    /// - `sor_acc`: F sor acceleration constant
+    ///
+    /// # Returns
+    /// `Result<(), anyhow::Error>`
+    ///
+    /// # Errors
+    /// - cells of `potential` or `charge_density` do not match nodes of `Poisson<F>`
+    /// - unknown nodes of `Poisson<F>` are not the interior nodes
+    pub fn sweep_rows(
+        &self,
+        potential: &mut ScalarField<F>,
+        charge_density: &ScalarField<F>,
+        sor_acc: F,
+    ) -> Result<(), anyhow::Error> {
+        self.check(potential, charge_density)?;
+        self.check_interior()?;
+        if self.cells.z < 3 {
+            return Ok(());
+        }
+
+        // partial residue of every interior node of a row
+        let mut partial = vec![F::zero(); self.cells.z - 2];
+
+        for i in 1..(self.cells.x - 1) {
+            for j in 1..(self.cells.y - 1) {
+                let (row, neighbours) = potential.stencil_rows_mut(i, j);
+                self.relax_row(
+                    (i, j),
+                    row,
+                    neighbours,
+                    charge_density.row(i, j),
+                    &mut partial,
+                    sor_acc,
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// performs a single red-black gauss-seidel sor sweep over interior nodes updating all odd planes of constant i
+    /// concurrently before all even planes, running over contiguous rows in j, k order within every plane
+    ///
+    /// every plane only reads planes of the other parity while it is updated, so that the sweep is independent of the
+    /// number of threads, it converges to the same solution as `Poisson::sweep_rows()` but differs from it after every
+    /// sweep as planes are visited in a different order
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `potential`: &mut ScalarField<F> (V) electric potential updated in place
+    /// - `charge_density`: &ScalarField<F> (C/m^3) electric charge density
+    /// - `sor_acc`: F sor acceleration constant
+    ///
+    /// # Returns
+    /// `Result<(), anyhow::Error>`
+    ///
+    /// # Errors
+    /// - cells of `potential` or `charge_density` do not match nodes of `Poisson<F>`
+    /// - unknown nodes of `Poisson<F>` are not the interior nodes
+    pub fn sweep_planes(
+        &self,
+        potential: &mut ScalarField<F>,
+        charge_density: &ScalarField<F>,
+        sor_acc: F,
+    ) -> Result<(), anyhow::Error> {
+        self.check(potential, charge_density)?;
+        self.check_interior()?;
+        if self.cells.z < 3 {
+            return Ok(());
+        }
+
+        let rho = charge_density.planes();
+        for parity in [1, 0] {
+            potential
+                .alternate_planes_mut(parity)
+                .into_par_iter()
+                .for_each_init(
+                    || vec![F::zero(); self.cells.z - 2],
+                    |partial, (mut plane, [west, east])| {
+                        let i = plane.index();
+                        for j in 1..(self.cells.y - 1) {
+                            let (row, [south, north]) = plane.stencil_rows_mut(j);
+                            let neighbours = [west.row(j), east.row(j), south, north];
+                            let rho = rho[i].row(j);
+                            self.relax_row((i, j), row, neighbours, rho, partial, sor_acc);
+                        }
+                    },
+                );
+        }
+
+        Ok(())
+    }
+
+    /// accumulates squared residues of unknown nodes indexing every node
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `potential`: &ScalarField<F> (V) electric potential
+    /// - `charge_density`: &ScalarField<F> (C/m^3) electric charge density
+    ///
+    /// # Returns
+    /// `Result<(f64, f64), anyhow::Error>` ((V/m^2)^2) sum of squared residues and sum of squared magnitudes of the
+    /// largest terms of the residues, which bound their rounding errors
+    ///
+    /// # Errors
+    /// - cells of `potential` or `charge_density` do not match nodes of `Poisson<F>`
+    pub fn residue_indexed(
+        &self,
+        potential: &ScalarField<F>,
+        charge_density: &ScalarField<F>,
+    ) -> Result<(f64, f64), anyhow::Error> {
+        self.check(potential, charge_density)?;
+
+        let (mut res_acc, mut term_acc) = (0.0, 0.0);
+        for i in self.unknown.x.clone() {
+            for j in self.unknown.y.clone() {
+                for k in self.unknown.z.clone() {
+                    let (res, diag) = self.residue(potential, charge_density, i, j, k);
+                    let res = res.widen();
+                    let term = (diag * potential[(i, j, k)]).widen().abs()
+                        + (charge_density[(i, j, k)].widen() * INV_VAC_PERM).abs();
+
+                    res_acc += res * res;
+                    term_acc += term * term;
+                }
+            }
+        }
+
+        Ok((res_acc, term_acc))
+    }
+
+    /// accumulates squared residues of interior nodes running over contiguous rows
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `potential`: &ScalarField<F> (V) electric potential
+    /// - `charge_density`: &ScalarField<F> (C/m^3) electric charge density
+    ///
+    /// # Returns
+    /// `Result<(f64, f64), anyhow::Error>` ((V/m^2)^2) sum of squared residues and sum of squared magnitudes of the
+    /// largest terms of the residues, which bound their rounding errors
+    ///
+    /// # Errors
+    /// - cells of `potential` or `charge_density` do not match nodes of `Poisson<F>`
+    /// - unknown nodes of `Poisson<F>` are not the interior nodes
+    pub fn residue_rows(
+        &self,
+        potential: &ScalarField<F>,
+        charge_density: &ScalarField<F>,
+    ) -> Result<(f64, f64), anyhow::Error> {
+        self.check(potential, charge_density)?;
+        self.check_interior()?;
+        if self.cells.z < 3 {
+            return Ok((0.0, 0.0));
+        }
+
+        let (mut res_acc, mut term_acc) = (0.0, 0.0);
+        for i in 1..(self.cells.x - 1) {
+            for j in 1..(self.cells.y - 1) {
+                let neighbours = [
+                    potential.row(i - 1, j),
+                    potential.row(i + 1, j),
+                    potential.row(i, j - 1),
+                    potential.row(i, j + 1),
+                ];
+                let (res, term) = self.row_residue(
+                    (i, j),
+                    potential.row(i, j),
+                    neighbours,
+                    charge_density.row(i, j),
+                );
+                res_acc += res;
+                term_acc += term;
+            }
+        }
+
+        Ok((res_acc, term_acc))
+    }
+
+    /// accumulates squared residues of interior nodes over planes of constant i concurrently, sums of every plane are
+    /// added in order of i so that the result is independent of the number of threads
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `potential`: &ScalarField<F> (V) electric potential
+    /// - `charge_density`: &ScalarField<F> (C/m^3) electric charge density
+    ///
+    /// # Returns
+    /// `Result<(f64, f64), anyhow::Error>` ((V/m^2)^2) sum of squared residues and sum of squared magnitudes of the
+    /// largest terms of the residues, which bound their rounding errors
+    ///
+    /// # Errors
+    /// - cells of `potential` or `charge_density` do not match nodes of `Poisson<F>`
+    /// - unknown nodes of `Poisson<F>` are not the interior nodes
+    pub fn residue_planes(
+        &self,
+        potential: &ScalarField<F>,
+        charge_density: &ScalarField<F>,
+    ) -> Result<(f64, f64), anyhow::Error> {
+        self.check(potential, charge_density)?;
+        self.check_interior()?;
+        if self.cells.x < 3 || self.cells.z < 3 {
+            return Ok((0.0, 0.0));
+        }
+
+        let (phi, rho) = (potential.planes(), charge_density.planes());
+        let partials: Vec<(f64, f64)> = (1..self.cells.x - 1)
+            .into_par_iter()
+            .map(|i| {
+                let (mut res_acc, mut term_acc) = (0.0, 0.0);
+                for j in 1..(self.cells.y - 1) {
+                    let neighbours = [
+                        phi[i - 1].row(j),
+                        phi[i + 1].row(j),
+                        phi[i].row(j - 1),
+                        phi[i].row(j + 1),
+                    ];
+                    let (res, term) =
+                        self.row_residue((i, j), phi[i].row(j), neighbours, rho[i].row(j));
+                    res_acc += res;
+                    term_acc += term;
+                }
+                (res_acc, term_acc)
+            })
+            .collect();
+
+        Ok(partials
+            .iter()
+            .fold((0.0, 0.0), |(res_acc, term_acc), (res, term)| {
+                (res_acc + res, term_acc + term)
+            }))
+    }
+
+    /// performs a gauss-seidel sor update of the interior nodes of a single row along k
+    ///
+    /// the residue of every node of the row is first accumulated from nodes not yet updated along the row, which
+    /// vectorizes, before a serial pass along k adds the freshly updated lower neighbour and applies sor
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `(i, j)`: (usize, usize) indices of the row along x and y
+    /// - `row`: &mut [F] (V) electric potential of the row updated in place
+    /// - `[west, east, south, north]`: [&[F]; 4] (V) electric potential of rows (i - 1, j), (i + 1, j), (i, j - 1),
+    ///   and (i, j + 1)
+    /// - `rho`: &[F] (C/m^3) electric charge density of the row
+    /// - `partial`: &mut [F] scratch space of a partial residue of every interior node of the row
+    /// - `sor_acc`: F sor acceleration constant
+    ///
+    /// # Returns
+    ///
+    /// # Errors
+    ///
+    fn relax_row(
+        &self,
+        (i, j): (usize, usize),
+        row: &mut [F],
+        [west, east, south, north]: [&[F]; 4],
+        rho: &[F],
+        partial: &mut [F],
+        sor_acc: F,
+    ) {
+        // coefficients along z of interior nodes, trimmed to the length of a row of interior nodes
+        let interior = 1..self.cells.z - 1;
+        let z_lower = &self.z_lower[interior.clone()];
+        let z_upper = &self.z_upper[interior.clone()];
+        let z_diagonal = &self.z_diagonal[interior.clone()];
+        let inv_vac_perm = F::narrow(INV_VAC_PERM);
+
+        let [x_lower, x_upper] = self.laplacian.x[i];
+        let [y_lower, y_upper] = self.laplacian.y[j];
+        let xy_diagonal = x_lower + x_upper + y_lower + y_upper;
+
+        // residue from charge density, neighbours along x and y, the node itself, and its upper neighbour along z,
+        // none of which change while the row is updated
+        let rho = &rho[interior.clone()];
+        let centre = row[interior.clone()].iter().zip(&row[2..]);
+        let x = west[1..].iter().zip(&east[1..]);
+        let y = south[1..].iter().zip(&north[1..]);
+        let z = z_upper.iter().zip(z_diagonal);
+        for (((((res, rho), (phi, up)), (w, e)), (s, n)), (zu, zd)) in
+            partial.iter_mut().zip(rho).zip(centre).zip(x).zip(y).zip(z)
+        {
+            *res = *rho * inv_vac_perm - (xy_diagonal + *zd) * *phi
+                + x_lower * *w
+                + x_upper * *e
+                + y_lower * *s
+                + y_upper * *n
+                + *zu * *up;
+        }
+
+        // serial pass along k adding the updated lower neighbour along z and applying sor
+        let mut below = row[0];
+        for ((phi, res), (zl, zd)) in row[interior]
+            .iter_mut()
+            .zip(partial.iter())
+            .zip(z_lower.iter().zip(z_diagonal))
+        {
+            *phi += sor_acc * (*res + *zl * below) / (xy_diagonal + *zd);
+            below = *phi;
+        }
+    }
+
+    /// accumulates squared residues of the interior nodes of a single row along k
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `(i, j)`: (usize, usize) indices of the row along x and y
+    /// - `row`: &[F] (V) electric potential of the row
+    /// - `[west, east, south, north]`: [&[F]; 4] (V) electric potential of rows (i - 1, j), (i + 1, j), (i, j - 1),
+    ///   and (i, j + 1)
+    /// - `rho`: &[F] (C/m^3) electric charge density of the row
+    ///
+    /// # Returns
+    /// `(f64, f64)` ((V/m^2)^2) sum of squared residues and sum of squared magnitudes of the largest terms of the
+    /// residues
+    ///
+    /// # Errors
+    ///
+    fn row_residue(
+        &self,
+        (i, j): (usize, usize),
+        row: &[F],
+        [west, east, south, north]: [&[F]; 4],
+        rho: &[F],
+    ) -> (f64, f64) {
+        let interior = 1..self.cells.z - 1;
+        let z_lower = &self.z_lower[interior.clone()];
+        let z_upper = &self.z_upper[interior.clone()];
+        let z_diagonal = &self.z_diagonal[interior.clone()];
+        let inv_vac_perm = F::narrow(INV_VAC_PERM);
+
+        let [x_lower, x_upper] = self.laplacian.x[i];
+        let [y_lower, y_upper] = self.laplacian.y[j];
+        let xy_diagonal = x_lower + x_upper + y_lower + y_upper;
+
+        let z = row.iter().zip(&row[2..]).zip(z_lower.iter().zip(z_upper));
+        let x = west[1..].iter().zip(&east[1..]);
+        let y = south[1..].iter().zip(&north[1..]);
+        let (mut res_acc, mut term_acc) = (0.0, 0.0);
+        for (((((rho, phi), ((down, up), (zl, zu))), (w, e)), (s, n)), zd) in rho[interior]
+            .iter()
+            .zip(&row[1..])
+            .zip(z)
+            .zip(x)
+            .zip(y)
+            .zip(z_diagonal)
+        {
+            let source = *rho * inv_vac_perm;
+            let centre = (xy_diagonal + *zd) * *phi;
+            let res = (source - centre
+                + x_lower * *w
+                + x_upper * *e
+                + y_lower * *s
+                + y_upper * *n
+                + *zl * *down
+                + *zu * *up)
+                .widen();
+            let term = centre.widen().abs() + source.widen().abs();
+
+            res_acc += res * res;
+            term_acc += term * term;
+        }
+
+        (res_acc, term_acc)
+    }
+
+    /// checks that fields are defined on the nodes of `Poisson<F>`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `potential`: &ScalarField<F> (V) electric potential
+    /// - `charge_density`: &ScalarField<F> (C/m^3) electric charge density
+    ///
+    /// # Returns
+    /// `Result<(), anyhow::Error>`
+    ///
+    /// # Errors
+    /// - cells of `potential` or `charge_density` do not match nodes of `Poisson<F>`
+    fn check(
+        &self,
+        potential: &ScalarField<F>,
+        charge_density: &ScalarField<F>,
+    ) -> Result<(), anyhow::Error> {
+        check_shapes(potential.cells(), &self.cells)?;
+        check_shapes(charge_density.cells(), &self.cells)
+    }
+
+    /// returns whether the unknown nodes of `Poisson<F>` are its interior nodes
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `bool`
+    ///
+    /// # Errors
+    ///
+    fn is_interior(&self) -> bool {
+        let interior = |n: usize| 1..n.saturating_sub(1);
+        self.unknown.x == interior(self.cells.x)
+            && self.unknown.y == interior(self.cells.y)
+            && self.unknown.z == interior(self.cells.z)
+    }
+
+    /// checks that the unknown nodes of `Poisson<F>` are its interior nodes, as row and plane kernels assume
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `Result<(), anyhow::Error>`
+    ///
+    /// # Errors
+    /// - unknown nodes of `Poisson<F>` are not the interior nodes
+    fn check_interior(&self) -> Result<(), anyhow::Error> {
+        if self.is_interior() {
+            Ok(())
+        } else {
+            Err(anyhow!(
+                "row and plane kernels require the interior nodes to be unknown, got {:?}",
+                self.unknown
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::constants::VAC_PERM;
+    use crate::engine::poisson::Poisson;
+    use crate::field::scalar::ScalarField;
+    use crate::grid::{Axis, Spacing};
+    use crate::precision::Real;
+    use crate::utils::coordinate_triplet::CoordinateTriplet;
+    use rayon::ThreadPoolBuilder;
+    use std::ops::Range;
+
+    /// helper function that sets up a `Poisson<F>` on stretched nodes with a potential and charge density varying
+    /// along every axis for testing
+    ///
+    /// # Arguments
+    /// - `ghost`: usize width of ghost layers of fields
+    ///
+    /// # Returns
+    /// `(Poisson<F>, ScalarField<F>, ScalarField<F>)` coefficients, potential, and charge density
+    ///
+    /// # Errors
+    ///
+    fn setup<F: Real>(ghost: usize) -> (Poisson<F>, ScalarField<F>, ScalarField<F>) {
+        let cells = CoordinateTriplet::new(7, 6, 9).unwrap();
+        let laplacian = CoordinateTriplet::new(
+            Axis::new(&Spacing::Tanh(1.5), 1.0, cells.x)
+                .unwrap()
+                .laplacian(),
+            Axis::new(&Spacing::Uniform, 2.0, cells.y)
+                .unwrap()
+                .laplacian(),
+            Axis::new(&Spacing::Tanh(1.0), 0.5, cells.z)
+                .unwrap()
+                .laplacian(),
+        )
+        .unwrap();
+        let poisson = Poisson::new(&laplacian).unwrap();
+
+        let mut potential: ScalarField<F> = ScalarField::with_ghost(&cells, ghost).unwrap();
+        let mut charge_density: ScalarField<F> = ScalarField::with_ghost(&cells, ghost).unwrap();
+        for i in 0..cells.x {
+            for j in 0..cells.y {
+                for k in 0..cells.z {
+                    let (x, y, z) = (i as f64, j as f64, k as f64);
+                    potential[(i, j, k)] = F::narrow((0.3 * x + 0.7 * y - 0.2 * z).sin());
+                    charge_density[(i, j, k)] = F::narrow(VAC_PERM * (x * y - z).cos());
+                }
+            }
+        }
+
+        (poisson, potential, charge_density)
+    }
+
+    /// tests `Poisson::sweep_rows()` against `Poisson::sweep_indexed()` over several sweeps
+    ///
+    /// # Errors
+    /// - potentials of both kernels differ by more than rounding on any node
+    /// - boundary nodes are modified by either kernel
+    ///
+    #[test]
+    fn sweep_rows_matches_indexed() {
+        for ghost in [0, 1] {
+            // setup
+            let (poisson, mut indexed, charge_density) = setup::<f64>(ghost);
+            let mut rows = indexed.clone();
+            let initial = indexed.clone();
+            for _ in 0..5 {
+                poisson
+                    .sweep_indexed(&mut indexed, &charge_density, 1.4)
+                    .unwrap();
+                poisson.sweep_rows(&mut rows, &charge_density, 1.4).unwrap();
+            }
+
+            // assertions
+            for (a, b) in indexed.iter().zip(rows.iter()) {
+                assert!((a - b).abs() < 1e-12 * a.abs().max(1.0));
+            }
+            assert_ne!(indexed, initial);
+            for (i, j, k) in [
+                (0, 2, 3),
+                (6, 2, 3),
+                (3, 0, 3),
+                (3, 5, 3),
+                (3, 2, 0),
+                (3, 2, 8),
+            ] {
+                assert_eq!(rows[(i, j, k)], initial[(i, j, k)]);
+            }
+        }
+    }
+
+    /// tests `Poisson::residue_rows()` against `Poisson::residue_indexed()` in double and single precision
+    ///
+    /// # Errors
+    /// - sums of squared residues or terms of both kernels differ by more than rounding
+    ///
+    #[test]
+    fn residue_rows_matches_indexed() {
+        // setup
+        let (poisson, potential, charge_density) = setup::<f64>(1);
+        let indexed = poisson
+            .residue_indexed(&potential, &charge_density)
+            .unwrap();
+        let rows = poisson.residue_rows(&potential, &charge_density).unwrap();
+        let (single, potential, charge_density) = setup::<f32>(0);
+        let single = single.residue_rows(&potential, &charge_density).unwrap();
+
+        // assertions
+        assert!(indexed.0 > 0.0);
+        assert!((indexed.0 - rows.0).abs() < 1e-12 * indexed.0);
+        assert!((indexed.1 - rows.1).abs() < 1e-12 * indexed.1);
+        assert!((indexed.0 - single.0).abs() < 1e-4 * indexed.0);
+    }
+
+    /// tests `Poisson::sweep_planes()` for convergence to the solution of `Poisson::sweep_rows()` and for
+    /// independence of the number of threads
+    ///
+    /// # Errors
+    /// - potentials of both kernels differ by more than the tolerance once converged
+    /// - potentials of `Poisson::sweep_planes()` differ on a single thread and several threads
+    /// - boundary nodes are modified
+    ///
+    #[test]
+    fn sweep_planes_converges() {
+        for ghost in [0, 1] {
+            // setup
+            let (poisson, mut rows, charge_density) = setup::<f64>(ghost);
+            let initial = rows.clone();
+            let mut planes = rows.clone();
+            let mut single = rows.clone();
+            let pool = ThreadPoolBuilder::new().num_threads(1).build().unwrap();
+            for _ in 0..200 {
+                poisson.sweep_rows(&mut rows, &charge_density, 1.4).unwrap();
+                poisson
+                    .sweep_planes(&mut planes, &charge_density, 1.4)
+                    .unwrap();
+                pool.install(|| poisson.sweep_planes(&mut single, &charge_density, 1.4))
+                    .unwrap();
+            }
+
+            // assertions
+            let (res, _) = poisson.residue_planes(&planes, &charge_density).unwrap();
+            assert!(res < 1e-20);
+            for (a, b) in rows.iter().zip(planes.iter()) {
+                assert!((a - b).abs() < 1e-10 * a.abs().max(1.0));
+            }
+            assert_eq!(planes, single);
+            for (i, j, k) in [(0, 2, 3), (6, 2, 3), (3, 0, 3), (3, 5, 3), (3, 2, 8)] {
+                assert_eq!(planes[(i, j, k)], initial[(i, j, k)]);
+            }
+        }
+    }
+
+    /// tests `Poisson::residue_planes()` against `Poisson::residue_rows()`
+    ///
+    /// # Errors
+    /// - sums of squared residues or terms of both kernels differ by more than rounding
+    ///
+    #[test]
+    fn residue_planes_matches_rows() {
+        // setup
+        let (poisson, potential, charge_density) = setup::<f64>(1);
+        let rows = poisson.residue_rows(&potential, &charge_density).unwrap();
+        let planes = poisson.residue_planes(&potential, &charge_density).unwrap();
+
+        // assertions
+        assert!(rows.0 > 0.0);
+        assert!((rows.0 - planes.0).abs() < 1e-12 * rows.0);
+        assert!((rows.1 - planes.1).abs() < 1e-12 * rows.1);
+    }
+
+    /// tests `Poisson::with_unknown()` for failure on unknown nodes beyond or coupling beyond an axis and row and
+    /// plane kernels for failure on unknown nodes other than the interior nodes
+    ///
+    /// # Errors
+    /// - `Poisson::with_unknown()` succeeds for unknown nodes exceeding an axis
+    /// - `Poisson::with_unknown()` succeeds for an unknown first node coupling to a lower neighbour
+    /// - `Poisson::with_unknown()` fails for an axis of a single unknown node of vanishing coefficients
+    /// - any row or plane kernel succeeds for unknown nodes other than the interior nodes
+    ///
+    #[test]
+    fn with_unknown_validation() {
+        // setup
+        let laplacian =
+            CoordinateTriplet::new(vec![[1.0; 2]; 4], vec![[0.0; 2]], vec![[1.0; 2]; 5]).unwrap();
+        let unknown = |x: Range<usize>| CoordinateTriplet::new(x, 0..1, 1..4).unwrap();
+        let cells = CoordinateTriplet::new(4, 1, 5).unwrap();
+        let mut potential: ScalarField<f64> = ScalarField::new(&cells).unwrap();
+        let charge_density = potential.clone();
+
+        // assertions
+        assert!(Poisson::<f64>::with_unknown(&laplacian, &unknown(1..5)).is_err());
+        assert!(Poisson::<f64>::with_unknown(&laplacian, &unknown(0..3)).is_err());
+        let poisson = Poisson::<f64>::with_unknown(&laplacian, &unknown(1..3)).unwrap();
+        assert!(poisson
+            .sweep_indexed(&mut potential, &charge_density, 1.4)
+            .is_ok());
+        assert!(poisson
+            .sweep_rows(&mut potential, &charge_density, 1.4)
+            .is_err());
+        assert!(poisson
+            .sweep_planes(&mut potential, &charge_density, 1.4)
+            .is_err());
+        assert!(poisson.residue_rows(&potential, &charge_density).is_err());
+        assert!(poisson.residue_planes(&potential, &charge_density).is_err());
+    }
+
+    /// tests `Poisson::solve()` on a symmetry plane against the solution on the mirrored grid and the exact parabolic
+    /// potential of a uniform charge density between grounded plates, along z on axes of a single node along x and y
+    ///
+    /// # Errors
+    /// - potential of the mirrored grid deviates from the exact potential by more than 1e-4 V
+    /// - potential of the half grid deviates from the mirrored grid by more than 1e-4 V
+    ///
+    #[test]
+    fn solve_symmetric() {
+        // setup
+        let dz_inv_sq = 100.0;
+        let solve = |laplacian: Vec<[f64; 2]>, unknown: Range<usize>| -> ScalarField<f64> {
+            let cells = CoordinateTriplet::new(1, 1, laplacian.len()).unwrap();
+            let laplacian =
+                CoordinateTriplet::new(vec![[0.0; 2]], vec![[0.0; 2]], laplacian).unwrap();
+            let unknown = CoordinateTriplet::new(0..1, 0..1, unknown).unwrap();
+            let poisson = Poisson::<f64>::with_unknown(&laplacian, &unknown).unwrap();
+            let mut potential: ScalarField<f64> = ScalarField::new(&cells).unwrap();
+            let mut charge_density = potential.clone();
+            charge_density.iter_mut().for_each(|rho| *rho = VAC_PERM);
+            poisson.solve(&mut potential, &charge_density).unwrap();
+            potential
+        };
+
+        let mut full = vec![[dz_inv_sq; 2]; 17];
+        full[0] = [0.0; 2];
+        full[16] = [0.0; 2];
+        let full = solve(full, 1..16);
+
+        let mut half = vec![[dz_inv_sq; 2]; 9];
+        half[0] = [0.0; 2];
+        half[8] = [2.0 * dz_inv_sq, 0.0];
+        let half = solve(half, 1..9);
+
+        // assertions
+        for k in 0..17 {
+            let z = 0.1 * k as f64;
+            assert!((full[(0, 0, k)] - 0.5 * z * (1.6 - z)).abs() < 1e-4);
+        }
+        for k in 0..9 {
+            assert!((half[(0, 0, k)] - full[(0, 0, k)]).abs() < 1e-4);
+        }
+    }
+
+    /// tests kernels of `Poisson<F>` for failure on fields of mismatched cells
+    ///
+    /// # Errors
+    /// - any kernel succeeds for a potential or charge density of cells other than the nodes of `Poisson<F>`
+    ///
+    #[test]
+    fn kernels_mismatch() {
+        // setup
+        let (poisson, mut potential, charge_density) = setup::<f64>(0);
+        let cells = CoordinateTriplet::new(7, 6, 8).unwrap();
+        let mut other: ScalarField<f64> = ScalarField::new(&cells).unwrap();
+
+        // assertions
+        assert!(poisson
+            .sweep_indexed(&mut other, &charge_density, 1.4)
+            .is_err());
+        assert!(poisson.sweep_rows(&mut potential, &other, 1.4).is_err());
+        assert!(poisson.residue_indexed(&other, &charge_density).is_err());
+        assert!(poisson.residue_rows(&potential, &other).is_err());
+        assert!(poisson
+            .sweep_planes(&mut other, &charge_density, 1.4)
+            .is_err());
+        assert!(poisson.residue_planes(&potential, &other).is_err());
+    }
+}
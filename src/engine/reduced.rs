@@ -0,0 +1,1158 @@
+//! reduced engine module
+//!
+//! describes electrostatic engines resolving only x (1D) or x and y (2D), macro-particles keep all three velocity
+//! components while their positions along unresolved axes stay fixed
+
+use crate::checkpoint::{
+    read_cells, read_clock, read_particles, write_cells, write_clock, write_particles,
+};
+use crate::collisions::coulomb::Coulomb;
+use crate::collisions::dsmc::Dsmc;
+use crate::collisions::mcc::Mcc;
+use crate::config::Config;
+use crate::constants::{INV_VAC_PERM, VAC_PERM};
+use crate::diagnostics::Diagnostics;
+use crate::engine::poisson::Poisson;
+use crate::engine::{boris, control_widths, rotation, Engine};
+use crate::field::differential::{BoundaryStencil, Differential};
+use crate::field::scalar::ScalarField;
+use crate::field::vector::VectorField;
+use crate::grid::uniform_nodes;
+use crate::output::{Field, Variable};
+use crate::plasma::PlasmaReport;
+use crate::species::{Particle, Species};
+use crate::utils::coordinate_triplet::CoordinateTriplet;
+use crate::utils::rng::Rng;
+use anyhow::anyhow;
+use std::io::{Read, Write};
+
+/// `Reduced` struct
+///
+/// a reduced-dimensionality electrostatic pic engine resolving x in 1D and x and y in 2D
+///
+/// fields hold a single node along every unresolved axis, which instead spans the full extent of the bounding box
+#[derive(Debug)]
+pub struct Reduced {
+    /// number of resolved dimensions, 1 or 2
+    dimensions: usize,
+
+    /// (m) size of bounding box
+    size: CoordinateTriplet<f64>,
+
+    /// number of nodes along every axis, 1 along unresolved axes
+    cells: CoordinateTriplet<usize>,
+
+    /// (m) spatial increment, the size of the bounding box along unresolved axes
+    delta: CoordinateTriplet<f64>,
+
+    /// (V) electric potential
+    potential: ScalarField<f64>,
+
+    /// (C/m^3) charge density
+    charge_density: ScalarField<f64>,
+
+    /// (V/m) electric field, zero along unresolved axes
+    electric_field: VectorField<f64>,
+
+    /// (m^3) node control volumes
+    cell_vol: ScalarField<f64>,
+
+    /// (m^-2) coefficients of the discrete laplacian along resolved axes for use in gauss-seidel sor scheme in 2D
+    poisson: Poisson<f64>,
+
+    /// finite difference stencils of the electric field solve
+    differential: Differential,
+
+    /// number of cells of the grid used to bin macro-particles for collisions
+    collision_cells: CoordinateTriplet<usize>,
+
+    /// (m) spatial increment of the grid used to bin macro-particles for collisions
+    collision_delta: CoordinateTriplet<f64>,
+
+    /// characteristic plasma parameters and their validation against discretization
+    plasma_report: PlasmaReport,
+
+    /// (s) time step
+    dt: f64,
+
+    /// (s) simulated time
+    time: f64,
+
+    /// number of completed time steps
+    step: u64,
+
+    /// (T) applied uniform magnetic flux density
+    magnetic_field: CoordinateTriplet<f64>,
+
+    /// species present in engine
+    species: Vec<Species>,
+
+    /// monte carlo collisions with background gas
+    mcc: Option<Mcc>,
+
+    /// binary coulomb collisions between charged species
+    coulomb: Option<Coulomb>,
+
+    /// direct simulation monte carlo collisions between neutral species
+    dsmc: Option<Dsmc>,
+
+    /// random number generator
+    rng: Rng,
+}
+
+impl Reduced {
+    /// `Reduced` constructor
+    ///
+    /// only the first `dimensions` entries of `config.cells` are used, collisions bin macro-particles on a grid
+    /// of a single cell along every unresolved axis, so that a background gas density profile must have
+    /// `config.cells[0]` nodes along x, `config.cells[1]` (2D) or 2 (1D) nodes along y, and 2 nodes along z
+    ///
+    /// # Arguments
+    /// - `config`: &Config model configuration
+    /// - `dimensions`: usize number of resolved dimensions, 1 or 2
+    ///
+    /// # Returns
+    /// `Result<Reduced, anyhow::Error>`
+    ///
+    /// # Errors
+    /// - `dimensions` is not 1 or 2
+    /// - grid is not uniform
+    /// - magnetostatic sources are configured
+    /// - secondary electron emission is configured
+    /// - any call to `CoordinateTriplet::new()` fails
+    /// - any call to `ScalarField::new()` fails
+    /// - any call to `VectorField::new()` fails
+    /// - call to `Poisson::with_unknown()` fails
+    /// - call to `Differential::uniform()` fails
+    /// - any plasma parameter check with `Severity::Error` fails
+    /// - call to `Mcc::new()` fails
+    /// - call to `Coulomb::new()` fails
+    /// - call to `Dsmc::new()` fails
+    pub fn new(config: &Config, dimensions: usize) -> Result<Reduced, anyhow::Error> {
+        if !(1..=2).contains(&dimensions) {
+            return Err(anyhow!(
+                "reduced engines resolve 1 or 2 dimensions, got {dimensions}"
+            ));
+        }
+        if !config.grid.is_uniform() {
+            return Err(anyhow!(
+                "non-uniform grids are not supported by reduced engines"
+            ));
+        }
+        if !config.magnetostatics.sources.is_empty() {
+            return Err(anyhow!(
+                "magnetostatic sources are not supported by reduced engines"
+            ));
+        }
+        if !config.emission.rules.is_empty() {
+            return Err(anyhow!(
+                "secondary electron emission is not supported by reduced engines"
+            ));
+        }
+
+        // unpack dimensions
+        let size: CoordinateTriplet<f64> =
+            CoordinateTriplet::new(config.size[0], config.size[1], config.size[2])?;
+
+        // unpack cells of resolved axes
+        let ny = if dimensions == 2 { config.cells[1] } else { 1 };
+        let cells: CoordinateTriplet<usize> = CoordinateTriplet::new(config.cells[0], ny, 1)?;
+
+        // initialize spatial increments, unresolved axes span the bounding box
+        let dx = size.x / (cells.x - 1) as f64;
+        let dy = if dimensions == 2 {
+            size.y / (cells.y - 1) as f64
+        } else {
+            size.y
+        };
+        let delta: CoordinateTriplet<f64> = CoordinateTriplet::new(dx, dy, size.z)?;
+
+        // validate plasma parameters against spatial and temporal discretization of resolved axes only
+        let resolved = CoordinateTriplet::new(dx, if dimensions == 2 { dy } else { dx }, dx)?;
+        let plasma_report = PlasmaReport::new(
+            &config.species,
+            &config.magnetic_field,
+            &resolved,
+            config.dt,
+            &config.validation,
+        );
+        plasma_report.enforce()?;
+
+        // initialize fields
+        let potential: ScalarField<f64> = ScalarField::new(&cells)?;
+        let charge_density: ScalarField<f64> = ScalarField::new(&cells)?;
+        let electric_field: VectorField<f64> = VectorField::new(&cells)?;
+
+        // initialize cell volumes as node control volumes spanning the bounding box along unresolved axes
+        let widths = |cells: usize, delta: f64, extent: f64| -> Vec<f64> {
+            if cells > 1 {
+                control_widths(&(0..cells).map(|i| i as f64 * delta).collect::<Vec<f64>>())
+            } else {
+                vec![extent]
+            }
+        };
+        let wx = widths(cells.x, dx, size.x);
+        let wy = widths(cells.y, dy, size.y);
+        let mut cell_vol: ScalarField<f64> = ScalarField::new(&cells)?;
+        for (i, wx) in wx.iter().enumerate() {
+            for (j, wy) in wy.iter().enumerate() {
+                cell_vol[(i, j, 0)] = wx * wy * size.z;
+            }
+        }
+
+        // coefficients of the second derivative along resolved axes, solving for interior nodes of resolved axes and
+        // the single node of unresolved axes, which does not couple to any neighbour
+        let coefficients = |cells: usize, delta: f64| -> Vec<[f64; 2]> {
+            (0..cells)
+                .map(|i| {
+                    if i == 0 || i == cells - 1 {
+                        [0.0; 2]
+                    } else {
+                        [1.0 / (delta * delta); 2]
+                    }
+                })
+                .collect()
+        };
+        let unknown = |cells: usize| {
+            if cells > 1 {
+                1..(cells - 1)
+            } else {
+                0..1
+            }
+        };
+        let laplacian = CoordinateTriplet::new(
+            coefficients(cells.x, dx),
+            coefficients(cells.y, dy),
+            coefficients(cells.z, size.z),
+        )?;
+        let unknown = CoordinateTriplet::new(unknown(cells.x), unknown(cells.y), unknown(cells.z))?;
+        let poisson = Poisson::with_unknown(&laplacian, &unknown)?;
+
+        // finite difference stencils along resolved axes
+        let boundary = CoordinateTriplet::new(
+            [BoundaryStencil::OneSided; 2],
+            [BoundaryStencil::OneSided; 2],
+            [BoundaryStencil::OneSided; 2],
+        )?;
+        let differential = Differential::uniform(&delta, &cells, &boundary)?;
+
+        // collision grid of a single cell along every unresolved axis
+        let collision_cells = CoordinateTriplet::new(cells.x, cells.y.max(2), 2)?;
+        let collision_delta = delta.clone();
+
+        // unpack applied magnetic flux density
+        let magnetic_field = CoordinateTriplet::new(
+            config.magnetic_field[0],
+            config.magnetic_field[1],
+            config.magnetic_field[2],
+        )?;
+
+        // load macro-particles of all species
+        let mut rng = Rng::new(config.seed);
+        let mut species = config.species.clone();
+        for s in species.iter_mut() {
+            s.load_maxwellian(&size, &mut rng);
+        }
+
+        // set up monte carlo collisions
+        let mcc = match &config.mcc.gas {
+            Some(gas) => Some(Mcc::new(gas, &config.mcc, &species, &collision_cells)?),
+            None => None,
+        };
+
+        // set up coulomb collisions
+        let coulomb = if config.coulomb.enabled {
+            Some(Coulomb::new(&config.coulomb, &species)?)
+        } else {
+            None
+        };
+
+        // set up dsmc collisions
+        let dsmc = if config.dsmc.molecules.is_empty() {
+            None
+        } else {
+            Some(Dsmc::new(&config.dsmc, &species)?)
+        };
+
+        Ok(Reduced {
+            dimensions,
+            size,
+            cells,
+            delta,
+            potential,
+            charge_density,
+            electric_field,
+            cell_vol,
+            poisson,
+            differential,
+            collision_cells,
+            collision_delta,
+            plasma_report,
+            dt: config.dt,
+            time: 0.0,
+            step: 0,
+            magnetic_field,
+            species,
+            mcc,
+            coulomb,
+            dsmc,
+            rng,
+        })
+    }
+
+    /// returns number of resolved dimensions of `Reduced`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `usize` number of resolved dimensions
+    ///
+    /// # Errors
+    ///
+    pub fn dimensions(&self) -> usize {
+        self.dimensions
+    }
+
+    /// returns species of `Reduced`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `&[Species]` species present in engine
+    ///
+    /// # Errors
+    ///
+    pub fn species(&self) -> &[Species] {
+        &self.species
+    }
+
+    /// returns electric potential of `Reduced`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `&ScalarField<f64>` (V) electric potential
+    ///
+    /// # Errors
+    ///
+    pub fn potential(&self) -> &ScalarField<f64> {
+        &self.potential
+    }
+
+    /// returns charge density of `Reduced`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `&ScalarField<f64>` (C/m^3) charge density
+    ///
+    /// # Errors
+    ///
+    pub fn charge_density(&self) -> &ScalarField<f64> {
+        &self.charge_density
+    }
+
+    /// returns electric field of `Reduced`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `&VectorField<f64>` (V/m) electric field
+    ///
+    /// # Errors
+    ///
+    pub fn electric_field(&self) -> &VectorField<f64> {
+        &self.electric_field
+    }
+
+    /// computes energy stored in the electric field of `Reduced`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `f64` (J) field energy
+    ///
+    /// # Errors
+    ///
+    pub fn field_energy(&self) -> f64 {
+        0.5 * VAC_PERM
+            * self
+                .electric_field
+                .magnitude_squared()
+                .integral(&self.cell_vol)
+    }
+
+    /// deposits charge density of all species onto nodes of `Reduced` using linear weighting
+    ///
+    /// # Arguments
+    /// - `self`: &mut self mutable reference to self
+    ///
+    /// # Returns
+    /// Result<(), anyhow::Error>`
+    ///
+    /// # Errors
+    fn deposit_charge(&mut self) -> Result<(), anyhow::Error> {
+        // clear previous charge density
+        self.charge_density.iter_mut().for_each(|rho| *rho = 0.0);
+
+        // accumulate charge on nodes
+        for species in self.species.iter() {
+            deposit(
+                &mut self.charge_density,
+                &species.particles,
+                species.charge * species.weight,
+                &self.delta,
+                &self.cells,
+            );
+        }
+
+        // divide by node control volumes
+        self.charge_density
+            .iter_mut()
+            .zip(self.cell_vol.iter())
+            .for_each(|(rho, vol)| *rho /= vol);
+
+        Ok(())
+    }
+
+    /// advances macro-particles of all species using the boris scheme, positions are only advanced along
+    /// resolved axes and macro-particles leaving the bounding box along a resolved axis are absorbed
+    ///
+    /// # Arguments
+    /// - `self`: &mut self mutable reference to self
+    ///
+    /// # Returns
+    /// Result<(), anyhow::Error>`
+    ///
+    /// # Errors
+    fn push_particles(&mut self) -> Result<(), anyhow::Error> {
+        let b = [
+            self.magnetic_field.x,
+            self.magnetic_field.y,
+            self.magnetic_field.z,
+        ];
+
+        let size = [self.size.x, self.size.y, self.size.z];
+        let dimensions = self.dimensions;
+
+        for species in self.species.iter_mut() {
+            // half step charge to mass ratio
+            let qm_half_dt = 0.5 * species.charge / species.mass * self.dt;
+
+            // boris rotation vectors
+            let (t, s) = rotation(&b, qm_half_dt);
+
+            for particle in species.particles.iter_mut() {
+                // gather electric field at macro-particle
+                let corners = weights(&particle.position, &self.delta, &self.cells);
+                let mut e = [0.0; 3];
+                for ((i, j), w) in corners {
+                    e[0] += w * self.electric_field.x[(i, j, 0)];
+                    e[1] += w * self.electric_field.y[(i, j, 0)];
+                }
+
+                // accelerate and rotate
+                particle.velocity = boris(&particle.velocity, &e, &t, &s, qm_half_dt);
+
+                // advance position along resolved axes
+                for (x, v) in particle
+                    .position
+                    .iter_mut()
+                    .zip(particle.velocity.iter())
+                    .take(dimensions)
+                {
+                    *x += v * self.dt;
+                }
+            }
+
+            // absorb macro-particles that left bounding box along a resolved axis
+            species.particles.retain(|p| {
+                p.position
+                    .iter()
+                    .zip(size.iter())
+                    .take(dimensions)
+                    .all(|(x, l)| (0.0..*l).contains(x))
+            });
+        }
+
+        Ok(())
+    }
+
+    /// updates electric potential of `Reduced` with grounded boundaries along resolved axes
+    ///
+    /// the potential is solved directly with the thomas algorithm in 1D and with gauss-seidel sor in 2D
+    ///
+    /// # Arguments
+    /// - `self`: &mut self mutable reference to self
+    ///
+    /// # Returns
+    /// Result<(), anyhow::Error>`
+    ///
+    /// # Errors
+    /// - solution to potential did not converge to tolerance of GS_TOL in GS_MAX_ITER iterations
+    fn update_potential(&mut self) -> Result<(), anyhow::Error> {
+        if self.dimensions == 1 {
+            self.update_potential_1d();
+            Ok(())
+        } else {
+            self.update_potential_2d()
+        }
+    }
+
+    /// solves the tridiagonal system of the 1D poisson equation using the thomas algorithm
+    ///
+    /// # Arguments
+    /// - `self`: &mut self mutable reference to self
+    ///
+    /// # Returns
+    ///
+    /// # Errors
+    ///
+    fn update_potential_1d(&mut self) {
+        let n = self.cells.x;
+        let dx_sq = self.delta.x * self.delta.x;
+
+        // forward sweep of phi[i - 1] - 2 phi[i] + phi[i + 1] = -rho[i] dx^2 / eps0 on interior nodes
+        let mut c_prime = vec![0.0; n];
+        let mut d_prime = vec![0.0; n];
+        for i in 1..(n - 1) {
+            let d = -self.charge_density[(i, 0, 0)] * dx_sq * INV_VAC_PERM;
+            let m = -2.0 - c_prime[i - 1];
+            c_prime[i] = 1.0 / m;
+            d_prime[i] = (d - d_prime[i - 1]) / m;
+        }
+
+        // back substitution with grounded boundaries
+        self.potential[(0, 0, 0)] = 0.0;
+        self.potential[(n - 1, 0, 0)] = 0.0;
+        for i in (1..(n - 1)).rev() {
+            self.potential[(i, 0, 0)] = d_prime[i] - c_prime[i] * self.potential[(i + 1, 0, 0)];
+        }
+    }
+
+    /// solves the 2D poisson equation using gauss-seidel sor
+    ///
+    /// # Arguments
+    /// - `self`: &mut self mutable reference to self
+    ///
+    /// # Returns
+    /// Result<(), anyhow::Error>`
+    ///
+    /// # Errors
+    /// - call to `Poisson::solve()` fails
+    fn update_potential_2d(&mut self) -> Result<(), anyhow::Error> {
+        self.poisson
+            .solve(&mut self.potential, &self.charge_density)
+    }
+
+    /// updates electric field of `Reduced` along resolved axes
+    ///
+    /// # Arguments
+    /// - `self`: &mut self mutable reference to self
+    ///
+    /// # Returns
+    /// Result<(), anyhow::Error>`
+    ///
+    /// # Errors
+    /// - call to `Differential::gradient()` fails
+    fn solve_electric_field(&mut self) -> Result<(), anyhow::Error> {
+        // electric field is the negative gradient of the potential, which vanishes along unresolved axes
+        self.electric_field = -self.differential.gradient(&self.potential)?;
+
+        Ok(())
+    }
+}
+
+impl Engine for Reduced {
+    /// updates `Reduced` to the next time step
+    ///
+    /// # Arguments
+    /// - `self`: &mut self mutable reference to self
+    ///
+    /// # Returns
+    /// Result<(), anyhow::Error>`
+    ///
+    /// # Errors
+    /// - any call to `Self::deposit_charge()` fails
+    /// - any call to `Self::update_potential()` fails
+    /// - any call to `Self::solve_electric_field()` fails
+    /// - any call to `Self::push_particles()` fails
+    fn update(&mut self) -> Result<(), anyhow::Error> {
+        // deposit charge density of all species
+        Self::deposit_charge(self)?;
+
+        // update electrostatic potential
+        Self::update_potential(self)?;
+
+        // update electric field from calculated electrostatic potential
+        Self::solve_electric_field(self)?;
+
+        // advance macro-particles in electric and magnetic field
+        Self::push_particles(self)?;
+
+        // collide macro-particles with background gas
+        if let Some(mcc) = self.mcc.as_ref() {
+            mcc.collide(
+                &mut self.species,
+                &self.collision_delta,
+                &self.collision_cells,
+                self.dt,
+                &mut self.rng,
+            );
+        }
+
+        // collide charged macro-particles with each other
+        if let Some(coulomb) = self.coulomb.as_ref() {
+            coulomb.collide(
+                &mut self.species,
+                &self.collision_delta,
+                &self.collision_cells,
+                self.dt,
+                &mut self.rng,
+            );
+        }
+
+        // collide neutral macro-particles with each other
+        if let Some(dsmc) = self.dsmc.as_ref() {
+            dsmc.collide(
+                &mut self.species,
+                &self.collision_delta,
+                &self.collision_cells,
+                self.dt,
+                &mut self.rng,
+            );
+        }
+
+        // advance time
+        self.step += 1;
+        self.time += self.dt;
+
+        Ok(())
+    }
+
+    /// returns simulated time of `Reduced`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `f64` (s) simulated time
+    ///
+    /// # Errors
+    ///
+    fn time(&self) -> f64 {
+        self.time
+    }
+
+    /// returns number of completed time steps of `Reduced`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `u64` number of completed time steps
+    ///
+    /// # Errors
+    ///
+    fn step(&self) -> u64 {
+        self.step
+    }
+
+    /// returns size of bounding box of `Reduced`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `&CoordinateTriplet<f64>` (m) size of bounding box
+    ///
+    /// # Errors
+    ///
+    fn size(&self) -> &CoordinateTriplet<f64> {
+        &self.size
+    }
+
+    /// returns number of cells of `Reduced`, 1 along unresolved axes
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `&CoordinateTriplet<usize>` number of cells in bounding box
+    ///
+    /// # Errors
+    ///
+    fn cells(&self) -> &CoordinateTriplet<usize> {
+        &self.cells
+    }
+
+    /// returns equally spaced node coordinates of `Reduced`, a single node along unresolved axes
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `CoordinateTriplet<Vec<f64>>` (m) node coordinates along each axis
+    ///
+    /// # Errors
+    ///
+    fn nodes(&self) -> CoordinateTriplet<Vec<f64>> {
+        CoordinateTriplet {
+            x: uniform_nodes(self.size.x, self.cells.x),
+            y: uniform_nodes(self.size.y, self.cells.y),
+            z: uniform_nodes(self.size.z, self.cells.z),
+        }
+    }
+
+    /// returns plasma parameter report of `Reduced`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `&PlasmaReport` characteristic plasma parameters and their validation against discretization
+    ///
+    /// # Errors
+    ///
+    fn plasma_report(&self) -> &PlasmaReport {
+        &self.plasma_report
+    }
+
+    /// computes number density of a single species on nodes of `Reduced` using linear weighting
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `index`: usize index of species
+    ///
+    /// # Returns
+    /// `Result<ScalarField<f64>, anyhow::Error>` (m^-3) number density
+    ///
+    /// # Errors
+    /// - `index` is out of bounds
+    /// - call to `ScalarField::new()` fails
+    fn number_density(&self, index: usize) -> Result<ScalarField<f64>, anyhow::Error> {
+        let species = self.species.get(index).ok_or_else(|| {
+            anyhow!(
+                "species index {index} out of bounds for {} species",
+                self.species.len()
+            )
+        })?;
+
+        let mut density = ScalarField::new(&self.cells)?;
+        deposit(
+            &mut density,
+            &species.particles,
+            species.weight,
+            &self.delta,
+            &self.cells,
+        );
+        density
+            .iter_mut()
+            .zip(self.cell_vol.iter())
+            .for_each(|(n, vol)| *n /= vol);
+
+        Ok(density)
+    }
+
+    /// returns variables of field output of `Reduced`, excluding number densities
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `Result<Vec<Variable>, anyhow::Error>`
+    ///
+    /// # Errors
+    /// - any call to `Variable::new()` fails
+    fn variables(&self) -> Result<Vec<Variable>, anyhow::Error> {
+        Ok(vec![
+            Variable::new("potential", "V", 1)?,
+            Variable::new("charge_density", "C/m^3", 1)?,
+            Variable::new("electric_field", "V/m", 3)?,
+        ])
+    }
+
+    /// returns fields of field output of `Reduced` in the order of `Reduced::variables()`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `Vec<Field<'_>>`
+    ///
+    /// # Errors
+    ///
+    fn fields(&self) -> Vec<Field<'_>> {
+        vec![
+            Field::Scalar(&self.potential),
+            Field::Scalar(&self.charge_density),
+            Field::Vector(&self.electric_field),
+        ]
+    }
+
+    /// computes conservation diagnostics of `Reduced`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `Diagnostics`
+    ///
+    /// # Errors
+    ///
+    fn diagnostics(&self) -> Diagnostics {
+        Diagnostics::reduced(self)
+    }
+
+    /// writes the complete evolving state of `Reduced` in a little endian binary format
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `writer`: &mut dyn Write destination of state
+    ///
+    /// # Returns
+    /// `Result<(), anyhow::Error>`
+    ///
+    /// # Errors
+    /// - any write fails
+    fn write_checkpoint(&self, writer: &mut dyn Write) -> Result<(), anyhow::Error> {
+        // time stepping and random number generator state
+        write_clock(writer, self.step, self.time, &self.rng)?;
+
+        // field state
+        write_cells(writer, &self.cells)?;
+        self.potential.write_binary(writer)?;
+        self.charge_density.write_binary(writer)?;
+        self.electric_field.write_binary(writer)?;
+
+        // particle state
+        write_particles(writer, &self.species)
+    }
+
+    /// reads the complete evolving state of `Reduced` from a little endian binary format
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `reader`: &mut dyn Read source of state
+    ///
+    /// # Returns
+    /// `Result<(), anyhow::Error>`
+    ///
+    /// # Errors
+    /// - any read fails
+    /// - number of cells does not match `Reduced`
+    /// - number of species does not match `Reduced`
+    fn read_checkpoint(&mut self, reader: &mut dyn Read) -> Result<(), anyhow::Error> {
+        // time stepping and random number generator state
+        (self.step, self.time, self.rng) = read_clock(reader)?;
+
+        // field state
+        read_cells(reader, &self.cells)?;
+        self.potential.read_binary(reader)?;
+        self.charge_density.read_binary(reader)?;
+        self.electric_field.read_binary(reader)?;
+
+        // particle state
+        read_particles(reader, &mut self.species)
+    }
+}
+
+/// computes linear weighting of a position on the x-y nodes of a reduced grid
+///
+/// axes holding a single node receive all weight on that node
+///
+/// # Arguments
+/// - `position`: &[f64; 3] (m) position inside bounding box
+/// - `delta`: &CoordinateTriplet<f64> (m) spatial increment
+/// - `cells`: &CoordinateTriplet<usize> number of cells in bounding box
+///
+/// # Returns
+/// `[((usize, usize), f64); 4]` (i, j) indices and weights of the four surrounding nodes
+///
+/// # Errors
+///
+fn weights(
+    position: &[f64; 3],
+    delta: &CoordinateTriplet<f64>,
+    cells: &CoordinateTriplet<usize>,
+) -> [((usize, usize), f64); 4] {
+    // lower node, upper node, and fractional offset from lower node along a single axis
+    let axis = |x: f64, delta: f64, cells: usize| -> (usize, usize, f64) {
+        if cells > 1 {
+            let l = x / delta;
+            let lower = (l as usize).min(cells - 2);
+            (lower, lower + 1, l - lower as f64)
+        } else {
+            (0, 0, 0.0)
+        }
+    };
+    let (i0, i1, fx) = axis(position[0], delta.x, cells.x);
+    let (j0, j1, fy) = axis(position[1], delta.y, cells.y);
+
+    [
+        ((i0, j0), (1.0 - fx) * (1.0 - fy)),
+        ((i1, j0), fx * (1.0 - fy)),
+        ((i0, j1), (1.0 - fx) * fy),
+        ((i1, j1), fx * fy),
+    ]
+}
+
+/// deposits a quantity carried by every macro-particle onto nodes of a reduced grid using linear weighting
+///
+/// # Arguments
+/// - `field`: &mut ScalarField<f64> field to accumulate onto
+/// - `particles`: &[Particle] macro-particles to deposit
+/// - `value`: f64 quantity carried by a single macro-particle
+/// - `delta`: &CoordinateTriplet<f64> (m) spatial increment
+/// - `cells`: &CoordinateTriplet<usize> number of cells in bounding box
+///
+/// # Returns
+///
+/// # Errors
+///
+fn deposit(
+    field: &mut ScalarField<f64>,
+    particles: &[Particle],
+    value: f64,
+    delta: &CoordinateTriplet<f64>,
+    cells: &CoordinateTriplet<usize>,
+) {
+    for particle in particles.iter() {
+        for ((i, j), w) in weights(&particle.position, delta, cells) {
+            field[(i, j, 0)] += value * w;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::Config;
+    use crate::constants::{ELEC_CHARGE, ELEC_MASS, EV_TEMP, VAC_PERM};
+    use crate::emission::{EmissionRule, Surface, YieldModel};
+    use crate::engine::reduced::Reduced;
+    use crate::engine::Engine;
+    use crate::magnetostatics::Source;
+    use crate::species::{Particle, Species};
+    use std::f64::consts::PI;
+
+    /// helper function that sets up a `Config` with electrons for testing
+    ///
+    /// # Arguments
+    ///
+    /// # Returns
+    /// `Result<Config, anyhow::Error>`
+    ///
+    /// # Errors
+    /// - call to `Config::new()` fails
+    /// - call to `Species::new()` fails
+    fn setup() -> Result<Config, anyhow::Error> {
+        let mut config = Config::new(&[0.01, 0.01, 0.01], &[21, 11, 3], 1e-11)?;
+        config.species = vec![Species::new(
+            "electron",
+            -ELEC_CHARGE,
+            ELEC_MASS,
+            1e12,
+            EV_TEMP,
+            1e4,
+        )?];
+        Ok(config)
+    }
+
+    /// tests `Reduced::new()` for failure on unsupported dimensions, magnetostatic sources, and secondary electron
+    /// emission
+    ///
+    /// # Errors
+    /// - `Reduced::new()` succeeds for 0 or 3 dimensions
+    /// - `Reduced::new()` succeeds with magnetostatic sources
+    /// - `Reduced::new()` succeeds with secondary electron emission
+    /// - `Reduced::new()` fails for 1 or 2 dimensions
+    ///
+    #[test]
+    fn new_failure() {
+        let mut config = setup().unwrap();
+        assert!(Reduced::new(&config, 0).is_err());
+        assert!(Reduced::new(&config, 3).is_err());
+        assert!(Reduced::new(&config, 1).is_ok());
+        assert!(Reduced::new(&config, 2).is_ok());
+
+        let mut magnetized = config.clone();
+        magnetized.magnetostatics.sources.push(Source::Block {
+            min: [0.0; 3],
+            max: [0.1; 3],
+            magnetization: [0.0, 0.0, 1e5],
+        });
+        assert!(Reduced::new(&magnetized, 1).is_err());
+
+        config.emission.rules.push(EmissionRule {
+            species: "electron".to_string(),
+            surfaces: vec![Surface::XLow],
+            yield_model: YieldModel::Constant(1.0),
+        });
+        assert!(Reduced::new(&config, 1).is_err());
+    }
+
+    /// tests `Reduced::new()` for a grid of a single node along unresolved axes and control volumes summing to
+    /// the volume of the bounding box
+    ///
+    /// # Errors
+    /// - cells are not reduced along unresolved axes
+    /// - control volumes do not sum to the volume of the bounding box
+    ///
+    #[test]
+    fn new_correct_cells() {
+        let config = setup().unwrap();
+        for (dimensions, cells) in [(1, [21, 1, 1]), (2, [21, 11, 1])] {
+            let engine = Reduced::new(&config, dimensions).unwrap();
+            assert_eq!(
+                [engine.cells().x, engine.cells().y, engine.cells().z],
+                cells
+            );
+            let volume: f64 = engine.cell_vol.iter().sum();
+            assert!((volume - 1e-6).abs() < 1e-18);
+        }
+    }
+
+    /// tests `Reduced::update_potential()` for the exact parabolic potential of a uniform charge density in 1D
+    ///
+    /// # Errors
+    /// - potential deviates from the analytic solution
+    ///
+    #[test]
+    fn update_potential_1d_parabola() {
+        // setup
+        let mut engine = Reduced::new(&setup().unwrap(), 1).unwrap();
+        let rho = 1e-6;
+        engine.charge_density.iter_mut().for_each(|r| *r = rho);
+        engine.update_potential().unwrap();
+
+        // assertions
+        let (dx, l) = (engine.delta.x, engine.size.x);
+        let peak = rho * l * l / (8.0 * VAC_PERM);
+        for i in 0..engine.cells.x {
+            let x = i as f64 * dx;
+            let exact = rho / (2.0 * VAC_PERM) * x * (l - x);
+            assert!((engine.potential[(i, 0, 0)] - exact).abs() < 1e-10 * peak);
+        }
+    }
+
+    /// tests `Reduced::update_potential()` and `Reduced::solve_electric_field()` for a manufactured solution in 2D
+    ///
+    /// # Errors
+    /// - potential deviates from the manufactured solution
+    /// - electric field deviates from the gradient of the manufactured solution
+    ///
+    #[test]
+    fn update_potential_2d_manufactured() {
+        // setup
+        let mut config = setup().unwrap();
+        config.cells = [33, 33, 3];
+        let mut engine = Reduced::new(&config, 2).unwrap();
+        let (lx, ly) = (engine.size.x, engine.size.y);
+        let (kx, ky) = (PI / lx, PI / ly);
+        let exact = |x: f64, y: f64| (kx * x).sin() * (ky * y).sin();
+        for i in 0..engine.cells.x {
+            for j in 0..engine.cells.y {
+                let (x, y) = (i as f64 * engine.delta.x, j as f64 * engine.delta.y);
+                engine.charge_density[(i, j, 0)] = VAC_PERM * (kx * kx + ky * ky) * exact(x, y);
+            }
+        }
+        engine.update_potential().unwrap();
+        engine.solve_electric_field().unwrap();
+
+        // assertions
+        for i in 0..engine.cells.x {
+            for j in 0..engine.cells.y {
+                let (x, y) = (i as f64 * engine.delta.x, j as f64 * engine.delta.y);
+                assert!((engine.potential[(i, j, 0)] - exact(x, y)).abs() < 1e-2);
+                let ex = -kx * (kx * x).cos() * (ky * y).sin();
+                assert!((engine.electric_field.x[(i, j, 0)] - ex).abs() < 1e-2 * kx);
+                assert_eq!(engine.electric_field.z[(i, j, 0)], 0.0);
+            }
+        }
+    }
+
+    /// tests `Reduced::push_particles()` for rotating all three velocity components while only advancing
+    /// positions along resolved axes
+    ///
+    /// # Errors
+    /// - position changes along an unresolved axis
+    /// - position does not advance along the resolved axis
+    /// - speed is not conserved by the magnetic rotation
+    /// - velocity is not rotated out of the resolved axis
+    ///
+    #[test]
+    fn push_particles_magnetized() {
+        // setup
+        let mut config = setup().unwrap();
+        config.magnetic_field = [0.0, 0.0, 0.01];
+        let mut engine = Reduced::new(&config, 1).unwrap();
+        engine.species[0].particles = vec![Particle {
+            position: [0.005, 0.003, 0.007],
+            velocity: [1e5, 0.0, 1e4],
+        }];
+        engine.push_particles().unwrap();
+
+        // assertions
+        let particle = engine.species[0].particles[0];
+        assert_eq!(particle.position[1], 0.003);
+        assert_eq!(particle.position[2], 0.007);
+        assert!(particle.position[0] > 0.005);
+        let speed = particle.velocity.iter().map(|v| v * v).sum::<f64>().sqrt();
+        assert!((speed - (1e10f64 + 1e8).sqrt()).abs() / speed < 1e-12);
+        assert!(particle.velocity[1] > 0.0);
+        assert_eq!(particle.velocity[2], 1e4);
+    }
+
+    /// tests `Reduced::number_density()` for conservation of macro-particle weight in 1D and 2D
+    ///
+    /// # Errors
+    /// - integrated number density does not match total weight of macro-particles
+    ///
+    #[test]
+    fn number_density_conservative() {
+        let config = setup().unwrap();
+        for dimensions in [1, 2] {
+            let engine = Reduced::new(&config, dimensions).unwrap();
+            let density = engine.number_density(0).unwrap();
+            let total: f64 = density
+                .iter()
+                .zip(engine.cell_vol.iter())
+                .map(|(n, vol)| n * vol)
+                .sum();
+            let expected = engine.species[0].particles.len() as f64 * engine.species[0].weight;
+            assert!((total - expected).abs() / expected < 1e-12);
+        }
+    }
+
+    /// tests `Reduced::write_checkpoint()` and `Reduced::read_checkpoint()` for a lossless round trip
+    ///
+    /// # Errors
+    /// - restored `Reduced` does not write an identical checkpoint
+    ///
+    #[test]
+    fn checkpoint_round_trip() {
+        // setup
+        let config = setup().unwrap();
+        let mut engine = Reduced::new(&config, 2).unwrap();
+        for _ in 0..3 {
+            engine.update().unwrap();
+        }
+        let mut a = Vec::new();
+        engine.write_checkpoint(&mut a).unwrap();
+
+        let mut restored = Reduced::new(&config, 2).unwrap();
+        restored.read_checkpoint(&mut a.as_slice()).unwrap();
+        let mut b = Vec::new();
+        restored.write_checkpoint(&mut b).unwrap();
+
+        // assertions
+        assert_eq!(a, b);
+        assert_eq!(restored.step(), 3);
+    }
+}
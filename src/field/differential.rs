@@ -0,0 +1,744 @@
+use crate::field::scalar::ScalarField;
+use crate::field::vector::VectorField;
+use crate::field::view::PlaneRowsMut;
+use crate::precision::Real;
+use crate::utils::coordinate_triplet::CoordinateTriplet;
+use anyhow::anyhow;
+use rayon::prelude::*;
+
+/// node indices and weights of a finite difference approximation at a single node, unused entries have zero weight
+type Stencil = ([usize; 4], [f64; 4]);
+
+/// `BoundaryStencil` enum
+///
+/// describes the finite difference stencil on a boundary node of an axis
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum BoundaryStencil {
+    /// second order one-sided differences using nodes inside the grid only
+    #[default]
+    OneSided,
+
+    /// first and last node of the axis coincide, must be set on both ends of an axis
+    Periodic,
+
+    /// field is mirrored evenly about the boundary node, so its normal derivative vanishes there
+    Symmetric,
+}
+
+/// `Differential` struct
+///
+/// precomputed finite difference stencils of first and second derivatives along every axis of a rectilinear grid of
+/// possibly non-uniformly spaced nodes, derivatives along axes of a single node vanish
+///
+/// first derivatives are second order accurate on every node, second derivatives are second order accurate on
+/// interior nodes of uniform axes and on one-sided boundary nodes of axes of at least 4 nodes
+///
+/// stencil weights are stored in double precision and narrowed to the precision of the field they are applied to
+#[derive(Debug, Clone, PartialEq)]
+pub struct Differential {
+    /// number of nodes along each axis
+    cells: CoordinateTriplet<usize>,
+
+    /// (m^-1) stencils of the first derivative on every node along every axis
+    first: CoordinateTriplet<Vec<Stencil>>,
+
+    /// (m^-2) stencils of the second derivative on every node along every axis
+    second: CoordinateTriplet<Vec<Stencil>>,
+}
+
+impl Differential {
+    /// `Differential` constructor
+    ///
+    /// # Arguments
+    /// - `x`: &[f64] (m) strictly increasing node coordinates along x
+    /// - `y`: &[f64] (m) strictly increasing node coordinates along y
+    /// - `z`: &[f64] (m) strictly increasing node coordinates along z
+    /// - `boundary`: &CoordinateTriplet<[BoundaryStencil; 2]> stencils on lower and upper boundary node of every axis
+    ///
+    /// # Returns
+    /// `Result<Differential, anyhow::Error>`
+    ///
+    /// # Errors
+    /// - any axis has no nodes or 2 nodes
+    /// - node coordinates of any axis are not strictly increasing
+    /// - periodic stencil is set on only one end of an axis
+    /// - call to `CoordinateTriplet::new()` fails
+    pub fn new(
+        x: &[f64],
+        y: &[f64],
+        z: &[f64],
+        boundary: &CoordinateTriplet<[BoundaryStencil; 2]>,
+    ) -> Result<Differential, anyhow::Error> {
+        let (first_x, second_x) = stencils(x, &boundary.x)?;
+        let (first_y, second_y) = stencils(y, &boundary.y)?;
+        let (first_z, second_z) = stencils(z, &boundary.z)?;
+
+        Ok(Differential {
+            cells: CoordinateTriplet::new(x.len(), y.len(), z.len())?,
+            first: CoordinateTriplet::new(first_x, first_y, first_z)?,
+            second: CoordinateTriplet::new(second_x, second_y, second_z)?,
+        })
+    }
+
+    /// `Differential` constructor for equally spaced nodes
+    ///
+    /// # Arguments
+    /// - `delta`: &CoordinateTriplet<f64> (m) positive spatial increments
+    /// - `cells`: &CoordinateTriplet<usize> number of nodes along each axis
+    /// - `boundary`: &CoordinateTriplet<[BoundaryStencil; 2]> stencils on lower and upper boundary node of every axis
+    ///
+    /// # Returns
+    /// `Result<Differential, anyhow::Error>`
+    ///
+    /// # Errors
+    /// - any spatial increment is not positive
+    /// - call to `Differential::new()` fails
+    pub fn uniform(
+        delta: &CoordinateTriplet<f64>,
+        cells: &CoordinateTriplet<usize>,
+        boundary: &CoordinateTriplet<[BoundaryStencil; 2]>,
+    ) -> Result<Differential, anyhow::Error> {
+        if [delta.x, delta.y, delta.z]
+            .iter()
+            .any(|d| d.is_nan() || *d <= 0.0)
+        {
+            return Err(anyhow!("spatial increments must be positive, got {delta}"));
+        }
+
+        let nodes = |delta: f64, n: usize| (0..n).map(|i| i as f64 * delta).collect::<Vec<f64>>();
+        Differential::new(
+            &nodes(delta.x, cells.x),
+            &nodes(delta.y, cells.y),
+            &nodes(delta.z, cells.z),
+            boundary,
+        )
+    }
+
+    /// returns number of nodes of `Differential`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `&CoordinateTriplet<usize>` number of nodes along each axis
+    ///
+    /// # Errors
+    ///
+    pub fn cells(&self) -> &CoordinateTriplet<usize> {
+        &self.cells
+    }
+
+    /// computes the gradient of a scalar field
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `field`: &ScalarField<T> field on nodes
+    ///
+    /// # Returns
+    /// `Result<VectorField<T>, anyhow::Error>` gradient on nodes
+    ///
+    /// # Errors
+    /// - cells of `field` do not match nodes of grid
+    /// - call to `VectorField::new()` fails
+    pub fn gradient<T: Real>(
+        &self,
+        field: &ScalarField<T>,
+    ) -> Result<VectorField<T>, anyhow::Error> {
+        self.check(field.cells())?;
+        let mut gradient: VectorField<T> = VectorField::new(&self.cells)?;
+
+        let VectorField { x, y, z, .. } = &mut gradient;
+        fill_planes([x, y, z], |i, j, k| self.first_derivatives(field, i, j, k));
+
+        Ok(gradient)
+    }
+
+    /// computes the divergence of a vector field
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `field`: &VectorField<T> field on nodes
+    ///
+    /// # Returns
+    /// `Result<ScalarField<T>, anyhow::Error>` divergence on nodes
+    ///
+    /// # Errors
+    /// - cells of `field` do not match nodes of grid
+    /// - call to `ScalarField::new()` fails
+    pub fn divergence<T: Real>(
+        &self,
+        field: &VectorField<T>,
+    ) -> Result<ScalarField<T>, anyhow::Error> {
+        self.check(field.cells())?;
+        let mut divergence: ScalarField<T> = ScalarField::new(&self.cells)?;
+
+        fill_planes([&mut divergence], |i, j, k| {
+            [self.first_derivatives(&field.x, i, j, k)[0]
+                + self.first_derivatives(&field.y, i, j, k)[1]
+                + self.first_derivatives(&field.z, i, j, k)[2]]
+        });
+
+        Ok(divergence)
+    }
+
+    /// computes the curl of a vector field
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `field`: &VectorField<T> field on nodes
+    ///
+    /// # Returns
+    /// `Result<VectorField<T>, anyhow::Error>` curl on nodes
+    ///
+    /// # Errors
+    /// - cells of `field` do not match nodes of grid
+    /// - call to `VectorField::new()` fails
+    pub fn curl<T: Real>(&self, field: &VectorField<T>) -> Result<VectorField<T>, anyhow::Error> {
+        self.check(field.cells())?;
+        let mut curl: VectorField<T> = VectorField::new(&self.cells)?;
+
+        let VectorField { x, y, z, .. } = &mut curl;
+        fill_planes([x, y, z], |i, j, k| {
+            let dfx = self.first_derivatives(&field.x, i, j, k);
+            let dfy = self.first_derivatives(&field.y, i, j, k);
+            let dfz = self.first_derivatives(&field.z, i, j, k);
+            [dfz[1] - dfy[2], dfx[2] - dfz[0], dfy[0] - dfx[1]]
+        });
+
+        Ok(curl)
+    }
+
+    /// computes the laplacian of a scalar field
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `field`: &ScalarField<T> field on nodes
+    ///
+    /// # Returns
+    /// `Result<ScalarField<T>, anyhow::Error>` laplacian on nodes
+    ///
+    /// # Errors
+    /// - cells of `field` do not match nodes of grid
+    /// - call to `ScalarField::new()` fails
+    pub fn laplacian<T: Real>(
+        &self,
+        field: &ScalarField<T>,
+    ) -> Result<ScalarField<T>, anyhow::Error> {
+        self.check(field.cells())?;
+        let mut laplacian: ScalarField<T> = ScalarField::new(&self.cells)?;
+
+        fill_planes([&mut laplacian], |i, j, k| {
+            [apply(&self.second.x, i, |m| field[(m, j, k)])
+                + apply(&self.second.y, j, |m| field[(i, m, k)])
+                + apply(&self.second.z, k, |m| field[(i, j, m)])]
+        });
+
+        Ok(laplacian)
+    }
+
+    /// computes the first derivatives of a scalar field along every axis at a single node
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `field`: &ScalarField<T> field on nodes
+    /// - `i`: usize index of node along x
+    /// - `j`: usize index of node along y
+    /// - `k`: usize index of node along z
+    ///
+    /// # Returns
+    /// `[T; 3]` derivatives along x, y, and z
+    ///
+    /// # Errors
+    ///
+    fn first_derivatives<T: Real>(
+        &self,
+        field: &ScalarField<T>,
+        i: usize,
+        j: usize,
+        k: usize,
+    ) -> [T; 3] {
+        [
+            apply(&self.first.x, i, |m| field[(m, j, k)]),
+            apply(&self.first.y, j, |m| field[(i, m, k)]),
+            apply(&self.first.z, k, |m| field[(i, j, m)]),
+        ]
+    }
+
+    /// checks that a field is defined on the nodes of `Differential`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `cells`: &CoordinateTriplet<usize> number of cells of field
+    ///
+    /// # Returns
+    /// `Result<(), anyhow::Error>`
+    ///
+    /// # Errors
+    /// - `cells` do not match nodes of grid
+    fn check(&self, cells: &CoordinateTriplet<usize>) -> Result<(), anyhow::Error> {
+        if *cells != self.cells {
+            return Err(anyhow!(
+                "field of {cells} cells does not match grid of {} nodes",
+                self.cells
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// sets every node of several fields of equal cells from a function of its indices, planes of constant i are filled
+/// concurrently
+///
+/// # Arguments
+/// - `fields`: [&mut ScalarField<T>; N] fields to fill
+/// - `value`: impl Fn(usize, usize, usize) -> [T; N] + Sync value of every field at an (i, j, k) index
+///
+/// # Returns
+///
+/// # Errors
+///
+fn fill_planes<T: Real, const N: usize>(
+    fields: [&mut ScalarField<T>; N],
+    value: impl Fn(usize, usize, usize) -> [T; N] + Sync,
+) {
+    let cells = fields[0].cells().clone();
+    let mut planes = fields.map(|field| field.planes_mut().into_iter());
+    let planes: Vec<[PlaneRowsMut<'_, T>; N]> = (0..cells.x)
+        .map(|_| planes.each_mut().map(|plane| plane.next().unwrap()))
+        .collect();
+
+    planes.into_par_iter().for_each(|mut planes| {
+        let i = planes[0].index();
+        for j in 0..cells.y {
+            for k in 0..cells.z {
+                for (plane, value) in planes.iter_mut().zip(value(i, j, k)) {
+                    plane.row_mut(j)[k] = value;
+                }
+            }
+        }
+    });
+}
+
+/// applies the stencil of a node to values along an axis
+///
+/// # Arguments
+/// - `stencils`: &[Stencil] stencils of every node along the axis, empty for an axis of a single node
+/// - `n`: usize index of node along the axis
+/// - `f`: impl Fn(usize) -> T value at a node index along the axis
+///
+/// # Returns
+/// `T` approximated derivative, zero along an axis of a single node
+///
+/// # Errors
+///
+fn apply<T: Real>(stencils: &[Stencil], n: usize, f: impl Fn(usize) -> T) -> T {
+    match stencils.get(n) {
+        Some((m, c)) => m
+            .iter()
+            .zip(c.iter())
+            .filter(|(_, c)| **c != 0.0)
+            .map(|(m, c)| T::narrow(*c) * f(*m))
+            .sum(),
+        None => T::zero(),
+    }
+}
+
+/// computes stencils of the first and second derivative on every node along a single axis
+///
+/// # Arguments
+/// - `x`: &[f64] (m) strictly increasing node coordinates
+/// - `boundary`: &[BoundaryStencil; 2] stencils on lower and upper boundary node
+///
+/// # Returns
+/// `Result<(Vec<Stencil>, Vec<Stencil>), anyhow::Error>` first and second derivative stencils, empty for a single
+/// node
+///
+/// # Errors
+/// - axis has no nodes or 2 nodes
+/// - node coordinates are not strictly increasing
+/// - periodic stencil is set on only one end
+fn stencils(
+    x: &[f64],
+    boundary: &[BoundaryStencil; 2],
+) -> Result<(Vec<Stencil>, Vec<Stencil>), anyhow::Error> {
+    let n = x.len();
+    if n == 1 {
+        return Ok((Vec::new(), Vec::new()));
+    }
+    if n < 3 {
+        return Err(anyhow!(
+            "an axis needs a single node or at least 3 nodes, got {n}"
+        ));
+    }
+    if x.windows(2).any(|w| w[1].is_nan() || w[1] <= w[0]) {
+        return Err(anyhow!("node coordinates must be strictly increasing"));
+    }
+    let periodic = boundary.map(|b| b == BoundaryStencil::Periodic);
+    if periodic[0] != periodic[1] {
+        return Err(anyhow!(
+            "periodic stencils must be set on both ends of an axis"
+        ));
+    }
+
+    let mut first = Vec::with_capacity(n);
+    let mut second = Vec::with_capacity(n);
+    for i in 0..n {
+        // node indices and coordinates of neighbours, unfolded across periodic and symmetric boundaries
+        let (lower, upper) = if i == 0 {
+            match boundary[0] {
+                BoundaryStencil::OneSided => {
+                    first.push(weights(x[0], &[(0, x[0]), (1, x[1]), (2, x[2])], 1));
+                    let points: Vec<(usize, f64)> = (0..n.min(4)).map(|m| (m, x[m])).collect();
+                    second.push(weights(x[0], &points, 2));
+                    continue;
+                }
+                BoundaryStencil::Periodic => ((n - 2, x[0] - (x[n - 1] - x[n - 2])), (1, x[1])),
+                BoundaryStencil::Symmetric => ((1, 2.0 * x[0] - x[1]), (1, x[1])),
+            }
+        } else if i == n - 1 {
+            match boundary[1] {
+                BoundaryStencil::OneSided => {
+                    first.push(weights(
+                        x[i],
+                        &[(n - 3, x[n - 3]), (n - 2, x[n - 2]), (n - 1, x[n - 1])],
+                        1,
+                    ));
+                    let points: Vec<(usize, f64)> = (n - n.min(4)..n).map(|m| (m, x[m])).collect();
+                    second.push(weights(x[i], &points, 2));
+                    continue;
+                }
+                BoundaryStencil::Periodic => ((n - 2, x[n - 2]), (1, x[i] + (x[1] - x[0]))),
+                BoundaryStencil::Symmetric => ((n - 2, x[n - 2]), (n - 2, 2.0 * x[i] - x[n - 2])),
+            }
+        } else {
+            ((i - 1, x[i - 1]), (i + 1, x[i + 1]))
+        };
+
+        // central differences
+        let points = [lower, (i, x[i]), upper];
+        first.push(weights(x[i], &points, 1));
+        second.push(weights(x[i], &points, 2));
+    }
+
+    Ok((first, second))
+}
+
+/// computes weights of a finite difference approximation of a derivative on arbitrarily spaced points using the
+/// algorithm of fornberg (1988)
+///
+/// # Arguments
+/// - `x0`: f64 (m) coordinate to approximate derivative at
+/// - `points`: &[(usize, f64)] node indices and (m) distinct coordinates of at most 4 points
+/// - `order`: usize order of derivative, less than the number of points
+///
+/// # Returns
+/// `Stencil` node indices and weights
+///
+/// # Errors
+///
+fn weights(x0: f64, points: &[(usize, f64)], order: usize) -> Stencil {
+    let n = points.len();
+    let mut c = [[0.0; 3]; 4];
+    c[0][0] = 1.0;
+    let mut c1 = 1.0;
+    let mut c4 = points[0].1 - x0;
+    for i in 1..n {
+        let mn = i.min(order);
+        let mut c2 = 1.0;
+        let c5 = c4;
+        c4 = points[i].1 - x0;
+        for j in 0..i {
+            let c3 = points[i].1 - points[j].1;
+            c2 *= c3;
+            if j == i - 1 {
+                for k in (1..=mn).rev() {
+                    c[i][k] = c1 * (k as f64 * c[i - 1][k - 1] - c5 * c[i - 1][k]) / c2;
+                }
+                c[i][0] = -c1 * c5 * c[i - 1][0] / c2;
+            }
+            for k in (1..=mn).rev() {
+                c[j][k] = (c4 * c[j][k] - k as f64 * c[j][k - 1]) / c3;
+            }
+            c[j][0] = c4 * c[j][0] / c3;
+        }
+        c1 = c2;
+    }
+
+    let mut stencil: Stencil = ([0; 4], [0.0; 4]);
+    for (m, ((index, _), weight)) in points.iter().zip(c.iter()).enumerate() {
+        stencil.0[m] = *index;
+        stencil.1[m] = weight[order];
+    }
+    stencil
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::field::differential::{BoundaryStencil, Differential};
+    use crate::field::scalar::ScalarField;
+    use crate::field::vector::VectorField;
+    use crate::grid::{Axis, Spacing};
+    use crate::utils::coordinate_triplet::CoordinateTriplet;
+    use std::f64::consts::PI;
+
+    /// helper function that returns one-sided stencils on every boundary node for testing
+    ///
+    /// # Arguments
+    ///
+    /// # Returns
+    /// `CoordinateTriplet<[BoundaryStencil; 2]>`
+    ///
+    /// # Errors
+    ///
+    fn one_sided() -> CoordinateTriplet<[BoundaryStencil; 2]> {
+        CoordinateTriplet::new(
+            [BoundaryStencil::OneSided; 2],
+            [BoundaryStencil::OneSided; 2],
+            [BoundaryStencil::OneSided; 2],
+        )
+        .unwrap()
+    }
+
+    /// helper function that samples a scalar function on the nodes of a grid
+    ///
+    /// # Arguments
+    /// - `nodes`: &[Vec<f64>; 3] (m) node coordinates along every axis
+    /// - `f`: impl Fn(f64, f64, f64) -> f64 function to sample
+    ///
+    /// # Returns
+    /// `ScalarField<f64>`
+    ///
+    /// # Errors
+    ///
+    fn sample(nodes: &[Vec<f64>; 3], f: impl Fn(f64, f64, f64) -> f64) -> ScalarField<f64> {
+        let cells = CoordinateTriplet::new(nodes[0].len(), nodes[1].len(), nodes[2].len()).unwrap();
+        let mut field = ScalarField::new(&cells).unwrap();
+        for (i, x) in nodes[0].iter().enumerate() {
+            for (j, y) in nodes[1].iter().enumerate() {
+                for (k, z) in nodes[2].iter().enumerate() {
+                    field[(i, j, k)] = f(*x, *y, *z);
+                }
+            }
+        }
+        field
+    }
+
+    /// helper function that computes the largest absolute difference between two scalar fields
+    ///
+    /// # Arguments
+    /// - `a`: &ScalarField<f64> lhs of comparison
+    /// - `b`: &ScalarField<f64> rhs of comparison
+    ///
+    /// # Returns
+    /// `f64` largest absolute difference
+    ///
+    /// # Errors
+    ///
+    fn max_error(a: &ScalarField<f64>, b: &ScalarField<f64>) -> f64 {
+        a.iter()
+            .zip(b.iter())
+            .map(|(a, b)| (a - b).abs())
+            .fold(0.0, f64::max)
+    }
+
+    /// helper function that computes the errors of every operator against an analytic vector field on a grid
+    ///
+    /// the vector field is F = (sin(x) cos(2y) e^z, x^2 y^3 z, cos(x + y z)), the scalar field is its x component
+    ///
+    /// # Arguments
+    /// - `spacing`: &Spacing distribution of nodes along every axis of the unit cube
+    /// - `cells`: usize number of nodes along every axis
+    ///
+    /// # Returns
+    /// `[f64; 4]` largest errors of gradient, divergence, curl, and laplacian on all nodes
+    ///
+    /// # Errors
+    ///
+    fn errors(spacing: &Spacing, cells: usize) -> [f64; 4] {
+        let axis = Axis::new(spacing, 1.0, cells).unwrap();
+        let nodes = [
+            axis.nodes().to_vec(),
+            axis.nodes().to_vec(),
+            axis.nodes().to_vec(),
+        ];
+        let differential =
+            Differential::new(&nodes[0], &nodes[1], &nodes[2], &one_sided()).unwrap();
+
+        let fx = |x: f64, y: f64, z: f64| x.sin() * (2.0 * y).cos() * z.exp();
+        let fy = |x: f64, y: f64, z: f64| x * x * y.powi(3) * z;
+        let fz = |x: f64, y: f64, z: f64| (x + y * z).cos();
+        let mut field: VectorField<f64> =
+            VectorField::new(&CoordinateTriplet::new(cells, cells, cells).unwrap()).unwrap();
+        field.x = sample(&nodes, fx);
+        field.y = sample(&nodes, fy);
+        field.z = sample(&nodes, fz);
+
+        // analytic derivatives
+        let dfx = [
+            |x: f64, y: f64, z: f64| x.cos() * (2.0 * y).cos() * z.exp(),
+            |x: f64, y: f64, z: f64| -2.0 * x.sin() * (2.0 * y).sin() * z.exp(),
+            |x: f64, y: f64, z: f64| x.sin() * (2.0 * y).cos() * z.exp(),
+        ];
+        let gradient = differential.gradient(&field.x).unwrap();
+        let gradient_error = max_error(&gradient.x, &sample(&nodes, dfx[0]))
+            .max(max_error(&gradient.y, &sample(&nodes, dfx[1])))
+            .max(max_error(&gradient.z, &sample(&nodes, dfx[2])));
+
+        let divergence = differential.divergence(&field).unwrap();
+        let divergence_error = max_error(
+            &divergence,
+            &sample(&nodes, |x, y, z| {
+                x.cos() * (2.0 * y).cos() * z.exp() + 3.0 * x * x * y * y * z
+                    - y * (x + y * z).sin()
+            }),
+        );
+
+        let curl = differential.curl(&field).unwrap();
+        let curl_error = max_error(
+            &curl.x,
+            &sample(&nodes, |x, y, z| -z * (x + y * z).sin() - x * x * y.powi(3)),
+        )
+        .max(max_error(
+            &curl.y,
+            &sample(&nodes, |x, y, z| {
+                x.sin() * (2.0 * y).cos() * z.exp() + (x + y * z).sin()
+            }),
+        ))
+        .max(max_error(
+            &curl.z,
+            &sample(&nodes, |x, y, z| {
+                2.0 * x * y.powi(3) * z + 2.0 * x.sin() * (2.0 * y).sin() * z.exp()
+            }),
+        ));
+
+        let laplacian = differential.laplacian(&field.x).unwrap();
+        let laplacian_error = max_error(
+            &laplacian,
+            &sample(&nodes, |x, y, z| -4.0 * x.sin() * (2.0 * y).cos() * z.exp()),
+        );
+
+        [
+            gradient_error,
+            divergence_error,
+            curl_error,
+            laplacian_error,
+        ]
+    }
+
+    /// tests `Differential::new()` for failure on invalid axes and boundary stencils
+    ///
+    /// # Errors
+    /// - `Differential::new()` succeeds for an axis of 2 nodes
+    /// - `Differential::new()` succeeds for decreasing node coordinates
+    /// - `Differential::new()` succeeds for a periodic stencil on a single end
+    /// - `Differential::new()` fails for an axis of a single node
+    /// - `Differential::uniform()` succeeds for a non-positive spatial increment
+    ///
+    #[test]
+    fn new_failure() {
+        let nodes = [0.0, 0.5, 1.0];
+        assert!(Differential::new(&[0.0, 1.0], &nodes, &nodes, &one_sided()).is_err());
+        assert!(Differential::new(&[0.0, 1.0, 0.5], &nodes, &nodes, &one_sided()).is_err());
+        let mut boundary = one_sided();
+        boundary.y[1] = BoundaryStencil::Periodic;
+        assert!(Differential::new(&nodes, &nodes, &nodes, &boundary).is_err());
+        assert!(Differential::new(&nodes, &nodes, &[0.0], &one_sided()).is_ok());
+
+        let delta = CoordinateTriplet::new(0.1, 0.0, 0.1).unwrap();
+        let cells = CoordinateTriplet::new(3, 3, 3).unwrap();
+        assert!(Differential::uniform(&delta, &cells, &one_sided()).is_err());
+    }
+
+    /// tests `Differential::gradient()` for failure on a field of mismatched cells
+    ///
+    /// # Errors
+    /// - `Differential::gradient()` succeeds for a field of mismatched cells
+    /// - `Differential::divergence()` succeeds for a field of mismatched cells
+    ///
+    #[test]
+    fn gradient_mismatch() {
+        let delta = CoordinateTriplet::new(0.1, 0.1, 0.1).unwrap();
+        let cells = CoordinateTriplet::new(3, 4, 5).unwrap();
+        let differential = Differential::uniform(&delta, &cells, &one_sided()).unwrap();
+        let other = CoordinateTriplet::new(3, 5, 4).unwrap();
+        assert!(differential
+            .gradient(&ScalarField::<f64>::new(&other).unwrap())
+            .is_err());
+        assert!(differential
+            .divergence(&VectorField::<f64>::new(&other).unwrap())
+            .is_err());
+    }
+
+    /// tests all operators for second order convergence on uniform and stretched grids with one-sided boundary
+    /// stencils
+    ///
+    /// # Errors
+    /// - largest error on all nodes of any operator does not decrease by at least 2^1.9 when halving the spacing
+    ///
+    #[test]
+    fn operators_second_order() {
+        for spacing in [Spacing::Uniform, Spacing::Tanh(1.0)] {
+            let coarse = errors(&spacing, 17);
+            let fine = errors(&spacing, 33);
+            for (c, f) in coarse.iter().zip(fine.iter()) {
+                assert!((c / f).log2() > 1.9, "{spacing:?}: {c} -> {f}");
+            }
+        }
+    }
+
+    /// tests periodic and symmetric boundary stencils for second order convergence on boundary nodes
+    ///
+    /// # Errors
+    /// - derivatives of a periodic function on the boundary nodes of a periodic axis are not second order accurate
+    /// - derivatives of an even function on the boundary nodes of a symmetric axis are not second order accurate
+    /// - first derivative on the boundary nodes of a symmetric axis does not vanish
+    /// - derivatives along an axis of a single node do not vanish
+    ///
+    #[test]
+    fn boundary_stencils_second_order() {
+        let boundary = CoordinateTriplet::new(
+            [BoundaryStencil::Periodic; 2],
+            [BoundaryStencil::Symmetric; 2],
+            [BoundaryStencil::OneSided; 2],
+        )
+        .unwrap();
+
+        let errors = |n: usize| {
+            let h = 1.0 / (n - 1) as f64;
+            let delta = CoordinateTriplet::new(h, h, 1.0).unwrap();
+            let cells = CoordinateTriplet::new(n, n, 1).unwrap();
+            let differential = Differential::uniform(&delta, &cells, &boundary).unwrap();
+            let nodes = [
+                (0..n).map(|i| i as f64 * h).collect::<Vec<f64>>(),
+                (0..n).map(|i| i as f64 * h).collect(),
+                vec![0.0],
+            ];
+            let field = sample(&nodes, |x, y, _| (2.0 * PI * x).sin() * (PI * y).cos());
+            let gradient = differential.gradient(&field).unwrap();
+            let laplacian = differential.laplacian(&field).unwrap();
+
+            let mut error = [0.0_f64; 2];
+            for (i, x) in nodes[0].iter().enumerate() {
+                for (j, y) in nodes[1].iter().enumerate() {
+                    if i == 0 || i == n - 1 || j == 0 || j == n - 1 {
+                        let dx = 2.0 * PI * (2.0 * PI * x).cos() * (PI * y).cos();
+                        let lap = -5.0 * PI * PI * (2.0 * PI * x).sin() * (PI * y).cos();
+                        error[0] = error[0].max((gradient.x[(i, j, 0)] - dx).abs());
+                        error[1] = error[1].max((laplacian[(i, j, 0)] - lap).abs());
+                    }
+                    if j == 0 || j == n - 1 {
+                        assert_eq!(gradient.y[(i, j, 0)], 0.0);
+                    }
+                    assert_eq!(gradient.z[(i, j, 0)], 0.0);
+                }
+            }
+            error
+        };
+
+        let (coarse, fine) = (errors(17), errors(33));
+        for (c, f) in coarse.iter().zip(fine.iter()) {
+            assert!((c / f).log2() > 1.9, "{c} -> {f}");
+        }
+    }
+}
@@ -1,6 +1,8 @@
 //! field module
 //!
-//! contains scalar and vector field types
+//! contains scalar and vector field types and views into them
 
+pub mod differential;
 pub mod scalar;
 pub mod vector;
+pub mod view;
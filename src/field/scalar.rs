@@ -1,12 +1,25 @@
+use crate::precision::Real;
 use crate::utils::coordinate_triplet::CoordinateTriplet;
+use anyhow::anyhow;
 use num::Num;
+use rayon::iter::Either;
+use rayon::prelude::*;
 use std::fmt::{Display, Formatter};
-use std::ops::{AddAssign, DivAssign, Index, IndexMut, MulAssign, SubAssign};
+use std::io::{Read, Write};
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Range, Sub, SubAssign,
+};
+
+/// number of values reduced together by a single task of a field reduction
+const REDUCTION_CHUNK: usize = 4096;
+
+/// number of values above which field operations are parallelized
+pub(crate) const PARALLEL_THRESHOLD: usize = 1 << 15;
 
 /// `ScalarField<T>` struct
 ///
 /// describes a scalar field
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct ScalarField<T> {
     /// scalar field data
     data: Vec<T>,
@@ -19,6 +32,35 @@ pub struct ScalarField<T> {
 
     /// scalar field plane offset
     p_offset: usize,
+
+    /// width of ghost layers surrounding the interior nodes on every side
+    ghost: usize,
+
+    /// linear index of the first interior node
+    origin: usize,
+}
+
+/// `Halo` struct
+///
+/// describes an (i, j, k) index of a `ScalarField<T>` relative to its first interior node, negative indices and
+/// indices beyond the last interior node address ghost layers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Halo(pub isize, pub isize, pub isize);
+
+/// `GhostBoundary<T>` enum
+///
+/// describes how ghost layers beyond a boundary node of an axis are filled from interior nodes
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GhostBoundary<T> {
+    /// first and last interior node of the axis coincide, ghost nodes wrap around to the opposite end of the axis
+    Periodic,
+
+    /// value on the boundary node, ghost nodes are odd reflections of interior nodes about this value
+    Dirichlet(T),
+
+    /// outward normal derivative times node spacing on the boundary node, ghost nodes are even reflections of
+    /// interior nodes offset to match it
+    Neumann(T),
 }
 
 impl<T: Num + Copy> ScalarField<T> {
@@ -33,25 +75,50 @@ impl<T: Num + Copy> ScalarField<T> {
     /// # Errors
     ///
     pub fn new(cells: &CoordinateTriplet<usize>) -> Result<ScalarField<T>, anyhow::Error> {
+        ScalarField::with_ghost(cells, 0)
+    }
+
+    /// `ScalarField<T>` constructor surrounding interior nodes with ghost layers
+    ///
+    /// ghost nodes are stored alongside interior nodes but are excluded from iteration, reductions, views, and
+    /// binary output, they are addressed through `Halo` indices and filled with `ScalarField::fill_ghosts()` or
+    /// `ScalarField::exchange_ghosts()`
+    ///
+    /// # Arguments
+    /// - `cells`: CoordinateTriplet<usize> number of interior cells in bounding box
+    /// - `ghost`: usize width of ghost layers on every side
+    ///
+    /// # Returns
+    /// `Result<ScalarField<T>, anyhow::Error>`
+    ///
+    /// # Errors
+    ///
+    pub fn with_ghost(
+        cells: &CoordinateTriplet<usize>,
+        ghost: usize,
+    ) -> Result<ScalarField<T>, anyhow::Error> {
         // clone cells
         let cells = cells.clone();
 
-        // define offsets
-        let r_offset = cells.z;
-        let p_offset = cells.y * cells.z;
+        // define offsets of storage including ghost layers
+        let r_offset = cells.z + 2 * ghost;
+        let p_offset = (cells.y + 2 * ghost) * r_offset;
+        let origin = ghost * (p_offset + r_offset + 1);
 
-        // define initial vector field
-        let data: Vec<T> = vec![T::zero(); cells.x * cells.y * cells.z];
+        // define initial scalar field
+        let data: Vec<T> = vec![T::zero(); (cells.x + 2 * ghost) * p_offset];
 
         Ok(ScalarField {
             data,
             cells,
             r_offset,
             p_offset,
+            ghost,
+            origin,
         })
     }
 
-    /// returns an iterator over `ScalarField<T>`
+    /// returns an iterator over interior nodes of `ScalarField<T>` with k varying fastest
     ///
     /// # Arguments
     /// - `&'a self` reference to self
@@ -62,10 +129,25 @@ impl<T: Num + Copy> ScalarField<T> {
     /// # Errors
     ///
     pub fn iter<'a>(&'a self) -> impl Iterator<Item = &'a T> + 'a {
-        self.data.iter()
+        // without ghost layers storage holds interior nodes only
+        if self.ghost == 0 {
+            return Either::Left(self.data.iter());
+        }
+
+        let (ghost, cells, rows) = (
+            self.ghost,
+            self.cells.clone(),
+            self.cells.y + 2 * self.ghost,
+        );
+        let interior = self.data[self.interior_planes()]
+            .chunks(self.r_offset)
+            .enumerate()
+            .filter(move |(m, _)| (ghost..ghost + cells.y).contains(&(m % rows)))
+            .flat_map(move |(_, row)| row[ghost..ghost + cells.z].iter());
+        Either::Right(interior)
     }
 
-    /// returns a mutable iterator over `ScalarField<T>`
+    /// returns a mutable iterator over interior nodes of `ScalarField<T>` with k varying fastest
     ///
     /// # Arguments
     /// - `&'a mut self` mutable reference to self
@@ -76,261 +158,1836 @@ impl<T: Num + Copy> ScalarField<T> {
     /// # Errors
     ///
     pub fn iter_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut T> + 'a {
-        self.data.iter_mut()
+        // without ghost layers storage holds interior nodes only
+        if self.ghost == 0 {
+            return Either::Left(self.data.iter_mut());
+        }
+
+        let (ghost, cells, rows) = (
+            self.ghost,
+            self.cells.clone(),
+            self.cells.y + 2 * self.ghost,
+        );
+        let planes = self.interior_planes();
+        let interior = self.data[planes]
+            .chunks_mut(self.r_offset)
+            .enumerate()
+            .filter(move |(m, _)| (ghost..ghost + cells.y).contains(&(m % rows)))
+            .flat_map(move |(_, row)| row[ghost..ghost + cells.z].iter_mut());
+        Either::Right(interior)
     }
 
-    /// maps a linear index to an (i, j, k) index in a `ScalarField<T>`
+    /// returns contiguous storage of `ScalarField<T>` including ghost layers with k varying fastest
     ///
     /// # Arguments
     /// - `&self` reference to self
-    /// - `index: usize` linear index
     ///
     /// # Returns
-    /// (usize, usize, usize)` (i, j, k) indices
+    /// `&[T]` values of every node
     ///
     /// # Errors
     ///
-    pub fn ijk_from_linear(&self, index: usize) -> (usize, usize, usize) {
-        let i = index / self.p_offset;
-        let j = (index / self.cells.z) % self.cells.y;
-        let k = index % self.cells.z;
-        (i, j, k)
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
     }
-}
 
-/// implements [] operator on `ScalarField<T>`
-impl<T> Index<(usize, usize, usize)> for ScalarField<T> {
-    type Output = T;
+    /// returns mutable contiguous storage of `ScalarField<T>` including ghost layers with k varying fastest
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    ///
+    /// # Returns
+    /// `&mut [T]` values of every node
+    ///
+    /// # Errors
+    ///
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.data
+    }
 
-    /// returns a reference to scalar field data stored at desired index
+    /// returns the contiguous row of interior nodes (i, j, 0..cells.z) of `ScalarField<T>`
     ///
     /// # Arguments
     /// - `&self` reference to self
-    /// - `idx: (usize, usize, usize)` i, j, and k indices on cartesian grid
+    /// - `i`: usize index of node along x
+    /// - `j`: usize index of node along y
     ///
     /// # Returns
-    /// `&T`
+    /// `&[T]` values of nodes along k
     ///
     /// # Errors
     ///
-    fn index(&self, idx: (usize, usize, usize)) -> &Self::Output {
-        // destructure idx into i, j, and k components
-        let (i, j, k) = idx;
+    /// # Panics
+    /// - `i` or `j` is out of bounds
+    ///
+    pub fn row(&self, i: usize, j: usize) -> &[T] {
+        let start = self.row_start(i, j);
+        &self.data[start..start + self.cells.z]
+    }
 
-        // linearly index into `ScalarField<T>` using row major ordering
-        &self.data[k + self.r_offset * j + self.p_offset * i]
+    /// returns the mutable contiguous row of interior nodes (i, j, 0..cells.z) of `ScalarField<T>`
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `i`: usize index of node along x
+    /// - `j`: usize index of node along y
+    ///
+    /// # Returns
+    /// `&mut [T]` values of nodes along k
+    ///
+    /// # Errors
+    ///
+    /// # Panics
+    /// - `i` or `j` is out of bounds
+    ///
+    pub fn row_mut(&mut self, i: usize, j: usize) -> &mut [T] {
+        let start = self.row_start(i, j);
+        &mut self.data[start..start + self.cells.z]
     }
-}
 
-/// implements mutable [] operator on `ScalarField<T>`
-impl<T> IndexMut<(usize, usize, usize)> for ScalarField<T> {
-    /// returns a mutable reference to scalar field data stored at desired index
+    /// returns the mutable row (i, j) of `ScalarField<T>` together with its neighbouring rows along x and y, so that
+    /// stencil kernels may run over contiguous rows without indexing every node
     ///
     /// # Arguments
     /// - `&mut self` mutable reference to self
-    /// - `idx: (usize, usize, usize)` i, j, and k indices on cartesian grid
+    /// - `i`: usize index of node along x
+    /// - `j`: usize index of node along y
     ///
     /// # Returns
-    /// `&mut T`
+    /// `(&mut [T], [&[T]; 4])` row (i, j) and rows (i - 1, j), (i + 1, j), (i, j - 1), and (i, j + 1)
     ///
     /// # Errors
     ///
-    fn index_mut(&mut self, index: (usize, usize, usize)) -> &mut Self::Output {
-        // destructure idx into i, j, and k components
-        let (i, j, k) = index;
+    /// # Panics
+    /// - (i, j) is not an interior row, every neighbouring row must lie within the interior nodes
+    ///
+    pub fn stencil_rows_mut(&mut self, i: usize, j: usize) -> (&mut [T], [&[T]; 4]) {
+        assert!(
+            0 < i && i + 1 < self.cells.x && 0 < j && j + 1 < self.cells.y,
+            "row ({i}, {j}) has no neighbouring rows in field of {} cells",
+            self.cells
+        );
+        let (start, len) = (self.row_start(i, j), self.cells.z);
+        let (p, r) = (self.p_offset, self.r_offset);
+
+        // neighbouring rows lie entirely before or after the row as rows never overlap
+        let (lower, rest) = self.data.split_at_mut(start);
+        let (row, upper) = rest.split_at_mut(len);
+        let (lower, upper): (&[T], &[T]) = (lower, upper);
+        let neighbours = [
+            &lower[start - p..start - p + len],
+            &upper[p - len..p],
+            &lower[start - r..start - r + len],
+            &upper[r - len..r],
+        ];
+
+        (row, neighbours)
+    }
 
-        // linearly index into `ScalarField<T>` using row major ordering
-        &mut self.data[k + self.r_offset * j + self.p_offset * i]
+    /// returns the linear index of the first node of row (i, j) of `ScalarField<T>`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `i`: usize index of node along x
+    /// - `j`: usize index of node along y
+    ///
+    /// # Returns
+    /// `usize` linear index into storage
+    ///
+    /// # Errors
+    ///
+    /// # Panics
+    /// - `i` or `j` is out of bounds
+    ///
+    fn row_start(&self, i: usize, j: usize) -> usize {
+        assert!(
+            i < self.cells.x && j < self.cells.y,
+            "row ({i}, {j}) out of bounds for field of {} cells",
+            self.cells
+        );
+        self.origin + i * self.p_offset + j * self.r_offset
     }
-}
 
-/// allows `ScalarField<T>` to be written in a text format
-impl<T: Display> Display for ScalarField<T> {
-    /// writes `ScalarField<T>` in a text format
+    /// returns number of cells of `ScalarField<T>`
     ///
     /// # Arguments
     /// - `&self` reference to self
-    /// - `f: &mut Formatter<'_>` formatter for writing
     ///
     /// # Returns
-    /// `std::fmt::Result`
+    /// `&CoordinateTriplet<usize>` number of cells
     ///
     /// # Errors
-    /// - call to `write!()` errors
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        for i in 0..self.cells.x {
-            for j in 0..self.cells.y {
-                for k in 0..self.cells.z {
-                    write!(
-                        f,
-                        "ScalarField({}, {}, {}) = {}\n",
-                        i,
-                        j,
-                        k,
-                        self[(i, j, k)]
-                    )?;
-                }
-            }
-        }
-        Ok(())
+    ///
+    pub fn cells(&self) -> &CoordinateTriplet<usize> {
+        &self.cells
     }
-}
 
-/// implements `ScalarField<T> += ScalarField<T>`
-impl<T: Copy + AddAssign> AddAssign<ScalarField<T>> for ScalarField<T> {
-    /// implements `ScalarField<T> += ScalarField<T>`
+    /// maps a linear index to an (i, j, k) index in a `ScalarField<T>`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `index: usize` linear index, the position of an interior node in iteration order
+    ///
+    /// # Returns
+    /// (usize, usize, usize)` (i, j, k) indices
+    ///
+    /// # Errors
+    ///
+    pub fn ijk_from_linear(&self, index: usize) -> (usize, usize, usize) {
+        let i = index / (self.cells.y * self.cells.z);
+        let j = (index / self.cells.z) % self.cells.y;
+        let k = index % self.cells.z;
+        (i, j, k)
+    }
+
+    /// adds a multiple of another `ScalarField<T>` to `ScalarField<T>` in a single pass without allocating
     ///
     /// # Arguments
     /// - `&mut self` mutable reference to self
-    /// - `rhs: ScalarField<T>` rhs of operation
+    /// - `alpha: T` multiple of `x` to add
+    /// - `x: &ScalarField<T>` field to add
     ///
     /// # Returns
     ///
     /// # Errors
     ///
-    fn add_assign(&mut self, rhs: ScalarField<T>) {
-        for (elem, num) in self.data.iter_mut().zip(&rhs.data) {
-            *elem += *num;
+    /// # Panics
+    /// - in debug builds when `cells` or ghost layers of `self` and `x` differ, see `ScalarField::try_axpy()`
+    ///
+    pub fn axpy(&mut self, alpha: T, x: &ScalarField<T>) {
+        self.debug_check_layout(x);
+        for (elem, num) in self.data.iter_mut().zip(&x.data) {
+            *elem = *elem + alpha * *num;
         }
     }
-}
 
-/// implements `ScalarField<T> -= ScalarField<T>`
-impl<T: Copy + SubAssign> SubAssign<ScalarField<T>> for ScalarField<T> {
-    /// implements `ScalarField<T> -= ScalarField<T>`
+    /// shape-checked `ScalarField<T> += &ScalarField<T>`
     ///
     /// # Arguments
     /// - `&mut self` mutable reference to self
-    /// - `rhs: ScalarField<T>` rhs of operation
+    /// - `rhs: &ScalarField<T>` rhs of operation
     ///
     /// # Returns
+    /// `Result<(), anyhow::Error>`
     ///
     /// # Errors
+    /// - `cells` or ghost layers of `self` and `rhs` differ, in which case `self` is left unmodified
+    ///
+    pub fn try_add_assign(&mut self, rhs: &ScalarField<T>) -> Result<(), anyhow::Error>
+    where
+        T: AddAssign,
+    {
+        self.check_layout(rhs)?;
+        *self += rhs;
+        Ok(())
+    }
+
+    /// shape-checked `ScalarField<T> -= &ScalarField<T>`
     ///
-    fn sub_assign(&mut self, rhs: ScalarField<T>) {
-        for (elem, num) in self.data.iter_mut().zip(&rhs.data) {
-            *elem -= *num;
-        }
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `rhs: &ScalarField<T>` rhs of operation
+    ///
+    /// # Returns
+    /// `Result<(), anyhow::Error>`
+    ///
+    /// # Errors
+    /// - `cells` or ghost layers of `self` and `rhs` differ, in which case `self` is left unmodified
+    ///
+    pub fn try_sub_assign(&mut self, rhs: &ScalarField<T>) -> Result<(), anyhow::Error>
+    where
+        T: SubAssign,
+    {
+        self.check_layout(rhs)?;
+        *self -= rhs;
+        Ok(())
     }
-}
 
-/// implements `ScalarField<T> *= ScalarField<T>`
-impl<T: Copy + MulAssign> MulAssign<ScalarField<T>> for ScalarField<T> {
-    /// implements `ScalarField<T> *= ScalarField<T>`
+    /// shape-checked `ScalarField<T> *= &ScalarField<T>`
     ///
     /// # Arguments
     /// - `&mut self` mutable reference to self
-    /// - `rhs: ScalarField<T>` rhs of operation
+    /// - `rhs: &ScalarField<T>` rhs of operation
     ///
     /// # Returns
+    /// `Result<(), anyhow::Error>`
     ///
     /// # Errors
+    /// - `cells` or ghost layers of `self` and `rhs` differ, in which case `self` is left unmodified
+    ///
+    pub fn try_mul_assign(&mut self, rhs: &ScalarField<T>) -> Result<(), anyhow::Error>
+    where
+        T: MulAssign,
+    {
+        self.check_layout(rhs)?;
+        *self *= rhs;
+        Ok(())
+    }
+
+    /// shape-checked `ScalarField<T> /= &ScalarField<T>`
     ///
-    fn mul_assign(&mut self, rhs: ScalarField<T>) {
-        for (elem, num) in self.data.iter_mut().zip(&rhs.data) {
-            *elem *= *num;
-        }
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `rhs: &ScalarField<T>` rhs of operation
+    ///
+    /// # Returns
+    /// `Result<(), anyhow::Error>`
+    ///
+    /// # Errors
+    /// - `cells` or ghost layers of `self` and `rhs` differ, in which case `self` is left unmodified
+    ///
+    pub fn try_div_assign(&mut self, rhs: &ScalarField<T>) -> Result<(), anyhow::Error>
+    where
+        T: DivAssign,
+    {
+        self.check_layout(rhs)?;
+        *self /= rhs;
+        Ok(())
     }
-}
 
-/// implements `ScalarField<T> /= ScalarField<T>`
-impl<T: Copy + DivAssign> DivAssign<ScalarField<T>> for ScalarField<T> {
-    /// implements `ScalarField<T> /= ScalarField<T>`
+    /// shape-checked `ScalarField::axpy()`
     ///
     /// # Arguments
     /// - `&mut self` mutable reference to self
-    /// - `rhs: ScalarField<T>` rhs of operation
+    /// - `alpha: T` multiple of `x` to add
+    /// - `x: &ScalarField<T>` field to add
     ///
     /// # Returns
+    /// `Result<(), anyhow::Error>`
     ///
     /// # Errors
+    /// - `cells` or ghost layers of `self` and `x` differ, in which case `self` is left unmodified
     ///
-    fn div_assign(&mut self, rhs: ScalarField<T>) {
-        for (elem, num) in self.data.iter_mut().zip(&rhs.data) {
-            *elem /= *num;
-        }
+    pub fn try_axpy(&mut self, alpha: T, x: &ScalarField<T>) -> Result<(), anyhow::Error> {
+        self.check_layout(x)?;
+        self.axpy(alpha, x);
+        Ok(())
     }
-}
 
-/// implements `ScalarField<T> += T`
-impl<T: Copy + AddAssign> AddAssign<T> for ScalarField<T> {
-    /// implements `ScalarField<T> += T`
+    /// returns width of ghost layers of `ScalarField<T>`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `usize` width of ghost layers on every side
+    ///
+    /// # Errors
+    ///
+    pub fn ghost(&self) -> usize {
+        self.ghost
+    }
+
+    /// fills the ghost layers beyond one boundary node of an axis of `ScalarField<T>` from its interior nodes
+    ///
+    /// ghost layers are filled across the full extent of the remaining axes including their ghost layers, so filling
+    /// axes in increasing order also fills edges and corners
     ///
     /// # Arguments
     /// - `&mut self` mutable reference to self
-    /// - `rhs: T` rhs of operation
+    /// - `axis`: usize axis normal to the boundary, 0 for x, 1 for y, and 2 for z
+    /// - `side`: usize 0 for the lower and 1 for the upper boundary node
+    /// - `boundary`: GhostBoundary<T> rule of filling ghost nodes
     ///
     /// # Returns
+    /// `Result<(), anyhow::Error>`
     ///
     /// # Errors
+    /// - `axis` is not 0, 1, or 2 or `side` is not 0 or 1
+    /// - ghost layers are as wide as or wider than the interior nodes along the axis
+    ///
+    pub fn fill_ghosts(
+        &mut self,
+        axis: usize,
+        side: usize,
+        boundary: GhostBoundary<T>,
+    ) -> Result<(), anyhow::Error> {
+        let nodes = self.ghost_axis(axis, side)?;
+        if self.ghost >= nodes {
+            return Err(anyhow!(
+                "{} ghost layers exceed {} nodes along axis {axis}",
+                self.ghost,
+                nodes
+            ));
+        }
+
+        let (g, last) = (self.ghost, self.ghost + nodes - 1);
+        let two = T::one() + T::one();
+        for m in 1..=g {
+            // storage coordinates along the axis of the ghost layer and of the interior layer it is filled from
+            let (ghost, mirror) = if side == 0 {
+                (g - m, g + m)
+            } else {
+                (last + m, last - m)
+            };
+            let source = match (boundary, side) {
+                (GhostBoundary::Periodic, 0) => last - m,
+                (GhostBoundary::Periodic, _) => g + m,
+                _ => mirror,
+            };
+
+            // distance between ghost layer and its mirror in node spacings
+            let distance = (0..2 * m).fold(T::zero(), |sum, _| sum + T::one());
+            let layers = self
+                .plane_indices(axis, ghost)
+                .zip(self.plane_indices(axis, source));
+            for (dst, src) in layers {
+                self.data[dst] = match boundary {
+                    GhostBoundary::Periodic => self.data[src],
+                    GhostBoundary::Dirichlet(value) => two * value - self.data[src],
+                    GhostBoundary::Neumann(slope) => self.data[src] + distance * slope,
+                };
+            }
+        }
+
+        Ok(())
+    }
+
+    /// fills the ghost layers of every boundary of `ScalarField<T>` in increasing axis order
     ///
-    fn add_assign(&mut self, rhs: T) {
-        for elem in self.data.iter_mut() {
-            *elem += rhs;
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `boundary`: &CoordinateTriplet<[GhostBoundary<T>; 2]> rules of filling ghost nodes beyond the lower and upper
+    ///   boundary node of every axis
+    ///
+    /// # Returns
+    /// `Result<(), anyhow::Error>`
+    ///
+    /// # Errors
+    /// - any call to `ScalarField::fill_ghosts()` fails
+    ///
+    pub fn fill_all_ghosts(
+        &mut self,
+        boundary: &CoordinateTriplet<[GhostBoundary<T>; 2]>,
+    ) -> Result<(), anyhow::Error> {
+        for (axis, sides) in [boundary.x, boundary.y, boundary.z].into_iter().enumerate() {
+            for (side, rule) in sides.into_iter().enumerate() {
+                self.fill_ghosts(axis, side, rule)?;
+            }
         }
+
+        Ok(())
     }
-}
 
-/// implements `ScalarField<T> -= T`
-impl<T: Copy + SubAssign> SubAssign<T> for ScalarField<T> {
-    /// implements `ScalarField<T> -= T`
+    /// fills the ghost layers beyond one boundary node of an axis of `ScalarField<T>` from the interior nodes of a
+    /// neighbouring field, as when exchanging halos between sub-domains
+    ///
+    /// neighbouring fields share their boundary node, so the first ghost layer beyond the upper boundary node holds
+    /// the second interior layer of the neighbour and vice versa
     ///
     /// # Arguments
     /// - `&mut self` mutable reference to self
-    /// - `rhs: T` rhs of operation
+    /// - `axis`: usize axis normal to the shared boundary, 0 for x, 1 for y, and 2 for z
+    /// - `side`: usize 0 for a neighbour below and 1 for a neighbour above the shared boundary node
+    /// - `neighbour`: &ScalarField<T> neighbouring field
     ///
     /// # Returns
+    /// `Result<(), anyhow::Error>`
     ///
     /// # Errors
+    /// - `axis` is not 0, 1, or 2 or `side` is not 0 or 1
+    /// - `cells` of `self` and `neighbour` differ along any other axis
+    /// - ghost layers of `self` and `neighbour` differ
+    /// - ghost layers are as wide as or wider than the interior nodes of `neighbour` along the axis
+    ///
+    pub fn exchange_ghosts(
+        &mut self,
+        axis: usize,
+        side: usize,
+        neighbour: &ScalarField<T>,
+    ) -> Result<(), anyhow::Error> {
+        let last = self.ghost + self.ghost_axis(axis, side)? - 1;
+        let nodes = neighbour.ghost_axis(axis, side)?;
+
+        // only the extents of the faces normal to the axis must agree
+        let mut faces = [self.cells.clone(), neighbour.cells.clone()];
+        for cells in faces.iter_mut() {
+            match axis {
+                0 => cells.x = 1,
+                1 => cells.y = 1,
+                _ => cells.z = 1,
+            }
+        }
+        check_shapes(&faces[0], &faces[1])?;
+        if self.ghost != neighbour.ghost {
+            return Err(anyhow!(ghost_mismatch(self.ghost, neighbour.ghost)));
+        }
+        if self.ghost >= nodes {
+            return Err(anyhow!(
+                "{} ghost layers exceed {} nodes of neighbour along axis {axis}",
+                self.ghost,
+                nodes
+            ));
+        }
+
+        let g = self.ghost;
+        for m in 1..=g {
+            let (ghost, source) = if side == 0 {
+                (g - m, g + nodes - 1 - m)
+            } else {
+                (last + m, g + m)
+            };
+            let layers = self
+                .plane_indices(axis, ghost)
+                .zip(neighbour.plane_indices(axis, source));
+            for (dst, src) in layers {
+                self.data[dst] = neighbour.data[src];
+            }
+        }
+
+        Ok(())
+    }
+
+    /// returns number of interior nodes of `ScalarField<T>` along an axis after validating a boundary
     ///
-    fn sub_assign(&mut self, rhs: T) {
-        for elem in self.data.iter_mut() {
-            *elem -= rhs;
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `axis`: usize axis, 0 for x, 1 for y, and 2 for z
+    /// - `side`: usize 0 for the lower and 1 for the upper boundary node
+    ///
+    /// # Returns
+    /// `Result<usize, anyhow::Error>` number of interior nodes along `axis`
+    ///
+    /// # Errors
+    /// - `axis` is not 0, 1, or 2 or `side` is not 0 or 1
+    ///
+    fn ghost_axis(&self, axis: usize, side: usize) -> Result<usize, anyhow::Error> {
+        match (axis, side) {
+            (0, 0..=1) => Ok(self.cells.x),
+            (1, 0..=1) => Ok(self.cells.y),
+            (2, 0..=1) => Ok(self.cells.z),
+            _ => Err(anyhow!(
+                "boundary of axis {axis} and side {side} does not exist"
+            )),
         }
     }
-}
 
-/// implements `ScalarField<T> *= T`
-impl<T: Copy + MulAssign> MulAssign<T> for ScalarField<T> {
-    /// implements `ScalarField<T> *= T`
+    /// returns linear indices of every stored node of a layer of `ScalarField<T>` normal to an axis
     ///
     /// # Arguments
-    /// - `&mut self` mutable reference to self
-    /// - `rhs: T` rhs of operation
+    /// - `&self` reference to self
+    /// - `axis`: usize axis normal to the layer, 0 for x, 1 for y, and 2 for z
+    /// - `layer`: usize storage coordinate of the layer along `axis`, counting ghost layers
+    ///
+    /// # Returns
+    /// `impl Iterator<Item = usize>` linear indices in storage order
+    ///
+    /// # Errors
+    ///
+    fn plane_indices(&self, axis: usize, layer: usize) -> impl Iterator<Item = usize> {
+        let strides = [self.p_offset, self.r_offset, 1];
+        let extents = [
+            self.cells.x + 2 * self.ghost,
+            self.cells.y + 2 * self.ghost,
+            self.cells.z + 2 * self.ghost,
+        ];
+        let (a, b) = match axis {
+            0 => (1, 2),
+            1 => (0, 2),
+            _ => (0, 1),
+        };
+        let base = layer * strides[axis];
+        (0..extents[a])
+            .flat_map(move |u| (0..extents[b]).map(move |v| base + u * strides[a] + v * strides[b]))
+    }
+
+    /// returns the range of storage holding the planes of interior nodes along x
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `Range<usize>` linear indices
+    ///
+    /// # Errors
+    ///
+    fn interior_planes(&self) -> Range<usize> {
+        self.ghost * self.p_offset..(self.ghost + self.cells.x) * self.p_offset
+    }
+
+    /// returns contiguous segments of storage holding a range of interior nodes in iteration order
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `range`: Range<usize> linear indices of interior nodes in iteration order
+    ///
+    /// # Returns
+    /// `impl Iterator<Item = &[T]>` segments within a single row each
+    ///
+    /// # Errors
+    ///
+    fn segments(&self, range: Range<usize>) -> impl Iterator<Item = &[T]> {
+        let columns = self.cells.z.max(1);
+        (range.start / columns..range.end.div_ceil(columns)).map(move |m| {
+            let (i, j) = (m / self.cells.y, m % self.cells.y);
+            let row = self.origin + i * self.p_offset + j * self.r_offset;
+            let lower = range.start.max(m * columns) - m * columns;
+            let upper = range.end.min((m + 1) * columns) - m * columns;
+            &self.data[row + lower..row + upper]
+        })
+    }
+
+    /// returns number of interior nodes of `ScalarField<T>`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `usize` number of interior nodes
+    ///
+    /// # Errors
+    ///
+    fn nodes(&self) -> usize {
+        self.cells.x * self.cells.y * self.cells.z
+    }
+}
+
+/// checks that the operands of an element-wise field operation share the same shape
+///
+/// # Arguments
+/// - `lhs`: &CoordinateTriplet<usize> cells of lhs of operation
+/// - `rhs`: &CoordinateTriplet<usize> cells of rhs of operation
+///
+/// # Returns
+/// `Result<(), anyhow::Error>`
+///
+/// # Errors
+/// - `lhs` and `rhs` differ
+///
+pub(crate) fn check_shapes(
+    lhs: &CoordinateTriplet<usize>,
+    rhs: &CoordinateTriplet<usize>,
+) -> Result<(), anyhow::Error> {
+    if lhs != rhs {
+        return Err(anyhow!(shape_mismatch(lhs, rhs)));
+    }
+
+    Ok(())
+}
+
+/// describes a shape mismatch between the operands of an element-wise field operation
+///
+/// # Arguments
+/// - `lhs`: &CoordinateTriplet<usize> cells of lhs of operation
+/// - `rhs`: &CoordinateTriplet<usize> cells of rhs of operation
+///
+/// # Returns
+/// `String` message naming both shapes
+///
+/// # Errors
+///
+pub(crate) fn shape_mismatch(
+    lhs: &CoordinateTriplet<usize>,
+    rhs: &CoordinateTriplet<usize>,
+) -> String {
+    format!("field shape mismatch: lhs of {lhs} cells, rhs of {rhs} cells")
+}
+
+/// describes a mismatch of ghost layers between the operands of an element-wise field operation
+///
+/// # Arguments
+/// - `lhs`: usize width of ghost layers of lhs of operation
+/// - `rhs`: usize width of ghost layers of rhs of operation
+///
+/// # Returns
+/// `String` message naming both widths
+///
+/// # Errors
+///
+fn ghost_mismatch(lhs: usize, rhs: usize) -> String {
+    format!("field shape mismatch: lhs of {lhs} ghost layers, rhs of {rhs} ghost layers")
+}
+
+impl<T: Real> ScalarField<T> {
+    /// writes data of `ScalarField<T>` in a little endian binary format, values are widened to double precision so
+    /// that the format does not depend on `T`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `writer: &mut dyn Write` destination of data
+    ///
+    /// # Returns
+    /// `Result<(), anyhow::Error>`
+    ///
+    /// # Errors
+    /// - any call to `Write::write_all()` fails
+    pub fn write_binary(&self, writer: &mut dyn Write) -> Result<(), anyhow::Error> {
+        for elem in self.iter() {
+            writer.write_all(&elem.widen().to_le_bytes())?;
+        }
+
+        Ok(())
+    }
+
+    /// reads data of `ScalarField<T>` from a little endian binary format, values are narrowed from double precision
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `reader: &mut dyn Read` source of data
+    ///
+    /// # Returns
+    /// `Result<(), anyhow::Error>`
+    ///
+    /// # Errors
+    /// - any call to `Read::read_exact()` fails
+    pub fn read_binary(&mut self, reader: &mut dyn Read) -> Result<(), anyhow::Error> {
+        let mut buf = [0u8; 8];
+        for elem in self.iter_mut() {
+            reader.read_exact(&mut buf)?;
+            *elem = T::narrow(f64::from_le_bytes(buf));
+        }
+
+        Ok(())
+    }
+
+    /// returns the smallest value of `ScalarField<T>`, NaN values are ignored
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `f64` smallest value
+    ///
+    /// # Errors
+    ///
+    pub fn min(&self) -> f64 {
+        reduce_chunks(
+            self.nodes(),
+            f64::INFINITY,
+            |range| {
+                self.segments(range)
+                    .flatten()
+                    .map(|v| v.widen())
+                    .fold(f64::INFINITY, f64::min)
+            },
+            f64::min,
+        )
+    }
+
+    /// returns the largest value of `ScalarField<T>`, NaN values are ignored
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `f64` largest value
+    ///
+    /// # Errors
+    ///
+    pub fn max(&self) -> f64 {
+        reduce_chunks(
+            self.nodes(),
+            f64::NEG_INFINITY,
+            |range| {
+                self.segments(range)
+                    .flatten()
+                    .map(|v| v.widen())
+                    .fold(f64::NEG_INFINITY, f64::max)
+            },
+            f64::max,
+        )
+    }
+
+    /// returns the sum of all values of `ScalarField<T>`
+    ///
+    /// values are accumulated in double precision regardless of `T`, as are all other reductions
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `f64` sum
+    ///
+    /// # Errors
+    ///
+    pub fn sum(&self) -> f64 {
+        reduce_chunks(
+            self.nodes(),
+            0.0,
+            |range| self.segments(range).flatten().map(|v| v.widen()).sum(),
+            |a, b| a + b,
+        )
+    }
+
+    /// returns the arithmetic mean of all values of `ScalarField<T>`
+    ///
+    /// nodes are weighted equally, see `ScalarField::integral()` for a volume-weighted reduction
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `f64` mean, NaN for a field without nodes
+    ///
+    /// # Errors
+    ///
+    pub fn mean(&self) -> f64 {
+        self.sum() / self.nodes() as f64
+    }
+
+    /// returns the L1 norm, the sum of absolute values, of `ScalarField<T>`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `f64` L1 norm
+    ///
+    /// # Errors
+    ///
+    pub fn norm_l1(&self) -> f64 {
+        reduce_chunks(
+            self.nodes(),
+            0.0,
+            |range| {
+                self.segments(range)
+                    .flatten()
+                    .map(|v| v.widen().abs())
+                    .sum()
+            },
+            |a, b| a + b,
+        )
+    }
+
+    /// returns the L2 norm, the square root of the sum of squares, of `ScalarField<T>`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `f64` L2 norm
+    ///
+    /// # Errors
+    ///
+    pub fn norm_l2(&self) -> f64 {
+        reduce_chunks(
+            self.nodes(),
+            0.0,
+            |range| {
+                self.segments(range)
+                    .flatten()
+                    .map(|v| v.widen() * v.widen())
+                    .sum()
+            },
+            |a, b| a + b,
+        )
+        .sqrt()
+    }
+
+    /// returns the L-infinity norm, the largest absolute value, of `ScalarField<T>`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `f64` L-infinity norm
+    ///
+    /// # Errors
+    ///
+    pub fn norm_linf(&self) -> f64 {
+        reduce_chunks(
+            self.nodes(),
+            0.0,
+            |range| {
+                self.segments(range)
+                    .flatten()
+                    .fold(0.0, |max: f64, v| max.max(v.widen().abs()))
+            },
+            f64::max,
+        )
+    }
+
+    /// returns the (i, j, k) index of the largest value of `ScalarField<T>`, the first in storage order on ties
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `(usize, usize, usize)` (i, j, k) indices
+    ///
+    /// # Errors
+    ///
+    pub fn argmax(&self) -> (usize, usize, usize) {
+        self.ijk_from_linear(self.extremum(|candidate, best| candidate > best))
+    }
+
+    /// returns the (i, j, k) index of the smallest value of `ScalarField<T>`, the first in storage order on ties
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `(usize, usize, usize)` (i, j, k) indices
+    ///
+    /// # Errors
+    ///
+    pub fn argmin(&self) -> (usize, usize, usize) {
+        self.ijk_from_linear(self.extremum(|candidate, best| candidate < best))
+    }
+
+    /// integrates `ScalarField<T>` over node control volumes
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `volumes: &ScalarField<f64>` (m^3) control volume of each node
+    ///
+    /// # Returns
+    /// `f64` sum of values weighted by control volumes
+    ///
+    /// # Errors
+    ///
+    /// # Panics
+    /// - in debug builds when `cells` of `self` and `volumes` differ, see `ScalarField::try_integral()`
+    ///
+    pub fn integral(&self, volumes: &ScalarField<f64>) -> f64 {
+        debug_assert!(
+            self.cells == volumes.cells,
+            "{}",
+            shape_mismatch(&self.cells, &volumes.cells)
+        );
+        reduce_chunks(
+            self.nodes().min(volumes.nodes()),
+            0.0,
+            |range| {
+                self.segments(range.clone())
+                    .flatten()
+                    .zip(volumes.segments(range).flatten())
+                    .map(|(v, vol)| v.widen() * vol)
+                    .sum()
+            },
+            |a, b| a + b,
+        )
+    }
+
+    /// shape-checked `ScalarField::integral()`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `volumes: &ScalarField<f64>` (m^3) control volume of each node
+    ///
+    /// # Returns
+    /// `Result<f64, anyhow::Error>` sum of values weighted by control volumes
+    ///
+    /// # Errors
+    /// - `cells` of `self` and `volumes` differ
+    ///
+    pub fn try_integral(&self, volumes: &ScalarField<f64>) -> Result<f64, anyhow::Error> {
+        check_shapes(&self.cells, &volumes.cells)?;
+        Ok(self.integral(volumes))
+    }
+
+    /// computes all statistics of `ScalarField<T>` in a single pass
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `Statistics` statistics of all values
+    ///
+    /// # Errors
+    ///
+    pub fn statistics(&self) -> Statistics {
+        let mut statistics = reduce_chunks(
+            self.nodes(),
+            Statistics::EMPTY,
+            |range| {
+                self.segments(range)
+                    .flatten()
+                    .fold(Statistics::EMPTY, |partial, v| {
+                        partial.accumulate(v.widen())
+                    })
+            },
+            Statistics::merge,
+        );
+
+        // partial statistics carry the sum of squares in place of the L2 norm
+        statistics.mean = statistics.sum / self.nodes() as f64;
+        statistics.norm_l2 = statistics.norm_l2.sqrt();
+        statistics
+    }
+
+    /// returns the linear index of the first value of `ScalarField<T>` preferred over all others
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `prefer: impl Fn(f64, f64) -> bool + Sync` whether a candidate value is preferred over the best so far
+    ///
+    /// # Returns
+    /// `usize` linear index, 0 when no value is preferred over NaN
+    ///
+    /// # Errors
+    ///
+    fn extremum(&self, prefer: impl Fn(f64, f64) -> bool + Sync) -> usize {
+        let keep = |best: (usize, f64), candidate: (usize, f64)| {
+            if best.1.is_nan() || prefer(candidate.1, best.1) {
+                candidate
+            } else {
+                best
+            }
+        };
+        reduce_chunks(
+            self.nodes(),
+            (0, f64::NAN),
+            |range| {
+                let start = range.start;
+                self.segments(range)
+                    .flatten()
+                    .enumerate()
+                    .fold((start, f64::NAN), |best, (n, v)| {
+                        keep(best, (start + n, v.widen()))
+                    })
+            },
+            |a, b| if b.1.is_nan() { a } else { keep(a, b) },
+        )
+        .0
+    }
+}
+
+/// `Statistics` struct
+///
+/// describes statistics of the values of a scalar field
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Statistics {
+    /// smallest value
+    pub min: f64,
+
+    /// largest value
+    pub max: f64,
+
+    /// sum of values
+    pub sum: f64,
+
+    /// arithmetic mean of values
+    pub mean: f64,
+
+    /// sum of absolute values
+    pub norm_l1: f64,
+
+    /// square root of the sum of squares
+    pub norm_l2: f64,
+
+    /// largest absolute value
+    pub norm_linf: f64,
+}
+
+impl Statistics {
+    /// statistics of no values, the identity of `Statistics::merge()`
+    const EMPTY: Statistics = Statistics {
+        min: f64::INFINITY,
+        max: f64::NEG_INFINITY,
+        sum: 0.0,
+        mean: 0.0,
+        norm_l1: 0.0,
+        norm_l2: 0.0,
+        norm_linf: 0.0,
+    };
+
+    /// accumulates a single value into partial statistics
+    ///
+    /// # Arguments
+    /// - `self` partial statistics, `norm_l2` holding the sum of squares
+    /// - `value`: f64 value to accumulate
+    ///
+    /// # Returns
+    /// `Statistics` partial statistics
+    ///
+    /// # Errors
+    ///
+    fn accumulate(self, value: f64) -> Statistics {
+        Statistics {
+            min: self.min.min(value),
+            max: self.max.max(value),
+            sum: self.sum + value,
+            mean: 0.0,
+            norm_l1: self.norm_l1 + value.abs(),
+            norm_l2: self.norm_l2 + value * value,
+            norm_linf: self.norm_linf.max(value.abs()),
+        }
+    }
+
+    /// merges two partial statistics
+    ///
+    /// # Arguments
+    /// - `self` partial statistics, `norm_l2` holding the sum of squares
+    /// - `other`: Statistics partial statistics, `norm_l2` holding the sum of squares
+    ///
+    /// # Returns
+    /// `Statistics` partial statistics
+    ///
+    /// # Errors
+    ///
+    fn merge(self, other: Statistics) -> Statistics {
+        Statistics {
+            min: self.min.min(other.min),
+            max: self.max.max(other.max),
+            sum: self.sum + other.sum,
+            mean: 0.0,
+            norm_l1: self.norm_l1 + other.norm_l1,
+            norm_l2: self.norm_l2 + other.norm_l2,
+            norm_linf: self.norm_linf.max(other.norm_linf),
+        }
+    }
+}
+
+/// reduces `len` values in fixed chunks of `REDUCTION_CHUNK` values
+///
+/// chunks are reduced in parallel for at least `PARALLEL_THRESHOLD` values and serially otherwise, partial results
+/// are always combined in chunk order so that the result is independent of the number of threads
+///
+/// # Arguments
+/// - `len`: usize number of values
+/// - `identity`: A result of reducing no values
+/// - `chunk`: impl Fn(Range<usize>) -> A + Sync reduces values over a range of linear indices
+/// - `combine`: impl Fn(A, A) -> A combines two partial results
+///
+/// # Returns
+/// `A` result of reduction
+///
+/// # Errors
+///
+pub(crate) fn reduce_chunks<A: Send>(
+    len: usize,
+    identity: A,
+    chunk: impl Fn(Range<usize>) -> A + Sync,
+    combine: impl Fn(A, A) -> A,
+) -> A {
+    let range = |n: usize| n * REDUCTION_CHUNK..len.min((n + 1) * REDUCTION_CHUNK);
+    let chunks = len.div_ceil(REDUCTION_CHUNK);
+    let partials: Vec<A> = if len >= PARALLEL_THRESHOLD {
+        (0..chunks)
+            .into_par_iter()
+            .map(|n| chunk(range(n)))
+            .collect()
+    } else {
+        (0..chunks).map(|n| chunk(range(n))).collect()
+    };
+
+    partials.into_iter().fold(identity, combine)
+}
+
+/// implements [] operator on `ScalarField<T>`
+impl<T> Index<(usize, usize, usize)> for ScalarField<T> {
+    type Output = T;
+
+    /// returns a reference to scalar field data stored at desired index
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `idx: (usize, usize, usize)` i, j, and k indices on cartesian grid
+    ///
+    /// # Returns
+    /// `&T`
+    ///
+    /// # Errors
+    ///
+    fn index(&self, idx: (usize, usize, usize)) -> &Self::Output {
+        // destructure idx into i, j, and k components
+        let (i, j, k) = idx;
+
+        // linearly index into `ScalarField<T>` using row major ordering
+        &self.data[self.origin + k + self.r_offset * j + self.p_offset * i]
+    }
+}
+
+/// implements mutable [] operator on `ScalarField<T>`
+impl<T> IndexMut<(usize, usize, usize)> for ScalarField<T> {
+    /// returns a mutable reference to scalar field data stored at desired index
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `idx: (usize, usize, usize)` i, j, and k indices on cartesian grid
+    ///
+    /// # Returns
+    /// `&mut T`
+    ///
+    /// # Errors
+    ///
+    fn index_mut(&mut self, index: (usize, usize, usize)) -> &mut Self::Output {
+        // destructure idx into i, j, and k components
+        let (i, j, k) = index;
+
+        // linearly index into `ScalarField<T>` using row major ordering
+        &mut self.data[self.origin + k + self.r_offset * j + self.p_offset * i]
+    }
+}
+
+/// implements [] operator on `ScalarField<T>` reaching into ghost layers
+impl<T> Index<Halo> for ScalarField<T> {
+    type Output = T;
+
+    /// returns a reference to scalar field data stored at desired index, including ghost nodes
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `idx: Halo` i, j, and k indices relative to the first interior node
+    ///
+    /// # Returns
+    /// `&T`
+    ///
+    /// # Errors
+    ///
+    /// # Panics
+    /// - `idx` is beyond the ghost layers along any axis
+    ///
+    fn index(&self, idx: Halo) -> &Self::Output {
+        &self.data[self.halo_linear(idx)]
+    }
+}
+
+/// implements mutable [] operator on `ScalarField<T>` reaching into ghost layers
+impl<T> IndexMut<Halo> for ScalarField<T> {
+    /// returns a mutable reference to scalar field data stored at desired index, including ghost nodes
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `idx: Halo` i, j, and k indices relative to the first interior node
+    ///
+    /// # Returns
+    /// `&mut T`
+    ///
+    /// # Errors
+    ///
+    /// # Panics
+    /// - `idx` is beyond the ghost layers along any axis
+    ///
+    fn index_mut(&mut self, idx: Halo) -> &mut Self::Output {
+        let linear = self.halo_linear(idx);
+        &mut self.data[linear]
+    }
+}
+
+impl<T> ScalarField<T> {
+    /// checks that `ScalarField<T>` and another field share the same cells and ghost layers
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `rhs`: &ScalarField<T> rhs of an element-wise operation
+    ///
+    /// # Returns
+    /// `Result<(), anyhow::Error>`
+    ///
+    /// # Errors
+    /// - `cells` of `self` and `rhs` differ
+    /// - ghost layers of `self` and `rhs` differ
+    ///
+    pub(crate) fn check_layout(&self, rhs: &ScalarField<T>) -> Result<(), anyhow::Error> {
+        check_shapes(&self.cells, &rhs.cells)?;
+        if self.ghost != rhs.ghost {
+            return Err(anyhow!(ghost_mismatch(self.ghost, rhs.ghost)));
+        }
+
+        Ok(())
+    }
+
+    /// panics in debug builds when `ScalarField<T>` and another field do not share the same cells and ghost layers
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `rhs`: &ScalarField<T> rhs of an element-wise operation
+    ///
+    /// # Returns
+    ///
+    /// # Errors
+    ///
+    /// # Panics
+    /// - in debug builds when call to `ScalarField::check_layout()` fails
+    ///
+    #[track_caller]
+    pub(crate) fn debug_check_layout(&self, rhs: &ScalarField<T>) {
+        if cfg!(debug_assertions) {
+            if let Err(err) = self.check_layout(rhs) {
+                panic!("{err}");
+            }
+        }
+    }
+
+    /// maps a `Halo` index to a linear index in storage of `ScalarField<T>`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `idx: Halo` i, j, and k indices relative to the first interior node
+    ///
+    /// # Returns
+    /// `usize` linear index
+    ///
+    /// # Errors
+    ///
+    /// # Panics
+    /// - `idx` is beyond the ghost layers along any axis
+    ///
+    fn halo_linear(&self, idx: Halo) -> usize {
+        let Halo(i, j, k) = idx;
+        let g = self.ghost as isize;
+        let inside = |n: isize, cells: usize| -g <= n && n < cells as isize + g;
+        assert!(
+            inside(i, self.cells.x) && inside(j, self.cells.y) && inside(k, self.cells.z),
+            "index ({i}, {j}, {k}) is beyond {} ghost layers of field of {} cells",
+            self.ghost,
+            self.cells
+        );
+        let linear =
+            self.origin as isize + k + self.r_offset as isize * j + self.p_offset as isize * i;
+        linear as usize
+    }
+}
+
+/// allows `ScalarField<T>` to be written in a text format
+impl<T: Display> Display for ScalarField<T> {
+    /// writes `ScalarField<T>` in a text format
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `f: &mut Formatter<'_>` formatter for writing
+    ///
+    /// # Returns
+    /// `std::fmt::Result`
+    ///
+    /// # Errors
+    /// - call to `writeln!()` errors
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for i in 0..self.cells.x {
+            for j in 0..self.cells.y {
+                for k in 0..self.cells.z {
+                    writeln!(f, "ScalarField({}, {}, {}) = {}", i, j, k, self[(i, j, k)])?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// implements `ScalarField<T> += ScalarField<T>`
+impl<T: Copy + AddAssign> AddAssign<ScalarField<T>> for ScalarField<T> {
+    /// implements `ScalarField<T> += ScalarField<T>`
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `rhs: ScalarField<T>` rhs of operation
+    ///
+    /// # Returns
+    ///
+    /// # Errors
+    ///
+    fn add_assign(&mut self, rhs: ScalarField<T>) {
+        *self += &rhs;
+    }
+}
+
+/// implements `ScalarField<T> -= ScalarField<T>`
+impl<T: Copy + SubAssign> SubAssign<ScalarField<T>> for ScalarField<T> {
+    /// implements `ScalarField<T> -= ScalarField<T>`
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `rhs: ScalarField<T>` rhs of operation
+    ///
+    /// # Returns
+    ///
+    /// # Errors
+    ///
+    fn sub_assign(&mut self, rhs: ScalarField<T>) {
+        *self -= &rhs;
+    }
+}
+
+/// implements `ScalarField<T> *= ScalarField<T>`
+impl<T: Copy + MulAssign> MulAssign<ScalarField<T>> for ScalarField<T> {
+    /// implements `ScalarField<T> *= ScalarField<T>`
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `rhs: ScalarField<T>` rhs of operation
+    ///
+    /// # Returns
+    ///
+    /// # Errors
+    ///
+    fn mul_assign(&mut self, rhs: ScalarField<T>) {
+        *self *= &rhs;
+    }
+}
+
+/// implements `ScalarField<T> /= ScalarField<T>`
+impl<T: Copy + DivAssign> DivAssign<ScalarField<T>> for ScalarField<T> {
+    /// implements `ScalarField<T> /= ScalarField<T>`
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `rhs: ScalarField<T>` rhs of operation
+    ///
+    /// # Returns
+    ///
+    /// # Errors
+    ///
+    fn div_assign(&mut self, rhs: ScalarField<T>) {
+        *self /= &rhs;
+    }
+}
+
+/// implements `ScalarField<T> += T`
+impl<T: Copy + AddAssign> AddAssign<T> for ScalarField<T> {
+    /// implements `ScalarField<T> += T`
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `rhs: T` rhs of operation
+    ///
+    /// # Returns
+    ///
+    /// # Errors
+    ///
+    fn add_assign(&mut self, rhs: T) {
+        for elem in self.data.iter_mut() {
+            *elem += rhs;
+        }
+    }
+}
+
+/// implements `ScalarField<T> -= T`
+impl<T: Copy + SubAssign> SubAssign<T> for ScalarField<T> {
+    /// implements `ScalarField<T> -= T`
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `rhs: T` rhs of operation
+    ///
+    /// # Returns
+    ///
+    /// # Errors
+    ///
+    fn sub_assign(&mut self, rhs: T) {
+        for elem in self.data.iter_mut() {
+            *elem -= rhs;
+        }
+    }
+}
+
+/// implements `ScalarField<T> *= T`
+impl<T: Copy + MulAssign> MulAssign<T> for ScalarField<T> {
+    /// implements `ScalarField<T> *= T`
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `rhs: T` rhs of operation
+    ///
+    /// # Returns
+    ///
+    /// # Errors
+    ///
+    fn mul_assign(&mut self, rhs: T) {
+        for elem in self.data.iter_mut() {
+            *elem *= rhs;
+        }
+    }
+}
+
+/// implements `ScalarField<T> /= T`
+impl<T: Copy + DivAssign> DivAssign<T> for ScalarField<T> {
+    /// implements `ScalarField<T> /= T`
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `rhs: T` rhs of operation
+    ///
+    /// # Returns
+    ///
+    /// # Errors
+    ///
+    fn div_assign(&mut self, rhs: T) {
+        for elem in self.data.iter_mut() {
+            *elem /= rhs;
+        }
+    }
+}
+
+/// implements `ScalarField<T> += &ScalarField<T>`
+impl<T: Copy + AddAssign> AddAssign<&ScalarField<T>> for ScalarField<T> {
+    /// implements `ScalarField<T> += &ScalarField<T>`
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `rhs: &ScalarField<T>` rhs of operation
+    ///
+    /// # Returns
+    ///
+    /// # Errors
+    ///
+    /// # Panics
+    /// - in debug builds when `cells` or ghost layers of `self` and `rhs` differ, see `ScalarField::try_add_assign()`
+    ///
+    fn add_assign(&mut self, rhs: &ScalarField<T>) {
+        self.debug_check_layout(rhs);
+        for (elem, num) in self.data.iter_mut().zip(&rhs.data) {
+            *elem += *num;
+        }
+    }
+}
+
+/// implements `ScalarField<T> -= &ScalarField<T>`
+impl<T: Copy + SubAssign> SubAssign<&ScalarField<T>> for ScalarField<T> {
+    /// implements `ScalarField<T> -= &ScalarField<T>`
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `rhs: &ScalarField<T>` rhs of operation
+    ///
+    /// # Returns
+    ///
+    /// # Errors
+    ///
+    /// # Panics
+    /// - in debug builds when `cells` or ghost layers of `self` and `rhs` differ, see `ScalarField::try_sub_assign()`
+    ///
+    fn sub_assign(&mut self, rhs: &ScalarField<T>) {
+        self.debug_check_layout(rhs);
+        for (elem, num) in self.data.iter_mut().zip(&rhs.data) {
+            *elem -= *num;
+        }
+    }
+}
+
+/// implements `ScalarField<T> *= &ScalarField<T>`
+impl<T: Copy + MulAssign> MulAssign<&ScalarField<T>> for ScalarField<T> {
+    /// implements `ScalarField<T> *= &ScalarField<T>`
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `rhs: &ScalarField<T>` rhs of operation
+    ///
+    /// # Returns
+    ///
+    /// # Errors
+    ///
+    /// # Panics
+    /// - in debug builds when `cells` or ghost layers of `self` and `rhs` differ, see `ScalarField::try_mul_assign()`
+    ///
+    fn mul_assign(&mut self, rhs: &ScalarField<T>) {
+        self.debug_check_layout(rhs);
+        for (elem, num) in self.data.iter_mut().zip(&rhs.data) {
+            *elem *= *num;
+        }
+    }
+}
+
+/// implements `ScalarField<T> /= &ScalarField<T>`
+impl<T: Copy + DivAssign> DivAssign<&ScalarField<T>> for ScalarField<T> {
+    /// implements `ScalarField<T> /= &ScalarField<T>`
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `rhs: &ScalarField<T>` rhs of operation
+    ///
+    /// # Returns
+    ///
+    /// # Errors
+    ///
+    /// # Panics
+    /// - in debug builds when `cells` or ghost layers of `self` and `rhs` differ, see `ScalarField::try_div_assign()`
+    ///
+    fn div_assign(&mut self, rhs: &ScalarField<T>) {
+        self.debug_check_layout(rhs);
+        for (elem, num) in self.data.iter_mut().zip(&rhs.data) {
+            *elem /= *num;
+        }
+    }
+}
+
+/// implements `&ScalarField<T> + &ScalarField<T>`
+impl<T: Copy + AddAssign> Add<&ScalarField<T>> for &ScalarField<T> {
+    type Output = ScalarField<T>;
+
+    /// implements `&ScalarField<T> + &ScalarField<T>`
+    ///
+    /// # Arguments
+    /// - `self` reference to lhs of operation
+    /// - `rhs: &ScalarField<T>` rhs of operation
+    ///
+    /// # Returns
+    /// `ScalarField<T>` result of operation
+    ///
+    /// # Errors
+    ///
+    fn add(self, rhs: &ScalarField<T>) -> ScalarField<T> {
+        let mut field = self.clone();
+        field += rhs;
+        field
+    }
+}
+
+/// implements `&ScalarField<T> - &ScalarField<T>`
+impl<T: Copy + SubAssign> Sub<&ScalarField<T>> for &ScalarField<T> {
+    type Output = ScalarField<T>;
+
+    /// implements `&ScalarField<T> - &ScalarField<T>`
+    ///
+    /// # Arguments
+    /// - `self` reference to lhs of operation
+    /// - `rhs: &ScalarField<T>` rhs of operation
+    ///
+    /// # Returns
+    /// `ScalarField<T>` result of operation
+    ///
+    /// # Errors
+    ///
+    fn sub(self, rhs: &ScalarField<T>) -> ScalarField<T> {
+        let mut field = self.clone();
+        field -= rhs;
+        field
+    }
+}
+
+/// implements `&ScalarField<T> * &ScalarField<T>`
+impl<T: Copy + MulAssign> Mul<&ScalarField<T>> for &ScalarField<T> {
+    type Output = ScalarField<T>;
+
+    /// implements `&ScalarField<T> * &ScalarField<T>`
+    ///
+    /// # Arguments
+    /// - `self` reference to lhs of operation
+    /// - `rhs: &ScalarField<T>` rhs of operation
+    ///
+    /// # Returns
+    /// `ScalarField<T>` result of operation
+    ///
+    /// # Errors
+    ///
+    fn mul(self, rhs: &ScalarField<T>) -> ScalarField<T> {
+        let mut field = self.clone();
+        field *= rhs;
+        field
+    }
+}
+
+/// implements `&ScalarField<T> / &ScalarField<T>`
+impl<T: Copy + DivAssign> Div<&ScalarField<T>> for &ScalarField<T> {
+    type Output = ScalarField<T>;
+
+    /// implements `&ScalarField<T> / &ScalarField<T>`
+    ///
+    /// # Arguments
+    /// - `self` reference to lhs of operation
+    /// - `rhs: &ScalarField<T>` rhs of operation
+    ///
+    /// # Returns
+    /// `ScalarField<T>` result of operation
+    ///
+    /// # Errors
+    ///
+    fn div(self, rhs: &ScalarField<T>) -> ScalarField<T> {
+        let mut field = self.clone();
+        field /= rhs;
+        field
+    }
+}
+
+/// implements `ScalarField<T> + T`, reusing the storage of the lhs
+impl<T: Copy + AddAssign> Add<T> for ScalarField<T> {
+    type Output = ScalarField<T>;
+
+    /// implements `ScalarField<T> + T`
+    ///
+    /// # Arguments
+    /// - `self` lhs of operation
+    /// - `rhs: T` rhs of operation
+    ///
+    /// # Returns
+    /// `ScalarField<T>` result of operation
+    ///
+    /// # Errors
+    ///
+    fn add(mut self, rhs: T) -> ScalarField<T> {
+        self += rhs;
+        self
+    }
+}
+
+/// implements `&ScalarField<T> + T`
+impl<T: Copy + AddAssign> Add<T> for &ScalarField<T> {
+    type Output = ScalarField<T>;
+
+    /// implements `&ScalarField<T> + T`
+    ///
+    /// # Arguments
+    /// - `self` reference to lhs of operation
+    /// - `rhs: T` rhs of operation
+    ///
+    /// # Returns
+    /// `ScalarField<T>` result of operation
+    ///
+    /// # Errors
+    ///
+    fn add(self, rhs: T) -> ScalarField<T> {
+        self.clone() + rhs
+    }
+}
+
+/// implements `ScalarField<T> - T`, reusing the storage of the lhs
+impl<T: Copy + SubAssign> Sub<T> for ScalarField<T> {
+    type Output = ScalarField<T>;
+
+    /// implements `ScalarField<T> - T`
+    ///
+    /// # Arguments
+    /// - `self` lhs of operation
+    /// - `rhs: T` rhs of operation
+    ///
+    /// # Returns
+    /// `ScalarField<T>` result of operation
+    ///
+    /// # Errors
+    ///
+    fn sub(mut self, rhs: T) -> ScalarField<T> {
+        self -= rhs;
+        self
+    }
+}
+
+/// implements `&ScalarField<T> - T`
+impl<T: Copy + SubAssign> Sub<T> for &ScalarField<T> {
+    type Output = ScalarField<T>;
+
+    /// implements `&ScalarField<T> - T`
+    ///
+    /// # Arguments
+    /// - `self` reference to lhs of operation
+    /// - `rhs: T` rhs of operation
+    ///
+    /// # Returns
+    /// `ScalarField<T>` result of operation
+    ///
+    /// # Errors
+    ///
+    fn sub(self, rhs: T) -> ScalarField<T> {
+        self.clone() - rhs
+    }
+}
+
+/// implements `ScalarField<T> * T`, reusing the storage of the lhs
+impl<T: Copy + MulAssign> Mul<T> for ScalarField<T> {
+    type Output = ScalarField<T>;
+
+    /// implements `ScalarField<T> * T`
+    ///
+    /// # Arguments
+    /// - `self` lhs of operation
+    /// - `rhs: T` rhs of operation
+    ///
+    /// # Returns
+    /// `ScalarField<T>` result of operation
+    ///
+    /// # Errors
+    ///
+    fn mul(mut self, rhs: T) -> ScalarField<T> {
+        self *= rhs;
+        self
+    }
+}
+
+/// implements `&ScalarField<T> * T`
+impl<T: Copy + MulAssign> Mul<T> for &ScalarField<T> {
+    type Output = ScalarField<T>;
+
+    /// implements `&ScalarField<T> * T`
+    ///
+    /// # Arguments
+    /// - `self` reference to lhs of operation
+    /// - `rhs: T` rhs of operation
+    ///
+    /// # Returns
+    /// `ScalarField<T>` result of operation
+    ///
+    /// # Errors
+    ///
+    fn mul(self, rhs: T) -> ScalarField<T> {
+        self.clone() * rhs
+    }
+}
+
+/// implements `ScalarField<T> / T`, reusing the storage of the lhs
+impl<T: Copy + DivAssign> Div<T> for ScalarField<T> {
+    type Output = ScalarField<T>;
+
+    /// implements `ScalarField<T> / T`
+    ///
+    /// # Arguments
+    /// - `self` lhs of operation
+    /// - `rhs: T` rhs of operation
+    ///
+    /// # Returns
+    /// `ScalarField<T>` result of operation
+    ///
+    /// # Errors
+    ///
+    fn div(mut self, rhs: T) -> ScalarField<T> {
+        self /= rhs;
+        self
+    }
+}
+
+/// implements `&ScalarField<T> / T`
+impl<T: Copy + DivAssign> Div<T> for &ScalarField<T> {
+    type Output = ScalarField<T>;
+
+    /// implements `&ScalarField<T> / T`
+    ///
+    /// # Arguments
+    /// - `self` reference to lhs of operation
+    /// - `rhs: T` rhs of operation
+    ///
+    /// # Returns
+    /// `ScalarField<T>` result of operation
+    ///
+    /// # Errors
+    ///
+    fn div(self, rhs: T) -> ScalarField<T> {
+        self.clone() / rhs
+    }
+}
+
+/// implements `-ScalarField<T>`, reusing the storage of the operand
+impl<T: Copy + Neg<Output = T>> Neg for ScalarField<T> {
+    type Output = ScalarField<T>;
+
+    /// implements `-ScalarField<T>`
+    ///
+    /// # Arguments
+    /// - `self` operand
     ///
     /// # Returns
+    /// `ScalarField<T>` result of operation
     ///
     /// # Errors
     ///
-    fn mul_assign(&mut self, rhs: T) {
+    fn neg(mut self) -> ScalarField<T> {
         for elem in self.data.iter_mut() {
-            *elem *= rhs;
+            *elem = -*elem;
         }
+        self
     }
 }
 
-/// implements `ScalarField<T> /= T`
-impl<T: Copy + DivAssign> DivAssign<T> for ScalarField<T> {
-    /// implements `ScalarField<T> /= T`
+/// implements `-&ScalarField<T>`
+impl<T: Copy + Neg<Output = T>> Neg for &ScalarField<T> {
+    type Output = ScalarField<T>;
+
+    /// implements `-&ScalarField<T>`
     ///
     /// # Arguments
-    /// - `&mut self` mutable reference to self
-    /// - `rhs: T` rhs of operation
+    /// - `self` reference to operand
     ///
     /// # Returns
+    /// `ScalarField<T>` result of operation
     ///
     /// # Errors
     ///
-    fn div_assign(&mut self, rhs: T) {
-        for elem in self.data.iter_mut() {
-            *elem /= rhs;
-        }
+    fn neg(self) -> ScalarField<T> {
+        -self.clone()
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::field::scalar::ScalarField;
+    use crate::field::scalar::{GhostBoundary, Halo, ScalarField, PARALLEL_THRESHOLD};
     use crate::utils::coordinate_triplet::CoordinateTriplet;
 
     /// helper function that sets up a `ScalarField<f64>` for testing
@@ -681,6 +2338,122 @@ mod tests {
         scalar_field.iter().for_each(|num| assert_eq!(*num, 2.0));
     }
 
+    /// tests `ScalarField<T>` for correct implementation of assignment operators taking `&ScalarField<T>`
+    ///
+    /// # Errors
+    /// - `ScalarField<T>` does not implement `AddAssign<&ScalarField<T>>` correctly
+    /// - `ScalarField<T>` does not implement `SubAssign<&ScalarField<T>>` correctly
+    /// - `ScalarField<T>` does not implement `MulAssign<&ScalarField<T>>` correctly
+    /// - `ScalarField<T>` does not implement `DivAssign<&ScalarField<T>>` correctly
+    /// - rhs is modified
+    ///
+    #[test]
+    fn impl_assign_ref_scalar_field() {
+        // setup
+        let mut scalar_field1: ScalarField<f64> = setup().unwrap();
+        scalar_field1 += 1.0;
+        let mut scalar_field2: ScalarField<f64> = setup().unwrap();
+        scalar_field2 += 4.0;
+
+        scalar_field1 += &scalar_field2;
+        scalar_field1 *= &scalar_field2;
+        scalar_field1 -= &scalar_field2;
+        scalar_field1 /= &scalar_field2;
+
+        // assertions
+        scalar_field1.iter().for_each(|num| assert_eq!(*num, 4.0));
+        scalar_field2.iter().for_each(|num| assert_eq!(*num, 4.0));
+    }
+
+    /// tests `ScalarField<T>` for correct implementation of non-assigning operators between references
+    ///
+    /// # Errors
+    /// - `&ScalarField<T>` does not implement `Add<&ScalarField<T>>` correctly
+    /// - `&ScalarField<T>` does not implement `Sub<&ScalarField<T>>` correctly
+    /// - `&ScalarField<T>` does not implement `Mul<&ScalarField<T>>` correctly
+    /// - `&ScalarField<T>` does not implement `Div<&ScalarField<T>>` correctly
+    /// - any operand is modified
+    ///
+    #[test]
+    fn impl_ops_ref_scalar_field() {
+        // setup
+        let mut scalar_field1: ScalarField<f64> = setup().unwrap();
+        scalar_field1 += 6.0;
+        let mut scalar_field2: ScalarField<f64> = setup().unwrap();
+        scalar_field2 += 2.0;
+
+        // assertions
+        (&scalar_field1 + &scalar_field2)
+            .iter()
+            .for_each(|num| assert_eq!(*num, 8.0));
+        (&scalar_field1 - &scalar_field2)
+            .iter()
+            .for_each(|num| assert_eq!(*num, 4.0));
+        (&scalar_field1 * &scalar_field2)
+            .iter()
+            .for_each(|num| assert_eq!(*num, 12.0));
+        (&scalar_field1 / &scalar_field2)
+            .iter()
+            .for_each(|num| assert_eq!(*num, 3.0));
+        scalar_field1.iter().for_each(|num| assert_eq!(*num, 6.0));
+        scalar_field2.iter().for_each(|num| assert_eq!(*num, 2.0));
+    }
+
+    /// tests `ScalarField<T>` for correct implementation of non-assigning operators with `T` and negation
+    ///
+    /// # Errors
+    /// - `ScalarField<T>` or `&ScalarField<T>` does not implement `Add<T>`, `Sub<T>`, `Mul<T>`, or `Div<T>`
+    ///   correctly
+    /// - `ScalarField<T>` or `&ScalarField<T>` does not implement `Neg` correctly
+    /// - `cells` of result differ from operand
+    ///
+    #[test]
+    fn impl_ops_t() {
+        // setup
+        let mut scalar_field: ScalarField<f64> = setup().unwrap();
+        scalar_field += 3.0;
+
+        // assertions
+        (&scalar_field + 1.0)
+            .iter()
+            .for_each(|num| assert_eq!(*num, 4.0));
+        (&scalar_field - 1.0)
+            .iter()
+            .for_each(|num| assert_eq!(*num, 2.0));
+        (&scalar_field * 2.0)
+            .iter()
+            .for_each(|num| assert_eq!(*num, 6.0));
+        (&scalar_field / 2.0)
+            .iter()
+            .for_each(|num| assert_eq!(*num, 1.5));
+        (-&scalar_field)
+            .iter()
+            .for_each(|num| assert_eq!(*num, -3.0));
+
+        let result = -(((scalar_field + 1.0) - 2.0) * 4.0 / 2.0);
+        assert_eq!(result.cells, CoordinateTriplet::new(2, 4, 6).unwrap());
+        result.iter().for_each(|num| assert_eq!(*num, -4.0));
+    }
+
+    /// tests `ScalarField::axpy()` for correctness
+    ///
+    /// # Errors
+    /// - `ScalarField::axpy()` does not add the multiple of the rhs
+    ///
+    #[test]
+    fn axpy_correct() {
+        // setup
+        let mut scalar_field1: ScalarField<f64> = setup().unwrap();
+        scalar_field1 += 1.0;
+        let mut scalar_field2: ScalarField<f64> = setup().unwrap();
+        scalar_field2 += 2.0;
+
+        scalar_field1.axpy(-3.0, &scalar_field2);
+
+        // assertions
+        scalar_field1.iter().for_each(|num| assert_eq!(*num, -5.0));
+    }
+
     /// tests `ScalarField<T>` for correct implementation of `ScalarField<T>::ijk_from_linear()`
     ///
     /// # Errors
@@ -702,4 +2475,448 @@ mod tests {
             }
         }
     }
+
+    /// tests `ScalarField<f64>::write_binary()` and `ScalarField<f64>::read_binary()` for a lossless round trip
+    ///
+    /// # Errors
+    /// - `ScalarField<f64>::write_binary()` writes incorrect number of bytes
+    /// - `ScalarField<f64>::read_binary()` does not reproduce written data
+    ///
+    #[test]
+    fn binary_round_trip() {
+        // setup
+        let mut scalar_field1: ScalarField<f64> = setup().unwrap();
+        scalar_field1
+            .iter_mut()
+            .enumerate()
+            .for_each(|(i, num)| *num = i as f64 / 3.0);
+        let mut scalar_field2: ScalarField<f64> = setup().unwrap();
+
+        let mut buf: Vec<u8> = Vec::new();
+        scalar_field1.write_binary(&mut buf).unwrap();
+        scalar_field2.read_binary(&mut buf.as_slice()).unwrap();
+
+        // assertions
+        assert_eq!(buf.len(), 48 * 8);
+        assert_eq!(scalar_field1, scalar_field2);
+    }
+
+    /// tests shape-checked operations on `ScalarField<T>` for correctness on matching shapes
+    ///
+    /// # Errors
+    /// - any shape-checked operation fails on matching shapes
+    /// - any shape-checked operation is not equivalent to its operator
+    ///
+    #[test]
+    fn try_ops_correct() {
+        // setup
+        let mut scalar_field1: ScalarField<f64> = setup().unwrap();
+        scalar_field1 += 1.0;
+        let mut scalar_field2: ScalarField<f64> = setup().unwrap();
+        scalar_field2 += 2.0;
+
+        // assertions
+        assert!(scalar_field1.try_add_assign(&scalar_field2).is_ok());
+        assert!(scalar_field1.try_mul_assign(&scalar_field2).is_ok());
+        assert!(scalar_field1.try_sub_assign(&scalar_field2).is_ok());
+        assert!(scalar_field1.try_div_assign(&scalar_field2).is_ok());
+        assert!(scalar_field1.try_axpy(0.5, &scalar_field2).is_ok());
+        scalar_field1.iter().for_each(|num| assert_eq!(*num, 3.0));
+    }
+
+    /// tests shape-checked operations on `ScalarField<T>` for failure on mismatched shapes
+    ///
+    /// # Errors
+    /// - any shape-checked operation succeeds on mismatched shapes
+    /// - error does not name both shapes
+    /// - lhs is modified
+    ///
+    #[test]
+    fn try_ops_mismatch() {
+        // setup
+        let mut scalar_field1: ScalarField<f64> = setup().unwrap();
+        scalar_field1 += 1.0;
+        let cells = CoordinateTriplet::new(2, 4, 5).unwrap();
+        let scalar_field2: ScalarField<f64> = ScalarField::new(&cells).unwrap();
+
+        let result = scalar_field1.try_add_assign(&scalar_field2);
+
+        // assertions
+        let message = result.unwrap_err().to_string();
+        assert!(message.contains("(2, 4, 6)"));
+        assert!(message.contains("(2, 4, 5)"));
+        assert!(scalar_field1.try_sub_assign(&scalar_field2).is_err());
+        assert!(scalar_field1.try_mul_assign(&scalar_field2).is_err());
+        assert!(scalar_field1.try_div_assign(&scalar_field2).is_err());
+        assert!(scalar_field1.try_axpy(1.0, &scalar_field2).is_err());
+        scalar_field1.iter().for_each(|num| assert_eq!(*num, 1.0));
+    }
+
+    /// tests `ScalarField<T>` operators for a panic on mismatched shapes in debug builds
+    ///
+    /// # Errors
+    /// - `ScalarField<T> += &ScalarField<T>` silently truncates mismatched shapes
+    ///
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "field shape mismatch")]
+    fn impl_ops_mismatch_panic() {
+        // setup
+        let mut scalar_field1: ScalarField<f64> = setup().unwrap();
+        let cells = CoordinateTriplet::new(2, 4, 5).unwrap();
+        let scalar_field2: ScalarField<f64> = ScalarField::new(&cells).unwrap();
+
+        // assertions
+        scalar_field1 += &scalar_field2;
+    }
+
+    /// tests reductions of `ScalarField<f64>` for correctness
+    ///
+    /// # Errors
+    /// - `ScalarField::min()`, `ScalarField::max()`, `ScalarField::sum()`, or `ScalarField::mean()` is incorrect
+    /// - `ScalarField::norm_l1()`, `ScalarField::norm_l2()`, or `ScalarField::norm_linf()` is incorrect
+    /// - `ScalarField::argmax()` or `ScalarField::argmin()` is incorrect or not the first on ties
+    ///
+    #[test]
+    fn reductions_correct() {
+        // setup
+        let mut scalar_field: ScalarField<f64> = setup().unwrap();
+        scalar_field += 1.0;
+        scalar_field[(1, 2, 3)] = 4.0;
+        scalar_field[(1, 3, 0)] = 4.0;
+        scalar_field[(0, 1, 5)] = -6.0;
+
+        // assertions
+        assert_eq!(scalar_field.min(), -6.0);
+        assert_eq!(scalar_field.max(), 4.0);
+        assert_eq!(scalar_field.sum(), 47.0);
+        assert_eq!(scalar_field.mean(), 47.0 / 48.0);
+        assert_eq!(scalar_field.norm_l1(), 59.0);
+        assert_eq!(scalar_field.norm_l2(), 113.0_f64.sqrt());
+        assert_eq!(scalar_field.norm_linf(), 6.0);
+        assert_eq!(scalar_field.argmax(), (1, 2, 3));
+        assert_eq!(scalar_field.argmin(), (0, 1, 5));
+    }
+
+    /// tests `ScalarField::integral()` for correctness
+    ///
+    /// # Errors
+    /// - `ScalarField::integral()` does not weight values by control volumes
+    /// - `ScalarField::try_integral()` succeeds for mismatched control volumes
+    ///
+    #[test]
+    fn integral_correct() {
+        // setup
+        let mut scalar_field: ScalarField<f64> = setup().unwrap();
+        scalar_field += 3.0;
+        let mut volumes: ScalarField<f64> = setup().unwrap();
+        volumes += 0.5;
+        volumes[(0, 0, 0)] = 2.0;
+        let cells = CoordinateTriplet::new(2, 4, 5).unwrap();
+        let mismatched: ScalarField<f64> = ScalarField::new(&cells).unwrap();
+
+        // assertions
+        assert_eq!(scalar_field.integral(&volumes), 3.0 * (47.0 * 0.5 + 2.0));
+        assert_eq!(scalar_field.try_integral(&volumes).unwrap(), 76.5);
+        assert!(scalar_field.try_integral(&mismatched).is_err());
+    }
+
+    /// tests `ScalarField::statistics()` for consistency with individual reductions on a field large enough to be
+    /// reduced in parallel
+    ///
+    /// # Errors
+    /// - any member of `Statistics` differs from its individual reduction
+    /// - parallel reductions differ from their exact values
+    ///
+    #[test]
+    fn statistics_parallel() {
+        // setup
+        let cells = CoordinateTriplet::new(40, 40, 40).unwrap();
+        assert!(cells.x * cells.y * cells.z >= PARALLEL_THRESHOLD);
+        let mut scalar_field: ScalarField<f64> = ScalarField::new(&cells).unwrap();
+        scalar_field
+            .iter_mut()
+            .enumerate()
+            .for_each(|(n, v)| *v = n as f64 - 32000.0);
+
+        let statistics = scalar_field.statistics();
+
+        // assertions
+        assert_eq!(statistics.min, scalar_field.min());
+        assert_eq!(statistics.max, scalar_field.max());
+        assert_eq!(statistics.sum, scalar_field.sum());
+        assert_eq!(statistics.mean, scalar_field.mean());
+        assert_eq!(statistics.norm_l1, scalar_field.norm_l1());
+        assert_eq!(statistics.norm_l2, scalar_field.norm_l2());
+        assert_eq!(statistics.norm_linf, scalar_field.norm_linf());
+        assert_eq!(statistics.min, -32000.0);
+        assert_eq!(statistics.max, 31999.0);
+        assert_eq!(statistics.sum, -32000.0);
+        assert_eq!(statistics.norm_linf, 32000.0);
+        assert_eq!(scalar_field.argmax(), (39, 39, 39));
+        assert_eq!(scalar_field.argmin(), (0, 0, 0));
+    }
+
+    /// helper function that sets up a `ScalarField<f64>` with ghost layers from a function of interior indices
+    ///
+    /// # Arguments
+    /// - `cells`: (usize, usize, usize) number of interior cells
+    /// - `ghost`: usize width of ghost layers
+    /// - `f`: impl Fn(usize, usize, usize) -> f64 value of every interior node
+    ///
+    /// # Returns
+    /// `ScalarField<f64>`
+    ///
+    /// # Errors
+    ///
+    fn setup_ghost(
+        cells: (usize, usize, usize),
+        ghost: usize,
+        f: impl Fn(usize, usize, usize) -> f64,
+    ) -> ScalarField<f64> {
+        let cells = CoordinateTriplet::new(cells.0, cells.1, cells.2).unwrap();
+        let mut scalar_field: ScalarField<f64> = ScalarField::with_ghost(&cells, ghost).unwrap();
+        for i in 0..cells.x {
+            for j in 0..cells.y {
+                for k in 0..cells.z {
+                    scalar_field[(i, j, k)] = f(i, j, k);
+                }
+            }
+        }
+        scalar_field
+    }
+
+    /// tests `ScalarField::with_ghost()` for correct separation of interior and ghost nodes
+    ///
+    /// # Errors
+    /// - [] operator does not address interior nodes through both index types
+    /// - iteration, reductions, or `ScalarField::ijk_from_linear()` include ghost nodes
+    /// - storage does not include ghost layers
+    ///
+    #[test]
+    fn with_ghost_correct() {
+        // setup
+        let mut scalar_field = setup_ghost((3, 4, 5), 2, |i, j, k| (20 * i + 5 * j + k) as f64);
+
+        scalar_field[Halo(-2, -2, -2)] = 1e6;
+        scalar_field[Halo(4, 5, 6)] = 1e6;
+
+        // assertions
+        assert_eq!(scalar_field.ghost(), 2);
+        assert_eq!(scalar_field.as_slice().len(), 7 * 8 * 9);
+        assert_eq!(scalar_field[(2, 1, 3)], scalar_field[Halo(2, 1, 3)]);
+        assert_eq!(scalar_field[Halo(-2, -2, -2)], 1e6);
+        for (n, v) in scalar_field.iter().enumerate() {
+            assert_eq!(*v, n as f64);
+        }
+        assert_eq!(scalar_field.sum(), 1770.0);
+        assert_eq!(scalar_field.max(), 59.0);
+        assert_eq!(scalar_field.argmax(), (2, 3, 4));
+        assert_eq!(scalar_field.ijk_from_linear(27), (1, 1, 2));
+    }
+
+    /// tests `ScalarField::iter()` and `ScalarField::iter_mut()` for equal order of interior nodes with and without
+    /// ghost layers
+    ///
+    /// # Errors
+    /// - interior nodes of a field without ghost layers are not visited in the order of a field with ghost layers
+    /// - mutable iteration without ghost layers does not visit every interior node once
+    ///
+    #[test]
+    fn iter_ghost_free() {
+        // setup
+        let index = |i: usize, j: usize, k: usize| (20 * i + 5 * j + k) as f64;
+        let mut ghost_free = setup_ghost((3, 4, 5), 0, index);
+        let ghosted = setup_ghost((3, 4, 5), 2, index);
+        ghost_free.iter_mut().for_each(|value| *value += 1.0);
+
+        // assertions
+        assert!(ghost_free
+            .iter()
+            .zip(ghosted.iter())
+            .all(|(a, b)| *a == b + 1.0));
+        assert_eq!(ghost_free.iter().count(), ghosted.iter().count());
+    }
+
+    /// tests `ScalarField::fill_all_ghosts()` for exact reproduction of linear fields including edges and corners
+    ///
+    /// # Errors
+    /// - Dirichlet ghost nodes are not odd reflections about the boundary value
+    /// - Neumann ghost nodes are not even reflections offset by the boundary slope
+    /// - periodic ghost nodes do not wrap around
+    /// - edge or corner ghost nodes are not filled
+    ///
+    #[test]
+    fn fill_all_ghosts_linear() {
+        // setup
+        let mut along_x = setup_ghost((3, 4, 5), 2, |i, _, _| 2.0 * i as f64 + 1.0);
+        let mut along_z = setup_ghost((3, 4, 5), 2, |_, _, k| k as f64);
+
+        along_x
+            .fill_all_ghosts(&CoordinateTriplet {
+                x: [GhostBoundary::Dirichlet(1.0), GhostBoundary::Dirichlet(5.0)],
+                y: [GhostBoundary::Neumann(0.0); 2],
+                z: [GhostBoundary::Periodic; 2],
+            })
+            .unwrap();
+        along_z
+            .fill_all_ghosts(&CoordinateTriplet {
+                x: [GhostBoundary::Periodic; 2],
+                y: [GhostBoundary::Dirichlet(0.0); 2],
+                z: [GhostBoundary::Neumann(-1.0), GhostBoundary::Neumann(1.0)],
+            })
+            .unwrap();
+
+        // assertions
+        for i in -2..5 {
+            for j in -2..6 {
+                for k in -2..7 {
+                    assert_eq!(along_x[Halo(i, j, k)], 2.0 * i as f64 + 1.0);
+                }
+            }
+        }
+        for i in -2..5 {
+            for k in -2..7 {
+                assert_eq!(along_z[Halo(i, 1, k)], k as f64);
+            }
+            for k in 0..5 {
+                assert_eq!(along_z[Halo(i, -1, k)], -(k as f64));
+            }
+        }
+    }
+
+    /// tests `ScalarField::fill_ghosts()` for correct wrapping of periodic ghost nodes
+    ///
+    /// # Errors
+    /// - periodic ghost nodes do not hold the interior node one spacing from the opposite boundary node
+    ///
+    #[test]
+    fn fill_ghosts_periodic() {
+        // setup
+        let mut scalar_field = setup_ghost((4, 3, 2), 1, |i, j, k| (100 * i + 10 * j + k) as f64);
+
+        scalar_field
+            .fill_ghosts(0, 0, GhostBoundary::Periodic)
+            .unwrap();
+        scalar_field
+            .fill_ghosts(0, 1, GhostBoundary::Periodic)
+            .unwrap();
+
+        // assertions
+        for j in 0..3 {
+            for k in 0..2 {
+                assert_eq!(scalar_field[Halo(-1, j, k)], scalar_field[Halo(2, j, k)]);
+                assert_eq!(scalar_field[Halo(4, j, k)], scalar_field[Halo(1, j, k)]);
+            }
+        }
+    }
+
+    /// tests `ScalarField::exchange_ghosts()` for consistency with a field spanning both sub-domains
+    ///
+    /// # Errors
+    /// - ghost nodes of either sub-domain differ from the corresponding nodes of the spanning field
+    ///
+    #[test]
+    fn exchange_ghosts_correct() {
+        // setup
+        let global = |i: usize, j: usize, k: usize| (100 * i + 10 * j + k) as f64;
+        let mut lower = setup_ghost((3, 2, 4), 2, global);
+        let mut upper = setup_ghost((4, 2, 4), 2, |i, j, k| global(i + 2, j, k));
+
+        lower.exchange_ghosts(0, 1, &upper).unwrap();
+        upper.exchange_ghosts(0, 0, &lower).unwrap();
+
+        // assertions
+        for j in 0..2 {
+            for k in 0..4 {
+                assert_eq!(lower[Halo(3, j as isize, k as isize)], global(3, j, k));
+                assert_eq!(lower[Halo(4, j as isize, k as isize)], global(4, j, k));
+                assert_eq!(upper[Halo(-1, j as isize, k as isize)], global(1, j, k));
+                assert_eq!(upper[Halo(-2, j as isize, k as isize)], global(0, j, k));
+            }
+        }
+    }
+
+    /// tests ghost layers of `ScalarField<T>` for failure
+    ///
+    /// # Errors
+    /// - `ScalarField::fill_ghosts()` succeeds for an invalid boundary or ghost layers wider than the interior
+    /// - `ScalarField::exchange_ghosts()` succeeds for mismatched ghost layers or faces
+    /// - shape-checked operations succeed for mismatched ghost layers
+    ///
+    #[test]
+    fn ghost_failure() {
+        // setup
+        let mut scalar_field = setup_ghost((2, 4, 4), 1, |_, _, _| 1.0);
+        let narrow = setup_ghost((2, 4, 4), 2, |_, _, _| 1.0);
+        let face = setup_ghost((2, 4, 3), 1, |_, _, _| 1.0);
+        let plain = setup_ghost((2, 4, 4), 0, |_, _, _| 1.0);
+
+        // assertions
+        assert!(scalar_field
+            .fill_ghosts(3, 0, GhostBoundary::Periodic)
+            .is_err());
+        assert!(scalar_field
+            .fill_ghosts(0, 2, GhostBoundary::Periodic)
+            .is_err());
+        assert!(scalar_field
+            .fill_ghosts(0, 0, GhostBoundary::Periodic)
+            .is_ok());
+        assert!(narrow
+            .clone()
+            .fill_ghosts(0, 0, GhostBoundary::Periodic)
+            .is_err());
+        assert!(scalar_field.exchange_ghosts(0, 1, &narrow).is_err());
+        assert!(scalar_field.exchange_ghosts(0, 1, &face).is_err());
+        assert!(scalar_field.exchange_ghosts(2, 1, &face).is_ok());
+        let message = scalar_field.try_add_assign(&plain).unwrap_err().to_string();
+        assert!(message.contains("ghost layers"));
+    }
+
+    /// tests `ScalarField::row()`, `ScalarField::row_mut()`, and `ScalarField::stencil_rows_mut()` for correctness
+    /// with and without ghost layers
+    ///
+    /// # Errors
+    /// - any row does not hold the interior nodes along k of its (i, j) index
+    /// - writes through a mutable row do not reach the field
+    /// - neighbouring rows are not (i - 1, j), (i + 1, j), (i, j - 1), and (i, j + 1)
+    ///
+    #[test]
+    fn rows_correct() {
+        for ghost in [0, 2] {
+            // setup
+            let value = |i: usize, j: usize, k: usize| (100 * i + 10 * j + k) as f64;
+            let mut scalar_field = setup_ghost((3, 4, 5), ghost, value);
+            let expected =
+                |i: usize, j: usize| (0..5).map(|k| value(i, j, k)).collect::<Vec<f64>>();
+
+            // assertions
+            assert_eq!(scalar_field.row(2, 3), expected(2, 3).as_slice());
+            scalar_field.row_mut(0, 1)[4] = -1.0;
+            assert_eq!(scalar_field[(0, 1, 4)], -1.0);
+
+            let (row, [west, east, south, north]) = scalar_field.stencil_rows_mut(1, 2);
+            assert_eq!(west, expected(0, 2).as_slice());
+            assert_eq!(east, expected(2, 2).as_slice());
+            assert_eq!(south, expected(1, 1).as_slice());
+            assert_eq!(north, expected(1, 3).as_slice());
+            row[0] = -2.0;
+            assert_eq!(scalar_field[(1, 2, 0)], -2.0);
+        }
+    }
+
+    /// tests `ScalarField::stencil_rows_mut()` for panic on a row without neighbouring rows
+    ///
+    /// # Errors
+    /// - `ScalarField::stencil_rows_mut()` does not panic for a boundary row
+    ///
+    #[test]
+    #[should_panic(expected = "has no neighbouring rows")]
+    fn stencil_rows_boundary_panic() {
+        // setup
+        let mut scalar_field = setup_ghost((3, 4, 5), 1, |_, _, _| 0.0);
+
+        // assertions
+        scalar_field.stencil_rows_mut(0, 1);
+    }
 }
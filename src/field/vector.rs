@@ -1,13 +1,18 @@
-use crate::field::scalar::ScalarField;
+use crate::field::scalar::{
+    shape_mismatch, GhostBoundary, ScalarField, Statistics, PARALLEL_THRESHOLD,
+};
+use crate::precision::Real;
 use crate::utils::coordinate_triplet::CoordinateTriplet;
 use num::Num;
+use rayon::prelude::*;
 use std::fmt::{Display, Formatter};
-use std::ops::{AddAssign, DivAssign, MulAssign, SubAssign};
+use std::io::{Read, Write};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
 /// `VectorField<T>` struct
 ///
 /// describes a vector field
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct VectorField<T> {
     /// number of cells in vector field
     cells: CoordinateTriplet<usize>,
@@ -34,414 +39,1311 @@ impl<T: Num + Copy> VectorField<T> {
     /// # Errors
     /// - any call to `ScalarField::new()` errors
     pub fn new(cells: &CoordinateTriplet<usize>) -> Result<VectorField<T>, anyhow::Error> {
+        VectorField::with_ghost(cells, 0)
+    }
+
+    /// `VectorField<T>` constructor surrounding interior nodes of every component with ghost layers
+    ///
+    /// # Arguments
+    /// - `cells: &CoordinateTriplet<usize>` number of interior cells in bounding box
+    /// - `ghost: usize` width of ghost layers on every side
+    ///
+    /// # Returns
+    /// `Result<VectorField<T>, anyhow::Error>`
+    ///
+    /// # Errors
+    /// - any call to `ScalarField::with_ghost()` errors
+    pub fn with_ghost(
+        cells: &CoordinateTriplet<usize>,
+        ghost: usize,
+    ) -> Result<VectorField<T>, anyhow::Error> {
         // clone cells
         let cells = cells.clone();
 
         // create subfields
-        let x = ScalarField::new(&cells)?;
-        let y = ScalarField::new(&cells)?;
-        let z = ScalarField::new(&cells)?;
+        let x = ScalarField::with_ghost(&cells, ghost)?;
+        let y = ScalarField::with_ghost(&cells, ghost)?;
+        let z = ScalarField::with_ghost(&cells, ghost)?;
 
         Ok(VectorField { cells, x, y, z })
     }
-}
 
-/// allows `VectorField<T>` to be written in a text format
-impl<T: Display> Display for VectorField<T> {
-    /// writes `VectorField<T>` in a text format
+    /// returns number of cells of `VectorField<T>`
     ///
     /// # Arguments
     /// - `&self` reference to self
-    /// - `f: &mut Formatter<'_>` formatter for writing
     ///
     /// # Returns
-    /// `std::fmt::Result`
+    /// `&CoordinateTriplet<usize>` number of cells
     ///
     /// # Errors
-    /// - call to `write!()` errors
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        for i in 0..self.cells.x {
-            for j in 0..self.cells.y {
-                for k in 0..self.cells.z {
-                    write!(
-                        f,
-                        "VectorField({}, {}, {}) = [{}, {}, {}]\n",
-                        i,
-                        j,
-                        k,
-                        self.x[(i, j, k)],
-                        self.y[(i, j, k)],
-                        self.z[(i, j, k)],
-                    )?;
-                }
-            }
-        }
+    ///
+    pub fn cells(&self) -> &CoordinateTriplet<usize> {
+        &self.cells
+    }
+
+    /// adds a multiple of another `VectorField<T>` to `VectorField<T>` in a single pass without allocating
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `alpha: T` multiple of `x` to add
+    /// - `x: &VectorField<T>` field to add
+    ///
+    /// # Returns
+    ///
+    /// # Errors
+    ///
+    /// # Panics
+    /// - in debug builds when `cells` of `self` and `x` differ, see `VectorField::try_axpy()`
+    ///
+    pub fn axpy(&mut self, alpha: T, x: &VectorField<T>) {
+        debug_assert!(
+            self.cells == x.cells,
+            "{}",
+            shape_mismatch(&self.cells, &x.cells)
+        );
+        self.x.axpy(alpha, &x.x);
+        self.y.axpy(alpha, &x.y);
+        self.z.axpy(alpha, &x.z);
+    }
+
+    /// shape-checked `VectorField<T> += &VectorField<T>`
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `rhs: &VectorField<T>` rhs of operation
+    ///
+    /// # Returns
+    /// `Result<(), anyhow::Error>`
+    ///
+    /// # Errors
+    /// - `cells` or ghost layers of `self` and `rhs` differ, in which case `self` is left unmodified
+    ///
+    pub fn try_add_assign(&mut self, rhs: &VectorField<T>) -> Result<(), anyhow::Error>
+    where
+        T: AddAssign,
+    {
+        self.check_layout(rhs)?;
+        *self += rhs;
         Ok(())
     }
-}
 
-/// implements `VectorField<T> += VectorField<T>`
-impl<T: Copy + AddAssign + Num> AddAssign<VectorField<T>> for VectorField<T> {
-    /// implements `VectorField<T> += VectorField<T>`
+    /// shape-checked `VectorField<T> += &ScalarField<T>`
     ///
     /// # Arguments
     /// - `&mut self` mutable reference to self
-    /// - `rhs: VectorField<T>` rhs of operation
+    /// - `rhs: &ScalarField<T>` rhs of operation applied to every component
     ///
     /// # Returns
+    /// `Result<(), anyhow::Error>`
     ///
     /// # Errors
+    /// - `cells` or ghost layers of `self` and `rhs` differ, in which case `self` is left unmodified
+    ///
+    pub fn try_add_assign_scalar_field(&mut self, rhs: &ScalarField<T>) -> Result<(), anyhow::Error>
+    where
+        T: AddAssign,
+    {
+        self.check_component_layout(rhs)?;
+        *self += rhs;
+        Ok(())
+    }
+
+    /// shape-checked `VectorField<T> -= &VectorField<T>`
     ///
-    fn add_assign(&mut self, rhs: VectorField<T>) {
-        // x component
-        for (elem, num) in self.x.iter_mut().zip(rhs.x.iter()) {
-            *elem += *num;
-        }
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `rhs: &VectorField<T>` rhs of operation
+    ///
+    /// # Returns
+    /// `Result<(), anyhow::Error>`
+    ///
+    /// # Errors
+    /// - `cells` or ghost layers of `self` and `rhs` differ, in which case `self` is left unmodified
+    ///
+    pub fn try_sub_assign(&mut self, rhs: &VectorField<T>) -> Result<(), anyhow::Error>
+    where
+        T: SubAssign,
+    {
+        self.check_layout(rhs)?;
+        *self -= rhs;
+        Ok(())
+    }
 
-        // y component
-        for (elem, num) in self.y.iter_mut().zip(rhs.y.iter()) {
-            *elem += *num;
-        }
+    /// shape-checked `VectorField<T> -= &ScalarField<T>`
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `rhs: &ScalarField<T>` rhs of operation applied to every component
+    ///
+    /// # Returns
+    /// `Result<(), anyhow::Error>`
+    ///
+    /// # Errors
+    /// - `cells` or ghost layers of `self` and `rhs` differ, in which case `self` is left unmodified
+    ///
+    pub fn try_sub_assign_scalar_field(&mut self, rhs: &ScalarField<T>) -> Result<(), anyhow::Error>
+    where
+        T: SubAssign,
+    {
+        self.check_component_layout(rhs)?;
+        *self -= rhs;
+        Ok(())
+    }
 
-        // z component
-        for (elem, num) in self.z.iter_mut().zip(rhs.z.iter()) {
-            *elem += *num;
-        }
+    /// shape-checked `VectorField<T> *= &VectorField<T>`
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `rhs: &VectorField<T>` rhs of operation
+    ///
+    /// # Returns
+    /// `Result<(), anyhow::Error>`
+    ///
+    /// # Errors
+    /// - `cells` or ghost layers of `self` and `rhs` differ, in which case `self` is left unmodified
+    ///
+    pub fn try_mul_assign(&mut self, rhs: &VectorField<T>) -> Result<(), anyhow::Error>
+    where
+        T: MulAssign,
+    {
+        self.check_layout(rhs)?;
+        *self *= rhs;
+        Ok(())
     }
-}
 
-/// implements `VectorField<T> -= VectorField<T>`
-impl<T: Copy + SubAssign + Num> SubAssign<VectorField<T>> for VectorField<T> {
-    /// implements `VectorField<T> -= VectorField<T>`
+    /// shape-checked `VectorField<T> *= &ScalarField<T>`
     ///
     /// # Arguments
     /// - `&mut self` mutable reference to self
-    /// - `rhs: VectorField<T>` rhs of operation
+    /// - `rhs: &ScalarField<T>` rhs of operation applied to every component
     ///
     /// # Returns
+    /// `Result<(), anyhow::Error>`
     ///
     /// # Errors
+    /// - `cells` or ghost layers of `self` and `rhs` differ, in which case `self` is left unmodified
+    ///
+    pub fn try_mul_assign_scalar_field(&mut self, rhs: &ScalarField<T>) -> Result<(), anyhow::Error>
+    where
+        T: MulAssign,
+    {
+        self.check_component_layout(rhs)?;
+        *self *= rhs;
+        Ok(())
+    }
+
+    /// shape-checked `VectorField<T> /= &VectorField<T>`
     ///
-    fn sub_assign(&mut self, rhs: VectorField<T>) {
-        // x component
-        for (elem, num) in self.x.iter_mut().zip(rhs.x.iter()) {
-            *elem -= *num;
-        }
-        // y component
-        for (elem, num) in self.y.iter_mut().zip(rhs.y.iter()) {
-            *elem -= *num;
-        }
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `rhs: &VectorField<T>` rhs of operation
+    ///
+    /// # Returns
+    /// `Result<(), anyhow::Error>`
+    ///
+    /// # Errors
+    /// - `cells` or ghost layers of `self` and `rhs` differ, in which case `self` is left unmodified
+    ///
+    pub fn try_div_assign(&mut self, rhs: &VectorField<T>) -> Result<(), anyhow::Error>
+    where
+        T: DivAssign,
+    {
+        self.check_layout(rhs)?;
+        *self /= rhs;
+        Ok(())
+    }
 
-        // z component
-        for (elem, num) in self.z.iter_mut().zip(rhs.z.iter()) {
-            *elem -= *num;
-        }
+    /// shape-checked `VectorField<T> /= &ScalarField<T>`
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `rhs: &ScalarField<T>` rhs of operation applied to every component
+    ///
+    /// # Returns
+    /// `Result<(), anyhow::Error>`
+    ///
+    /// # Errors
+    /// - `cells` or ghost layers of `self` and `rhs` differ, in which case `self` is left unmodified
+    ///
+    pub fn try_div_assign_scalar_field(&mut self, rhs: &ScalarField<T>) -> Result<(), anyhow::Error>
+    where
+        T: DivAssign,
+    {
+        self.check_component_layout(rhs)?;
+        *self /= rhs;
+        Ok(())
     }
-}
 
-/// implements `VectorField<T> *= VectorField<T>`
-impl<T: Copy + MulAssign + Num> MulAssign<VectorField<T>> for VectorField<T> {
-    /// implements `VectorField<T> *= VectorField<T>`
+    /// shape-checked `VectorField::axpy()`
     ///
     /// # Arguments
     /// - `&mut self` mutable reference to self
-    /// - `rhs: VectorField<T>` rhs of operation
+    /// - `alpha: T` multiple of `x` to add
+    /// - `x: &VectorField<T>` field to add
     ///
     /// # Returns
+    /// `Result<(), anyhow::Error>`
     ///
     /// # Errors
+    /// - `cells` or ghost layers of `self` and `x` differ, in which case `self` is left unmodified
     ///
-    fn mul_assign(&mut self, rhs: VectorField<T>) {
-        // x component
-        for (elem, num) in self.x.iter_mut().zip(rhs.x.iter()) {
-            *elem *= *num;
-        }
+    pub fn try_axpy(&mut self, alpha: T, x: &VectorField<T>) -> Result<(), anyhow::Error> {
+        self.check_layout(x)?;
+        self.axpy(alpha, x);
+        Ok(())
+    }
 
-        // y component
-        for (elem, num) in self.y.iter_mut().zip(rhs.y.iter()) {
-            *elem *= *num;
-        }
+    /// returns width of ghost layers of `VectorField<T>`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `usize` width of ghost layers of the x component on every side
+    ///
+    /// # Errors
+    ///
+    pub fn ghost(&self) -> usize {
+        self.x.ghost()
+    }
 
-        // z component
-        for (elem, num) in self.z.iter_mut().zip(rhs.z.iter()) {
-            *elem *= *num;
-        }
+    /// fills the ghost layers beyond one boundary node of an axis of every component of `VectorField<T>`
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `axis`: usize axis normal to the boundary, 0 for x, 1 for y, and 2 for z
+    /// - `side`: usize 0 for the lower and 1 for the upper boundary node
+    /// - `boundary`: [GhostBoundary<T>; 3] rules of filling ghost nodes of x, y, and z component
+    ///
+    /// # Returns
+    /// `Result<(), anyhow::Error>`
+    ///
+    /// # Errors
+    /// - any call to `ScalarField::fill_ghosts()` fails
+    ///
+    pub fn fill_ghosts(
+        &mut self,
+        axis: usize,
+        side: usize,
+        boundary: [GhostBoundary<T>; 3],
+    ) -> Result<(), anyhow::Error> {
+        self.x.fill_ghosts(axis, side, boundary[0])?;
+        self.y.fill_ghosts(axis, side, boundary[1])?;
+        self.z.fill_ghosts(axis, side, boundary[2])?;
+
+        Ok(())
+    }
+
+    /// fills the ghost layers beyond one boundary node of an axis of every component of `VectorField<T>` from the
+    /// interior nodes of a neighbouring field
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `axis`: usize axis normal to the shared boundary, 0 for x, 1 for y, and 2 for z
+    /// - `side`: usize 0 for a neighbour below and 1 for a neighbour above the shared boundary node
+    /// - `neighbour`: &VectorField<T> neighbouring field
+    ///
+    /// # Returns
+    /// `Result<(), anyhow::Error>`
+    ///
+    /// # Errors
+    /// - any call to `ScalarField::exchange_ghosts()` fails
+    ///
+    pub fn exchange_ghosts(
+        &mut self,
+        axis: usize,
+        side: usize,
+        neighbour: &VectorField<T>,
+    ) -> Result<(), anyhow::Error> {
+        self.x.exchange_ghosts(axis, side, &neighbour.x)?;
+        self.y.exchange_ghosts(axis, side, &neighbour.y)?;
+        self.z.exchange_ghosts(axis, side, &neighbour.z)?;
+
+        Ok(())
+    }
+
+    /// checks that every component of `VectorField<T>` shares the cells and ghost layers of another field
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `rhs`: &VectorField<T> rhs of an element-wise operation
+    ///
+    /// # Returns
+    /// `Result<(), anyhow::Error>`
+    ///
+    /// # Errors
+    /// - any call to `ScalarField::check_layout()` fails
+    ///
+    fn check_layout(&self, rhs: &VectorField<T>) -> Result<(), anyhow::Error> {
+        self.x.check_layout(&rhs.x)?;
+        self.y.check_layout(&rhs.y)?;
+        self.z.check_layout(&rhs.z)
+    }
+
+    /// checks that every component of `VectorField<T>` shares the cells and ghost layers of a scalar field
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `rhs`: &ScalarField<T> rhs of an element-wise operation applied to every component
+    ///
+    /// # Returns
+    /// `Result<(), anyhow::Error>`
+    ///
+    /// # Errors
+    /// - any call to `ScalarField::check_layout()` fails
+    ///
+    fn check_component_layout(&self, rhs: &ScalarField<T>) -> Result<(), anyhow::Error> {
+        self.x.check_layout(rhs)?;
+        self.y.check_layout(rhs)?;
+        self.z.check_layout(rhs)
     }
 }
 
-/// implements `VectorField<T> /= VectorField<T>`
-impl<T: Copy + DivAssign + Num> DivAssign<VectorField<T>> for VectorField<T> {
-    /// implements `VectorField<T> /= VectorField<T>`
+impl<T: Real> VectorField<T> {
+    /// writes data of `VectorField<T>` in a little endian binary format
+    ///
+    /// components are written consecutively in x, y, z order
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `writer: &mut dyn Write` destination of data
+    ///
+    /// # Returns
+    /// `Result<(), anyhow::Error>`
+    ///
+    /// # Errors
+    /// - any call to `ScalarField<T>::write_binary()` fails
+    pub fn write_binary(&self, writer: &mut dyn Write) -> Result<(), anyhow::Error> {
+        self.x.write_binary(writer)?;
+        self.y.write_binary(writer)?;
+        self.z.write_binary(writer)?;
+
+        Ok(())
+    }
+
+    /// reads data of `VectorField<T>` from a little endian binary format
     ///
     /// # Arguments
     /// - `&mut self` mutable reference to self
-    /// - `rhs: VectorField<T>` rhs of operation
+    /// - `reader: &mut dyn Read` source of data
     ///
     /// # Returns
+    /// `Result<(), anyhow::Error>`
     ///
     /// # Errors
+    /// - any call to `ScalarField<T>::read_binary()` fails
+    pub fn read_binary(&mut self, reader: &mut dyn Read) -> Result<(), anyhow::Error> {
+        self.x.read_binary(reader)?;
+        self.y.read_binary(reader)?;
+        self.z.read_binary(reader)?;
+
+        Ok(())
+    }
+
+    /// computes the magnitude of `VectorField<T>` at every node
     ///
-    fn div_assign(&mut self, rhs: VectorField<T>) {
-        // x component
-        for (elem, num) in self.x.iter_mut().zip(rhs.x.iter()) {
-            *elem /= *num;
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `ScalarField<T>` magnitude of every node
+    ///
+    /// # Errors
+    ///
+    pub fn magnitude(&self) -> ScalarField<T> {
+        let mut magnitude = self.magnitude_squared();
+        let root = |m: &mut T| *m = m.sqrt();
+        if magnitude.as_slice().len() >= PARALLEL_THRESHOLD {
+            magnitude.as_mut_slice().par_iter_mut().for_each(root);
+        } else {
+            magnitude.as_mut_slice().iter_mut().for_each(root);
         }
+        magnitude
+    }
 
-        // y component
-        for (elem, num) in self.y.iter_mut().zip(rhs.y.iter()) {
-            *elem /= *num;
+    /// computes the squared magnitude of `VectorField<T>` at every node
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `ScalarField<T>` squared magnitude of every node
+    ///
+    /// # Errors
+    ///
+    pub fn magnitude_squared(&self) -> ScalarField<T> {
+        // every node is overwritten so the x component only provides storage of the correct shape
+        let mut magnitude = self.x.clone();
+        let (x, y, z) = (self.x.as_slice(), self.y.as_slice(), self.z.as_slice());
+        let node = |(n, m): (usize, &mut T)| *m = x[n] * x[n] + y[n] * y[n] + z[n] * z[n];
+        if x.len() >= PARALLEL_THRESHOLD {
+            magnitude
+                .as_mut_slice()
+                .par_iter_mut()
+                .enumerate()
+                .for_each(node);
+        } else {
+            magnitude
+                .as_mut_slice()
+                .iter_mut()
+                .enumerate()
+                .for_each(node);
         }
+        magnitude
+    }
 
-        // z component
-        for (elem, num) in self.z.iter_mut().zip(rhs.z.iter()) {
-            *elem /= *num;
+    /// returns the (i, j, k) index of the largest magnitude of `VectorField<T>`, the first in storage order on ties
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `(usize, usize, usize)` (i, j, k) indices
+    ///
+    /// # Errors
+    ///
+    pub fn argmax_magnitude(&self) -> (usize, usize, usize) {
+        self.magnitude_squared().argmax()
+    }
+
+    /// computes statistics of each component of `VectorField<T>`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `CoordinateTriplet<Statistics>` statistics of x, y, and z components
+    ///
+    /// # Errors
+    ///
+    pub fn statistics(&self) -> CoordinateTriplet<Statistics> {
+        CoordinateTriplet {
+            x: self.x.statistics(),
+            y: self.y.statistics(),
+            z: self.z.statistics(),
         }
     }
+
+    /// computes statistics of the magnitude of `VectorField<T>`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `Statistics` statistics of magnitudes
+    ///
+    /// # Errors
+    ///
+    pub fn magnitude_statistics(&self) -> Statistics {
+        self.magnitude().statistics()
+    }
+}
+
+/// allows `VectorField<T>` to be written in a text format
+impl<T: Display> Display for VectorField<T> {
+    /// writes `VectorField<T>` in a text format
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `f: &mut Formatter<'_>` formatter for writing
+    ///
+    /// # Returns
+    /// `std::fmt::Result`
+    ///
+    /// # Errors
+    /// - call to `writeln!()` errors
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for i in 0..self.cells.x {
+            for j in 0..self.cells.y {
+                for k in 0..self.cells.z {
+                    writeln!(
+                        f,
+                        "VectorField({}, {}, {}) = [{}, {}, {}]",
+                        i,
+                        j,
+                        k,
+                        self.x[(i, j, k)],
+                        self.y[(i, j, k)],
+                        self.z[(i, j, k)],
+                    )?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// implements `VectorField<T> += VectorField<T>`
+impl<T: Copy + AddAssign + Num> AddAssign<VectorField<T>> for VectorField<T> {
+    /// implements `VectorField<T> += VectorField<T>`
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `rhs: VectorField<T>` rhs of operation
+    ///
+    /// # Returns
+    ///
+    /// # Errors
+    ///
+    fn add_assign(&mut self, rhs: VectorField<T>) {
+        *self += &rhs;
+    }
+}
+
+/// implements `VectorField<T> -= VectorField<T>`
+impl<T: Copy + SubAssign + Num> SubAssign<VectorField<T>> for VectorField<T> {
+    /// implements `VectorField<T> -= VectorField<T>`
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `rhs: VectorField<T>` rhs of operation
+    ///
+    /// # Returns
+    ///
+    /// # Errors
+    ///
+    fn sub_assign(&mut self, rhs: VectorField<T>) {
+        *self -= &rhs;
+    }
+}
+
+/// implements `VectorField<T> *= VectorField<T>`
+impl<T: Copy + MulAssign + Num> MulAssign<VectorField<T>> for VectorField<T> {
+    /// implements `VectorField<T> *= VectorField<T>`
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `rhs: VectorField<T>` rhs of operation
+    ///
+    /// # Returns
+    ///
+    /// # Errors
+    ///
+    fn mul_assign(&mut self, rhs: VectorField<T>) {
+        *self *= &rhs;
+    }
+}
+
+/// implements `VectorField<T> /= VectorField<T>`
+impl<T: Copy + DivAssign + Num> DivAssign<VectorField<T>> for VectorField<T> {
+    /// implements `VectorField<T> /= VectorField<T>`
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `rhs: VectorField<T>` rhs of operation
+    ///
+    /// # Returns
+    ///
+    /// # Errors
+    ///
+    fn div_assign(&mut self, rhs: VectorField<T>) {
+        *self /= &rhs;
+    }
+}
+
+/// implements `VectorField<T> += T`
+impl<T: Copy + AddAssign + Num> AddAssign<T> for VectorField<T> {
+    /// implements `VectorField<T> += T`
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `rhs: T` rhs of operation
+    ///
+    /// # Returns
+    ///
+    /// # Errors
+    ///
+    fn add_assign(&mut self, rhs: T) {
+        // x component
+        for elem in self.x.iter_mut() {
+            *elem += rhs;
+        }
+
+        // y component
+        for elem in self.y.iter_mut() {
+            *elem += rhs;
+        }
+
+        // z component
+        for elem in self.z.iter_mut() {
+            *elem += rhs;
+        }
+    }
+}
+
+/// implements `VectorField<T> -= T`
+impl<T: Copy + SubAssign + Num> SubAssign<T> for VectorField<T> {
+    /// implements `VectorField<T> -= T`
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `rhs: T` rhs of operation
+    ///
+    /// # Returns
+    ///
+    /// # Errors
+    ///
+    fn sub_assign(&mut self, rhs: T) {
+        // x component
+        for elem in self.x.iter_mut() {
+            *elem -= rhs;
+        }
+
+        // y component
+        for elem in self.y.iter_mut() {
+            *elem -= rhs;
+        }
+
+        // z component
+        for elem in self.z.iter_mut() {
+            *elem -= rhs;
+        }
+    }
+}
+
+/// implements `VectorField<T> *= T`
+impl<T: Copy + MulAssign + Num> MulAssign<T> for VectorField<T> {
+    /// implements `VectorField<T> *= T`
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `rhs: T` rhs of operation
+    ///
+    /// # Returns
+    ///
+    /// # Errors
+    ///
+    fn mul_assign(&mut self, rhs: T) {
+        // x component
+        for elem in self.x.iter_mut() {
+            *elem *= rhs;
+        }
+
+        // y component
+        for elem in self.y.iter_mut() {
+            *elem *= rhs;
+        }
+
+        // z component
+        for elem in self.z.iter_mut() {
+            *elem *= rhs;
+        }
+    }
+}
+
+/// implements `VectorField<T> /= T`
+impl<T: Copy + DivAssign + Num> DivAssign<T> for VectorField<T> {
+    /// implements `VectorField<T> /= T`
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `rhs: T` rhs of operation
+    ///
+    /// # Returns
+    ///
+    /// # Errors
+    ///
+    fn div_assign(&mut self, rhs: T) {
+        // x component
+        for elem in self.x.iter_mut() {
+            *elem /= rhs;
+        }
+
+        // y component
+        for elem in self.y.iter_mut() {
+            *elem /= rhs;
+        }
+
+        // z component
+        for elem in self.z.iter_mut() {
+            *elem /= rhs;
+        }
+    }
+}
+
+/// implements `VectorField<T> += ScalarField<T>`
+impl<T: Copy + AddAssign + Num> AddAssign<ScalarField<T>> for VectorField<T> {
+    /// implements `VectorField<T> += ScalarField<T>`
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `rhs: ScalarField<T>` rhs of operation
+    ///
+    /// # Returns
+    ///
+    /// # Errors
+    ///
+    fn add_assign(&mut self, rhs: ScalarField<T>) {
+        *self += &rhs;
+    }
+}
+
+/// implements `VectorField<T> -= ScalarField<T>`
+impl<T: Copy + SubAssign + Num> SubAssign<ScalarField<T>> for VectorField<T> {
+    /// implements `VectorField<T> -= ScalarField<T>`
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `rhs: ScalarField<T>` rhs of operation
+    ///
+    /// # Returns
+    ///
+    /// # Errors
+    ///
+    fn sub_assign(&mut self, rhs: ScalarField<T>) {
+        *self -= &rhs;
+    }
+}
+
+/// implements `VectorField<T> *= ScalarField<T>`
+impl<T: Copy + MulAssign + Num> MulAssign<ScalarField<T>> for VectorField<T> {
+    /// implements `VectorField<T> *= ScalarField<T>`
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `rhs: ScalarField<T>` rhs of operation
+    ///
+    /// # Returns
+    ///
+    /// # Errors
+    ///
+    fn mul_assign(&mut self, rhs: ScalarField<T>) {
+        *self *= &rhs;
+    }
+}
+
+/// implements `VectorField<T> /= ScalarField<T>`
+impl<T: Copy + DivAssign + Num> DivAssign<ScalarField<T>> for VectorField<T> {
+    /// implements `VectorField<T> /= ScalarField<T>`
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `rhs: ScalarField<T>` rhs of operation
+    ///
+    /// # Returns
+    ///
+    /// # Errors
+    ///
+    fn div_assign(&mut self, rhs: ScalarField<T>) {
+        *self /= &rhs;
+    }
+}
+
+/// implements `VectorField<T> += &VectorField<T>`
+impl<T: Copy + AddAssign + Num> AddAssign<&VectorField<T>> for VectorField<T> {
+    /// implements `VectorField<T> += &VectorField<T>`
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `rhs: &VectorField<T>` rhs of operation
+    ///
+    /// # Returns
+    ///
+    /// # Errors
+    ///
+    /// # Panics
+    /// - in debug builds when `cells` of `self` and `rhs` differ, see `VectorField::try_add_assign()`
+    ///
+    fn add_assign(&mut self, rhs: &VectorField<T>) {
+        debug_assert!(
+            self.cells == *rhs.cells(),
+            "{}",
+            shape_mismatch(&self.cells, rhs.cells())
+        );
+        self.x += &rhs.x;
+        self.y += &rhs.y;
+        self.z += &rhs.z;
+    }
+}
+
+/// implements `VectorField<T> += &ScalarField<T>`
+impl<T: Copy + AddAssign + Num> AddAssign<&ScalarField<T>> for VectorField<T> {
+    /// implements `VectorField<T> += &ScalarField<T>`
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `rhs: &ScalarField<T>` rhs of operation applied to every component
+    ///
+    /// # Returns
+    ///
+    /// # Errors
+    ///
+    /// # Panics
+    /// - in debug builds when `cells` of `self` and `rhs` differ, see `VectorField::try_add_assign_scalar_field()`
+    ///
+    fn add_assign(&mut self, rhs: &ScalarField<T>) {
+        debug_assert!(
+            self.cells == *rhs.cells(),
+            "{}",
+            shape_mismatch(&self.cells, rhs.cells())
+        );
+        self.x += rhs;
+        self.y += rhs;
+        self.z += rhs;
+    }
+}
+
+/// implements `VectorField<T> -= &VectorField<T>`
+impl<T: Copy + SubAssign + Num> SubAssign<&VectorField<T>> for VectorField<T> {
+    /// implements `VectorField<T> -= &VectorField<T>`
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `rhs: &VectorField<T>` rhs of operation
+    ///
+    /// # Returns
+    ///
+    /// # Errors
+    ///
+    /// # Panics
+    /// - in debug builds when `cells` of `self` and `rhs` differ, see `VectorField::try_sub_assign()`
+    ///
+    fn sub_assign(&mut self, rhs: &VectorField<T>) {
+        debug_assert!(
+            self.cells == *rhs.cells(),
+            "{}",
+            shape_mismatch(&self.cells, rhs.cells())
+        );
+        self.x -= &rhs.x;
+        self.y -= &rhs.y;
+        self.z -= &rhs.z;
+    }
+}
+
+/// implements `VectorField<T> -= &ScalarField<T>`
+impl<T: Copy + SubAssign + Num> SubAssign<&ScalarField<T>> for VectorField<T> {
+    /// implements `VectorField<T> -= &ScalarField<T>`
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `rhs: &ScalarField<T>` rhs of operation applied to every component
+    ///
+    /// # Returns
+    ///
+    /// # Errors
+    ///
+    /// # Panics
+    /// - in debug builds when `cells` of `self` and `rhs` differ, see `VectorField::try_sub_assign_scalar_field()`
+    ///
+    fn sub_assign(&mut self, rhs: &ScalarField<T>) {
+        debug_assert!(
+            self.cells == *rhs.cells(),
+            "{}",
+            shape_mismatch(&self.cells, rhs.cells())
+        );
+        self.x -= rhs;
+        self.y -= rhs;
+        self.z -= rhs;
+    }
+}
+
+/// implements `VectorField<T> *= &VectorField<T>`
+impl<T: Copy + MulAssign + Num> MulAssign<&VectorField<T>> for VectorField<T> {
+    /// implements `VectorField<T> *= &VectorField<T>`
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `rhs: &VectorField<T>` rhs of operation
+    ///
+    /// # Returns
+    ///
+    /// # Errors
+    ///
+    /// # Panics
+    /// - in debug builds when `cells` of `self` and `rhs` differ, see `VectorField::try_mul_assign()`
+    ///
+    fn mul_assign(&mut self, rhs: &VectorField<T>) {
+        debug_assert!(
+            self.cells == *rhs.cells(),
+            "{}",
+            shape_mismatch(&self.cells, rhs.cells())
+        );
+        self.x *= &rhs.x;
+        self.y *= &rhs.y;
+        self.z *= &rhs.z;
+    }
+}
+
+/// implements `VectorField<T> *= &ScalarField<T>`
+impl<T: Copy + MulAssign + Num> MulAssign<&ScalarField<T>> for VectorField<T> {
+    /// implements `VectorField<T> *= &ScalarField<T>`
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `rhs: &ScalarField<T>` rhs of operation applied to every component
+    ///
+    /// # Returns
+    ///
+    /// # Errors
+    ///
+    /// # Panics
+    /// - in debug builds when `cells` of `self` and `rhs` differ, see `VectorField::try_mul_assign_scalar_field()`
+    ///
+    fn mul_assign(&mut self, rhs: &ScalarField<T>) {
+        debug_assert!(
+            self.cells == *rhs.cells(),
+            "{}",
+            shape_mismatch(&self.cells, rhs.cells())
+        );
+        self.x *= rhs;
+        self.y *= rhs;
+        self.z *= rhs;
+    }
+}
+
+/// implements `VectorField<T> /= &VectorField<T>`
+impl<T: Copy + DivAssign + Num> DivAssign<&VectorField<T>> for VectorField<T> {
+    /// implements `VectorField<T> /= &VectorField<T>`
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `rhs: &VectorField<T>` rhs of operation
+    ///
+    /// # Returns
+    ///
+    /// # Errors
+    ///
+    /// # Panics
+    /// - in debug builds when `cells` of `self` and `rhs` differ, see `VectorField::try_div_assign()`
+    ///
+    fn div_assign(&mut self, rhs: &VectorField<T>) {
+        debug_assert!(
+            self.cells == *rhs.cells(),
+            "{}",
+            shape_mismatch(&self.cells, rhs.cells())
+        );
+        self.x /= &rhs.x;
+        self.y /= &rhs.y;
+        self.z /= &rhs.z;
+    }
+}
+
+/// implements `VectorField<T> /= &ScalarField<T>`
+impl<T: Copy + DivAssign + Num> DivAssign<&ScalarField<T>> for VectorField<T> {
+    /// implements `VectorField<T> /= &ScalarField<T>`
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `rhs: &ScalarField<T>` rhs of operation applied to every component
+    ///
+    /// # Returns
+    ///
+    /// # Errors
+    ///
+    /// # Panics
+    /// - in debug builds when `cells` of `self` and `rhs` differ, see `VectorField::try_div_assign_scalar_field()`
+    ///
+    fn div_assign(&mut self, rhs: &ScalarField<T>) {
+        debug_assert!(
+            self.cells == *rhs.cells(),
+            "{}",
+            shape_mismatch(&self.cells, rhs.cells())
+        );
+        self.x /= rhs;
+        self.y /= rhs;
+        self.z /= rhs;
+    }
+}
+
+/// implements `&VectorField<T> + &VectorField<T>`
+impl<T: Copy + AddAssign + Num> Add<&VectorField<T>> for &VectorField<T> {
+    type Output = VectorField<T>;
+
+    /// implements `&VectorField<T> + &VectorField<T>`
+    ///
+    /// # Arguments
+    /// - `self` reference to lhs of operation
+    /// - `rhs: &VectorField<T>` rhs of operation
+    ///
+    /// # Returns
+    /// `VectorField<T>` result of operation
+    ///
+    /// # Errors
+    ///
+    fn add(self, rhs: &VectorField<T>) -> VectorField<T> {
+        let mut field = self.clone();
+        field += rhs;
+        field
+    }
+}
+
+/// implements `&VectorField<T> - &VectorField<T>`
+impl<T: Copy + SubAssign + Num> Sub<&VectorField<T>> for &VectorField<T> {
+    type Output = VectorField<T>;
+
+    /// implements `&VectorField<T> - &VectorField<T>`
+    ///
+    /// # Arguments
+    /// - `self` reference to lhs of operation
+    /// - `rhs: &VectorField<T>` rhs of operation
+    ///
+    /// # Returns
+    /// `VectorField<T>` result of operation
+    ///
+    /// # Errors
+    ///
+    fn sub(self, rhs: &VectorField<T>) -> VectorField<T> {
+        let mut field = self.clone();
+        field -= rhs;
+        field
+    }
+}
+
+/// implements `&VectorField<T> * &VectorField<T>`
+impl<T: Copy + MulAssign + Num> Mul<&VectorField<T>> for &VectorField<T> {
+    type Output = VectorField<T>;
+
+    /// implements `&VectorField<T> * &VectorField<T>`
+    ///
+    /// # Arguments
+    /// - `self` reference to lhs of operation
+    /// - `rhs: &VectorField<T>` rhs of operation
+    ///
+    /// # Returns
+    /// `VectorField<T>` result of operation
+    ///
+    /// # Errors
+    ///
+    fn mul(self, rhs: &VectorField<T>) -> VectorField<T> {
+        let mut field = self.clone();
+        field *= rhs;
+        field
+    }
+}
+
+/// implements `&VectorField<T> / &VectorField<T>`
+impl<T: Copy + DivAssign + Num> Div<&VectorField<T>> for &VectorField<T> {
+    type Output = VectorField<T>;
+
+    /// implements `&VectorField<T> / &VectorField<T>`
+    ///
+    /// # Arguments
+    /// - `self` reference to lhs of operation
+    /// - `rhs: &VectorField<T>` rhs of operation
+    ///
+    /// # Returns
+    /// `VectorField<T>` result of operation
+    ///
+    /// # Errors
+    ///
+    fn div(self, rhs: &VectorField<T>) -> VectorField<T> {
+        let mut field = self.clone();
+        field /= rhs;
+        field
+    }
+}
+
+/// implements `VectorField<T> + T`, reusing the storage of the lhs
+impl<T: Copy + AddAssign + Num> Add<T> for VectorField<T> {
+    type Output = VectorField<T>;
+
+    /// implements `VectorField<T> + T`
+    ///
+    /// # Arguments
+    /// - `self` lhs of operation
+    /// - `rhs: T` rhs of operation
+    ///
+    /// # Returns
+    /// `VectorField<T>` result of operation
+    ///
+    /// # Errors
+    ///
+    fn add(mut self, rhs: T) -> VectorField<T> {
+        self += rhs;
+        self
+    }
+}
+
+/// implements `&VectorField<T> + T`
+impl<T: Copy + AddAssign + Num> Add<T> for &VectorField<T> {
+    type Output = VectorField<T>;
+
+    /// implements `&VectorField<T> + T`
+    ///
+    /// # Arguments
+    /// - `self` reference to lhs of operation
+    /// - `rhs: T` rhs of operation
+    ///
+    /// # Returns
+    /// `VectorField<T>` result of operation
+    ///
+    /// # Errors
+    ///
+    fn add(self, rhs: T) -> VectorField<T> {
+        self.clone() + rhs
+    }
 }
 
-/// implements `VectorField<T> += T`
-impl<T: Copy + AddAssign + Num> AddAssign<T> for VectorField<T> {
-    /// implements `VectorField<T> += T`
+/// implements `VectorField<T> - T`, reusing the storage of the lhs
+impl<T: Copy + SubAssign + Num> Sub<T> for VectorField<T> {
+    type Output = VectorField<T>;
+
+    /// implements `VectorField<T> - T`
     ///
     /// # Arguments
-    /// - `&mut self` mutable reference to self
+    /// - `self` lhs of operation
     /// - `rhs: T` rhs of operation
     ///
     /// # Returns
+    /// `VectorField<T>` result of operation
     ///
     /// # Errors
     ///
-    fn add_assign(&mut self, rhs: T) {
-        // x component
-        for elem in self.x.iter_mut() {
-            *elem += rhs;
-        }
-
-        // y component
-        for elem in self.y.iter_mut() {
-            *elem += rhs;
-        }
-
-        // z component
-        for elem in self.z.iter_mut() {
-            *elem += rhs;
-        }
+    fn sub(mut self, rhs: T) -> VectorField<T> {
+        self -= rhs;
+        self
     }
 }
 
-/// implements `VectorField<T> -= T`
-impl<T: Copy + SubAssign + Num> SubAssign<T> for VectorField<T> {
-    /// implements `VectorField<T> -= T`
+/// implements `&VectorField<T> - T`
+impl<T: Copy + SubAssign + Num> Sub<T> for &VectorField<T> {
+    type Output = VectorField<T>;
+
+    /// implements `&VectorField<T> - T`
     ///
     /// # Arguments
-    /// - `&mut self` mutable reference to self
+    /// - `self` reference to lhs of operation
     /// - `rhs: T` rhs of operation
     ///
     /// # Returns
+    /// `VectorField<T>` result of operation
     ///
     /// # Errors
     ///
-    fn sub_assign(&mut self, rhs: T) {
-        // x component
-        for elem in self.x.iter_mut() {
-            *elem -= rhs;
-        }
-
-        // y component
-        for elem in self.y.iter_mut() {
-            *elem -= rhs;
-        }
-
-        // z component
-        for elem in self.z.iter_mut() {
-            *elem -= rhs;
-        }
+    fn sub(self, rhs: T) -> VectorField<T> {
+        self.clone() - rhs
     }
 }
 
-/// implements `VectorField<T> *= T`
-impl<T: Copy + MulAssign + Num> MulAssign<T> for VectorField<T> {
-    /// implements `VectorField<T> *= T`
+/// implements `VectorField<T> * T`, reusing the storage of the lhs
+impl<T: Copy + MulAssign + Num> Mul<T> for VectorField<T> {
+    type Output = VectorField<T>;
+
+    /// implements `VectorField<T> * T`
     ///
     /// # Arguments
-    /// - `&mut self` mutable reference to self
+    /// - `self` lhs of operation
     /// - `rhs: T` rhs of operation
     ///
     /// # Returns
+    /// `VectorField<T>` result of operation
     ///
     /// # Errors
     ///
-    fn mul_assign(&mut self, rhs: T) {
-        // x component
-        for elem in self.x.iter_mut() {
-            *elem *= rhs;
-        }
-
-        // y component
-        for elem in self.y.iter_mut() {
-            *elem *= rhs;
-        }
-
-        // z component
-        for elem in self.z.iter_mut() {
-            *elem *= rhs;
-        }
+    fn mul(mut self, rhs: T) -> VectorField<T> {
+        self *= rhs;
+        self
     }
 }
 
-/// implements `VectorField<T> /= T`
-impl<T: Copy + DivAssign + Num> DivAssign<T> for VectorField<T> {
-    /// implements `VectorField<T> /= T`
+/// implements `&VectorField<T> * T`
+impl<T: Copy + MulAssign + Num> Mul<T> for &VectorField<T> {
+    type Output = VectorField<T>;
+
+    /// implements `&VectorField<T> * T`
     ///
     /// # Arguments
-    /// - `&mut self` mutable reference to self
+    /// - `self` reference to lhs of operation
     /// - `rhs: T` rhs of operation
     ///
     /// # Returns
+    /// `VectorField<T>` result of operation
     ///
     /// # Errors
     ///
-    fn div_assign(&mut self, rhs: T) {
-        // x component
-        for elem in self.x.iter_mut() {
-            *elem /= rhs;
-        }
-
-        // y component
-        for elem in self.y.iter_mut() {
-            *elem /= rhs;
-        }
-
-        // z component
-        for elem in self.z.iter_mut() {
-            *elem /= rhs;
-        }
+    fn mul(self, rhs: T) -> VectorField<T> {
+        self.clone() * rhs
     }
 }
 
-/// implements `VectorField<T> += ScalarField<T>`
-impl<T: Copy + AddAssign + Num> AddAssign<ScalarField<T>> for VectorField<T> {
-    /// implements `VectorField<T> += ScalarField<T>`
+/// implements `VectorField<T> / T`, reusing the storage of the lhs
+impl<T: Copy + DivAssign + Num> Div<T> for VectorField<T> {
+    type Output = VectorField<T>;
+
+    /// implements `VectorField<T> / T`
     ///
     /// # Arguments
-    /// - `&mut self` mutable reference to self
-    /// - `rhs: ScalarField<T>` rhs of operation
+    /// - `self` lhs of operation
+    /// - `rhs: T` rhs of operation
     ///
     /// # Returns
+    /// `VectorField<T>` result of operation
     ///
     /// # Errors
     ///
-    fn add_assign(&mut self, rhs: ScalarField<T>) {
-        // x component
-        for (elem, num) in self.x.iter_mut().zip(rhs.iter()) {
-            *elem += *num;
-        }
-
-        // y component
-        for (elem, num) in self.y.iter_mut().zip(rhs.iter()) {
-            *elem += *num;
-        }
-
-        // z component
-        for (elem, num) in self.z.iter_mut().zip(rhs.iter()) {
-            *elem += *num;
-        }
+    fn div(mut self, rhs: T) -> VectorField<T> {
+        self /= rhs;
+        self
     }
 }
 
-/// implements `VectorField<T> -= ScalarField<T>`
-impl<T: Copy + SubAssign + Num> SubAssign<ScalarField<T>> for VectorField<T> {
-    /// implements `VectorField<T> -= ScalarField<T>`
+/// implements `&VectorField<T> / T`
+impl<T: Copy + DivAssign + Num> Div<T> for &VectorField<T> {
+    type Output = VectorField<T>;
+
+    /// implements `&VectorField<T> / T`
     ///
     /// # Arguments
-    /// - `&mut self` mutable reference to self
-    /// - `rhs: ScalarField<T>` rhs of operation
+    /// - `self` reference to lhs of operation
+    /// - `rhs: T` rhs of operation
     ///
     /// # Returns
+    /// `VectorField<T>` result of operation
     ///
     /// # Errors
     ///
-    fn sub_assign(&mut self, rhs: ScalarField<T>) {
-        // x component
-        for (elem, num) in self.x.iter_mut().zip(rhs.iter()) {
-            *elem -= *num;
-        }
-
-        // y component
-        for (elem, num) in self.y.iter_mut().zip(rhs.iter()) {
-            *elem -= *num;
-        }
-
-        // z component
-        for (elem, num) in self.z.iter_mut().zip(rhs.iter()) {
-            *elem -= *num;
-        }
+    fn div(self, rhs: T) -> VectorField<T> {
+        self.clone() / rhs
     }
 }
 
-/// implements `VectorField<T> *= ScalarField<T>`
-impl<T: Copy + MulAssign + Num> MulAssign<ScalarField<T>> for VectorField<T> {
-    /// implements `VectorField<T> *= ScalarField<T>`
+/// implements `-VectorField<T>`, reusing the storage of the operand
+impl<T: Copy + Neg<Output = T>> Neg for VectorField<T> {
+    type Output = VectorField<T>;
+
+    /// implements `-VectorField<T>`
     ///
     /// # Arguments
-    /// - `&mut self` mutable reference to self
-    /// - `rhs: ScalarField<T>` rhs of operation
+    /// - `self` operand
     ///
     /// # Returns
+    /// `VectorField<T>` result of operation
     ///
     /// # Errors
     ///
-    fn mul_assign(&mut self, rhs: ScalarField<T>) {
-        // x component
-        for (elem, num) in self.x.iter_mut().zip(rhs.iter()) {
-            *elem *= *num;
-        }
-
-        // y component
-        for (elem, num) in self.y.iter_mut().zip(rhs.iter()) {
-            *elem *= *num;
-        }
-
-        // z component
-        for (elem, num) in self.z.iter_mut().zip(rhs.iter()) {
-            *elem *= *num;
+    fn neg(self) -> VectorField<T> {
+        VectorField {
+            cells: self.cells,
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
         }
     }
 }
 
-/// implements `VectorField<T> /= ScalarField<T>`
-impl<T: Copy + DivAssign + Num> DivAssign<ScalarField<T>> for VectorField<T> {
-    /// implements `VectorField<T> /= ScalarField<T>`
+/// implements `-&VectorField<T>`
+impl<T: Copy + Neg<Output = T>> Neg for &VectorField<T> {
+    type Output = VectorField<T>;
+
+    /// implements `-&VectorField<T>`
     ///
     /// # Arguments
-    /// - `&mut self` mutable reference to self
-    /// - `rhs: ScalarField<T>` rhs of operation
+    /// - `self` reference to operand
     ///
     /// # Returns
+    /// `VectorField<T>` result of operation
     ///
     /// # Errors
     ///
-    fn div_assign(&mut self, rhs: ScalarField<T>) {
-        // x component
-        for (elem, num) in self.x.iter_mut().zip(rhs.iter()) {
-            *elem /= *num;
-        }
-
-        // y component
-        for (elem, num) in self.y.iter_mut().zip(rhs.iter()) {
-            *elem /= *num;
-        }
-
-        // z component
-        for (elem, num) in self.z.iter_mut().zip(rhs.iter()) {
-            *elem /= *num;
-        }
+    fn neg(self) -> VectorField<T> {
+        -self.clone()
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::field::scalar::ScalarField;
+    use crate::field::scalar::{GhostBoundary, Halo, ScalarField};
     use crate::field::vector::VectorField;
     use crate::utils::coordinate_triplet::CoordinateTriplet;
 
@@ -828,4 +1730,250 @@ mod tests {
         vector_field.y.iter().for_each(|num| assert_eq!(*num, 5.0));
         vector_field.z.iter().for_each(|num| assert_eq!(*num, 5.0));
     }
+
+    /// tests `VectorField<T>` for correct implementation of assignment operators taking references
+    ///
+    /// # Errors
+    /// - `VectorField<T>` does not implement `AddAssign<&VectorField<T>>` or `MulAssign<&VectorField<T>>` correctly
+    /// - `VectorField<T>` does not implement `SubAssign<&ScalarField<T>>` or `DivAssign<&ScalarField<T>>` correctly
+    /// - rhs is modified
+    ///
+    #[test]
+    fn impl_assign_ref() {
+        // setup
+        let mut vector_field1: VectorField<f64> = setup().unwrap();
+        vector_field1 += 1.0;
+        let mut vector_field2: VectorField<f64> = setup().unwrap();
+        vector_field2 += 3.0;
+        let cells: CoordinateTriplet<usize> = CoordinateTriplet::new(2, 4, 6).unwrap();
+        let mut scalar_field: ScalarField<f64> = ScalarField::new(&cells).unwrap();
+        scalar_field += 2.0;
+
+        vector_field1 += &vector_field2;
+        vector_field1 *= &vector_field2;
+        vector_field1 -= &scalar_field;
+        vector_field1 /= &scalar_field;
+
+        // assertions
+        vector_field1.x.iter().for_each(|num| assert_eq!(*num, 5.0));
+        vector_field1.y.iter().for_each(|num| assert_eq!(*num, 5.0));
+        vector_field1.z.iter().for_each(|num| assert_eq!(*num, 5.0));
+        vector_field2.x.iter().for_each(|num| assert_eq!(*num, 3.0));
+        scalar_field.iter().for_each(|num| assert_eq!(*num, 2.0));
+    }
+
+    /// tests `VectorField<T>` for correct implementation of non-assigning operators and negation
+    ///
+    /// # Errors
+    /// - `&VectorField<T>` does not implement `Add<&VectorField<T>>` or `Div<&VectorField<T>>` correctly
+    /// - `VectorField<T>` or `&VectorField<T>` does not implement `Mul<T>` or `Sub<T>` correctly
+    /// - `VectorField<T>` or `&VectorField<T>` does not implement `Neg` correctly
+    /// - any referenced operand is modified
+    ///
+    #[test]
+    fn impl_ops() {
+        // setup
+        let mut vector_field1: VectorField<f64> = setup().unwrap();
+        vector_field1 += 6.0;
+        let mut vector_field2: VectorField<f64> = setup().unwrap();
+        vector_field2 += 2.0;
+
+        let sum = &vector_field1 + &vector_field2;
+        let quotient = &vector_field1 / &vector_field2;
+        let scaled = &vector_field1 * 0.5;
+        let negated = -&vector_field2;
+        let chained = -(vector_field2.clone() - 1.0);
+
+        // assertions
+        sum.x.iter().for_each(|num| assert_eq!(*num, 8.0));
+        quotient.y.iter().for_each(|num| assert_eq!(*num, 3.0));
+        scaled.z.iter().for_each(|num| assert_eq!(*num, 3.0));
+        negated.x.iter().for_each(|num| assert_eq!(*num, -2.0));
+        chained.z.iter().for_each(|num| assert_eq!(*num, -1.0));
+        vector_field1.x.iter().for_each(|num| assert_eq!(*num, 6.0));
+        vector_field2.z.iter().for_each(|num| assert_eq!(*num, 2.0));
+    }
+
+    /// tests `VectorField::axpy()` for correctness
+    ///
+    /// # Errors
+    /// - `VectorField::axpy()` does not add the multiple of the rhs to every component
+    ///
+    #[test]
+    fn axpy_correct() {
+        // setup
+        let mut vector_field1: VectorField<f64> = setup().unwrap();
+        let mut vector_field2: VectorField<f64> = setup().unwrap();
+        vector_field2 += 2.0;
+
+        vector_field1.axpy(0.25, &vector_field2);
+
+        // assertions
+        vector_field1.x.iter().for_each(|num| assert_eq!(*num, 0.5));
+        vector_field1.y.iter().for_each(|num| assert_eq!(*num, 0.5));
+        vector_field1.z.iter().for_each(|num| assert_eq!(*num, 0.5));
+    }
+
+    /// tests shape-checked operations on `VectorField<T>` for correctness on matching shapes
+    ///
+    /// # Errors
+    /// - any shape-checked operation fails on matching shapes
+    /// - any shape-checked operation is not equivalent to its operator
+    ///
+    #[test]
+    fn try_ops_correct() {
+        // setup
+        let mut vector_field1: VectorField<f64> = setup().unwrap();
+        vector_field1 += 1.0;
+        let mut vector_field2: VectorField<f64> = setup().unwrap();
+        vector_field2 += 2.0;
+        let cells = CoordinateTriplet::new(2, 4, 6).unwrap();
+        let mut scalar_field: ScalarField<f64> = ScalarField::new(&cells).unwrap();
+        scalar_field += 3.0;
+
+        // assertions
+        assert!(vector_field1.try_add_assign(&vector_field2).is_ok());
+        assert!(vector_field1
+            .try_mul_assign_scalar_field(&scalar_field)
+            .is_ok());
+        assert!(vector_field1
+            .try_sub_assign_scalar_field(&scalar_field)
+            .is_ok());
+        assert!(vector_field1.try_div_assign(&vector_field2).is_ok());
+        assert!(vector_field1.try_axpy(1.0, &vector_field2).is_ok());
+        vector_field1.x.iter().for_each(|num| assert_eq!(*num, 5.0));
+        vector_field1.y.iter().for_each(|num| assert_eq!(*num, 5.0));
+        vector_field1.z.iter().for_each(|num| assert_eq!(*num, 5.0));
+    }
+
+    /// tests shape-checked operations on `VectorField<T>` for failure on mismatched shapes
+    ///
+    /// # Errors
+    /// - any shape-checked operation succeeds on mismatched shapes
+    /// - error does not name both shapes
+    /// - lhs is modified
+    ///
+    #[test]
+    fn try_ops_mismatch() {
+        // setup
+        let mut vector_field1: VectorField<f64> = setup().unwrap();
+        vector_field1 += 1.0;
+        let cells = CoordinateTriplet::new(3, 4, 6).unwrap();
+        let vector_field2: VectorField<f64> = VectorField::new(&cells).unwrap();
+        let scalar_field: ScalarField<f64> = ScalarField::new(&cells).unwrap();
+
+        let result = vector_field1.try_add_assign_scalar_field(&scalar_field);
+
+        // assertions
+        let message = result.unwrap_err().to_string();
+        assert!(message.contains("(2, 4, 6)"));
+        assert!(message.contains("(3, 4, 6)"));
+        assert!(vector_field1.try_add_assign(&vector_field2).is_err());
+        assert!(vector_field1.try_sub_assign(&vector_field2).is_err());
+        assert!(vector_field1.try_mul_assign(&vector_field2).is_err());
+        assert!(vector_field1.try_div_assign(&vector_field2).is_err());
+        assert!(vector_field1
+            .try_sub_assign_scalar_field(&scalar_field)
+            .is_err());
+        assert!(vector_field1
+            .try_mul_assign_scalar_field(&scalar_field)
+            .is_err());
+        assert!(vector_field1
+            .try_div_assign_scalar_field(&scalar_field)
+            .is_err());
+        assert!(vector_field1.try_axpy(1.0, &vector_field2).is_err());
+        vector_field1.x.iter().for_each(|num| assert_eq!(*num, 1.0));
+        vector_field1.y.iter().for_each(|num| assert_eq!(*num, 1.0));
+        vector_field1.z.iter().for_each(|num| assert_eq!(*num, 1.0));
+    }
+
+    /// tests `VectorField<T>` operators for a panic on mismatched shapes in debug builds
+    ///
+    /// # Errors
+    /// - `VectorField<T> *= &ScalarField<T>` silently truncates mismatched shapes
+    ///
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "field shape mismatch")]
+    fn impl_ops_mismatch_panic() {
+        // setup
+        let mut vector_field: VectorField<f64> = setup().unwrap();
+        let cells = CoordinateTriplet::new(3, 4, 6).unwrap();
+        let scalar_field: ScalarField<f64> = ScalarField::new(&cells).unwrap();
+
+        // assertions
+        vector_field *= &scalar_field;
+    }
+
+    /// tests magnitudes and statistics of `VectorField<f64>` for correctness
+    ///
+    /// # Errors
+    /// - `VectorField::magnitude()` or `VectorField::magnitude_squared()` is incorrect
+    /// - `VectorField::argmax_magnitude()` is incorrect
+    /// - `VectorField::statistics()` or `VectorField::magnitude_statistics()` is incorrect
+    ///
+    #[test]
+    fn magnitude_statistics_correct() {
+        // setup
+        let mut vector_field: VectorField<f64> = setup().unwrap();
+        vector_field.x += 3.0;
+        vector_field.y -= 4.0;
+        vector_field.z[(1, 2, 3)] = 12.0;
+
+        let magnitude = vector_field.magnitude();
+        let statistics = vector_field.statistics();
+        let magnitude_statistics = vector_field.magnitude_statistics();
+
+        // assertions
+        assert_eq!(magnitude[(0, 0, 0)], 5.0);
+        assert_eq!(magnitude[(1, 2, 3)], 13.0);
+        assert_eq!(vector_field.magnitude_squared()[(1, 2, 3)], 169.0);
+        assert_eq!(vector_field.argmax_magnitude(), (1, 2, 3));
+        assert_eq!(statistics.x.mean, 3.0);
+        assert_eq!(statistics.y.min, -4.0);
+        assert_eq!(statistics.z.max, 12.0);
+        assert_eq!(magnitude_statistics.min, 5.0);
+        assert_eq!(magnitude_statistics.max, 13.0);
+        assert_eq!(magnitude_statistics.sum, 47.0 * 5.0 + 13.0);
+    }
+
+    /// tests ghost layers of `VectorField<T>` for correctness
+    ///
+    /// # Errors
+    /// - `VectorField::with_ghost()` does not surround every component with ghost layers
+    /// - `VectorField::fill_ghosts()` does not apply the rule of each component
+    /// - `VectorField::exchange_ghosts()` does not exchange every component
+    ///
+    #[test]
+    fn ghosts_correct() {
+        // setup
+        let cells = CoordinateTriplet::new(3, 3, 3).unwrap();
+        let mut vector_field: VectorField<f64> = VectorField::with_ghost(&cells, 1).unwrap();
+        vector_field += 2.0;
+        let mut neighbour: VectorField<f64> = VectorField::with_ghost(&cells, 1).unwrap();
+        neighbour += 5.0;
+
+        vector_field
+            .fill_ghosts(
+                1,
+                0,
+                [
+                    GhostBoundary::Dirichlet(0.0),
+                    GhostBoundary::Neumann(1.0),
+                    GhostBoundary::Periodic,
+                ],
+            )
+            .unwrap();
+        vector_field.exchange_ghosts(2, 1, &neighbour).unwrap();
+
+        // assertions
+        assert_eq!(vector_field.ghost(), 1);
+        assert_eq!(vector_field.x[Halo(1, -1, 1)], -2.0);
+        assert_eq!(vector_field.y[Halo(1, -1, 1)], 4.0);
+        assert_eq!(vector_field.z[Halo(1, -1, 1)], 2.0);
+        assert_eq!(vector_field.x[Halo(0, 0, 3)], 5.0);
+        assert_eq!(vector_field.y[Halo(2, 2, 3)], 5.0);
+        assert_eq!(vector_field.z[Halo(1, 0, 3)], 5.0);
+        vector_field.x.iter().for_each(|num| assert_eq!(*num, 2.0));
+    }
 }
@@ -0,0 +1,1430 @@
+use crate::field::scalar::{check_shapes, ScalarField};
+use crate::field::vector::VectorField;
+use crate::utils::coordinate_triplet::CoordinateTriplet;
+use anyhow::anyhow;
+use num::Num;
+use std::ops::{Index, IndexMut};
+
+/// `Region` struct
+///
+/// describes a box of nodes within a field
+#[derive(Debug, Clone, PartialEq)]
+struct Region {
+    /// (i, j, k) index of the first node of the box within the field
+    start: (usize, usize, usize),
+
+    /// number of nodes of the box along each axis
+    cells: CoordinateTriplet<usize>,
+
+    /// number of interior nodes of the field along each axis
+    field: CoordinateTriplet<usize>,
+
+    /// width of ghost layers of the field on every side
+    ghost: usize,
+}
+
+impl Region {
+    /// `Region` constructor of a sub-box
+    ///
+    /// # Arguments
+    /// - `field`: &CoordinateTriplet<usize> number of interior nodes of the field along each axis
+    /// - `ghost`: usize width of ghost layers of the field on every side
+    /// - `start`: (usize, usize, usize) (i, j, k) index of the first node of the box within the field
+    /// - `cells`: &CoordinateTriplet<usize> number of nodes of the box along each axis
+    ///
+    /// # Returns
+    /// `Result<Region, anyhow::Error>`
+    ///
+    /// # Errors
+    /// - box has no nodes along any axis
+    /// - box extends beyond the interior nodes of the field along any axis
+    ///
+    fn new(
+        field: &CoordinateTriplet<usize>,
+        ghost: usize,
+        start: (usize, usize, usize),
+        cells: &CoordinateTriplet<usize>,
+    ) -> Result<Region, anyhow::Error> {
+        if cells.x == 0 || cells.y == 0 || cells.z == 0 {
+            return Err(anyhow!("view of {cells} cells has no nodes"));
+        }
+        if start.0 + cells.x > field.x || start.1 + cells.y > field.y || start.2 + cells.z > field.z
+        {
+            return Err(anyhow!(
+                "view of {cells} cells starting at ({}, {}, {}) extends beyond field of {field} cells",
+                start.0,
+                start.1,
+                start.2
+            ));
+        }
+
+        Ok(Region {
+            start,
+            cells: cells.clone(),
+            field: field.clone(),
+            ghost,
+        })
+    }
+
+    /// `Region` constructor of a plane of a single node normal to an axis
+    ///
+    /// # Arguments
+    /// - `field`: &CoordinateTriplet<usize> number of interior nodes of the field along each axis
+    /// - `ghost`: usize width of ghost layers of the field on every side
+    /// - `axis`: usize axis normal to the plane, 0 for x, 1 for y, and 2 for z
+    /// - `index`: usize index of the plane along `axis`
+    ///
+    /// # Returns
+    /// `Result<Region, anyhow::Error>`
+    ///
+    /// # Errors
+    /// - `axis` is not 0, 1, or 2
+    /// - `index` is outside of the field
+    ///
+    fn plane(
+        field: &CoordinateTriplet<usize>,
+        ghost: usize,
+        axis: usize,
+        index: usize,
+    ) -> Result<Region, anyhow::Error> {
+        let (start, cells) = match axis {
+            0 => ((index, 0, 0), CoordinateTriplet::new(1, field.y, field.z)?),
+            1 => ((0, index, 0), CoordinateTriplet::new(field.x, 1, field.z)?),
+            2 => ((0, 0, index), CoordinateTriplet::new(field.x, field.y, 1)?),
+            _ => return Err(anyhow!("axis {axis} of plane is not 0, 1, or 2")),
+        };
+
+        Region::new(field, ghost, start, &cells)
+    }
+
+    /// `Region` constructor of a line of nodes along an axis
+    ///
+    /// # Arguments
+    /// - `field`: &CoordinateTriplet<usize> number of interior nodes of the field along each axis
+    /// - `ghost`: usize width of ghost layers of the field on every side
+    /// - `axis`: usize axis along the line, 0 for x, 1 for y, and 2 for z
+    /// - `fixed`: (usize, usize) indices of the line along the remaining axes in increasing axis order
+    ///
+    /// # Returns
+    /// `Result<Region, anyhow::Error>`
+    ///
+    /// # Errors
+    /// - `axis` is not 0, 1, or 2
+    /// - `fixed` is outside of the field
+    ///
+    fn line(
+        field: &CoordinateTriplet<usize>,
+        ghost: usize,
+        axis: usize,
+        fixed: (usize, usize),
+    ) -> Result<Region, anyhow::Error> {
+        let (a, b) = fixed;
+        let (start, cells) = match axis {
+            0 => ((0, a, b), CoordinateTriplet::new(field.x, 1, 1)?),
+            1 => ((a, 0, b), CoordinateTriplet::new(1, field.y, 1)?),
+            2 => ((a, b, 0), CoordinateTriplet::new(1, 1, field.z)?),
+            _ => return Err(anyhow!("axis {axis} of line is not 0, 1, or 2")),
+        };
+
+        Region::new(field, ghost, start, &cells)
+    }
+
+    /// maps an (i, j, k) index within the box to a linear index within the field
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `idx`: (usize, usize, usize) (i, j, k) index within the box
+    ///
+    /// # Returns
+    /// `usize` linear index within the field
+    ///
+    /// # Errors
+    ///
+    /// # Panics
+    /// - `idx` is outside of the box
+    ///
+    fn linear(&self, idx: (usize, usize, usize)) -> usize {
+        let (i, j, k) = idx;
+        assert!(
+            i < self.cells.x && j < self.cells.y && k < self.cells.z,
+            "index ({i}, {j}, {k}) is outside of view of {} cells",
+            self.cells
+        );
+        let g = self.ghost;
+        let (rows, columns) = (self.field.y + 2 * g, self.field.z + 2 * g);
+        ((self.start.0 + g + i) * rows + self.start.1 + g + j) * columns + self.start.2 + g + k
+    }
+
+    /// returns the rows of the box within the storage of the field, rows vary along k
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `data`: &'a [T] storage of the field
+    ///
+    /// # Returns
+    /// `impl Iterator<Item = &'a [T]>` rows of the box in storage order
+    ///
+    /// # Errors
+    ///
+    fn rows<'a, T>(&self, data: &'a [T]) -> impl Iterator<Item = &'a [T]> {
+        let (start, cells, g) = (self.start, self.cells.clone(), self.ghost);
+        let (rows, columns) = (self.field.y + 2 * g, self.field.z + 2 * g);
+        data[(start.0 + g) * rows * columns..(start.0 + g + cells.x) * rows * columns]
+            .chunks(columns)
+            .enumerate()
+            .filter(move |(m, _)| (start.1 + g..start.1 + g + cells.y).contains(&(m % rows)))
+            .map(move |(_, row)| &row[start.2 + g..start.2 + g + cells.z])
+    }
+
+    /// returns the mutable rows of the box within the storage of the field, rows vary along k
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `data`: &'a mut [T] storage of the field
+    ///
+    /// # Returns
+    /// `impl Iterator<Item = &'a mut [T]>` rows of the box in storage order
+    ///
+    /// # Errors
+    ///
+    fn rows_mut<'a, T>(&self, data: &'a mut [T]) -> impl Iterator<Item = &'a mut [T]> {
+        let (start, cells, g) = (self.start, self.cells.clone(), self.ghost);
+        let (rows, columns) = (self.field.y + 2 * g, self.field.z + 2 * g);
+        data[(start.0 + g) * rows * columns..(start.0 + g + cells.x) * rows * columns]
+            .chunks_mut(columns)
+            .enumerate()
+            .filter(move |(m, _)| (start.1 + g..start.1 + g + cells.y).contains(&(m % rows)))
+            .map(move |(_, row)| &mut row[start.2 + g..start.2 + g + cells.z])
+    }
+}
+
+/// `ScalarView<'a, T>` struct
+///
+/// describes a borrowed box of nodes of a `ScalarField<T>`, indexed relative to the first node of the box
+#[derive(Debug, Clone)]
+pub struct ScalarView<'a, T> {
+    /// storage of the viewed field
+    data: &'a [T],
+
+    /// viewed box of nodes
+    region: Region,
+}
+
+impl<'a, T: Num + Copy> ScalarView<'a, T> {
+    /// returns number of cells of `ScalarView<'a, T>`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `&CoordinateTriplet<usize>` number of cells
+    ///
+    /// # Errors
+    ///
+    pub fn cells(&self) -> &CoordinateTriplet<usize> {
+        &self.region.cells
+    }
+
+    /// returns the (i, j, k) index of the first node of `ScalarView<'a, T>` within the viewed field
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `(usize, usize, usize)` (i, j, k) indices
+    ///
+    /// # Errors
+    ///
+    pub fn start(&self) -> (usize, usize, usize) {
+        self.region.start
+    }
+
+    /// returns an iterator over `ScalarView<'a, T>` with k varying fastest
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `impl Iterator<Item = &'a T> + '_`
+    ///
+    /// # Errors
+    ///
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> + '_ {
+        self.region.rows(self.data).flatten()
+    }
+
+    /// copies `ScalarView<'a, T>` into a new `ScalarField<T>`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `Result<ScalarField<T>, anyhow::Error>` field of the cells of the view
+    ///
+    /// # Errors
+    /// - call to `ScalarField::new()` fails
+    ///
+    pub fn to_field(&self) -> Result<ScalarField<T>, anyhow::Error> {
+        let mut field = ScalarField::new(&self.region.cells)?;
+        field
+            .iter_mut()
+            .zip(self.iter())
+            .for_each(|(elem, num)| *elem = *num);
+
+        Ok(field)
+    }
+}
+
+/// implements [] operator on `ScalarView<'a, T>`
+impl<T> Index<(usize, usize, usize)> for ScalarView<'_, T> {
+    type Output = T;
+
+    /// implements [] operator on `ScalarView<'a, T>`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `idx: (usize, usize, usize)` (i, j, k) index relative to the first node of the view
+    ///
+    /// # Returns
+    /// `&Self::Output` reference to data at (i, j, k) index of the view
+    ///
+    /// # Errors
+    ///
+    /// # Panics
+    /// - `idx` is outside of the view
+    ///
+    fn index(&self, idx: (usize, usize, usize)) -> &Self::Output {
+        &self.data[self.region.linear(idx)]
+    }
+}
+
+/// `ScalarViewMut<'a, T>` struct
+///
+/// describes a mutably borrowed box of nodes of a `ScalarField<T>`, indexed relative to the first node of the box
+#[derive(Debug)]
+pub struct ScalarViewMut<'a, T> {
+    /// storage of the viewed field
+    data: &'a mut [T],
+
+    /// viewed box of nodes
+    region: Region,
+}
+
+impl<T: Num + Copy> ScalarViewMut<'_, T> {
+    /// reborrows `ScalarViewMut<'a, T>` as an immutable view
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `ScalarView<'_, T>` view of the same nodes
+    ///
+    /// # Errors
+    ///
+    pub fn as_view(&self) -> ScalarView<'_, T> {
+        ScalarView {
+            data: self.data,
+            region: self.region.clone(),
+        }
+    }
+
+    /// returns number of cells of `ScalarViewMut<'a, T>`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `&CoordinateTriplet<usize>` number of cells
+    ///
+    /// # Errors
+    ///
+    pub fn cells(&self) -> &CoordinateTriplet<usize> {
+        &self.region.cells
+    }
+
+    /// returns a mutable iterator over `ScalarViewMut<'a, T>` with k varying fastest
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    ///
+    /// # Returns
+    /// `impl Iterator<Item = &mut T> + '_`
+    ///
+    /// # Errors
+    ///
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> + '_ {
+        self.region.rows_mut(self.data).flatten()
+    }
+
+    /// sets every node of `ScalarViewMut<'a, T>` to a value
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `value`: T value of every node
+    ///
+    /// # Returns
+    ///
+    /// # Errors
+    ///
+    pub fn fill(&mut self, value: T) {
+        self.iter_mut().for_each(|elem| *elem = value);
+    }
+
+    /// copies values of a view of equal shape into `ScalarViewMut<'a, T>`
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `source`: &ScalarView<T> view of values to copy
+    ///
+    /// # Returns
+    /// `Result<(), anyhow::Error>`
+    ///
+    /// # Errors
+    /// - `cells` of `self` and `source` differ, in which case `self` is left unmodified
+    ///
+    pub fn copy_from(&mut self, source: &ScalarView<T>) -> Result<(), anyhow::Error> {
+        check_shapes(&self.region.cells, &source.region.cells)?;
+        self.iter_mut()
+            .zip(source.iter())
+            .for_each(|(elem, num)| *elem = *num);
+
+        Ok(())
+    }
+}
+
+/// implements [] operator on `ScalarViewMut<'a, T>`
+impl<T> Index<(usize, usize, usize)> for ScalarViewMut<'_, T> {
+    type Output = T;
+
+    /// implements [] operator on `ScalarViewMut<'a, T>`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `idx: (usize, usize, usize)` (i, j, k) index relative to the first node of the view
+    ///
+    /// # Returns
+    /// `&Self::Output` reference to data at (i, j, k) index of the view
+    ///
+    /// # Errors
+    ///
+    /// # Panics
+    /// - `idx` is outside of the view
+    ///
+    fn index(&self, idx: (usize, usize, usize)) -> &Self::Output {
+        &self.data[self.region.linear(idx)]
+    }
+}
+
+/// implements mutable [] operator on `ScalarViewMut<'a, T>`
+impl<T> IndexMut<(usize, usize, usize)> for ScalarViewMut<'_, T> {
+    /// implements mutable [] operator on `ScalarViewMut<'a, T>`
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `idx: (usize, usize, usize)` (i, j, k) index relative to the first node of the view
+    ///
+    /// # Returns
+    /// `&mut Self::Output` mutable reference to data at (i, j, k) index of the view
+    ///
+    /// # Errors
+    ///
+    /// # Panics
+    /// - `idx` is outside of the view
+    ///
+    fn index_mut(&mut self, idx: (usize, usize, usize)) -> &mut Self::Output {
+        &mut self.data[self.region.linear(idx)]
+    }
+}
+
+impl<T: Num + Copy> ScalarField<T> {
+    /// returns a view of a sub-box of `ScalarField<T>`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `start`: (usize, usize, usize) (i, j, k) index of the first node of the sub-box
+    /// - `cells`: &CoordinateTriplet<usize> number of nodes of the sub-box along each axis
+    ///
+    /// # Returns
+    /// `Result<ScalarView<'_, T>, anyhow::Error>`
+    ///
+    /// # Errors
+    /// - sub-box has no nodes along any axis
+    /// - sub-box extends beyond `ScalarField<T>` along any axis
+    ///
+    pub fn sub_box(
+        &self,
+        start: (usize, usize, usize),
+        cells: &CoordinateTriplet<usize>,
+    ) -> Result<ScalarView<'_, T>, anyhow::Error> {
+        let region = Region::new(self.cells(), self.ghost(), start, cells)?;
+        Ok(ScalarView {
+            data: self.as_slice(),
+            region,
+        })
+    }
+
+    /// returns a mutable view of a sub-box of `ScalarField<T>`
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `start`: (usize, usize, usize) (i, j, k) index of the first node of the sub-box
+    /// - `cells`: &CoordinateTriplet<usize> number of nodes of the sub-box along each axis
+    ///
+    /// # Returns
+    /// `Result<ScalarViewMut<'_, T>, anyhow::Error>`
+    ///
+    /// # Errors
+    /// - sub-box has no nodes along any axis
+    /// - sub-box extends beyond `ScalarField<T>` along any axis
+    ///
+    pub fn sub_box_mut(
+        &mut self,
+        start: (usize, usize, usize),
+        cells: &CoordinateTriplet<usize>,
+    ) -> Result<ScalarViewMut<'_, T>, anyhow::Error> {
+        let region = Region::new(self.cells(), self.ghost(), start, cells)?;
+        Ok(ScalarViewMut {
+            data: self.as_mut_slice(),
+            region,
+        })
+    }
+
+    /// returns a view of a plane of `ScalarField<T>` normal to an axis
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `axis`: usize axis normal to the plane, 0 for x, 1 for y, and 2 for z
+    /// - `index`: usize index of the plane along `axis`
+    ///
+    /// # Returns
+    /// `Result<ScalarView<'_, T>, anyhow::Error>` view of a single node along `axis`
+    ///
+    /// # Errors
+    /// - `axis` is not 0, 1, or 2
+    /// - `index` is outside of `ScalarField<T>`
+    ///
+    pub fn plane(&self, axis: usize, index: usize) -> Result<ScalarView<'_, T>, anyhow::Error> {
+        let region = Region::plane(self.cells(), self.ghost(), axis, index)?;
+        Ok(ScalarView {
+            data: self.as_slice(),
+            region,
+        })
+    }
+
+    /// returns a mutable view of a plane of `ScalarField<T>` normal to an axis
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `axis`: usize axis normal to the plane, 0 for x, 1 for y, and 2 for z
+    /// - `index`: usize index of the plane along `axis`
+    ///
+    /// # Returns
+    /// `Result<ScalarViewMut<'_, T>, anyhow::Error>` view of a single node along `axis`
+    ///
+    /// # Errors
+    /// - `axis` is not 0, 1, or 2
+    /// - `index` is outside of `ScalarField<T>`
+    ///
+    pub fn plane_mut(
+        &mut self,
+        axis: usize,
+        index: usize,
+    ) -> Result<ScalarViewMut<'_, T>, anyhow::Error> {
+        let region = Region::plane(self.cells(), self.ghost(), axis, index)?;
+        Ok(ScalarViewMut {
+            data: self.as_mut_slice(),
+            region,
+        })
+    }
+
+    /// returns a view of a line of `ScalarField<T>` along an axis
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `axis`: usize axis along the line, 0 for x, 1 for y, and 2 for z
+    /// - `fixed`: (usize, usize) indices of the line along the remaining axes in increasing axis order
+    ///
+    /// # Returns
+    /// `Result<ScalarView<'_, T>, anyhow::Error>` view of a single node along the remaining axes
+    ///
+    /// # Errors
+    /// - `axis` is not 0, 1, or 2
+    /// - `fixed` is outside of `ScalarField<T>`
+    ///
+    pub fn line(
+        &self,
+        axis: usize,
+        fixed: (usize, usize),
+    ) -> Result<ScalarView<'_, T>, anyhow::Error> {
+        let region = Region::line(self.cells(), self.ghost(), axis, fixed)?;
+        Ok(ScalarView {
+            data: self.as_slice(),
+            region,
+        })
+    }
+
+    /// returns a mutable view of a line of `ScalarField<T>` along an axis
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `axis`: usize axis along the line, 0 for x, 1 for y, and 2 for z
+    /// - `fixed`: (usize, usize) indices of the line along the remaining axes in increasing axis order
+    ///
+    /// # Returns
+    /// `Result<ScalarViewMut<'_, T>, anyhow::Error>` view of a single node along the remaining axes
+    ///
+    /// # Errors
+    /// - `axis` is not 0, 1, or 2
+    /// - `fixed` is outside of `ScalarField<T>`
+    ///
+    pub fn line_mut(
+        &mut self,
+        axis: usize,
+        fixed: (usize, usize),
+    ) -> Result<ScalarViewMut<'_, T>, anyhow::Error> {
+        let region = Region::line(self.cells(), self.ghost(), axis, fixed)?;
+        Ok(ScalarViewMut {
+            data: self.as_mut_slice(),
+            region,
+        })
+    }
+}
+
+/// `PlaneRows<'a, T>` struct
+///
+/// describes a borrowed plane of nodes of constant i of a `ScalarField<T>` as contiguous rows along k, planes borrow
+/// disjoint storage so that distinct planes may be processed on distinct threads
+#[derive(Debug, Clone, Copy)]
+pub struct PlaneRows<'a, T> {
+    /// storage of the plane including ghost nodes
+    data: &'a [T],
+
+    /// index of the plane along x
+    index: usize,
+
+    /// number of interior nodes along y and z
+    cells: (usize, usize),
+
+    /// width of ghost layers of the field on every side
+    ghost: usize,
+}
+
+impl<'a, T> PlaneRows<'a, T> {
+    /// returns index along x of `PlaneRows<'a, T>`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `usize` index of the plane along x
+    ///
+    /// # Errors
+    ///
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// returns the contiguous row of interior nodes (j, 0..cells.z) of `PlaneRows<'a, T>`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `j`: usize index of node along y
+    ///
+    /// # Returns
+    /// `&'a [T]` values of nodes along k
+    ///
+    /// # Errors
+    ///
+    /// # Panics
+    /// - `j` is out of bounds
+    ///
+    pub fn row(&self, j: usize) -> &'a [T] {
+        let start = row_start(self.cells, self.ghost, j);
+        &self.data[start..start + self.cells.1]
+    }
+}
+
+/// `PlaneRowsMut<'a, T>` struct
+///
+/// describes a mutably borrowed plane of nodes of constant i of a `ScalarField<T>` as contiguous rows along k
+#[derive(Debug)]
+pub struct PlaneRowsMut<'a, T> {
+    /// storage of the plane including ghost nodes
+    data: &'a mut [T],
+
+    /// index of the plane along x
+    index: usize,
+
+    /// number of interior nodes along y and z
+    cells: (usize, usize),
+
+    /// width of ghost layers of the field on every side
+    ghost: usize,
+}
+
+impl<T> PlaneRowsMut<'_, T> {
+    /// returns index along x of `PlaneRowsMut<'a, T>`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `usize` index of the plane along x
+    ///
+    /// # Errors
+    ///
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// returns the contiguous row of interior nodes (j, 0..cells.z) of `PlaneRowsMut<'a, T>`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `j`: usize index of node along y
+    ///
+    /// # Returns
+    /// `&[T]` values of nodes along k
+    ///
+    /// # Errors
+    ///
+    /// # Panics
+    /// - `j` is out of bounds
+    ///
+    pub fn row(&self, j: usize) -> &[T] {
+        let start = row_start(self.cells, self.ghost, j);
+        &self.data[start..start + self.cells.1]
+    }
+
+    /// returns the mutable contiguous row of interior nodes (j, 0..cells.z) of `PlaneRowsMut<'a, T>`
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `j`: usize index of node along y
+    ///
+    /// # Returns
+    /// `&mut [T]` values of nodes along k
+    ///
+    /// # Errors
+    ///
+    /// # Panics
+    /// - `j` is out of bounds
+    ///
+    pub fn row_mut(&mut self, j: usize) -> &mut [T] {
+        let start = row_start(self.cells, self.ghost, j);
+        &mut self.data[start..start + self.cells.1]
+    }
+
+    /// returns the mutable row j of `PlaneRowsMut<'a, T>` together with its neighbouring rows along y
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `j`: usize index of node along y
+    ///
+    /// # Returns
+    /// `(&mut [T], [&[T]; 2])` row j and rows j - 1 and j + 1
+    ///
+    /// # Errors
+    ///
+    /// # Panics
+    /// - j is not an interior row, both neighbouring rows must lie within the interior nodes
+    ///
+    pub fn stencil_rows_mut(&mut self, j: usize) -> (&mut [T], [&[T]; 2]) {
+        assert!(
+            0 < j && j + 1 < self.cells.0,
+            "row {j} has no neighbouring rows in plane of {} rows",
+            self.cells.0
+        );
+        let (start, len) = (row_start(self.cells, self.ghost, j), self.cells.1);
+        let r = self.cells.1 + 2 * self.ghost;
+
+        let (lower, rest) = self.data.split_at_mut(start);
+        let (row, upper) = rest.split_at_mut(len);
+        let (lower, upper): (&[T], &[T]) = (lower, upper);
+
+        (
+            row,
+            [&lower[start - r..start - r + len], &upper[r - len..r]],
+        )
+    }
+}
+
+/// returns the index of the first interior node of row j within the storage of a plane
+///
+/// # Arguments
+/// - `cells`: (usize, usize) number of interior nodes of the plane along y and z
+/// - `ghost`: usize width of ghost layers of the field on every side
+/// - `j`: usize index of node along y
+///
+/// # Returns
+/// `usize` index into storage of the plane
+///
+/// # Errors
+///
+/// # Panics
+/// - `j` is out of bounds
+///
+fn row_start(cells: (usize, usize), ghost: usize, j: usize) -> usize {
+    assert!(
+        j < cells.0,
+        "row {j} out of bounds for plane of {} rows",
+        cells.0
+    );
+    (j + ghost) * (cells.1 + 2 * ghost) + ghost
+}
+
+impl<T: Num + Copy> ScalarField<T> {
+    /// returns every plane of interior nodes of constant i of `ScalarField<T>` in order of i
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `Vec<PlaneRows<'_, T>>` planes of index 0..cells.x
+    ///
+    /// # Errors
+    ///
+    pub fn planes(&self) -> Vec<PlaneRows<'_, T>> {
+        let (cells, ghost) = ((self.cells().y, self.cells().z), self.ghost());
+        self.as_slice()
+            .chunks(self.plane_len())
+            .skip(ghost)
+            .take(self.cells().x)
+            .enumerate()
+            .map(|(index, data)| PlaneRows {
+                data,
+                index,
+                cells,
+                ghost,
+            })
+            .collect()
+    }
+
+    /// returns every mutable plane of interior nodes of constant i of `ScalarField<T>` in order of i
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    ///
+    /// # Returns
+    /// `Vec<PlaneRowsMut<'_, T>>` planes of index 0..cells.x
+    ///
+    /// # Errors
+    ///
+    pub fn planes_mut(&mut self) -> Vec<PlaneRowsMut<'_, T>> {
+        let (cells, ghost) = ((self.cells().y, self.cells().z), self.ghost());
+        let (len, nx) = (self.plane_len(), self.cells().x);
+        self.as_mut_slice()
+            .chunks_mut(len)
+            .skip(ghost)
+            .take(nx)
+            .enumerate()
+            .map(|(index, data)| PlaneRowsMut {
+                data,
+                index,
+                cells,
+                ghost,
+            })
+            .collect()
+    }
+
+    /// returns every mutable interior plane of constant i of a given parity of `ScalarField<T>` together with its
+    /// neighbouring planes along x, which are of the other parity, so that red-black schemes may update all planes of
+    /// a single parity concurrently
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `parity`: usize parity of i of the returned planes, 0 for even and 1 for odd
+    ///
+    /// # Returns
+    /// `Vec<(PlaneRowsMut<'_, T>, [PlaneRows<'_, T>; 2])>` planes 0 < i < cells.x - 1 of parity in order of i
+    /// together with planes i - 1 and i + 1
+    ///
+    /// # Errors
+    ///
+    pub fn alternate_planes_mut(
+        &mut self,
+        parity: usize,
+    ) -> Vec<(PlaneRowsMut<'_, T>, [PlaneRows<'_, T>; 2])> {
+        let mut own = Vec::new();
+        let mut neighbours = Vec::new();
+        for plane in self.planes_mut() {
+            if plane.index % 2 == parity % 2 {
+                own.push(plane);
+            } else {
+                let PlaneRowsMut {
+                    data,
+                    index,
+                    cells,
+                    ghost,
+                } = plane;
+                neighbours.push(PlaneRows {
+                    data,
+                    index,
+                    cells,
+                    ghost,
+                });
+            }
+        }
+
+        // planes of the other parity hold planes i - 1 and i + 1 at consecutive positions (i - 1) / 2 and (i + 1) / 2
+        let nx = neighbours.len() + own.len();
+        own.into_iter()
+            .filter(|plane| 0 < plane.index && plane.index + 1 < nx)
+            .map(|plane| {
+                let lower = (plane.index - 1) / 2;
+                (plane, [neighbours[lower], neighbours[lower + 1]])
+            })
+            .collect()
+    }
+
+    /// returns the length of storage of a single plane of constant i of `ScalarField<T>` including ghost nodes
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `usize` number of stored values per plane
+    ///
+    /// # Errors
+    ///
+    fn plane_len(&self) -> usize {
+        let ghost = self.ghost();
+        (self.cells().y + 2 * ghost) * (self.cells().z + 2 * ghost)
+    }
+}
+
+/// `VectorView<'a, T>` struct
+///
+/// describes a borrowed box of nodes of every component of a `VectorField<T>`
+#[derive(Debug, Clone)]
+pub struct VectorView<'a, T> {
+    /// view of x component
+    pub x: ScalarView<'a, T>,
+
+    /// view of y component
+    pub y: ScalarView<'a, T>,
+
+    /// view of z component
+    pub z: ScalarView<'a, T>,
+}
+
+impl<T: Num + Copy> VectorView<'_, T> {
+    /// returns number of cells of `VectorView<'a, T>`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `&CoordinateTriplet<usize>` number of cells
+    ///
+    /// # Errors
+    ///
+    pub fn cells(&self) -> &CoordinateTriplet<usize> {
+        self.x.cells()
+    }
+
+    /// copies `VectorView<'a, T>` into a new `VectorField<T>`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `Result<VectorField<T>, anyhow::Error>` field of the cells of the view
+    ///
+    /// # Errors
+    /// - call to `VectorField::new()` fails
+    /// - any call to `ScalarView::to_field()` fails
+    ///
+    pub fn to_field(&self) -> Result<VectorField<T>, anyhow::Error> {
+        let mut field = VectorField::new(self.cells())?;
+        field.x = self.x.to_field()?;
+        field.y = self.y.to_field()?;
+        field.z = self.z.to_field()?;
+
+        Ok(field)
+    }
+}
+
+/// `VectorViewMut<'a, T>` struct
+///
+/// describes a mutably borrowed box of nodes of every component of a `VectorField<T>`
+#[derive(Debug)]
+pub struct VectorViewMut<'a, T> {
+    /// mutable view of x component
+    pub x: ScalarViewMut<'a, T>,
+
+    /// mutable view of y component
+    pub y: ScalarViewMut<'a, T>,
+
+    /// mutable view of z component
+    pub z: ScalarViewMut<'a, T>,
+}
+
+impl<T: Num + Copy> VectorViewMut<'_, T> {
+    /// reborrows `VectorViewMut<'a, T>` as an immutable view
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `VectorView<'_, T>` view of the same nodes
+    ///
+    /// # Errors
+    ///
+    pub fn as_view(&self) -> VectorView<'_, T> {
+        VectorView {
+            x: self.x.as_view(),
+            y: self.y.as_view(),
+            z: self.z.as_view(),
+        }
+    }
+
+    /// sets every node of every component of `VectorViewMut<'a, T>` to a value
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `value`: [T; 3] value of x, y, and z component of every node
+    ///
+    /// # Returns
+    ///
+    /// # Errors
+    ///
+    pub fn fill(&mut self, value: [T; 3]) {
+        self.x.fill(value[0]);
+        self.y.fill(value[1]);
+        self.z.fill(value[2]);
+    }
+
+    /// copies values of a view of equal shape into `VectorViewMut<'a, T>`
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `source`: &VectorView<T> view of values to copy
+    ///
+    /// # Returns
+    /// `Result<(), anyhow::Error>`
+    ///
+    /// # Errors
+    /// - `cells` of `self` and `source` differ, in which case `self` is left unmodified
+    ///
+    pub fn copy_from(&mut self, source: &VectorView<T>) -> Result<(), anyhow::Error> {
+        check_shapes(self.x.cells(), source.cells())?;
+        self.x.copy_from(&source.x)?;
+        self.y.copy_from(&source.y)?;
+        self.z.copy_from(&source.z)?;
+
+        Ok(())
+    }
+}
+
+impl<T: Num + Copy> VectorField<T> {
+    /// returns a view of a sub-box of `VectorField<T>`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `start`: (usize, usize, usize) (i, j, k) index of the first node of the sub-box
+    /// - `cells`: &CoordinateTriplet<usize> number of nodes of the sub-box along each axis
+    ///
+    /// # Returns
+    /// `Result<VectorView<'_, T>, anyhow::Error>`
+    ///
+    /// # Errors
+    /// - any call to `ScalarField::sub_box()` fails
+    ///
+    pub fn sub_box(
+        &self,
+        start: (usize, usize, usize),
+        cells: &CoordinateTriplet<usize>,
+    ) -> Result<VectorView<'_, T>, anyhow::Error> {
+        Ok(VectorView {
+            x: self.x.sub_box(start, cells)?,
+            y: self.y.sub_box(start, cells)?,
+            z: self.z.sub_box(start, cells)?,
+        })
+    }
+
+    /// returns a mutable view of a sub-box of `VectorField<T>`
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `start`: (usize, usize, usize) (i, j, k) index of the first node of the sub-box
+    /// - `cells`: &CoordinateTriplet<usize> number of nodes of the sub-box along each axis
+    ///
+    /// # Returns
+    /// `Result<VectorViewMut<'_, T>, anyhow::Error>`
+    ///
+    /// # Errors
+    /// - any call to `ScalarField::sub_box_mut()` fails
+    ///
+    pub fn sub_box_mut(
+        &mut self,
+        start: (usize, usize, usize),
+        cells: &CoordinateTriplet<usize>,
+    ) -> Result<VectorViewMut<'_, T>, anyhow::Error> {
+        Ok(VectorViewMut {
+            x: self.x.sub_box_mut(start, cells)?,
+            y: self.y.sub_box_mut(start, cells)?,
+            z: self.z.sub_box_mut(start, cells)?,
+        })
+    }
+
+    /// returns a view of a plane of `VectorField<T>` normal to an axis
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `axis`: usize axis normal to the plane, 0 for x, 1 for y, and 2 for z
+    /// - `index`: usize index of the plane along `axis`
+    ///
+    /// # Returns
+    /// `Result<VectorView<'_, T>, anyhow::Error>` view of a single node along `axis`
+    ///
+    /// # Errors
+    /// - any call to `ScalarField::plane()` fails
+    ///
+    pub fn plane(&self, axis: usize, index: usize) -> Result<VectorView<'_, T>, anyhow::Error> {
+        Ok(VectorView {
+            x: self.x.plane(axis, index)?,
+            y: self.y.plane(axis, index)?,
+            z: self.z.plane(axis, index)?,
+        })
+    }
+
+    /// returns a mutable view of a plane of `VectorField<T>` normal to an axis
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `axis`: usize axis normal to the plane, 0 for x, 1 for y, and 2 for z
+    /// - `index`: usize index of the plane along `axis`
+    ///
+    /// # Returns
+    /// `Result<VectorViewMut<'_, T>, anyhow::Error>` view of a single node along `axis`
+    ///
+    /// # Errors
+    /// - any call to `ScalarField::plane_mut()` fails
+    ///
+    pub fn plane_mut(
+        &mut self,
+        axis: usize,
+        index: usize,
+    ) -> Result<VectorViewMut<'_, T>, anyhow::Error> {
+        Ok(VectorViewMut {
+            x: self.x.plane_mut(axis, index)?,
+            y: self.y.plane_mut(axis, index)?,
+            z: self.z.plane_mut(axis, index)?,
+        })
+    }
+
+    /// returns a view of a line of `VectorField<T>` along an axis
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `axis`: usize axis along the line, 0 for x, 1 for y, and 2 for z
+    /// - `fixed`: (usize, usize) indices of the line along the remaining axes in increasing axis order
+    ///
+    /// # Returns
+    /// `Result<VectorView<'_, T>, anyhow::Error>` view of a single node along the remaining axes
+    ///
+    /// # Errors
+    /// - any call to `ScalarField::line()` fails
+    ///
+    pub fn line(
+        &self,
+        axis: usize,
+        fixed: (usize, usize),
+    ) -> Result<VectorView<'_, T>, anyhow::Error> {
+        Ok(VectorView {
+            x: self.x.line(axis, fixed)?,
+            y: self.y.line(axis, fixed)?,
+            z: self.z.line(axis, fixed)?,
+        })
+    }
+
+    /// returns a mutable view of a line of `VectorField<T>` along an axis
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `axis`: usize axis along the line, 0 for x, 1 for y, and 2 for z
+    /// - `fixed`: (usize, usize) indices of the line along the remaining axes in increasing axis order
+    ///
+    /// # Returns
+    /// `Result<VectorViewMut<'_, T>, anyhow::Error>` view of a single node along the remaining axes
+    ///
+    /// # Errors
+    /// - any call to `ScalarField::line_mut()` fails
+    ///
+    pub fn line_mut(
+        &mut self,
+        axis: usize,
+        fixed: (usize, usize),
+    ) -> Result<VectorViewMut<'_, T>, anyhow::Error> {
+        Ok(VectorViewMut {
+            x: self.x.line_mut(axis, fixed)?,
+            y: self.y.line_mut(axis, fixed)?,
+            z: self.z.line_mut(axis, fixed)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::field::scalar::ScalarField;
+    use crate::field::vector::VectorField;
+    use crate::utils::coordinate_triplet::CoordinateTriplet;
+
+    /// helper function that sets up a `ScalarField<f64>` holding the linear index of every node for testing
+    ///
+    /// # Arguments
+    ///
+    /// # Returns
+    /// `ScalarField<f64>`
+    ///
+    /// # Errors
+    ///
+    fn setup() -> ScalarField<f64> {
+        let cells = CoordinateTriplet::new(3, 4, 5).unwrap();
+        let mut scalar_field: ScalarField<f64> = ScalarField::new(&cells).unwrap();
+        scalar_field
+            .iter_mut()
+            .enumerate()
+            .for_each(|(n, v)| *v = n as f64);
+        scalar_field
+    }
+
+    /// tests view constructors of `ScalarField<T>` for failure
+    ///
+    /// # Errors
+    /// - `ScalarField::sub_box()` succeeds for a box without nodes or extending beyond the field
+    /// - `ScalarField::plane()` or `ScalarField::line()` succeeds for an invalid axis or index
+    ///
+    #[test]
+    fn views_failure() {
+        // setup
+        let scalar_field = setup();
+        let empty = CoordinateTriplet::new(1, 0, 1).unwrap();
+        let large = CoordinateTriplet::new(2, 2, 2).unwrap();
+
+        // assertions
+        assert!(scalar_field.sub_box((0, 0, 0), &empty).is_err());
+        assert!(scalar_field.sub_box((2, 0, 0), &large).is_err());
+        assert!(scalar_field.sub_box((1, 2, 3), &large).is_ok());
+        assert!(scalar_field.plane(3, 0).is_err());
+        assert!(scalar_field.plane(1, 4).is_err());
+        assert!(scalar_field.line(2, (3, 0)).is_err());
+        assert!(scalar_field.line(4, (0, 0)).is_err());
+    }
+
+    /// tests `ScalarView<'a, T>` for correct iteration, indexing, and copying
+    ///
+    /// # Errors
+    /// - `ScalarView::iter()` does not visit nodes of the sub-box in storage order
+    /// - [] operator on `ScalarView<'a, T>` is not relative to the first node of the sub-box
+    /// - `ScalarView::to_field()` does not copy nodes of the sub-box
+    ///
+    #[test]
+    fn sub_box_correct() {
+        // setup
+        let scalar_field = setup();
+        let cells = CoordinateTriplet::new(2, 2, 3).unwrap();
+        let view = scalar_field.sub_box((1, 2, 1), &cells).unwrap();
+
+        let copy = view.to_field().unwrap();
+
+        // assertions
+        let values: Vec<f64> = view.iter().copied().collect();
+        assert_eq!(
+            values,
+            vec![31.0, 32.0, 33.0, 36.0, 37.0, 38.0, 51.0, 52.0, 53.0, 56.0, 57.0, 58.0]
+        );
+        assert_eq!(view[(1, 0, 2)], scalar_field[(2, 2, 3)]);
+        assert_eq!(view.start(), (1, 2, 1));
+        assert_eq!(*copy.cells(), cells);
+        assert_eq!(copy[(1, 1, 1)], 57.0);
+    }
+
+    /// tests planes and lines of `ScalarField<T>` for correctness
+    ///
+    /// # Errors
+    /// - `ScalarField::plane()` does not view every node with the fixed index along the normal axis
+    /// - `ScalarField::line()` does not view every node along the axis
+    ///
+    #[test]
+    fn plane_line_correct() {
+        // setup
+        let scalar_field = setup();
+
+        let plane = scalar_field.plane(1, 3).unwrap();
+        let line = scalar_field.line(0, (1, 4)).unwrap();
+
+        // assertions
+        assert_eq!(*plane.cells(), CoordinateTriplet::new(3, 1, 5).unwrap());
+        assert_eq!(plane.iter().count(), 15);
+        assert!(plane.iter().all(|v| (*v as usize / 5) % 4 == 3));
+        assert_eq!(plane[(2, 0, 4)], scalar_field[(2, 3, 4)]);
+        let values: Vec<f64> = line.iter().copied().collect();
+        assert_eq!(values, vec![9.0, 29.0, 49.0]);
+    }
+
+    /// tests `ScalarViewMut<'a, T>` for correct mutation of the viewed field
+    ///
+    /// # Errors
+    /// - `ScalarViewMut::fill()` modifies nodes outside of the view
+    /// - mutable [] operator on `ScalarViewMut<'a, T>` is not relative to the first node of the view
+    /// - `ScalarViewMut::copy_from()` does not copy a view of equal shape or accepts a view of different shape
+    ///
+    #[test]
+    fn view_mut_correct() {
+        // setup
+        let mut scalar_field = setup();
+        let source = setup();
+        let cells = CoordinateTriplet::new(3, 4, 1).unwrap();
+
+        scalar_field.plane_mut(2, 0).unwrap().fill(-1.0);
+        scalar_field.line_mut(2, (0, 0)).unwrap()[(0, 0, 3)] = -2.0;
+        let mut face = scalar_field.plane_mut(2, 4).unwrap();
+        let copied = face.copy_from(&source.sub_box((0, 0, 2), &cells).unwrap());
+        let mismatched = face.copy_from(&source.plane(0, 0).unwrap());
+
+        // assertions
+        assert!(copied.is_ok());
+        assert!(mismatched.is_err());
+        for (n, v) in scalar_field.iter().enumerate() {
+            let expected = match (n % 5, n) {
+                (_, 3) => -2.0,
+                (0, _) => -1.0,
+                (4, _) => n as f64 - 2.0,
+                _ => n as f64,
+            };
+            assert_eq!(*v, expected);
+        }
+    }
+
+    /// tests views of `VectorField<T>` for correctness
+    ///
+    /// # Errors
+    /// - `VectorField::plane()` does not view every component
+    /// - `VectorViewMut::fill()` or `VectorViewMut::copy_from()` does not set every component
+    /// - `VectorView::to_field()` does not copy every component
+    ///
+    #[test]
+    fn vector_views_correct() {
+        // setup
+        let cells = CoordinateTriplet::new(3, 4, 5).unwrap();
+        let mut vector_field: VectorField<f64> = VectorField::new(&cells).unwrap();
+        vector_field.x = setup();
+
+        vector_field
+            .line_mut(1, (0, 0))
+            .unwrap()
+            .fill([7.0, 8.0, 9.0]);
+        let source = vector_field.clone();
+        let mut line = vector_field.line_mut(1, (2, 4)).unwrap();
+        let copied = line.copy_from(&source.line(1, (0, 0)).unwrap());
+        let copy = vector_field.plane(0, 2).unwrap().to_field().unwrap();
+
+        // assertions
+        assert!(copied.is_ok());
+        assert_eq!(*copy.cells(), CoordinateTriplet::new(1, 4, 5).unwrap());
+        assert_eq!(copy.x[(0, 0, 0)], 40.0);
+        assert_eq!(copy.x[(0, 3, 4)], 7.0);
+        assert_eq!(copy.y[(0, 2, 4)], 8.0);
+        assert_eq!(copy.z[(0, 1, 3)], 0.0);
+        assert_eq!(vector_field.z[(0, 3, 0)], 9.0);
+    }
+
+    /// tests views of a `ScalarField<T>` with ghost layers for correctness
+    ///
+    /// # Errors
+    /// - views include ghost nodes or are offset by the ghost layers
+    /// - mutable views modify ghost nodes
+    ///
+    #[test]
+    fn views_ghost() {
+        // setup
+        let cells = CoordinateTriplet::new(3, 4, 5).unwrap();
+        let mut scalar_field: ScalarField<f64> = ScalarField::with_ghost(&cells, 2).unwrap();
+        scalar_field
+            .iter_mut()
+            .enumerate()
+            .for_each(|(n, v)| *v = n as f64);
+
+        scalar_field.plane_mut(0, 0).unwrap().fill(-1.0);
+
+        // assertions
+        let reference = setup();
+        let values: Vec<f64> = scalar_field
+            .line(1, (2, 3))
+            .unwrap()
+            .iter()
+            .copied()
+            .collect();
+        let expected: Vec<f64> = reference.line(1, (2, 3)).unwrap().iter().copied().collect();
+        assert_eq!(values, expected);
+        assert!(scalar_field.sub_box((1, 1, 1), &cells).is_err());
+        assert_eq!(
+            scalar_field
+                .as_slice()
+                .iter()
+                .filter(|v| **v == -1.0)
+                .count(),
+            20
+        );
+        assert_eq!(
+            scalar_field.plane(2, 4).unwrap()[(1, 2, 0)],
+            reference[(1, 2, 4)]
+        );
+    }
+
+    /// tests planes of constant i of `ScalarField<T>` for correctness with and without ghost layers
+    ///
+    /// # Errors
+    /// - rows of `ScalarField::planes()` or `ScalarField::planes_mut()` do not match rows of the field
+    /// - `ScalarField::alternate_planes_mut()` returns boundary planes, planes of the other parity, or wrong neighbours
+    /// - `PlaneRowsMut::stencil_rows_mut()` does not return rows j - 1 and j + 1
+    ///
+    #[test]
+    fn planes_correct() {
+        for ghost in [0, 2] {
+            // setup
+            let cells = CoordinateTriplet::new(6, 4, 5).unwrap();
+            let mut scalar_field: ScalarField<f64> =
+                ScalarField::with_ghost(&cells, ghost).unwrap();
+            scalar_field
+                .iter_mut()
+                .enumerate()
+                .for_each(|(n, v)| *v = n as f64);
+            for mut plane in scalar_field.planes_mut() {
+                let i = plane.index() as f64;
+                plane.row_mut(3)[4] = -i;
+            }
+            let reference = scalar_field.clone();
+
+            // assertions
+            let planes = reference.planes();
+            assert_eq!(planes.len(), 6);
+            for (i, plane) in planes.iter().enumerate() {
+                assert_eq!(plane.index(), i);
+                for j in 0..4 {
+                    assert_eq!(plane.row(j), reference.row(i, j));
+                }
+            }
+            for i in 0..6 {
+                assert_eq!(scalar_field[(i, 3, 4)], -(i as f64));
+            }
+            for (parity, expected) in [(0, vec![2, 4]), (1, vec![1, 3])] {
+                let mut alternate = scalar_field.alternate_planes_mut(parity);
+                let indices: Vec<usize> =
+                    alternate.iter().map(|(plane, _)| plane.index()).collect();
+                assert_eq!(indices, expected);
+                for (plane, [west, east]) in alternate.iter_mut() {
+                    assert_eq!(west.index() + 1, plane.index());
+                    assert_eq!(east.index(), plane.index() + 1);
+                    let i = plane.index();
+                    let (row, [south, north]) = plane.stencil_rows_mut(2);
+                    assert_eq!(row, reference.row(i, 2));
+                    assert_eq!(south, reference.row(i, 1));
+                    assert_eq!(north, reference.row(i, 3));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,340 @@
+//! grid module
+//!
+//! describes the node coordinates of rectilinear grids with uniform or non-uniform (stretched) spacing per axis
+
+use anyhow::anyhow;
+
+/// `Spacing` enum
+///
+/// describes the distribution of nodes along a single axis
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum Spacing {
+    /// equally spaced nodes
+    #[default]
+    Uniform,
+
+    /// nodes clustered towards both walls by a hyperbolic tangent stretching of positive strength, larger
+    /// strengths give finer cells at the walls
+    Tanh(f64),
+
+    /// (m) explicit strictly increasing node coordinates from 0 to the size of the bounding box
+    Nodes(Vec<f64>),
+}
+
+/// `GridConfig` struct
+///
+/// describes the spacing of nodes along every axis
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GridConfig {
+    /// spacing of nodes along x
+    pub x: Spacing,
+
+    /// spacing of nodes along y
+    pub y: Spacing,
+
+    /// spacing of nodes along z
+    pub z: Spacing,
+}
+
+impl GridConfig {
+    /// returns whether nodes are equally spaced along every axis
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `bool`
+    ///
+    /// # Errors
+    ///
+    pub fn is_uniform(&self) -> bool {
+        [&self.x, &self.y, &self.z]
+            .iter()
+            .all(|s| **s == Spacing::Uniform)
+    }
+}
+
+/// `Axis` struct
+///
+/// node coordinates along a single axis of a rectilinear grid
+#[derive(Debug, Clone, PartialEq)]
+pub struct Axis {
+    /// (m) strictly increasing node coordinates
+    nodes: Vec<f64>,
+
+    /// (m) spatial increment if nodes are equally spaced
+    uniform: Option<f64>,
+}
+
+impl Axis {
+    /// `Axis` constructor
+    ///
+    /// # Arguments
+    /// - `spacing`: &Spacing distribution of nodes
+    /// - `size`: f64 (m) size of bounding box along axis
+    /// - `cells`: usize number of nodes along axis, at least 3
+    ///
+    /// # Returns
+    /// `Result<Axis, anyhow::Error>`
+    ///
+    /// # Errors
+    /// - `cells` is less than 3
+    /// - stretching strength is not positive
+    /// - explicit node coordinates do not number `cells`, are not strictly increasing, or do not span [0, `size`]
+    pub fn new(spacing: &Spacing, size: f64, cells: usize) -> Result<Axis, anyhow::Error> {
+        if cells < 3 {
+            return Err(anyhow!("an axis needs at least 3 nodes, got {cells}"));
+        }
+
+        let xi = |i: usize| i as f64 / (cells - 1) as f64;
+        match spacing {
+            Spacing::Uniform => Ok(Axis {
+                nodes: uniform_nodes(size, cells),
+                uniform: Some(size / (cells - 1) as f64),
+            }),
+            Spacing::Tanh(beta) => {
+                if beta.is_nan() || *beta <= 0.0 {
+                    return Err(anyhow!("stretching strength must be positive, got {beta}"));
+                }
+                let nodes = (0..cells)
+                    .map(|i| 0.5 * size * (1.0 + (beta * (2.0 * xi(i) - 1.0)).tanh() / beta.tanh()))
+                    .collect();
+                Ok(Axis {
+                    nodes,
+                    uniform: None,
+                })
+            }
+            Spacing::Nodes(nodes) => {
+                if nodes.len() != cells {
+                    return Err(anyhow!(
+                        "expected {cells} node coordinates, got {}",
+                        nodes.len()
+                    ));
+                }
+                if nodes.windows(2).any(|w| w[1].is_nan() || w[1] <= w[0]) {
+                    return Err(anyhow!("node coordinates must be strictly increasing"));
+                }
+                let last = nodes[cells - 1];
+                if nodes[0] != 0.0 || (last - size).abs() > 1e-12 * size {
+                    return Err(anyhow!(
+                        "node coordinates must span [0, {size}], got [{}, {last}]",
+                        nodes[0]
+                    ));
+                }
+                Ok(Axis {
+                    nodes: nodes.clone(),
+                    uniform: None,
+                })
+            }
+        }
+    }
+
+    /// returns node coordinates of `Axis`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `&[f64]` (m) strictly increasing node coordinates
+    ///
+    /// # Errors
+    ///
+    pub fn nodes(&self) -> &[f64] {
+        &self.nodes
+    }
+
+    /// returns largest spacing between neighbouring nodes of `Axis`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `f64` (m) largest spatial increment
+    ///
+    /// # Errors
+    ///
+    pub fn max_delta(&self) -> f64 {
+        match self.uniform {
+            Some(delta) => delta,
+            None => self
+                .nodes
+                .windows(2)
+                .map(|w| w[1] - w[0])
+                .fold(0.0, f64::max),
+        }
+    }
+
+    /// locates the cell of `Axis` containing a coordinate
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `x`: f64 (m) coordinate inside bounding box
+    ///
+    /// # Returns
+    /// `(usize, f64)` index of lower node, clamped so that the upper node is inside the grid, and fractional offset
+    /// from lower node
+    ///
+    /// # Errors
+    ///
+    pub fn locate(&self, x: f64) -> (usize, f64) {
+        let n = self.nodes.len();
+        match self.uniform {
+            Some(delta) => {
+                let l = x / delta;
+                let i = (l as usize).min(n - 2);
+                (i, l - i as f64)
+            }
+            None => {
+                let i = self
+                    .nodes
+                    .partition_point(|node| *node <= x)
+                    .saturating_sub(1)
+                    .min(n - 2);
+                (i, (x - self.nodes[i]) / (self.nodes[i + 1] - self.nodes[i]))
+            }
+        }
+    }
+
+    /// computes coefficients of the second derivative at every node of `Axis`
+    ///
+    /// the second derivative at interior node i is approximated by `c[0] * (f[i - 1] - f[i]) + c[1] * (f[i + 1] -
+    /// f[i])`, which reduces to the standard three point stencil of coefficients 1 / delta^2 on uniform axes
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `Vec<[f64; 2]>` (m^-2) coefficients of lower and upper neighbour, zero on boundary nodes
+    ///
+    /// # Errors
+    ///
+    pub fn laplacian(&self) -> Vec<[f64; 2]> {
+        let n = self.nodes.len();
+        (0..n)
+            .map(|i| {
+                if i == 0 || i == n - 1 {
+                    return [0.0; 2];
+                }
+                match self.uniform {
+                    Some(delta) => [1.0 / (delta * delta); 2],
+                    None => {
+                        let lower = self.nodes[i] - self.nodes[i - 1];
+                        let upper = self.nodes[i + 1] - self.nodes[i];
+                        [
+                            2.0 / (lower * (lower + upper)),
+                            2.0 / (upper * (lower + upper)),
+                        ]
+                    }
+                }
+            })
+            .collect()
+    }
+}
+
+/// computes equally spaced node coordinates along a single axis
+///
+/// an axis holding a single node, as in reduced-dimensionality engines, spans the bounding box with its node at the
+/// origin
+///
+/// # Arguments
+/// - `size`: f64 (m) size of bounding box along axis
+/// - `cells`: usize number of nodes along axis
+///
+/// # Returns
+/// `Vec<f64>` (m) node coordinates
+///
+/// # Errors
+///
+pub fn uniform_nodes(size: f64, cells: usize) -> Vec<f64> {
+    let delta = if cells > 1 {
+        size / (cells - 1) as f64
+    } else {
+        size
+    };
+    (0..cells).map(|i| i as f64 * delta).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::grid::{Axis, Spacing};
+
+    /// tests `Axis::new()` for failure on invalid spacing
+    ///
+    /// # Errors
+    /// - `Axis::new()` succeeds for a non-positive stretching strength
+    /// - `Axis::new()` succeeds for explicit nodes of wrong count, order, or extent
+    /// - `Axis::new()` fails for valid explicit nodes
+    ///
+    #[test]
+    fn new_failure() {
+        assert!(Axis::new(&Spacing::Tanh(0.0), 1.0, 5).is_err());
+        assert!(Axis::new(&Spacing::Nodes(vec![0.0, 0.5, 1.0]), 1.0, 4).is_err());
+        assert!(Axis::new(&Spacing::Nodes(vec![0.0, 0.6, 0.5, 1.0]), 1.0, 4).is_err());
+        assert!(Axis::new(&Spacing::Nodes(vec![0.0, 0.2, 0.5, 0.9]), 1.0, 4).is_err());
+        assert!(Axis::new(&Spacing::Nodes(vec![0.0, 0.2, 0.5, 1.0]), 1.0, 4).is_ok());
+    }
+
+    /// tests `Axis::new()` for tanh stretching clustered symmetrically towards both walls
+    ///
+    /// # Errors
+    /// - nodes do not span the bounding box
+    /// - nodes are not symmetric about the center
+    /// - wall cells are not finer than center cells
+    ///
+    #[test]
+    fn new_tanh_correct() {
+        // setup
+        let axis = Axis::new(&Spacing::Tanh(2.0), 2.0, 21).unwrap();
+        let x = axis.nodes();
+
+        // assertions
+        assert!(x[0].abs() < 1e-15);
+        assert!((x[20] - 2.0).abs() < 1e-15);
+        for i in 0..21 {
+            assert!((x[i] + x[20 - i] - 2.0).abs() < 1e-14);
+        }
+        assert!(x[1] - x[0] < 0.5 * (x[11] - x[10]));
+        assert_eq!(axis.max_delta(), x[11] - x[10]);
+    }
+
+    /// tests `Axis::locate()` for consistency between uniform and explicit equally spaced nodes
+    ///
+    /// # Errors
+    /// - lower node or fractional offset differ
+    /// - coordinates on the upper wall are not located in the last cell
+    ///
+    #[test]
+    fn locate_correct() {
+        // setup
+        let uniform = Axis::new(&Spacing::Uniform, 1.0, 5).unwrap();
+        let explicit = Axis::new(&Spacing::Nodes(vec![0.0, 0.25, 0.5, 0.75, 1.0]), 1.0, 5).unwrap();
+
+        // assertions
+        for x in [0.0, 0.1, 0.25, 0.6, 0.99, 1.0] {
+            let (i, f) = uniform.locate(x);
+            let (j, g) = explicit.locate(x);
+            assert_eq!(i, j);
+            assert!((f - g).abs() < 1e-12);
+        }
+        assert_eq!(explicit.locate(1.0), (3, 1.0));
+    }
+
+    /// tests `Axis::laplacian()` for exactness on a quadratic on stretched nodes
+    ///
+    /// # Errors
+    /// - second derivative of a quadratic is not exact on interior nodes
+    ///
+    #[test]
+    fn laplacian_exact_quadratic() {
+        // setup
+        let axis = Axis::new(&Spacing::Tanh(1.5), 1.0, 11).unwrap();
+        let x = axis.nodes();
+        let f: Vec<f64> = x.iter().map(|x| 3.0 * x * x - x + 2.0).collect();
+
+        // assertions
+        for (i, c) in axis.laplacian().iter().enumerate().take(10).skip(1) {
+            let second = c[0] * (f[i - 1] - f[i]) + c[1] * (f[i + 1] - f[i]);
+            assert!((second - 6.0).abs() < 1e-9);
+        }
+    }
+}
@@ -1,7 +1,19 @@
 //! picrs library
 
-mod constants;
-mod engine;
-mod field;
+pub mod checkpoint;
+pub mod collisions;
+pub mod config;
+pub mod constants;
+pub mod diagnostics;
+pub mod emission;
+pub mod engine;
+pub mod field;
+pub mod grid;
+pub mod magnetostatics;
 pub mod model;
-mod utils;
+pub mod output;
+pub mod parallel;
+pub mod plasma;
+pub mod precision;
+pub mod species;
+pub mod utils;
@@ -0,0 +1,835 @@
+//! magnetostatics module
+//!
+//! computes static applied magnetic flux densities of current loops, solenoids, and uniformly magnetized blocks
+
+use crate::constants::VAC_PERMEABILITY;
+use crate::field::vector::VectorField;
+use crate::utils::coordinate_triplet::CoordinateTriplet;
+use anyhow::anyhow;
+use std::f64::consts::PI;
+use std::path::{Path, PathBuf};
+use vtkio::model::{
+    Attribute, Attributes, ByteOrder, Coordinates, DataSet, Extent, RectilinearGridPiece, Version,
+};
+use vtkio::Vtk;
+
+/// `Source` enum
+///
+/// describes a source of static magnetic flux density
+#[derive(Debug, Clone, PartialEq)]
+pub enum Source {
+    /// circular loop of wire, current circulates counterclockwise about `normal`
+    Loop {
+        /// (m) center of loop
+        center: [f64; 3],
+
+        /// normal of plane of loop, need not be normalized
+        normal: [f64; 3],
+
+        /// (m) radius of loop
+        radius: f64,
+
+        /// (A) current through loop
+        current: f64,
+    },
+
+    /// solenoid of equally spaced circular turns, current circulates counterclockwise about `axis`
+    Solenoid {
+        /// (m) center of solenoid
+        center: [f64; 3],
+
+        /// axis of solenoid, need not be normalized
+        axis: [f64; 3],
+
+        /// (m) radius of turns
+        radius: f64,
+
+        /// (m) length of solenoid
+        length: f64,
+
+        /// number of turns
+        turns: usize,
+
+        /// (A) current through each turn
+        current: f64,
+    },
+
+    /// uniformly magnetized rectangular block aligned with the axes of the bounding box
+    Block {
+        /// (m) lower corner of block
+        min: [f64; 3],
+
+        /// (m) upper corner of block
+        max: [f64; 3],
+
+        /// (A/m) magnetization of block
+        magnetization: [f64; 3],
+    },
+}
+
+impl Source {
+    /// validates geometry of `Source`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `Result<(), anyhow::Error>`
+    ///
+    /// # Errors
+    /// - radius or length of a loop or solenoid is not positive
+    /// - normal or axis of a loop or solenoid is zero
+    /// - solenoid has no turns
+    /// - lower corner of a block is not below its upper corner along every axis
+    pub fn validate(&self) -> Result<(), anyhow::Error> {
+        match self {
+            Source::Loop { normal, radius, .. } => {
+                if radius.is_nan() || *radius <= 0.0 {
+                    return Err(anyhow!("loop radius must be positive, got {radius}"));
+                }
+                if norm(normal) == 0.0 {
+                    return Err(anyhow!("loop normal must be non-zero"));
+                }
+            }
+            Source::Solenoid {
+                axis,
+                radius,
+                length,
+                turns,
+                ..
+            } => {
+                if radius.is_nan() || *radius <= 0.0 || length.is_nan() || *length <= 0.0 {
+                    return Err(anyhow!(
+                        "solenoid radius and length must be positive, got {radius} and {length}"
+                    ));
+                }
+                if norm(axis) == 0.0 {
+                    return Err(anyhow!("solenoid axis must be non-zero"));
+                }
+                if *turns == 0 {
+                    return Err(anyhow!("solenoid must have at least one turn"));
+                }
+            }
+            Source::Block { min, max, .. } => {
+                if min
+                    .iter()
+                    .zip(max.iter())
+                    .any(|(lo, hi)| lo.is_nan() || lo >= hi)
+                {
+                    return Err(anyhow!(
+                        "lower corner of block must be below upper corner, got {min:?} and {max:?}"
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// computes magnetic flux density of `Source` at a point
+    ///
+    /// loops and solenoids are integrated with the biot-savart law over inscribed polygons, blocks are evaluated
+    /// analytically from the magnetic surface charges on their faces, the field is finite but ill-defined on the
+    /// wires of loops and the edges of blocks and takes the average of both sides on the faces of blocks
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `point`: &[f64; 3] (m) point to evaluate at
+    /// - `segments`: usize number of straight segments approximating each circular turn
+    ///
+    /// # Returns
+    /// `[f64; 3]` (T) magnetic flux density
+    ///
+    /// # Errors
+    ///
+    pub fn field(&self, point: &[f64; 3], segments: usize) -> [f64; 3] {
+        match self {
+            Source::Loop {
+                center,
+                normal,
+                radius,
+                current,
+            } => loop_field(point, center, normal, *radius, *current, segments),
+            Source::Solenoid {
+                center,
+                axis,
+                radius,
+                length,
+                turns,
+                current,
+            } => {
+                let n = normalize(axis);
+                (0..*turns).fold([0.0; 3], |b, m| {
+                    // turns are centered in equal slices of the length of the solenoid
+                    let offset = ((m as f64 + 0.5) / *turns as f64 - 0.5) * length;
+                    let c = [
+                        center[0] + offset * n[0],
+                        center[1] + offset * n[1],
+                        center[2] + offset * n[2],
+                    ];
+                    add(&b, &loop_field(point, &c, &n, *radius, *current, segments))
+                })
+            }
+            Source::Block {
+                min,
+                max,
+                magnetization,
+            } => block_field(point, min, max, magnetization),
+        }
+    }
+}
+
+/// `MagnetostaticConfig` struct
+///
+/// describes static sources of applied magnetic flux density in addition to the uniform applied field
+#[derive(Debug, Clone, PartialEq)]
+pub struct MagnetostaticConfig {
+    /// sources of magnetic flux density, empty applies only the uniform applied field
+    pub sources: Vec<Source>,
+
+    /// number of straight segments approximating each circular turn of loops and solenoids
+    pub segments: usize,
+
+    /// destination of a legacy vtk file of the applied field on the nodes of the grid, `None` disables output
+    pub vtk: Option<PathBuf>,
+}
+
+impl Default for MagnetostaticConfig {
+    /// `MagnetostaticConfig` default constructor, no sources
+    ///
+    /// # Arguments
+    ///
+    /// # Returns
+    /// `MagnetostaticConfig`
+    ///
+    /// # Errors
+    ///
+    fn default() -> MagnetostaticConfig {
+        MagnetostaticConfig {
+            sources: Vec::new(),
+            segments: 256,
+            vtk: None,
+        }
+    }
+}
+
+/// computes applied magnetic flux density on the nodes of a rectilinear grid
+///
+/// # Arguments
+/// - `config`: &MagnetostaticConfig sources of magnetic flux density
+/// - `uniform`: &[f64; 3] (T) uniform applied magnetic flux density
+/// - `x`: &[f64] (m) node coordinates along x
+/// - `y`: &[f64] (m) node coordinates along y
+/// - `z`: &[f64] (m) node coordinates along z
+///
+/// # Returns
+/// `Result<VectorField<f64>, anyhow::Error>` (T) sum of uniform field and fields of all sources on every node
+///
+/// # Errors
+/// - fewer than 3 segments per turn
+/// - any call to `Source::validate()` fails
+/// - call to `CoordinateTriplet::new()` fails
+/// - call to `VectorField::new()` fails
+pub fn applied_field(
+    config: &MagnetostaticConfig,
+    uniform: &[f64; 3],
+    x: &[f64],
+    y: &[f64],
+    z: &[f64],
+) -> Result<VectorField<f64>, anyhow::Error> {
+    if config.segments < 3 {
+        return Err(anyhow!(
+            "circular turns need at least 3 segments, got {}",
+            config.segments
+        ));
+    }
+    for source in config.sources.iter() {
+        source.validate()?;
+    }
+
+    let cells = CoordinateTriplet::new(x.len(), y.len(), z.len())?;
+    let mut field: VectorField<f64> = VectorField::new(&cells)?;
+    for (i, px) in x.iter().enumerate() {
+        for (j, py) in y.iter().enumerate() {
+            for (k, pz) in z.iter().enumerate() {
+                let point = [*px, *py, *pz];
+                let b = config.sources.iter().fold(*uniform, |b, source| {
+                    add(&b, &source.field(&point, config.segments))
+                });
+                field.x[(i, j, k)] = b[0];
+                field.y[(i, j, k)] = b[1];
+                field.z[(i, j, k)] = b[2];
+            }
+        }
+    }
+
+    Ok(field)
+}
+
+/// writes a magnetic flux density on the nodes of a rectilinear grid to a legacy vtk file
+///
+/// # Arguments
+/// - `path`: &Path destination of file, the extension selects the vtk format
+/// - `field`: &VectorField<f64> (T) magnetic flux density on every node
+/// - `x`: &[f64] (m) node coordinates along x
+/// - `y`: &[f64] (m) node coordinates along y
+/// - `z`: &[f64] (m) node coordinates along z
+///
+/// # Returns
+/// `Result<(), anyhow::Error>`
+///
+/// # Errors
+/// - node coordinates do not match the cells of `field`
+/// - file could not be written
+pub fn write_vtk(
+    path: &Path,
+    field: &VectorField<f64>,
+    x: &[f64],
+    y: &[f64],
+    z: &[f64],
+) -> Result<(), anyhow::Error> {
+    let cells = field.cells();
+    if (x.len(), y.len(), z.len()) != (cells.x, cells.y, cells.z) {
+        return Err(anyhow!(
+            "node coordinates of {}x{}x{} nodes do not match field of {}x{}x{} nodes",
+            x.len(),
+            y.len(),
+            z.len(),
+            cells.x,
+            cells.y,
+            cells.z
+        ));
+    }
+
+    // vtk orders points with x varying fastest
+    let mut data = Vec::with_capacity(3 * cells.x * cells.y * cells.z);
+    for k in 0..cells.z {
+        for j in 0..cells.y {
+            for i in 0..cells.x {
+                data.extend_from_slice(&[
+                    field.x[(i, j, k)],
+                    field.y[(i, j, k)],
+                    field.z[(i, j, k)],
+                ]);
+            }
+        }
+    }
+
+    let extent = Extent::Dims([cells.x as u32, cells.y as u32, cells.z as u32]);
+    let vtk = Vtk {
+        version: Version::new((4, 2)),
+        title: String::from("picrs applied magnetic flux density"),
+        byte_order: ByteOrder::BigEndian,
+        file_path: None,
+        data: DataSet::from(RectilinearGridPiece {
+            extent,
+            coords: Coordinates {
+                x: x.to_vec().into(),
+                y: y.to_vec().into(),
+                z: z.to_vec().into(),
+            },
+            data: Attributes {
+                point: vec![Attribute::vectors("magnetic_field").with_data(data)],
+                cell: Vec::new(),
+            },
+        }),
+    };
+    vtk.export(path)
+        .map_err(|e| anyhow!("could not write {}: {e}", path.display()))?;
+
+    Ok(())
+}
+
+/// computes magnetic flux density of a circular loop with the biot-savart law over an inscribed polygon
+///
+/// # Arguments
+/// - `point`: &[f64; 3] (m) point to evaluate at
+/// - `center`: &[f64; 3] (m) center of loop
+/// - `normal`: &[f64; 3] non-zero normal of plane of loop
+/// - `radius`: f64 (m) radius of loop
+/// - `current`: f64 (A) current circulating counterclockwise about `normal`
+/// - `segments`: usize number of straight segments
+///
+/// # Returns
+/// `[f64; 3]` (T) magnetic flux density
+///
+/// # Errors
+///
+fn loop_field(
+    point: &[f64; 3],
+    center: &[f64; 3],
+    normal: &[f64; 3],
+    radius: f64,
+    current: f64,
+    segments: usize,
+) -> [f64; 3] {
+    // right handed orthonormal basis (e1, e2, n) of loop
+    let n = normalize(normal);
+    let helper = if n[0].abs() < 0.9 {
+        [1.0, 0.0, 0.0]
+    } else {
+        [0.0, 1.0, 0.0]
+    };
+    let e1 = normalize(&cross(&n, &helper));
+    let e2 = cross(&n, &e1);
+
+    let vertex = |m: usize| {
+        let theta = 2.0 * PI * m as f64 / segments as f64;
+        let (sin, cos) = theta.sin_cos();
+        [
+            center[0] + radius * (cos * e1[0] + sin * e2[0]),
+            center[1] + radius * (cos * e1[1] + sin * e2[1]),
+            center[2] + radius * (cos * e1[2] + sin * e2[2]),
+        ]
+    };
+
+    (0..segments).fold([0.0; 3], |b, m| {
+        add(
+            &b,
+            &segment_field(point, &vertex(m), &vertex(m + 1), current),
+        )
+    })
+}
+
+/// computes magnetic flux density of a straight current segment with the biot-savart law
+///
+/// # Arguments
+/// - `point`: &[f64; 3] (m) point to evaluate at
+/// - `a`: &[f64; 3] (m) start of segment
+/// - `b`: &[f64; 3] (m) end of segment
+/// - `current`: f64 (A) current flowing from `a` to `b`
+///
+/// # Returns
+/// `[f64; 3]` (T) magnetic flux density, zero on the line through the segment
+///
+/// # Errors
+///
+fn segment_field(point: &[f64; 3], a: &[f64; 3], b: &[f64; 3], current: f64) -> [f64; 3] {
+    let r1 = sub(point, a);
+    let r2 = sub(point, b);
+    let (n1, n2) = (norm(&r1), norm(&r2));
+    let denominator = n1 * n2 * (n1 * n2 + dot(&r1, &r2));
+
+    // points on the line through the segment experience no field from it
+    if denominator <= 1e-12 * (n1 * n2).powi(2) {
+        return [0.0; 3];
+    }
+
+    let factor = VAC_PERMEABILITY * current / (4.0 * PI) * (n1 + n2) / denominator;
+    let c = cross(&r1, &r2);
+    [factor * c[0], factor * c[1], factor * c[2]]
+}
+
+/// computes magnetic flux density of a uniformly magnetized block aligned with the axes
+///
+/// the magnetic field strength is the field of the magnetic surface charges on the faces of the block, inside the
+/// block the magnetization is added to it, weighted by one half on faces, one quarter on edges, and one eighth on
+/// corners of the block
+///
+/// # Arguments
+/// - `point`: &[f64; 3] (m) point to evaluate at
+/// - `min`: &[f64; 3] (m) lower corner of block
+/// - `max`: &[f64; 3] (m) upper corner of block
+/// - `magnetization`: &[f64; 3] (A/m) magnetization of block
+///
+/// # Returns
+/// `[f64; 3]` (T) magnetic flux density
+///
+/// # Errors
+///
+fn block_field(
+    point: &[f64; 3],
+    min: &[f64; 3],
+    max: &[f64; 3],
+    magnetization: &[f64; 3],
+) -> [f64; 3] {
+    let mut h = [0.0; 3];
+    for n in 0..3 {
+        // lower face has outward normal -n and upper face +n
+        for (plane, sigma) in [(min[n], -magnetization[n]), (max[n], magnetization[n])] {
+            if sigma != 0.0 {
+                h = add(&h, &face_field(point, n, plane, min, max, sigma));
+            }
+        }
+    }
+
+    let inside: f64 = (0..3)
+        .map(|n| {
+            if point[n] > min[n] && point[n] < max[n] {
+                1.0
+            } else if point[n] == min[n] || point[n] == max[n] {
+                0.5
+            } else {
+                0.0
+            }
+        })
+        .product();
+
+    [
+        VAC_PERMEABILITY * (h[0] + inside * magnetization[0]),
+        VAC_PERMEABILITY * (h[1] + inside * magnetization[1]),
+        VAC_PERMEABILITY * (h[2] + inside * magnetization[2]),
+    ]
+}
+
+/// computes magnetic field strength of a uniformly charged rectangular face of a block
+///
+/// # Arguments
+/// - `point`: &[f64; 3] (m) point to evaluate at
+/// - `n`: usize index of axis normal to face
+/// - `plane`: f64 (m) coordinate of face along axis `n`
+/// - `min`: &[f64; 3] (m) lower corner of block
+/// - `max`: &[f64; 3] (m) upper corner of block
+/// - `sigma`: f64 (A/m) magnetic surface charge density
+///
+/// # Returns
+/// `[f64; 3]` (A/m) magnetic field strength
+///
+/// # Errors
+///
+fn face_field(
+    point: &[f64; 3],
+    n: usize,
+    plane: f64,
+    min: &[f64; 3],
+    max: &[f64; 3],
+    sigma: f64,
+) -> [f64; 3] {
+    // in-plane axes and offsets of point from the edges of the face
+    let (a, b) = ((n + 1) % 3, (n + 2) % 3);
+    let u = [point[a] - max[a], point[a] - min[a]];
+    let v = [point[b] - max[b], point[b] - min[b]];
+    let w = point[n] - plane;
+
+    let factor = sigma / (4.0 * PI);
+    let mut h = [0.0; 3];
+    h[a] = -factor * (log_difference(u[1], w, v[0], v[1]) - log_difference(u[0], w, v[0], v[1]));
+    h[b] = -factor * (log_difference(v[1], w, u[0], u[1]) - log_difference(v[0], w, u[0], u[1]));
+
+    // the normal component jumps across the face and is taken as the average of both sides on it
+    if w != 0.0 {
+        let solid = |u: f64, v: f64| (u * v / (w * (u * u + v * v + w * w).sqrt())).atan();
+        h[n] = factor
+            * (solid(u[1], v[1]) - solid(u[1], v[0]) - solid(u[0], v[1]) + solid(u[0], v[0]));
+    }
+
+    h
+}
+
+/// computes ln(v2 + r2) - ln(v1 + r1) with r = sqrt(u^2 + v^2 + w^2) without cancellation for negative v
+///
+/// # Arguments
+/// - `u`: f64 (m) first transverse offset
+/// - `w`: f64 (m) second transverse offset
+/// - `v1`: f64 (m) lower bound of integration
+/// - `v2`: f64 (m) upper bound of integration
+///
+/// # Returns
+/// `f64` logarithmic difference, zero on the line through the edge where it is singular
+///
+/// # Errors
+///
+fn log_difference(u: f64, w: f64, v1: f64, v2: f64) -> f64 {
+    let rho_sq = u * u + w * w;
+    let r1 = (rho_sq + v1 * v1).sqrt();
+    let r2 = (rho_sq + v2 * v2).sqrt();
+    if v1 >= 0.0 {
+        if v1 + r1 == 0.0 {
+            return 0.0;
+        }
+        ((v2 + r2) / (v1 + r1)).ln()
+    } else if v2 <= 0.0 {
+        ((r1 - v1) / (r2 - v2)).ln()
+    } else if rho_sq == 0.0 {
+        0.0
+    } else {
+        ((v2 + r2) * (r1 - v1) / rho_sq).ln()
+    }
+}
+
+/// computes the difference of two vectors
+///
+/// # Arguments
+/// - `a`: &[f64; 3] lhs of operation
+/// - `b`: &[f64; 3] rhs of operation
+///
+/// # Returns
+/// `[f64; 3]` a - b
+///
+/// # Errors
+///
+fn sub(a: &[f64; 3], b: &[f64; 3]) -> [f64; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+/// computes the sum of two vectors
+///
+/// # Arguments
+/// - `a`: &[f64; 3] lhs of operation
+/// - `b`: &[f64; 3] rhs of operation
+///
+/// # Returns
+/// `[f64; 3]` a + b
+///
+/// # Errors
+///
+fn add(a: &[f64; 3], b: &[f64; 3]) -> [f64; 3] {
+    [a[0] + b[0], a[1] + b[1], a[2] + b[2]]
+}
+
+/// computes the dot product of two vectors
+///
+/// # Arguments
+/// - `a`: &[f64; 3] lhs of operation
+/// - `b`: &[f64; 3] rhs of operation
+///
+/// # Returns
+/// `f64` a . b
+///
+/// # Errors
+///
+fn dot(a: &[f64; 3], b: &[f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+/// computes the cross product of two vectors
+///
+/// # Arguments
+/// - `a`: &[f64; 3] lhs of operation
+/// - `b`: &[f64; 3] rhs of operation
+///
+/// # Returns
+/// `[f64; 3]` a x b
+///
+/// # Errors
+///
+fn cross(a: &[f64; 3], b: &[f64; 3]) -> [f64; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+/// computes the euclidean norm of a vector
+///
+/// # Arguments
+/// - `a`: &[f64; 3] vector
+///
+/// # Returns
+/// `f64` |a|
+///
+/// # Errors
+///
+fn norm(a: &[f64; 3]) -> f64 {
+    dot(a, a).sqrt()
+}
+
+/// normalizes a non-zero vector
+///
+/// # Arguments
+/// - `a`: &[f64; 3] non-zero vector
+///
+/// # Returns
+/// `[f64; 3]` a / |a|
+///
+/// # Errors
+///
+fn normalize(a: &[f64; 3]) -> [f64; 3] {
+    let n = norm(a);
+    [a[0] / n, a[1] / n, a[2] / n]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::constants::VAC_PERMEABILITY;
+    use crate::magnetostatics::{applied_field, write_vtk, MagnetostaticConfig, Source};
+    use vtkio::model::{Attribute, DataArray, DataSet, Piece};
+    use vtkio::Vtk;
+
+    /// tests `applied_field()` for failure on invalid sources
+    ///
+    /// # Errors
+    /// - `applied_field()` succeeds for a non-positive loop radius
+    /// - `applied_field()` succeeds for a zero solenoid axis
+    /// - `applied_field()` succeeds for an inverted block
+    /// - `applied_field()` succeeds for fewer than 3 segments
+    ///
+    #[test]
+    fn applied_field_failure() {
+        // setup
+        let nodes = [0.0, 0.5, 1.0];
+        let invalid = [
+            Source::Loop {
+                center: [0.5; 3],
+                normal: [0.0, 0.0, 1.0],
+                radius: 0.0,
+                current: 1.0,
+            },
+            Source::Solenoid {
+                center: [0.5; 3],
+                axis: [0.0; 3],
+                radius: 0.1,
+                length: 0.5,
+                turns: 10,
+                current: 1.0,
+            },
+            Source::Block {
+                min: [0.6, 0.4, 0.4],
+                max: [0.5, 0.6, 0.6],
+                magnetization: [0.0, 0.0, 1.0],
+            },
+        ];
+
+        // assertions
+        for source in invalid {
+            let config = MagnetostaticConfig {
+                sources: vec![source],
+                ..MagnetostaticConfig::default()
+            };
+            assert!(applied_field(&config, &[0.0; 3], &nodes, &nodes, &nodes).is_err());
+        }
+        let config = MagnetostaticConfig {
+            segments: 2,
+            ..MagnetostaticConfig::default()
+        };
+        assert!(applied_field(&config, &[0.0; 3], &nodes, &nodes, &nodes).is_err());
+    }
+
+    /// tests `Source::field()` for a loop against the analytic field on its axis
+    ///
+    /// # Errors
+    /// - field on axis deviates from mu0 I R^2 / (2 (R^2 + z^2)^(3/2))
+    /// - field on axis has transverse components
+    ///
+    #[test]
+    fn field_loop_on_axis() {
+        // setup
+        let (radius, current) = (0.2, 3.0);
+        let source = Source::Loop {
+            center: [0.1, -0.2, 0.3],
+            normal: [0.0, 0.0, 2.0],
+            radius,
+            current,
+        };
+
+        // assertions
+        for z in [0.0, 0.1, 0.3, 1.0] {
+            let b = source.field(&[0.1, -0.2, 0.3 + z], 256);
+            let exact = VAC_PERMEABILITY * current * radius * radius
+                / (2.0 * (radius * radius + z * z).powf(1.5));
+            assert!((b[2] - exact).abs() < 1e-3 * exact);
+            assert!(b[0].abs() < 1e-9 * exact && b[1].abs() < 1e-9 * exact);
+        }
+    }
+
+    /// tests `Source::field()` for a tilted solenoid against the analytic field at its center
+    ///
+    /// # Errors
+    /// - field at center deviates from mu0 n I L / sqrt(L^2 + 4 R^2) along the axis
+    ///
+    #[test]
+    fn field_solenoid_center() {
+        // setup
+        let (radius, length, turns, current) = (0.05, 1.0, 400, 2.0);
+        let axis = [1.0, 1.0, 0.0];
+        let source = Source::Solenoid {
+            center: [0.0; 3],
+            axis,
+            radius,
+            length,
+            turns,
+            current,
+        };
+        let b = source.field(&[0.0; 3], 128);
+
+        // assertions
+        let exact = VAC_PERMEABILITY * turns as f64 / length * current * length
+            / (length * length + 4.0 * radius * radius).sqrt();
+        let along = (b[0] + b[1]) / 2.0_f64.sqrt();
+        assert!((along - exact).abs() < 1e-3 * exact);
+        assert!((b[0] - b[1]).abs() < 1e-9 * exact && b[2].abs() < 1e-9 * exact);
+    }
+
+    /// tests `Source::field()` for a magnetized cube at its center and in its far field
+    ///
+    /// # Errors
+    /// - field at center deviates from 2/3 mu0 M of a demagnetizing factor of 1/3
+    /// - field far along the magnetization deviates from that of a point dipole
+    /// - field on a face is not the average of both sides
+    ///
+    #[test]
+    fn field_block_cube() {
+        // setup
+        let m = 1e5;
+        let source = Source::Block {
+            min: [-0.5; 3],
+            max: [0.5; 3],
+            magnetization: [0.0, 0.0, m],
+        };
+
+        // assertions
+        let b = source.field(&[0.0; 3], 0);
+        assert!((b[2] - 2.0 / 3.0 * VAC_PERMEABILITY * m).abs() < 1e-12 * VAC_PERMEABILITY * m);
+        assert!(b[0].abs() < 1e-12 && b[1].abs() < 1e-12);
+
+        let d = 20.0;
+        let b = source.field(&[0.0, 0.0, d], 0);
+        let dipole = VAC_PERMEABILITY * m / (2.0 * std::f64::consts::PI * d * d * d);
+        assert!((b[2] - dipole).abs() < 1e-2 * dipole);
+
+        let face = source.field(&[0.1, 0.2, 0.5], 0)[2];
+        let below = source.field(&[0.1, 0.2, 0.5 - 1e-9], 0)[2];
+        let above = source.field(&[0.1, 0.2, 0.5 + 1e-9], 0)[2];
+        assert!((face - 0.5 * (below + above)).abs() < 1e-6 * VAC_PERMEABILITY * m);
+    }
+
+    /// tests `write_vtk()` for a legacy vtk file that reads back the applied field in x-fastest order
+    ///
+    /// # Errors
+    /// - any call to `applied_field()` fails
+    /// - call to `write_vtk()` fails
+    /// - file does not read back as a rectilinear grid with the node coordinates and field
+    ///
+    #[test]
+    fn write_vtk_round_trip() {
+        // setup
+        let (x, y, z) = (
+            vec![0.0, 0.5, 1.0],
+            vec![0.0, 0.25, 0.75, 1.0],
+            vec![0.0, 2.0],
+        );
+        let config = MagnetostaticConfig {
+            sources: vec![Source::Loop {
+                center: [0.5, 0.5, 1.0],
+                normal: [0.0, 1.0, 1.0],
+                radius: 0.3,
+                current: 1e3,
+            }],
+            ..MagnetostaticConfig::default()
+        };
+        let field = applied_field(&config, &[0.1, 0.0, 0.0], &x, &y, &z).unwrap();
+        let path =
+            std::env::temp_dir().join(format!("picrs_magnetostatics_{}.vtk", std::process::id()));
+        write_vtk(&path, &field, &x, &y, &z).unwrap();
+        let vtk = Vtk::import(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        // assertions
+        let DataSet::RectilinearGrid { pieces, .. } = vtk.data else {
+            panic!("expected a rectilinear grid");
+        };
+        let Piece::Inline(piece) = &pieces[0] else {
+            panic!("expected an inline piece");
+        };
+        assert_eq!(piece.coords.y.clone().cast_into::<f64>().unwrap(), y);
+        let Attribute::DataArray(DataArray { data, .. }) = &piece.data.point[0] else {
+            panic!("expected a data array");
+        };
+        let data = data.clone().cast_into::<f64>().unwrap();
+        assert_eq!(data.len(), 3 * 3 * 4 * 2);
+        let n = 3 * (1 + 3 * (2 + 4));
+        assert_eq!(data[n], field.x[(1, 2, 1)]);
+        assert_eq!(data[n + 2], field.z[(1, 2, 1)]);
+    }
+}
@@ -3,7 +3,11 @@
 //! picrs build target
 
 use anyhow::Result;
+use picrs::config::Config;
+use picrs::constants::{AMU, ELEC_CHARGE, ELEC_MASS, EV_TEMP};
 use picrs::model::Model;
+use picrs::species::Species;
+use std::path::Path;
 
 /// main driver function
 ///
@@ -17,9 +21,35 @@ fn main() -> Result<(), anyhow::Error> {
     // todo take io prefix from command line
 
     // todo create configuration from disk somehow
-
-    // construct model
-    let mut model = Model::new()?;
+    let mut config = Config::new(&[0.01, 0.01, 0.01], &[16, 16, 16], 1e-10)?;
+    config.species = vec![
+        Species::new(
+            "electron",
+            -ELEC_CHARGE,
+            ELEC_MASS,
+            1e13,
+            2.0 * EV_TEMP,
+            1e3,
+        )?,
+        Species::new(
+            "argon+",
+            ELEC_CHARGE,
+            39.948 * AMU,
+            1e13,
+            0.1 * EV_TEMP,
+            1e3,
+        )?,
+    ];
+
+    // construct model, restarting from a checkpoint if one is given on the command line
+    let mut model = match std::env::args().nth(1) {
+        Some(path) => Model::restart(&config, Path::new(&path))?,
+        None => Model::new(&config)?,
+    };
+    print!("{}", model.plasma_report());
+    for check in model.plasma_report().enforce()? {
+        eprintln!("warning: plasma parameter check failed: {check}");
+    }
 
     // run model
     model.run()?;
@@ -28,6 +58,4 @@ fn main() -> Result<(), anyhow::Error> {
 }
 
 #[cfg(test)]
-mod tests {
-
-}
+mod tests {}
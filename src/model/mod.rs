@@ -2,38 +2,182 @@
 //!
 //! describes a model facade struct for using picrs
 
-use crate::engine::Electrostatic;
+use crate::checkpoint;
+use crate::config::Config;
+use crate::diagnostics::HistoryWriter;
+use crate::engine::{self, Engine};
+use crate::output::{Field, FieldWriter, Variable};
+use crate::plasma::PlasmaReport;
+use anyhow::anyhow;
+use signal_hook::SigId;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 
 /// `Model` struct
 ///
 /// provides a facade for using picrs
 pub struct Model {
-    // engine simulation object
-    engine: Electrostatic,
+    // engine simulation object selected in configuration
+    engine: Box<dyn Engine>,
+
+    // model configuration
+    config: Config,
+
+    // hash identifying model configuration in checkpoints
+    config_hash: u64,
+
+    // set when SIGTERM is received
+    terminate: Arc<AtomicBool>,
+
+    // registered SIGTERM handler, `None` if checkpoints on SIGTERM are disabled
+    sigterm: Option<SigId>,
+
+    // field time series, `None` if field output is disabled
+    output: Option<FieldWriter>,
+
+    // diagnostics time history, `None` if diagnostics are disabled
+    history: Option<HistoryWriter>,
 }
 
 impl Model {
     /// `Model` constructor
     ///
     /// # Arguments
+    /// - `config`: &Config model configuration
     ///
     /// # Returns
     /// `Result<Model, anyhow::Error>`
     ///
     /// # Errors
+    /// - call to `Model::build()` fails
+    pub fn new(config: &Config) -> Result<Model, anyhow::Error> {
+        Model::build(config, None)
+    }
+
+    /// `Model` constructor restarting from a checkpoint
     ///
-    pub fn new() -> Result<Model, anyhow::Error> {
-        // todo read in from input deck
-        let size: [f64; 3] = [1.0, 1.0, 1.0];
-        let cells: [usize; 3] = [10, 10, 10];
+    /// # Arguments
+    /// - `config`: &Config model configuration, must match configuration checkpoint was written with
+    /// - `path`: &Path checkpoint to restart from
+    ///
+    /// # Returns
+    /// `Result<Model, anyhow::Error>`
+    ///
+    /// # Errors
+    /// - call to `Model::build()` fails
+    pub fn restart(config: &Config, path: &Path) -> Result<Model, anyhow::Error> {
+        Model::build(config, Some(path))
+    }
 
+    /// constructs a `Model`, optionally restarting from a checkpoint
+    ///
+    /// # Arguments
+    /// - `config`: &Config model configuration
+    /// - `checkpoint`: Option<&Path> checkpoint to restart from
+    ///
+    /// # Returns
+    /// `Result<Model, anyhow::Error>`
+    ///
+    /// # Errors
+    /// - call to `engine::build()` fails
+    /// - call to `checkpoint::load()` fails
+    /// - field time series could not be created or resumed
+    /// - diagnostics time history could not be created or resumed
+    /// - SIGTERM handler could not be registered
+    fn build(config: &Config, checkpoint: Option<&Path>) -> Result<Model, anyhow::Error> {
         // construct engine
-        let engine = Electrostatic::new(&size, &cells)?;
+        let mut engine = engine::build(config)?;
+
+        // restore engine state
+        let config_hash = checkpoint::config_hash(config);
+        if let Some(path) = checkpoint {
+            checkpoint::load(path, config_hash, engine.as_mut())?;
+        }
+
+        // open field time series, number densities of neutral species follow the fields of the engine
+        let output = if config.output.interval > 0 {
+            let mut variables = engine.variables()?;
+            for s in config.species.iter().filter(|s| s.charge == 0.0) {
+                variables.push(Variable::new(&format!("{}_density", s.name), "m^-3", 1)?);
+            }
+            let (path, nodes) = (&config.output.path, engine.nodes());
+            Some(match checkpoint {
+                Some(_) => {
+                    FieldWriter::resume(path, engine.size(), &nodes, &variables, engine.step())?
+                }
+                None => FieldWriter::create(path, engine.size(), &nodes, &variables)?,
+            })
+        } else {
+            None
+        };
+
+        // open diagnostics time history
+        let history = if config.diagnostics.interval > 0 {
+            let names: Vec<String> = config.species.iter().map(|s| s.name.clone()).collect();
+            let path = &config.diagnostics.path;
+            Some(match checkpoint {
+                Some(_) => HistoryWriter::resume(path, &names, engine.step())?,
+                None => HistoryWriter::create(path, &names)?,
+            })
+        } else {
+            None
+        };
 
-        Ok(Model { engine })
+        // register SIGTERM handler
+        let terminate = Arc::new(AtomicBool::new(false));
+        let sigterm = if config.checkpoint.on_sigterm {
+            Some(signal_hook::flag::register(
+                signal_hook::consts::SIGTERM,
+                Arc::clone(&terminate),
+            )?)
+        } else {
+            None
+        };
+
+        Ok(Model {
+            engine,
+            config: config.clone(),
+            config_hash,
+            terminate,
+            sigterm,
+            output,
+            history,
+        })
+    }
+
+    /// returns plasma parameter report of configured `Model`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `&PlasmaReport` characteristic plasma parameters and their validation against discretization
+    ///
+    /// # Errors
+    ///
+    pub fn plasma_report(&self) -> &PlasmaReport {
+        self.engine.plasma_report()
     }
 
-    /// runs configured `Model`
+    /// writes a checkpoint of the current state of `Model`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `Result<PathBuf, anyhow::Error>` path of written checkpoint
+    ///
+    /// # Errors
+    /// - call to `checkpoint::save()` fails
+    pub fn checkpoint(&self) -> Result<PathBuf, anyhow::Error> {
+        let path = checkpoint::path(&self.config.checkpoint.directory, self.engine.step());
+        checkpoint::save(&path, self.config_hash, self.engine.as_ref())?;
+
+        Ok(path)
+    }
+
+    /// runs configured `Model` until `Config.steps` time steps are completed
     ///
     /// # Arguments
     /// - `&mut self` mutable reference to self
@@ -42,15 +186,167 @@ impl Model {
     /// `Result<(), anyhow::Error>`
     ///
     /// # Errors
-    ///
+    /// - any call to `Engine::update()` fails
+    /// - any call to `Model::checkpoint()` fails
+    /// - any call to `Engine::number_density()` fails
+    /// - any call to `FieldWriter::write_frame()` fails
+    /// - any call to `HistoryWriter::write()` fails
+    /// - SIGTERM is received, after a checkpoint is written
     pub fn run(&mut self) -> Result<(), anyhow::Error> {
-        for step in 0..10 {
+        while self.engine.step() < self.config.steps {
             self.engine.update()?;
+
+            // field output
+            if let Some(output) = self.output.as_mut() {
+                if self.engine.step() % self.config.output.interval == 0 {
+                    let densities = (0..self.config.species.len())
+                        .filter(|s| self.config.species[*s].charge == 0.0)
+                        .map(|s| self.engine.number_density(s))
+                        .collect::<Result<Vec<_>, _>>()?;
+
+                    let mut fields = self.engine.fields();
+                    fields.extend(densities.iter().map(Field::Scalar));
+                    output.write_frame(self.engine.step(), self.engine.time(), &fields)?;
+                }
+            }
+
+            // conservation diagnostics
+            if let Some(history) = self.history.as_mut() {
+                if self.engine.step() % self.config.diagnostics.interval == 0 {
+                    history.write(&self.engine.diagnostics())?;
+                }
+            }
+
+            // periodic checkpoint
+            let interval = self.config.checkpoint.interval;
+            if interval > 0 && self.engine.step() % interval == 0 {
+                self.checkpoint()?;
+            }
+
+            // checkpoint and stop on SIGTERM
+            if self.terminate.load(Ordering::Relaxed) {
+                let path = self.checkpoint()?;
+                return Err(anyhow!(
+                    "received SIGTERM at step {}, wrote checkpoint {}",
+                    self.engine.step(),
+                    path.display()
+                ));
+            }
         }
 
         Ok(())
     }
 }
 
+impl Drop for Model {
+    /// unregisters the SIGTERM handler of `Model` so that dropped models do not accumulate handlers
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    ///
+    /// # Returns
+    ///
+    /// # Errors
+    ///
+    fn drop(&mut self) {
+        if let Some(sigterm) = self.sigterm.take() {
+            signal_hook::low_level::unregister(sigterm);
+        }
+    }
+}
+
 #[cfg(test)]
-mod tests {}
+mod tests {
+    use crate::checkpoint;
+    use crate::config::{Config, EngineKind};
+    use crate::constants::{ELEC_CHARGE, ELEC_MASS, EV_TEMP};
+    use crate::model::Model;
+    use crate::species::Species;
+
+    /// tests `Model::run()` for periodic checkpoints and `Model::restart()` for resuming from them
+    ///
+    /// # Errors
+    /// - `Model::run()` does not write checkpoints at configured interval
+    /// - `Model::restart()` does not resume from checkpoint
+    /// - restarted `Model` does not resume field time series
+    /// - restarted `Model` does not resume diagnostics time history
+    ///
+    #[test]
+    fn run_checkpoint_restart() {
+        // setup
+        let dir = std::env::temp_dir().join(format!("picrs_model_{}", std::process::id()));
+        let mut config = Config::new(&[1.0, 1.0, 1.0], &[5, 5, 5], 1e-9).unwrap();
+        config.steps = 4;
+        config.checkpoint.interval = 2;
+        config.checkpoint.directory = dir.clone();
+        config.checkpoint.on_sigterm = false;
+        config.output.interval = 1;
+        config.output.path = dir.join("fields.pfld");
+        config.diagnostics.interval = 1;
+        config.diagnostics.path = dir.join("diagnostics.csv");
+
+        let mut model = Model::new(&config).unwrap();
+        model.run().unwrap();
+
+        // assertions
+        assert!(checkpoint::path(&dir, 2).exists());
+        assert!(checkpoint::path(&dir, 4).exists());
+
+        let full = std::fs::metadata(&config.output.path).unwrap().len();
+        let history = std::fs::read_to_string(&config.diagnostics.path).unwrap();
+        assert_eq!(history.lines().count(), 5);
+        let mut restarted = Model::restart(&config, &checkpoint::path(&dir, 2)).unwrap();
+        assert_eq!(restarted.engine.step(), 2);
+        restarted.run().unwrap();
+        drop(restarted);
+        assert_eq!(std::fs::metadata(&config.output.path).unwrap().len(), full);
+        assert_eq!(
+            std::fs::read_to_string(&config.diagnostics.path)
+                .unwrap()
+                .lines()
+                .count(),
+            5
+        );
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    /// tests `Model::run()` for driving an electromagnetic engine selected in configuration
+    ///
+    /// # Errors
+    /// - `Model::new()` fails for an electromagnetic engine
+    /// - `Model::run()` does not write field output or diagnostics
+    /// - restarted `Model` does not resume from checkpoint
+    ///
+    #[test]
+    fn run_electromagnetic() {
+        // setup
+        let dir = std::env::temp_dir().join(format!("picrs_model_em_{}", std::process::id()));
+        let mut config = Config::new(&[0.01, 0.01, 0.01], &[5, 5, 5], 1e-12).unwrap();
+        config.engine = EngineKind::Electromagnetic;
+        config.species =
+            vec![Species::new("electron", -ELEC_CHARGE, ELEC_MASS, 1e12, EV_TEMP, 1e4).unwrap()];
+        config.steps = 4;
+        config.checkpoint.interval = 2;
+        config.checkpoint.directory = dir.clone();
+        config.checkpoint.on_sigterm = false;
+        config.output.interval = 2;
+        config.output.path = dir.join("fields.pfld");
+        config.diagnostics.interval = 1;
+        config.diagnostics.path = dir.join("diagnostics.csv");
+
+        let mut model = Model::new(&config).unwrap();
+        model.run().unwrap();
+
+        // assertions
+        assert!(config.output.path.exists());
+        let history = std::fs::read_to_string(&config.diagnostics.path).unwrap();
+        assert_eq!(history.lines().count(), 5);
+        let mut restarted = Model::restart(&config, &checkpoint::path(&dir, 2)).unwrap();
+        assert_eq!(restarted.engine.step(), 2);
+        restarted.run().unwrap();
+        assert_eq!(restarted.engine.step(), 4);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
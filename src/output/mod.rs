@@ -0,0 +1,714 @@
+//! output module
+//!
+//! describes a self-describing binary time series format for fields
+//!
+//! a file consists of
+//! - an 8 byte magic `PICRSFLD`
+//! - a little endian u32 format version
+//! - a little endian u32 length of the json header in bytes
+//! - a json header describing the grid including the node coordinates along every axis, variables, and frame layout,
+//!   padded with spaces so that frames start at a
+//!   multiple of 64 bytes
+//! - any number of fixed size frames, each holding a u64 step, a f64 time, and the data of every variable
+//!
+//! all data is little endian f64 in (i, j, k) row major order with k varying fastest, vector variables store their
+//! x, y, and z components consecutively, so that a file may be memory mapped with a single structured dtype
+
+use crate::field::scalar::ScalarField;
+use crate::field::vector::VectorField;
+use crate::grid::uniform_nodes;
+use crate::utils::coordinate_triplet::CoordinateTriplet;
+use anyhow::{anyhow, Context};
+use std::fs::{File, OpenOptions};
+use std::io::{BufWriter, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+
+/// identifies a file as a picrs field time series
+const MAGIC: &[u8; 8] = b"PICRSFLD";
+
+/// field time series format version, increment whenever the layout of a file changes
+const VERSION: u32 = 2;
+
+/// alignment in bytes of the first frame
+const ALIGNMENT: usize = 64;
+
+/// (bytes) size of the step and time stored at the start of every frame
+const FRAME_HEADER_BYTES: usize = 16;
+
+/// `OutputConfig` struct
+///
+/// describes when and where field output is written
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutputConfig {
+    /// number of steps between frames, 0 disables field output
+    pub interval: u64,
+
+    /// path of field time series
+    pub path: PathBuf,
+}
+
+impl Default for OutputConfig {
+    /// default `OutputConfig`
+    ///
+    /// field output is disabled
+    ///
+    /// # Arguments
+    ///
+    /// # Returns
+    /// `OutputConfig`
+    ///
+    /// # Errors
+    ///
+    fn default() -> OutputConfig {
+        OutputConfig {
+            interval: 0,
+            path: PathBuf::from("fields.pfld"),
+        }
+    }
+}
+
+/// `Variable` struct
+///
+/// describes a single variable stored in every frame
+#[derive(Debug, Clone, PartialEq)]
+pub struct Variable {
+    /// name of variable
+    pub name: String,
+
+    /// units of variable
+    pub units: String,
+
+    /// number of components, 1 for scalar and 3 for vector fields
+    pub components: usize,
+}
+
+impl Variable {
+    /// `Variable` constructor
+    ///
+    /// # Arguments
+    /// - `name`: &str name of variable
+    /// - `units`: &str units of variable
+    /// - `components`: usize number of components, 1 for scalar and 3 for vector fields
+    ///
+    /// # Returns
+    /// `Result<Variable, anyhow::Error>`
+    ///
+    /// # Errors
+    /// - `name` or `units` contain a quote, backslash, or control character, which would corrupt the json header
+    /// - `components` is neither 1 nor 3
+    pub fn new(name: &str, units: &str, components: usize) -> Result<Variable, anyhow::Error> {
+        for text in [name, units] {
+            if text
+                .chars()
+                .any(|c| c == '"' || c == '\\' || c.is_control())
+            {
+                return Err(anyhow!(
+                    "variable {name:?} of units {units:?} must not contain quotes, backslashes, or control characters"
+                ));
+            }
+        }
+
+        if components != 1 && components != 3 {
+            return Err(anyhow!(
+                "variable `{name}` must have 1 or 3 components, got {components}"
+            ));
+        }
+
+        Ok(Variable {
+            name: name.to_string(),
+            units: units.to_string(),
+            components,
+        })
+    }
+}
+
+/// `Field` enum
+///
+/// borrowed field data written into a frame
+#[derive(Debug, Clone, Copy)]
+pub enum Field<'a> {
+    /// scalar field
+    Scalar(&'a ScalarField<f64>),
+
+    /// vector field
+    Vector(&'a VectorField<f64>),
+
+    /// single precision scalar field, widened to double precision when written
+    SingleScalar(&'a ScalarField<f32>),
+
+    /// single precision vector field, widened to double precision when written
+    SingleVector(&'a VectorField<f32>),
+}
+
+/// `FieldWriter` struct
+///
+/// writes frames of a field time series
+#[derive(Debug)]
+pub struct FieldWriter {
+    /// destination of frames
+    writer: BufWriter<File>,
+
+    /// variables stored in every frame
+    variables: Vec<Variable>,
+
+    /// number of cells of every field
+    cells: CoordinateTriplet<usize>,
+}
+
+impl FieldWriter {
+    /// `FieldWriter` constructor creating a new time series, any existing file is truncated
+    ///
+    /// # Arguments
+    /// - `path`: &Path destination of time series
+    /// - `size`: &CoordinateTriplet<f64> (m) size of bounding box
+    /// - `nodes`: &CoordinateTriplet<Vec<f64>> (m) node coordinates along each axis
+    /// - `variables`: &[Variable] variables stored in every frame
+    ///
+    /// # Returns
+    /// `Result<FieldWriter, anyhow::Error>`
+    ///
+    /// # Errors
+    /// - file could not be created or written
+    pub fn create(
+        path: &Path,
+        size: &CoordinateTriplet<f64>,
+        nodes: &CoordinateTriplet<Vec<f64>>,
+        variables: &[Variable],
+    ) -> Result<FieldWriter, anyhow::Error> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("could not create directory {}", parent.display()))?;
+        }
+
+        let mut writer = BufWriter::new(
+            File::create(path).with_context(|| format!("could not create {}", path.display()))?,
+        );
+        writer.write_all(&preamble(size, nodes, variables))?;
+        writer.flush()?;
+
+        Ok(FieldWriter {
+            writer,
+            variables: variables.to_vec(),
+            cells: node_counts(nodes),
+        })
+    }
+
+    /// `FieldWriter` constructor resuming an existing time series after a restart
+    ///
+    /// frames written after `step` and any incomplete trailing frame are discarded, a new time series is created if
+    /// none exists at `path`
+    ///
+    /// # Arguments
+    /// - `path`: &Path destination of time series
+    /// - `size`: &CoordinateTriplet<f64> (m) size of bounding box
+    /// - `nodes`: &CoordinateTriplet<Vec<f64>> (m) node coordinates along each axis
+    /// - `variables`: &[Variable] variables stored in every frame
+    /// - `step`: u64 step the model restarts from
+    ///
+    /// # Returns
+    /// `Result<FieldWriter, anyhow::Error>`
+    ///
+    /// # Errors
+    /// - file could not be opened, read, or written
+    /// - existing time series describes different grid or variables
+    pub fn resume(
+        path: &Path,
+        size: &CoordinateTriplet<f64>,
+        nodes: &CoordinateTriplet<Vec<f64>>,
+        variables: &[Variable],
+        step: u64,
+    ) -> Result<FieldWriter, anyhow::Error> {
+        if !path.exists() {
+            return FieldWriter::create(path, size, nodes, variables);
+        }
+
+        let mut file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .open(path)
+            .with_context(|| format!("could not open {}", path.display()))?;
+
+        // existing preamble must match exactly
+        let expected = preamble(size, nodes, variables);
+        let mut existing = vec![0u8; expected.len()];
+        file.read_exact(&mut existing)
+            .with_context(|| format!("could not read header of {}", path.display()))?;
+        if existing != expected {
+            return Err(anyhow!(
+                "{} describes a different grid or different variables",
+                path.display()
+            ));
+        }
+
+        // keep complete frames up to and including step
+        let cells = node_counts(nodes);
+        let frame_bytes = frame_bytes(variables, cells.x * cells.y * cells.z) as u64;
+        let frames = (file.metadata()?.len() - expected.len() as u64) / frame_bytes;
+        let mut kept = 0;
+        let mut buf = [0u8; 8];
+        while kept < frames {
+            file.seek(SeekFrom::Start(expected.len() as u64 + kept * frame_bytes))?;
+            file.read_exact(&mut buf)?;
+            if u64::from_le_bytes(buf) > step {
+                break;
+            }
+            kept += 1;
+        }
+
+        let end = expected.len() as u64 + kept * frame_bytes;
+        file.set_len(end)?;
+        file.seek(SeekFrom::Start(end))?;
+
+        Ok(FieldWriter {
+            writer: BufWriter::new(file),
+            variables: variables.to_vec(),
+            cells,
+        })
+    }
+
+    /// writes a single frame
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `step`: u64 step of frame
+    /// - `time`: f64 (s) time of frame
+    /// - `fields`: &[Field] data of every variable, in the order the variables were declared
+    ///
+    /// # Returns
+    /// `Result<(), anyhow::Error>`
+    ///
+    /// # Errors
+    /// - `fields` does not match declared variables
+    /// - any write fails
+    pub fn write_frame(
+        &mut self,
+        step: u64,
+        time: f64,
+        fields: &[Field],
+    ) -> Result<(), anyhow::Error> {
+        if fields.len() != self.variables.len() {
+            return Err(anyhow!(
+                "frame has {} fields but {} variables are declared",
+                fields.len(),
+                self.variables.len()
+            ));
+        }
+
+        for (field, variable) in fields.iter().zip(self.variables.iter()) {
+            let (components, cells) = match field {
+                Field::Scalar(f) => (1, f.cells()),
+                Field::Vector(f) => (3, f.cells()),
+                Field::SingleScalar(f) => (1, f.cells()),
+                Field::SingleVector(f) => (3, f.cells()),
+            };
+            if components != variable.components || *cells != self.cells {
+                return Err(anyhow!(
+                    "field of variable `{}` has {components} components of {cells} cells, expected {} components of {} cells",
+                    variable.name,
+                    variable.components,
+                    self.cells
+                ));
+            }
+        }
+
+        self.writer.write_all(&step.to_le_bytes())?;
+        self.writer.write_all(&time.to_le_bytes())?;
+        for field in fields.iter() {
+            match field {
+                Field::Scalar(f) => f.write_binary(&mut self.writer)?,
+                Field::Vector(f) => f.write_binary(&mut self.writer)?,
+                Field::SingleScalar(f) => f.write_binary(&mut self.writer)?,
+                Field::SingleVector(f) => f.write_binary(&mut self.writer)?,
+            }
+        }
+        self.writer.flush()?;
+
+        Ok(())
+    }
+}
+
+/// computes the size of a single frame
+///
+/// # Arguments
+/// - `variables`: &[Variable] variables stored in every frame
+/// - `nodes`: usize number of nodes of every component
+///
+/// # Returns
+/// `usize` (bytes) size of a single frame
+///
+/// # Errors
+///
+fn frame_bytes(variables: &[Variable], nodes: usize) -> usize {
+    FRAME_HEADER_BYTES
+        + variables
+            .iter()
+            .map(|v| v.components * nodes * 8)
+            .sum::<usize>()
+}
+
+/// counts the nodes along every axis
+///
+/// # Arguments
+/// - `nodes`: &CoordinateTriplet<Vec<f64>> (m) node coordinates along each axis
+///
+/// # Returns
+/// `CoordinateTriplet<usize>` number of nodes along each axis
+///
+/// # Errors
+///
+fn node_counts(nodes: &CoordinateTriplet<Vec<f64>>) -> CoordinateTriplet<usize> {
+    CoordinateTriplet {
+        x: nodes.x.len(),
+        y: nodes.y.len(),
+        z: nodes.z.len(),
+    }
+}
+
+/// builds the magic, version, header length, and padded json header of a time series
+///
+/// the spatial increment is only written if nodes are equally spaced along every axis, the node coordinates along
+/// every axis are always written
+///
+/// # Arguments
+/// - `size`: &CoordinateTriplet<f64> (m) size of bounding box
+/// - `nodes`: &CoordinateTriplet<Vec<f64>> (m) node coordinates along each axis
+/// - `variables`: &[Variable] variables stored in every frame
+///
+/// # Returns
+/// `Vec<u8>`
+///
+/// # Errors
+///
+fn preamble(
+    size: &CoordinateTriplet<f64>,
+    nodes: &CoordinateTriplet<Vec<f64>>,
+    variables: &[Variable],
+) -> Vec<u8> {
+    let cells = node_counts(nodes);
+    let axes = [(&nodes.x, size.x), (&nodes.y, size.y), (&nodes.z, size.z)];
+
+    // spatial increment of equally spaced nodes, axes holding a single node, as in reduced-dimensionality engines,
+    // span the bounding box
+    let spacing = |size: f64, cells: usize| {
+        if cells > 1 {
+            size / (cells - 1) as f64
+        } else {
+            size
+        }
+    };
+    let delta = if axes
+        .iter()
+        .all(|(nodes, size)| **nodes == uniform_nodes(*size, nodes.len()))
+    {
+        format!(
+            "\"delta\": [{:?}, {:?}, {:?}], ",
+            spacing(size.x, cells.x),
+            spacing(size.y, cells.y),
+            spacing(size.z, cells.z)
+        )
+    } else {
+        String::new()
+    };
+    let coordinates: Vec<String> = axes
+        .iter()
+        .map(|(nodes, _)| {
+            let nodes: Vec<String> = nodes.iter().map(|x| format!("{x:?}")).collect();
+            format!("[{}]", nodes.join(", "))
+        })
+        .collect();
+    let nodes = cells.x * cells.y * cells.z;
+
+    // variable descriptions with their byte offsets inside a frame
+    let mut offset = FRAME_HEADER_BYTES;
+    let variables_json: Vec<String> = variables
+        .iter()
+        .map(|v| {
+            let json = format!(
+                "{{\"name\": \"{}\", \"units\": \"{}\", \"components\": {}, \"offset\": {}}}",
+                v.name, v.units, v.components, offset
+            );
+            offset += v.components * nodes * 8;
+            json
+        })
+        .collect();
+
+    let mut header = format!(
+        "{{\"format\": \"picrs-fields\", \"version\": {VERSION}, \"byte_order\": \"little\", \"dtype\": \"f64\", \
+         \"grid\": {{\"cells\": [{}, {}, {}], \"size\": [{:?}, {:?}, {:?}], {delta}\
+         \"nodes\": {{\"x\": {}, \"y\": {}, \"z\": {}}}, \
+         \"origin\": [0.0, 0.0, 0.0], \"units\": \"m\", \"order\": \"ijk, k fastest\"}}, \
+         \"frame\": {{\"bytes\": {}, \"step\": {{\"offset\": 0, \"dtype\": \"u64\"}}, \
+         \"time\": {{\"offset\": 8, \"dtype\": \"f64\", \"units\": \"s\"}}}}, \
+         \"variables\": [{}]}}",
+        cells.x,
+        cells.y,
+        cells.z,
+        size.x,
+        size.y,
+        size.z,
+        coordinates[0],
+        coordinates[1],
+        coordinates[2],
+        frame_bytes(variables, nodes),
+        variables_json.join(", ")
+    );
+
+    // pad header so that frames are aligned
+    let unpadded = MAGIC.len() + 8 + header.len() + 1;
+    header.push_str(&" ".repeat(unpadded.next_multiple_of(ALIGNMENT) - unpadded));
+    header.push('\n');
+
+    let mut bytes = Vec::with_capacity(MAGIC.len() + 8 + header.len());
+    bytes.extend_from_slice(MAGIC);
+    bytes.extend_from_slice(&VERSION.to_le_bytes());
+    bytes.extend_from_slice(&(header.len() as u32).to_le_bytes());
+    bytes.extend_from_slice(header.as_bytes());
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::field::scalar::ScalarField;
+    use crate::field::vector::VectorField;
+    use crate::grid::{uniform_nodes, Axis, Spacing};
+    use crate::output::{Field, FieldWriter, Variable, ALIGNMENT};
+    use crate::utils::coordinate_triplet::CoordinateTriplet;
+    use std::path::PathBuf;
+
+    /// helper function that returns a unique temporary path for testing
+    ///
+    /// # Arguments
+    /// - `name`: &str name of test
+    ///
+    /// # Returns
+    /// `PathBuf`
+    ///
+    /// # Errors
+    ///
+    fn tmp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("picrs_output_{name}_{}.pfld", std::process::id()))
+    }
+
+    /// helper function that returns equally spaced node coordinates for testing
+    ///
+    /// # Arguments
+    /// - `size`: &CoordinateTriplet<f64> (m) size of bounding box
+    /// - `cells`: &CoordinateTriplet<usize> number of nodes along each axis
+    ///
+    /// # Returns
+    /// `CoordinateTriplet<Vec<f64>>`
+    ///
+    /// # Errors
+    ///
+    fn nodes(
+        size: &CoordinateTriplet<f64>,
+        cells: &CoordinateTriplet<usize>,
+    ) -> CoordinateTriplet<Vec<f64>> {
+        CoordinateTriplet {
+            x: uniform_nodes(size.x, cells.x),
+            y: uniform_nodes(size.y, cells.y),
+            z: uniform_nodes(size.z, cells.z),
+        }
+    }
+
+    /// helper function that writes frames of a scalar and a vector field for testing
+    ///
+    /// # Arguments
+    /// - `writer`: &mut FieldWriter destination of frames
+    /// - `steps`: &[u64] steps of frames
+    ///
+    /// # Returns
+    ///
+    /// # Errors
+    ///
+    fn write_frames(writer: &mut FieldWriter, steps: &[u64]) {
+        let cells = CoordinateTriplet::new(2, 3, 4).unwrap();
+        let mut scalar: ScalarField<f64> = ScalarField::new(&cells).unwrap();
+        let mut vector: VectorField<f64> = VectorField::new(&cells).unwrap();
+        for step in steps {
+            scalar += 1.0;
+            vector += 2.0;
+            writer
+                .write_frame(
+                    *step,
+                    *step as f64 * 1e-9,
+                    &[Field::Scalar(&scalar), Field::Vector(&vector)],
+                )
+                .unwrap();
+        }
+    }
+
+    /// helper function that returns variables for testing
+    ///
+    /// # Arguments
+    ///
+    /// # Returns
+    /// `Vec<Variable>`
+    ///
+    /// # Errors
+    ///
+    fn variables() -> Vec<Variable> {
+        vec![
+            Variable::new("potential", "V", 1).unwrap(),
+            Variable::new("electric_field", "V/m", 3).unwrap(),
+        ]
+    }
+
+    /// tests `Variable::new()` for failure on invalid input
+    ///
+    /// # Errors
+    /// - `Variable::new()` succeeds for a name or units containing a quote, backslash, or control character
+    /// - `Variable::new()` succeeds for neither 1 nor 3 components
+    ///
+    #[test]
+    fn variable_new_failure() {
+        for text in ["pot\"ential", "pot\\ential", "pot\tential"] {
+            assert!(Variable::new(text, "V", 1).is_err());
+            assert!(Variable::new("potential", text, 1).is_err());
+        }
+        assert!(Variable::new("potential", "V", 2).is_err());
+    }
+
+    /// tests `FieldWriter` for a correct self-describing layout
+    ///
+    /// # Errors
+    /// - magic or header is incorrect
+    /// - frames are not aligned
+    /// - file size does not match number of frames
+    /// - frame data is incorrect
+    ///
+    #[test]
+    fn write_frame_layout() {
+        // setup
+        let path = tmp_path("layout");
+        let cells = CoordinateTriplet::new(2, 3, 4).unwrap();
+        let size = CoordinateTriplet::new(1.0, 2.0, 3.0).unwrap();
+        let mut writer =
+            FieldWriter::create(&path, &size, &nodes(&size, &cells), &variables()).unwrap();
+        write_frames(&mut writer, &[1, 2]);
+        drop(writer);
+        let bytes = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        // assertions
+        assert_eq!(&bytes[0..8], b"PICRSFLD");
+        let header_len = u32::from_le_bytes(bytes[12..16].try_into().unwrap()) as usize;
+        let data_offset = 16 + header_len;
+        assert_eq!(data_offset % ALIGNMENT, 0);
+
+        let header = std::str::from_utf8(&bytes[16..data_offset]).unwrap();
+        assert!(header.contains("\"cells\": [2, 3, 4]"));
+        assert!(header.contains("\"delta\": [1.0, 1.0, 1.0]"));
+        assert!(header.contains(
+            "\"nodes\": {\"x\": [0.0, 1.0], \"y\": [0.0, 1.0, 2.0], \"z\": [0.0, 1.0, 2.0, 3.0]}"
+        ));
+        assert!(header.contains(
+            "\"name\": \"electric_field\", \"units\": \"V/m\", \"components\": 3, \"offset\": 208"
+        ));
+
+        let frame_bytes = 16 + 24 * 8 * 4;
+        assert_eq!(bytes.len(), data_offset + 2 * frame_bytes);
+
+        let second = &bytes[data_offset + frame_bytes..];
+        assert_eq!(u64::from_le_bytes(second[0..8].try_into().unwrap()), 2);
+        assert_eq!(f64::from_le_bytes(second[16..24].try_into().unwrap()), 2.0);
+        assert_eq!(
+            f64::from_le_bytes(second[208..216].try_into().unwrap()),
+            4.0
+        );
+    }
+
+    /// tests `FieldWriter::write_frame()` for rejection of mismatched fields
+    ///
+    /// # Errors
+    /// - `FieldWriter::write_frame()` succeeds for a missing field
+    /// - `FieldWriter::write_frame()` succeeds for a scalar field in place of a vector field
+    ///
+    #[test]
+    fn write_frame_mismatch() {
+        // setup
+        let path = tmp_path("mismatch");
+        let cells = CoordinateTriplet::new(2, 3, 4).unwrap();
+        let size = CoordinateTriplet::new(1.0, 2.0, 3.0).unwrap();
+        let mut writer =
+            FieldWriter::create(&path, &size, &nodes(&size, &cells), &variables()).unwrap();
+        let scalar: ScalarField<f64> = ScalarField::new(&cells).unwrap();
+
+        // assertions
+        assert!(writer
+            .write_frame(0, 0.0, &[Field::Scalar(&scalar)])
+            .is_err());
+        assert!(writer
+            .write_frame(0, 0.0, &[Field::Scalar(&scalar), Field::Scalar(&scalar)])
+            .is_err());
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    /// tests `FieldWriter::resume()` discards frames written after the restart step
+    ///
+    /// # Errors
+    /// - frames after restart step are kept
+    /// - `FieldWriter::resume()` succeeds for a different grid
+    ///
+    #[test]
+    fn resume_truncates() {
+        // setup
+        let path = tmp_path("resume");
+        let cells = CoordinateTriplet::new(2, 3, 4).unwrap();
+        let size = CoordinateTriplet::new(1.0, 2.0, 3.0).unwrap();
+        let mut writer =
+            FieldWriter::create(&path, &size, &nodes(&size, &cells), &variables()).unwrap();
+        write_frames(&mut writer, &[1, 2, 3]);
+        drop(writer);
+        let full = std::fs::metadata(&path).unwrap().len();
+
+        let mut writer =
+            FieldWriter::resume(&path, &size, &nodes(&size, &cells), &variables(), 2).unwrap();
+        writer.writer.get_ref().sync_all().unwrap();
+        let resumed = std::fs::metadata(&path).unwrap().len();
+        write_frames(&mut writer, &[3]);
+        drop(writer);
+        let rewritten = std::fs::metadata(&path).unwrap().len();
+
+        let other = CoordinateTriplet::new(2, 3, 5).unwrap();
+        let mismatch = FieldWriter::resume(&path, &size, &nodes(&size, &other), &variables(), 2);
+        std::fs::remove_file(&path).unwrap();
+
+        // assertions
+        let frame_bytes = 16 + 24 * 8 * 4;
+        assert_eq!(resumed, full - frame_bytes);
+        assert_eq!(rewritten, full);
+        assert!(mismatch.is_err());
+    }
+
+    /// tests `FieldWriter::create()` for node coordinates and no spatial increment on a stretched grid
+    ///
+    /// # Errors
+    /// - header contains a spatial increment
+    /// - header does not contain the stretched node coordinates
+    ///
+    #[test]
+    fn create_stretched() {
+        // setup
+        let path = tmp_path("stretched");
+        let cells = CoordinateTriplet::new(2, 3, 4).unwrap();
+        let size = CoordinateTriplet::new(1.0, 2.0, 3.0).unwrap();
+        let mut nodes = nodes(&size, &cells);
+        nodes.z = Axis::new(&Spacing::Tanh(2.0), size.z, cells.z)
+            .unwrap()
+            .nodes()
+            .to_vec();
+        let writer = FieldWriter::create(&path, &size, &nodes, &variables()).unwrap();
+        drop(writer);
+        let bytes = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        // assertions
+        let header_len = u32::from_le_bytes(bytes[12..16].try_into().unwrap()) as usize;
+        let header = std::str::from_utf8(&bytes[16..16 + header_len]).unwrap();
+        assert!(!header.contains("\"delta\""));
+        let z: Vec<String> = nodes.z.iter().map(|z| format!("{z:?}")).collect();
+        assert!(header.contains(&format!("\"z\": [{}]", z.join(", "))));
+        assert!(nodes.z[1] != 1.0);
+    }
+}
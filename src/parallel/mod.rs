@@ -0,0 +1,88 @@
+//! parallel module
+//!
+//! describes shared-memory parallelism of the electrostatic engine
+
+use rayon::{ThreadPool, ThreadPoolBuilder};
+
+/// `ParallelConfig` struct
+///
+/// describes the threads the electrostatic engine runs on and whether its reductions are reproducible
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParallelConfig {
+    /// number of threads, 0 uses the number of threads rayon selects by default, one per logical core unless
+    /// overridden by the `RAYON_NUM_THREADS` environment variable
+    pub threads: usize,
+
+    /// deposit in fixed blocks of macro-particles reduced in a fixed order, so that runs are reproducible bitwise on
+    /// any number of threads and restarts resume identically, otherwise blocks are split adaptively and reduced in
+    /// the order they complete, which is faster but differs between runs by rounding
+    pub deterministic: bool,
+}
+
+impl Default for ParallelConfig {
+    /// default `ParallelConfig`
+    ///
+    /// one thread per logical core with deterministic reductions
+    ///
+    /// # Arguments
+    ///
+    /// # Returns
+    /// `ParallelConfig`
+    ///
+    /// # Errors
+    ///
+    fn default() -> ParallelConfig {
+        ParallelConfig {
+            threads: 0,
+            deterministic: true,
+        }
+    }
+}
+
+impl ParallelConfig {
+    /// builds the thread pool described by `ParallelConfig`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `Result<ThreadPool, anyhow::Error>`
+    ///
+    /// # Errors
+    /// - call to `ThreadPoolBuilder::build()` fails
+    pub fn thread_pool(&self) -> Result<ThreadPool, anyhow::Error> {
+        Ok(ThreadPoolBuilder::new().num_threads(self.threads).build()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parallel::ParallelConfig;
+
+    /// tests `ParallelConfig::thread_pool()` for the configured number of threads
+    ///
+    /// # Errors
+    /// - thread pool of a configured number of threads has a different number of threads
+    /// - default configuration is not deterministic
+    /// - thread pool of the default configuration has no threads
+    ///
+    #[test]
+    fn thread_pool_correct() {
+        // setup
+        let config = ParallelConfig {
+            threads: 3,
+            deterministic: true,
+        };
+
+        // assertions
+        assert_eq!(config.thread_pool().unwrap().current_num_threads(), 3);
+        assert!(ParallelConfig::default().deterministic);
+        assert!(
+            ParallelConfig::default()
+                .thread_pool()
+                .unwrap()
+                .current_num_threads()
+                >= 1
+        );
+    }
+}
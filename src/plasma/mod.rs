@@ -0,0 +1,622 @@
+//! plasma module
+//!
+//! computes characteristic plasma parameters and validates them against the discretization of a model
+
+use crate::constants::{BOLTZMANN, VAC_PERM};
+use crate::species::Species;
+use crate::utils::coordinate_triplet::CoordinateTriplet;
+use anyhow::anyhow;
+use std::f64::consts::PI;
+use std::fmt::{Display, Formatter};
+
+/// `Severity` enum
+///
+/// describes how a failed validation check is handled
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Severity {
+    /// failed check is ignored
+    Ignore,
+
+    /// failed check is reported as a warning
+    Warn,
+
+    /// failed check is reported as an error
+    Error,
+}
+
+/// `Criterion` struct
+///
+/// describes a single validation criterion
+#[derive(Debug, Clone, PartialEq)]
+pub struct Criterion {
+    /// limiting value of criterion
+    pub limit: f64,
+
+    /// handling of a failed criterion
+    pub severity: Severity,
+}
+
+/// `ValidationConfig` struct
+///
+/// describes the criteria used to validate plasma parameters against a discretization
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationConfig {
+    /// maximum ratio of spatial increment to total debye length
+    pub delta_debye_ratio: Criterion,
+
+    /// maximum product of total plasma frequency and time step
+    pub plasma_frequency_dt: Criterion,
+
+    /// maximum product of gyrofrequency and time step, only checked if a magnetic field is present
+    pub gyrofrequency_dt: Criterion,
+
+    /// minimum number of macro-particles per debye sphere
+    pub particles_per_debye_sphere: Criterion,
+}
+
+impl Default for ValidationConfig {
+    /// default `ValidationConfig`
+    ///
+    /// under-resolving the debye length leads to finite-grid-instability heating and is an error, the remaining
+    /// criteria only warn
+    ///
+    /// # Arguments
+    ///
+    /// # Returns
+    /// `ValidationConfig`
+    ///
+    /// # Errors
+    ///
+    fn default() -> ValidationConfig {
+        ValidationConfig {
+            delta_debye_ratio: Criterion {
+                limit: 1.0,
+                severity: Severity::Error,
+            },
+            plasma_frequency_dt: Criterion {
+                limit: 0.2,
+                severity: Severity::Warn,
+            },
+            gyrofrequency_dt: Criterion {
+                limit: 0.2,
+                severity: Severity::Warn,
+            },
+            particles_per_debye_sphere: Criterion {
+                limit: 1.0,
+                severity: Severity::Warn,
+            },
+        }
+    }
+}
+
+/// `SpeciesParameters` struct
+///
+/// describes the characteristic plasma parameters of a single species
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpeciesParameters {
+    /// name of species
+    pub name: String,
+
+    /// (m) debye length
+    pub debye_length: f64,
+
+    /// (rad/s) plasma frequency
+    pub plasma_frequency: f64,
+
+    /// (rad/s) gyrofrequency, `None` if no magnetic field is present
+    pub gyrofrequency: Option<f64>,
+
+    /// number of macro-particles per debye sphere
+    pub particles_per_debye_sphere: f64,
+}
+
+/// `Check` struct
+///
+/// describes the outcome of a single validation check
+#[derive(Debug, Clone, PartialEq)]
+pub struct Check {
+    /// name of check
+    pub name: &'static str,
+
+    /// checked value
+    pub value: f64,
+
+    /// limiting value
+    pub limit: f64,
+
+    /// true if `limit` is an upper bound, false if it is a lower bound
+    pub upper: bool,
+
+    /// handling of a failed check
+    pub severity: Severity,
+
+    /// true if check passed
+    pub passed: bool,
+}
+
+impl Check {
+    /// `Check` constructor
+    ///
+    /// # Arguments
+    /// - `name`: &'static str name of check
+    /// - `value`: f64 checked value
+    /// - `criterion`: &Criterion criterion to check against
+    /// - `upper`: bool true if `criterion.limit` is an upper bound
+    ///
+    /// # Returns
+    /// `Check`
+    ///
+    /// # Errors
+    ///
+    fn new(name: &'static str, value: f64, criterion: &Criterion, upper: bool) -> Check {
+        let passed = if upper {
+            value <= criterion.limit
+        } else {
+            value >= criterion.limit
+        };
+
+        Check {
+            name,
+            value,
+            limit: criterion.limit,
+            upper,
+            severity: criterion.severity,
+            passed,
+        }
+    }
+}
+
+/// `PlasmaReport` struct
+///
+/// structured report of characteristic plasma parameters and their validation against a discretization
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlasmaReport {
+    /// per species parameters
+    pub species: Vec<SpeciesParameters>,
+
+    /// (m) total debye length
+    pub debye_length: f64,
+
+    /// (rad/s) total plasma frequency
+    pub plasma_frequency: f64,
+
+    /// total number of macro-particles per total debye sphere
+    pub particles_per_debye_sphere: f64,
+
+    /// outcome of validation checks
+    pub checks: Vec<Check>,
+}
+
+impl PlasmaReport {
+    /// `PlasmaReport` constructor
+    ///
+    /// # Arguments
+    /// - `species`: &[Species] species present in model
+    /// - `magnetic_field`: &[f64; 3] (T) uniform magnetic flux density
+    /// - `delta`: &CoordinateTriplet<f64> (m) spatial increment
+    /// - `dt`: f64 (s) time step
+    /// - `config`: &ValidationConfig validation criteria
+    ///
+    /// # Returns
+    /// `PlasmaReport`
+    ///
+    /// # Errors
+    ///
+    pub fn new(
+        species: &[Species],
+        magnetic_field: &[f64; 3],
+        delta: &CoordinateTriplet<f64>,
+        dt: f64,
+        config: &ValidationConfig,
+    ) -> PlasmaReport {
+        // magnitude of magnetic flux density
+        let b_mag = magnetic_field.iter().map(|b| b * b).sum::<f64>().sqrt();
+
+        // per species parameters
+        let species_parameters: Vec<SpeciesParameters> = species
+            .iter()
+            .map(|s| {
+                let debye_length = debye_length(s.density, s.temperature, s.charge);
+                SpeciesParameters {
+                    name: s.name.clone(),
+                    debye_length,
+                    plasma_frequency: plasma_frequency(s.density, s.charge, s.mass),
+                    gyrofrequency: (b_mag > 0.0).then(|| gyrofrequency(s.charge, s.mass, b_mag)),
+                    particles_per_debye_sphere: particles_per_debye_sphere(
+                        s.density / s.weight,
+                        debye_length,
+                    ),
+                }
+            })
+            .collect();
+
+        // total debye length from sum of inverse squares of warm species
+        let inv_sq_sum: f64 = species_parameters
+            .iter()
+            .filter(|s| s.debye_length > 0.0)
+            .map(|s| 1.0 / (s.debye_length * s.debye_length))
+            .sum();
+        let debye_length = if inv_sq_sum > 0.0 {
+            1.0 / inv_sq_sum.sqrt()
+        } else {
+            f64::INFINITY
+        };
+
+        // total plasma frequency from sum of squares
+        let plasma_frequency = species_parameters
+            .iter()
+            .map(|s| s.plasma_frequency * s.plasma_frequency)
+            .sum::<f64>()
+            .sqrt();
+
+        // total macro-particles per total debye sphere
+        let macro_density: f64 = species.iter().map(|s| s.density / s.weight).sum();
+        let particles_per_debye_sphere = particles_per_debye_sphere(macro_density, debye_length);
+
+        // validation checks are only meaningful if species are present
+        let mut checks: Vec<Check> = Vec::new();
+        if !species.is_empty() {
+            let max_delta = delta.x.max(delta.y).max(delta.z);
+            checks.push(Check::new(
+                "delta / debye length",
+                max_delta / debye_length,
+                &config.delta_debye_ratio,
+                true,
+            ));
+
+            checks.push(Check::new(
+                "plasma frequency * dt",
+                plasma_frequency * dt,
+                &config.plasma_frequency_dt,
+                true,
+            ));
+
+            if b_mag > 0.0 {
+                let max_gyrofrequency = species_parameters
+                    .iter()
+                    .filter_map(|s| s.gyrofrequency)
+                    .fold(0.0, f64::max);
+                checks.push(Check::new(
+                    "gyrofrequency * dt",
+                    max_gyrofrequency * dt,
+                    &config.gyrofrequency_dt,
+                    true,
+                ));
+            }
+
+            checks.push(Check::new(
+                "macro-particles per debye sphere",
+                particles_per_debye_sphere,
+                &config.particles_per_debye_sphere,
+                false,
+            ));
+        }
+
+        PlasmaReport {
+            species: species_parameters,
+            debye_length,
+            plasma_frequency,
+            particles_per_debye_sphere,
+            checks,
+        }
+    }
+
+    /// enforces the severity of failed checks in `PlasmaReport`
+    ///
+    /// failed checks with `Severity::Warn` are returned for the caller to report
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `Result<Vec<Check>, anyhow::Error>` failed checks with `Severity::Warn`
+    ///
+    /// # Errors
+    /// - any failed check has `Severity::Error`
+    pub fn enforce(&self) -> Result<Vec<Check>, anyhow::Error> {
+        let mut warnings: Vec<Check> = Vec::new();
+        let mut errors: Vec<String> = Vec::new();
+
+        for check in self.checks.iter().filter(|c| !c.passed) {
+            match check.severity {
+                Severity::Ignore => {}
+                Severity::Warn => warnings.push(check.clone()),
+                Severity::Error => errors.push(check.to_string()),
+            }
+        }
+
+        if !errors.is_empty() {
+            return Err(anyhow!(
+                "plasma parameter checks failed: {}",
+                errors.join("; ")
+            ));
+        }
+
+        Ok(warnings)
+    }
+}
+
+/// allows `Check` to be written in a text format
+impl Display for Check {
+    /// writes `Check` in a text format
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `f: &mut Formatter<'_>` formatter for writing
+    ///
+    /// # Returns
+    /// `std::fmt::Result`
+    ///
+    /// # Errors
+    /// - call to `write!()` errors
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let bound = if self.upper { "<=" } else { ">=" };
+        let status = if self.passed { "pass" } else { "fail" };
+        write!(
+            f,
+            "{} = {:.4e} ({} {:.4e}) {}",
+            self.name, self.value, bound, self.limit, status
+        )
+    }
+}
+
+/// allows `PlasmaReport` to be written in a text format
+impl Display for PlasmaReport {
+    /// writes `PlasmaReport` in a text format
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    /// - `f: &mut Formatter<'_>` formatter for writing
+    ///
+    /// # Returns
+    /// `std::fmt::Result`
+    ///
+    /// # Errors
+    /// - call to `writeln!()` errors
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for s in self.species.iter() {
+            write!(
+                f,
+                "species `{}`: debye length = {:.4e} m, plasma frequency = {:.4e} rad/s",
+                s.name, s.debye_length, s.plasma_frequency
+            )?;
+            if let Some(gyrofrequency) = s.gyrofrequency {
+                write!(f, ", gyrofrequency = {gyrofrequency:.4e} rad/s")?;
+            }
+            writeln!(
+                f,
+                ", macro-particles per debye sphere = {:.4e}",
+                s.particles_per_debye_sphere
+            )?;
+        }
+        writeln!(
+            f,
+            "total: debye length = {:.4e} m, plasma frequency = {:.4e} rad/s, macro-particles per debye sphere = {:.4e}",
+            self.debye_length, self.plasma_frequency, self.particles_per_debye_sphere
+        )?;
+        for check in self.checks.iter() {
+            writeln!(f, "check: {check}")?;
+        }
+        Ok(())
+    }
+}
+
+/// computes the debye length of a single species
+///
+/// # Arguments
+/// - `density`: f64 (m^-3) number density
+/// - `temperature`: f64 (K) temperature
+/// - `charge`: f64 (C) particle charge
+///
+/// # Returns
+/// `f64` (m) debye length, infinite for a species that does not screen
+///
+/// # Errors
+///
+pub fn debye_length(density: f64, temperature: f64, charge: f64) -> f64 {
+    let denominator = density * charge * charge;
+    if denominator > 0.0 {
+        (VAC_PERM * BOLTZMANN * temperature / denominator).sqrt()
+    } else {
+        f64::INFINITY
+    }
+}
+
+/// computes the plasma frequency of a single species
+///
+/// # Arguments
+/// - `density`: f64 (m^-3) number density
+/// - `charge`: f64 (C) particle charge
+/// - `mass`: f64 (kg) particle mass
+///
+/// # Returns
+/// `f64` (rad/s) plasma frequency
+///
+/// # Errors
+///
+pub fn plasma_frequency(density: f64, charge: f64, mass: f64) -> f64 {
+    (density * charge * charge / (VAC_PERM * mass)).sqrt()
+}
+
+/// computes the gyrofrequency of a single species
+///
+/// # Arguments
+/// - `charge`: f64 (C) particle charge
+/// - `mass`: f64 (kg) particle mass
+/// - `b_mag`: f64 (T) magnitude of magnetic flux density
+///
+/// # Returns
+/// `f64` (rad/s) gyrofrequency
+///
+/// # Errors
+///
+pub fn gyrofrequency(charge: f64, mass: f64, b_mag: f64) -> f64 {
+    charge.abs() * b_mag / mass
+}
+
+/// computes the number of macro-particles in a debye sphere
+///
+/// # Arguments
+/// - `macro_density`: f64 (m^-3) macro-particle number density
+/// - `debye_length`: f64 (m) debye length
+///
+/// # Returns
+/// `f64` number of macro-particles per debye sphere, infinite if `debye_length` is infinite
+///
+/// # Errors
+///
+fn particles_per_debye_sphere(macro_density: f64, debye_length: f64) -> f64 {
+    if debye_length.is_infinite() {
+        f64::INFINITY
+    } else {
+        4.0 / 3.0 * PI * debye_length.powi(3) * macro_density
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::constants::{ELEC_CHARGE, ELEC_MASS, EV_TEMP};
+    use crate::plasma::{
+        debye_length, gyrofrequency, plasma_frequency, PlasmaReport, Severity, ValidationConfig,
+    };
+    use crate::species::Species;
+    use crate::utils::coordinate_triplet::CoordinateTriplet;
+
+    /// helper function that sets up a single electron species for testing
+    ///
+    /// # Arguments
+    ///
+    /// # Returns
+    /// `Result<Species, anyhow::Error>`
+    ///
+    /// # Errors
+    /// - `Species::new()` fails
+    fn setup() -> Result<Species, anyhow::Error> {
+        Species::new("electron", -ELEC_CHARGE, ELEC_MASS, 1e12, EV_TEMP, 1e3)
+    }
+
+    /// tests `debye_length()` against a known value
+    ///
+    /// # Errors
+    /// - `debye_length()` is incorrect for a 1 eV, 1e12 m^-3 electron species
+    /// - `debye_length()` is not infinite for an empty species
+    ///
+    #[test]
+    fn debye_length_correct() {
+        let lambda = debye_length(1e12, EV_TEMP, ELEC_CHARGE);
+        assert!((lambda - 7.4339e-3).abs() / 7.4339e-3 < 1e-4);
+        assert!(debye_length(0.0, EV_TEMP, ELEC_CHARGE).is_infinite());
+    }
+
+    /// tests `plasma_frequency()` against a known value
+    ///
+    /// # Errors
+    /// - `plasma_frequency()` is incorrect for a 1e12 m^-3 electron species
+    ///
+    #[test]
+    fn plasma_frequency_correct() {
+        let omega = plasma_frequency(1e12, ELEC_CHARGE, ELEC_MASS);
+        assert!((omega - 5.6414e7).abs() / 5.6414e7 < 1e-4);
+    }
+
+    /// tests `gyrofrequency()` against a known value
+    ///
+    /// # Errors
+    /// - `gyrofrequency()` is incorrect for an electron in a 1 T field
+    ///
+    #[test]
+    fn gyrofrequency_correct() {
+        let omega = gyrofrequency(-ELEC_CHARGE, ELEC_MASS, 1.0);
+        assert!((omega - 1.75882e11).abs() / 1.75882e11 < 1e-4);
+    }
+
+    /// tests `PlasmaReport::new()` for correct totals with two identical species
+    ///
+    /// # Errors
+    /// - total debye length is not reduced by a factor of sqrt(2)
+    /// - total plasma frequency is not increased by a factor of sqrt(2)
+    ///
+    #[test]
+    fn new_correct_totals() {
+        let species = vec![setup().unwrap(), setup().unwrap()];
+        let delta = CoordinateTriplet::new(1e-3, 1e-3, 1e-3).unwrap();
+        let report = PlasmaReport::new(
+            &species,
+            &[0.0; 3],
+            &delta,
+            1e-10,
+            &ValidationConfig::default(),
+        );
+
+        let single = &report.species[0];
+        assert!((report.debye_length * 2.0_f64.sqrt() - single.debye_length).abs() < 1e-12);
+        assert!((report.plasma_frequency - single.plasma_frequency * 2.0_f64.sqrt()).abs() < 1e-3);
+        assert!(single.gyrofrequency.is_none());
+    }
+
+    /// tests `PlasmaReport::new()` for gyrofrequency check only when a magnetic field is present
+    ///
+    /// # Errors
+    /// - gyrofrequency check is present without a magnetic field
+    /// - gyrofrequency check is missing with a magnetic field
+    ///
+    #[test]
+    fn new_gyrofrequency_check() {
+        let species = vec![setup().unwrap()];
+        let delta = CoordinateTriplet::new(1e-3, 1e-3, 1e-3).unwrap();
+        let config = ValidationConfig::default();
+
+        let report = PlasmaReport::new(&species, &[0.0; 3], &delta, 1e-10, &config);
+        assert!(report.checks.iter().all(|c| c.name != "gyrofrequency * dt"));
+
+        let report = PlasmaReport::new(&species, &[0.0, 0.0, 1.0], &delta, 1e-10, &config);
+        assert!(report.checks.iter().any(|c| c.name == "gyrofrequency * dt"));
+    }
+
+    /// tests `PlasmaReport::enforce()` for hard and soft failures
+    ///
+    /// # Errors
+    /// - resolved debye length fails
+    /// - resolved debye length returns warnings
+    /// - under-resolved debye length does not fail with `Severity::Error`
+    /// - under-resolved debye length fails or does not return a single warning with `Severity::Warn`
+    /// - under-resolved debye length fails or returns warnings with `Severity::Ignore`
+    ///
+    #[test]
+    fn enforce_severity() {
+        let species = vec![setup().unwrap()];
+        let fine = CoordinateTriplet::new(1e-3, 1e-3, 1e-3).unwrap();
+        let coarse = CoordinateTriplet::new(1e-1, 1e-3, 1e-3).unwrap();
+        let mut config = ValidationConfig::default();
+
+        let report = PlasmaReport::new(&species, &[0.0; 3], &fine, 1e-10, &config);
+        assert!(report.enforce().unwrap().is_empty());
+
+        let report = PlasmaReport::new(&species, &[0.0; 3], &coarse, 1e-10, &config);
+        assert!(report.enforce().is_err());
+
+        config.delta_debye_ratio.severity = Severity::Warn;
+        let report = PlasmaReport::new(&species, &[0.0; 3], &coarse, 1e-10, &config);
+        let warnings = report.enforce().unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].severity, Severity::Warn);
+        assert!(!warnings[0].passed);
+
+        config.delta_debye_ratio.severity = Severity::Ignore;
+        let report = PlasmaReport::new(&species, &[0.0; 3], &coarse, 1e-10, &config);
+        assert!(report.enforce().unwrap().is_empty());
+    }
+
+    /// tests `PlasmaReport::new()` performs no checks without species
+    ///
+    /// # Errors
+    /// - checks are performed without species
+    ///
+    #[test]
+    fn new_no_species() {
+        let delta = CoordinateTriplet::new(1.0, 1.0, 1.0).unwrap();
+        let report = PlasmaReport::new(&[], &[0.0; 3], &delta, 1.0, &ValidationConfig::default());
+        assert!(report.checks.is_empty());
+        assert!(report.debye_length.is_infinite());
+    }
+}
@@ -0,0 +1,184 @@
+//! precision module
+//!
+//! describes the floating point precision fields and macro-particles of an engine are stored in
+
+use crate::field::scalar::ScalarField;
+use crate::field::vector::VectorField;
+use crate::output::Field;
+use num::Float;
+use std::fmt::{Debug, Display};
+use std::iter::Sum;
+use std::ops::{AddAssign, DivAssign, MulAssign, SubAssign};
+
+/// `Precision` enum
+///
+/// describes the floating point precision of the electrostatic engine, all other engines run in double precision
+///
+/// macro-particles are stored and pushed in the precision of the push, collisions, emission, and deposition widen them
+/// to double precision
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum Precision {
+    /// fields stored and solved in double precision, macro-particles stored and pushed in double precision
+    #[default]
+    Double,
+
+    /// fields and macro-particles stored in single precision, halving their memory
+    Single,
+
+    /// fields stored and solved in double precision, macro-particles stored and pushed in single precision
+    Mixed,
+}
+
+/// `Real` trait
+///
+/// describes a floating point type fields and macro-particles may be stored in, configuration, output, and checkpoints
+/// remain in double precision so values are narrowed and widened at those boundaries
+pub trait Real:
+    Float
+    + Default
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+    + Sum
+    + Debug
+    + Display
+    + Send
+    + Sync
+    + 'static
+{
+    /// narrows a double precision value to `Self`, rounding to nearest
+    ///
+    /// # Arguments
+    /// - `value`: f64 value to narrow
+    ///
+    /// # Returns
+    /// `Self` nearest representable value
+    ///
+    /// # Errors
+    ///
+    fn narrow(value: f64) -> Self;
+
+    /// widens `Self` to double precision, which is exact
+    ///
+    /// # Arguments
+    /// - `self` value to widen
+    ///
+    /// # Returns
+    /// `f64` widened value
+    ///
+    /// # Errors
+    ///
+    fn widen(self) -> f64;
+
+    /// borrows a scalar field of `Self` as field output
+    ///
+    /// # Arguments
+    /// - `field`: &ScalarField<Self> field to borrow
+    ///
+    /// # Returns
+    /// `Field<'_>`
+    ///
+    /// # Errors
+    ///
+    fn scalar_output(field: &ScalarField<Self>) -> Field<'_>;
+
+    /// borrows a vector field of `Self` as field output
+    ///
+    /// # Arguments
+    /// - `field`: &VectorField<Self> field to borrow
+    ///
+    /// # Returns
+    /// `Field<'_>`
+    ///
+    /// # Errors
+    ///
+    fn vector_output(field: &VectorField<Self>) -> Field<'_>;
+}
+
+impl Real for f64 {
+    fn narrow(value: f64) -> f64 {
+        value
+    }
+
+    fn widen(self) -> f64 {
+        self
+    }
+
+    fn scalar_output(field: &ScalarField<f64>) -> Field<'_> {
+        Field::Scalar(field)
+    }
+
+    fn vector_output(field: &VectorField<f64>) -> Field<'_> {
+        Field::Vector(field)
+    }
+}
+
+impl Real for f32 {
+    fn narrow(value: f64) -> f32 {
+        value as f32
+    }
+
+    fn widen(self) -> f64 {
+        self as f64
+    }
+
+    fn scalar_output(field: &ScalarField<f32>) -> Field<'_> {
+        Field::SingleScalar(field)
+    }
+
+    fn vector_output(field: &VectorField<f32>) -> Field<'_> {
+        Field::SingleVector(field)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::field::scalar::ScalarField;
+    use crate::field::vector::VectorField;
+    use crate::output::Field;
+    use crate::precision::{Precision, Real};
+    use crate::utils::coordinate_triplet::CoordinateTriplet;
+
+    /// tests `Real::narrow()` and `Real::widen()` for correctness
+    ///
+    /// # Errors
+    /// - double precision values do not round trip exactly
+    /// - single precision values are not rounded to nearest
+    /// - widening a single precision value is not exact
+    #[test]
+    fn narrow_widen_correct() {
+        // assertions
+        assert_eq!(f64::narrow(0.1).widen(), 0.1);
+        assert_eq!(f32::narrow(0.1), 0.1f32);
+        assert_eq!(f32::narrow(0.1).widen(), 0.1f32 as f64);
+        assert!((f32::narrow(0.1).widen() - 0.1).abs() <= 0.1 * f32::EPSILON as f64);
+        assert_eq!(Precision::default(), Precision::Double);
+    }
+
+    /// tests `Real::scalar_output()` and `Real::vector_output()` for selection of the field output variant
+    ///
+    /// # Errors
+    /// - double precision fields are not borrowed as `Field::Scalar` or `Field::Vector`
+    /// - single precision fields are not borrowed as `Field::SingleScalar` or `Field::SingleVector`
+    #[test]
+    fn output_correct() {
+        // setup
+        let cells = CoordinateTriplet::new(3, 3, 3).unwrap();
+        let double: VectorField<f64> = VectorField::new(&cells).unwrap();
+        let single: VectorField<f32> = VectorField::new(&cells).unwrap();
+        let scalar: ScalarField<f32> = ScalarField::new(&cells).unwrap();
+
+        // assertions
+        assert!(matches!(f64::scalar_output(&double.x), Field::Scalar(_)));
+        assert!(matches!(f64::vector_output(&double), Field::Vector(_)));
+        assert!(matches!(
+            f32::scalar_output(&scalar),
+            Field::SingleScalar(_)
+        ));
+        assert!(matches!(
+            f32::vector_output(&single),
+            Field::SingleVector(_)
+        ));
+    }
+}
@@ -0,0 +1,293 @@
+//! species module
+//!
+//! describes the particle species present in a pic model
+
+use crate::constants::BOLTZMANN;
+use crate::precision::Real;
+use crate::utils::coordinate_triplet::CoordinateTriplet;
+use crate::utils::rng::Rng;
+use anyhow::anyhow;
+
+/// `Particle` struct
+///
+/// describes a single macro-particle stored in precision `P`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Particle<P = f64> {
+    /// (m) position
+    pub position: [P; 3],
+
+    /// (m/s) velocity
+    pub velocity: [P; 3],
+}
+
+impl<P: Real> Particle<P> {
+    /// narrows a double precision macro-particle to precision `P`, rounding to nearest
+    ///
+    /// # Arguments
+    /// - `particle`: &Particle macro-particle to narrow
+    ///
+    /// # Returns
+    /// `Particle<P>`
+    ///
+    /// # Errors
+    ///
+    pub fn narrow(particle: &Particle) -> Particle<P> {
+        Particle {
+            position: particle.position.map(P::narrow),
+            velocity: particle.velocity.map(P::narrow),
+        }
+    }
+
+    /// widens a macro-particle to double precision, which is exact
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `Particle`
+    ///
+    /// # Errors
+    ///
+    pub fn widen(&self) -> Particle {
+        Particle {
+            position: self.position.map(P::widen),
+            velocity: self.velocity.map(P::widen),
+        }
+    }
+}
+
+/// `Species` struct
+///
+/// describes a single species of charged particles whose macro-particles are stored in precision `P`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Species<P = f64> {
+    /// name of species
+    pub name: String,
+
+    /// (C) charge of a single physical particle
+    pub charge: f64,
+
+    /// (kg) mass of a single physical particle
+    pub mass: f64,
+
+    /// (m^-3) initial number density
+    pub density: f64,
+
+    /// (K) initial temperature
+    pub temperature: f64,
+
+    /// number of physical particles represented by a single macro-particle
+    pub weight: f64,
+
+    /// macro-particles of species
+    pub particles: Vec<Particle<P>>,
+}
+
+impl Species {
+    /// `Species` constructor
+    ///
+    /// # Arguments
+    /// - `name`: &str name of species
+    /// - `charge`: f64 (C) charge of a single physical particle
+    /// - `mass`: f64 (kg) mass of a single physical particle
+    /// - `density`: f64 (m^-3) initial number density
+    /// - `temperature`: f64 (K) initial temperature
+    /// - `weight`: f64 number of physical particles represented by a single macro-particle
+    ///
+    /// # Returns
+    /// `Result<Species, anyhow::Error>`
+    ///
+    /// # Errors
+    /// - `name` contains a quote, backslash, or control character, which would corrupt output headers
+    /// - `mass` is not positive
+    /// - `density` is negative
+    /// - `temperature` is negative
+    /// - `weight` is not positive
+    pub fn new(
+        name: &str,
+        charge: f64,
+        mass: f64,
+        density: f64,
+        temperature: f64,
+        weight: f64,
+    ) -> Result<Species, anyhow::Error> {
+        if name
+            .chars()
+            .any(|c| c == '"' || c == '\\' || c.is_control())
+        {
+            return Err(anyhow!(
+                "species name {name:?} must not contain quotes, backslashes, or control characters"
+            ));
+        }
+
+        if mass.is_nan() || mass <= 0.0 {
+            return Err(anyhow!(
+                "species `{name}` mass must be positive, got {mass}"
+            ));
+        }
+
+        if density.is_nan() || density < 0.0 {
+            return Err(anyhow!(
+                "species `{name}` density must be non-negative, got {density}"
+            ));
+        }
+
+        if temperature.is_nan() || temperature < 0.0 {
+            return Err(anyhow!(
+                "species `{name}` temperature must be non-negative, got {temperature}"
+            ));
+        }
+
+        if weight.is_nan() || weight <= 0.0 {
+            return Err(anyhow!(
+                "species `{name}` weight must be positive, got {weight}"
+            ));
+        }
+
+        Ok(Species {
+            name: name.to_string(),
+            charge,
+            mass,
+            density,
+            temperature,
+            weight,
+            particles: Vec::new(),
+        })
+    }
+}
+
+impl<P: Real> Species<P> {
+    /// narrows a double precision species to macro-particles stored in precision `P`
+    ///
+    /// # Arguments
+    /// - `species`: &Species species to narrow
+    ///
+    /// # Returns
+    /// `Species<P>` species of equal properties and macro-particles rounded to nearest
+    ///
+    /// # Errors
+    ///
+    pub fn narrow(species: &Species) -> Species<P> {
+        Species {
+            name: species.name.clone(),
+            charge: species.charge,
+            mass: species.mass,
+            density: species.density,
+            temperature: species.temperature,
+            weight: species.weight,
+            particles: species.particles.iter().map(Particle::narrow).collect(),
+        }
+    }
+
+    /// loads macro-particles of `Species<P>` uniformly in a bounding box with a maxwellian velocity distribution
+    ///
+    /// the number of macro-particles is chosen such that `Species.density` is reproduced, any existing
+    /// macro-particles are replaced, positions and velocities are drawn in double precision and rounded to `P`
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    /// - `size`: &CoordinateTriplet<f64> (m) size of bounding box
+    /// - `rng`: &mut Rng random number generator
+    ///
+    /// # Returns
+    ///
+    /// # Errors
+    ///
+    pub fn load_maxwellian(&mut self, size: &CoordinateTriplet<f64>, rng: &mut Rng) {
+        // number of macro-particles
+        let volume = size.x * size.y * size.z;
+        let count = (self.density * volume / self.weight).round() as usize;
+
+        // (m/s) thermal velocity
+        let v_th = (BOLTZMANN * self.temperature / self.mass).sqrt();
+
+        self.particles = (0..count)
+            .map(|_| {
+                Particle::narrow(&Particle {
+                    position: [
+                        rng.uniform() * size.x,
+                        rng.uniform() * size.y,
+                        rng.uniform() * size.z,
+                    ],
+                    velocity: [
+                        rng.normal() * v_th,
+                        rng.normal() * v_th,
+                        rng.normal() * v_th,
+                    ],
+                })
+            })
+            .collect();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::constants::{BOLTZMANN, ELEC_CHARGE, ELEC_MASS, EV_TEMP};
+    use crate::species::Species;
+    use crate::utils::coordinate_triplet::CoordinateTriplet;
+    use crate::utils::rng::Rng;
+
+    /// tests `Species::new()` for success
+    ///
+    /// # Errors
+    /// - `Species::new()` fails for valid input
+    ///
+    #[test]
+    fn new_success() {
+        assert!(Species::new("electron", -ELEC_CHARGE, ELEC_MASS, 1e12, 1e4, 1e3).is_ok());
+    }
+
+    /// tests `Species::new()` for failure on invalid input
+    ///
+    /// # Errors
+    /// - `Species::new()` succeeds for a name containing a quote, backslash, or control character
+    /// - `Species::new()` succeeds for non-positive mass
+    /// - `Species::new()` succeeds for negative density
+    /// - `Species::new()` succeeds for negative temperature
+    /// - `Species::new()` succeeds for non-positive weight
+    ///
+    #[test]
+    fn new_failure() {
+        for name in ["elec\"tron", "elec\\tron", "elec\ntron"] {
+            assert!(Species::new(name, -ELEC_CHARGE, ELEC_MASS, 1e12, 1e4, 1e3).is_err());
+        }
+        assert!(Species::new("electron", -ELEC_CHARGE, 0.0, 1e12, 1e4, 1e3).is_err());
+        assert!(Species::new("electron", -ELEC_CHARGE, ELEC_MASS, -1e12, 1e4, 1e3).is_err());
+        assert!(Species::new("electron", -ELEC_CHARGE, ELEC_MASS, 1e12, -1e4, 1e3).is_err());
+        assert!(Species::new("electron", -ELEC_CHARGE, ELEC_MASS, 1e12, 1e4, 0.0).is_err());
+    }
+
+    /// tests `Species::load_maxwellian()` for correct count, bounds, and temperature
+    ///
+    /// # Errors
+    /// - incorrect number of macro-particles is loaded
+    /// - any macro-particle lies outside of bounding box
+    /// - temperature of loaded macro-particles is incorrect
+    ///
+    #[test]
+    fn load_maxwellian_correct() {
+        // setup
+        let mut species =
+            Species::new("electron", -ELEC_CHARGE, ELEC_MASS, 1e12, EV_TEMP, 1e4).unwrap();
+        let size = CoordinateTriplet::new(0.1, 0.2, 0.05).unwrap();
+        let mut rng = Rng::new(0);
+        species.load_maxwellian(&size, &mut rng);
+
+        // assertions
+        assert_eq!(species.particles.len(), 100000);
+        assert!(species.particles.iter().all(|p| {
+            (0.0..size.x).contains(&p.position[0])
+                && (0.0..size.y).contains(&p.position[1])
+                && (0.0..size.z).contains(&p.position[2])
+        }));
+
+        let v_sq: f64 = species
+            .particles
+            .iter()
+            .map(|p| p.velocity.iter().map(|v| v * v).sum::<f64>())
+            .sum::<f64>()
+            / species.particles.len() as f64;
+        let temperature = ELEC_MASS * v_sq / (3.0 * BOLTZMANN);
+        assert!((temperature - EV_TEMP).abs() / EV_TEMP < 1e-2);
+    }
+}
@@ -53,10 +53,17 @@ mod tests {
     fn new_success() {
         // test f64
         let a: CoordinateTriplet<f64> = CoordinateTriplet::new(1.0, 2.0, 3.0).unwrap();
-        assert_eq!(CoordinateTriplet {x: 1.0, y: 2.0, z: 3.0}, a);
-        
+        assert_eq!(
+            CoordinateTriplet {
+                x: 1.0,
+                y: 2.0,
+                z: 3.0
+            },
+            a
+        );
+
         // test usize
         let b: CoordinateTriplet<usize> = CoordinateTriplet::new(1, 2, 3).unwrap();
-        assert_eq!(CoordinateTriplet {x: 1, y: 2, z: 3}, b);
+        assert_eq!(CoordinateTriplet { x: 1, y: 2, z: 3 }, b);
     }
-}
\ No newline at end of file
+}
@@ -2,4 +2,5 @@
 //!
 //! contains helper code that does not belong to any one module
 
-pub mod coordinate_triplet;
\ No newline at end of file
+pub mod coordinate_triplet;
+pub mod rng;
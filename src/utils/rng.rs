@@ -0,0 +1,170 @@
+use std::f64::consts::PI;
+
+/// `Rng` struct
+///
+/// xoshiro256** pseudo random number generator with an exposed state so that it may be checkpointed
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rng {
+    /// generator state
+    state: [u64; 4],
+}
+
+impl Rng {
+    /// `Rng` constructor
+    ///
+    /// expands `seed` into the full generator state using splitmix64
+    ///
+    /// # Arguments
+    /// - `seed`: u64 seed
+    ///
+    /// # Returns
+    /// `Rng`
+    ///
+    /// # Errors
+    ///
+    pub fn new(seed: u64) -> Rng {
+        let mut x = seed;
+        let mut state = [0; 4];
+        for elem in state.iter_mut() {
+            x = x.wrapping_add(0x9e3779b97f4a7c15);
+            let mut z = x;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+            *elem = z ^ (z >> 31);
+        }
+        Rng { state }
+    }
+
+    /// `Rng` constructor from a previously saved state
+    ///
+    /// # Arguments
+    /// - `state`: [u64; 4] generator state
+    ///
+    /// # Returns
+    /// `Rng`
+    ///
+    /// # Errors
+    ///
+    pub fn from_state(state: [u64; 4]) -> Rng {
+        Rng { state }
+    }
+
+    /// returns generator state of `Rng`
+    ///
+    /// # Arguments
+    /// - `&self` reference to self
+    ///
+    /// # Returns
+    /// `[u64; 4]` generator state
+    ///
+    /// # Errors
+    ///
+    pub fn state(&self) -> [u64; 4] {
+        self.state
+    }
+
+    /// returns next random u64 and advances `Rng`
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    ///
+    /// # Returns
+    /// `u64`
+    ///
+    /// # Errors
+    ///
+    pub fn next_u64(&mut self) -> u64 {
+        let result = self.state[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = self.state[1] << 17;
+
+        self.state[2] ^= self.state[0];
+        self.state[3] ^= self.state[1];
+        self.state[1] ^= self.state[2];
+        self.state[0] ^= self.state[3];
+        self.state[2] ^= t;
+        self.state[3] = self.state[3].rotate_left(45);
+
+        result
+    }
+
+    /// returns a uniformly distributed random number on [0, 1)
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    ///
+    /// # Returns
+    /// `f64`
+    ///
+    /// # Errors
+    ///
+    pub fn uniform(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+    }
+
+    /// returns a normally distributed random number with zero mean and unit variance
+    ///
+    /// # Arguments
+    /// - `&mut self` mutable reference to self
+    ///
+    /// # Returns
+    /// `f64`
+    ///
+    /// # Errors
+    ///
+    pub fn normal(&mut self) -> f64 {
+        // box-muller transform, 1 - uniform lies on (0, 1] so that the logarithm is finite
+        let u1 = 1.0 - self.uniform();
+        let u2 = self.uniform();
+        (-2.0 * u1.ln()).sqrt() * (2.0 * PI * u2).cos()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::rng::Rng;
+
+    /// tests `Rng::from_state()` reproduces the sequence of the saved generator
+    ///
+    /// # Errors
+    /// - restored generator produces a different sequence
+    ///
+    #[test]
+    fn from_state_reproduces_sequence() {
+        let mut a = Rng::new(42);
+        a.next_u64();
+        let mut b = Rng::from_state(a.state());
+
+        for _ in 0..100 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+    }
+
+    /// tests `Rng::uniform()` and `Rng::normal()` for correct moments
+    ///
+    /// # Errors
+    /// - `Rng::uniform()` is outside of [0, 1) or has incorrect mean
+    /// - `Rng::normal()` has incorrect mean or variance
+    ///
+    #[test]
+    fn distributions_correct() {
+        let mut rng = Rng::new(7);
+        let n = 100000;
+
+        let mut sum = 0.0;
+        for _ in 0..n {
+            let u = rng.uniform();
+            assert!((0.0..1.0).contains(&u));
+            sum += u;
+        }
+        assert!((sum / n as f64 - 0.5).abs() < 1e-2);
+
+        let (mut sum, mut sum_sq) = (0.0, 0.0);
+        for _ in 0..n {
+            let x = rng.normal();
+            sum += x;
+            sum_sq += x * x;
+        }
+        assert!((sum / n as f64).abs() < 1e-2);
+        assert!((sum_sq / n as f64 - 1.0).abs() < 2e-2);
+    }
+}